@@ -9,8 +9,9 @@ use crate::binary::var_int::VarInt;
 use crate::binary::var_uint::VarUInt;
 use crate::decimal::coefficient::Coefficient;
 use crate::ion_data::IonEq;
-use crate::result::{IonFailure, IonResult};
-use crate::{Decimal, Int, IonError};
+use crate::result::IonResult;
+use crate::Decimal;
+use num_traits::Zero;
 
 const MAX_INLINE_LENGTH: usize = 13;
 
@@ -51,7 +52,7 @@ where
         bytes_written += VarInt::write_i64(self, decimal.exponent)?;
 
         match decimal.coefficient.as_int() {
-            Some(int) if int == Int::ZERO => {
+            Some(int) if int.is_zero() => {
                 // From the spec: "The subfield should not be present (that is, it
                 // has zero length) when the coefficient’s value is (positive)
                 // zero."
@@ -72,10 +73,18 @@ where
         // First encode the decimal value to a stack-allocated buffer.
         // We need to know its encoded length before we can write out
         // the preceding type descriptor.
-        let mut encoded: ArrayVec<u8, DECIMAL_BUFFER_SIZE> = ArrayVec::new();
-        encoded.encode_decimal(decimal).map_err(|_e| {
-            IonError::encoding_error("found a decimal that was too large for the configured buffer")
-        })?;
+        let mut buffer: ArrayVec<u8, DECIMAL_BUFFER_SIZE> = ArrayVec::new();
+        let heap_buffer: Vec<u8>;
+        let encoded: &[u8] = if buffer.encode_decimal(decimal).is_ok() {
+            buffer.as_slice()
+        } else {
+            // The coefficient was too large for the stack-allocated buffer. This can only happen
+            // when the coefficient is outside the i128 range, so we can afford to allocate.
+            let mut vec = Vec::new();
+            vec.encode_decimal(decimal)?;
+            heap_buffer = vec;
+            heap_buffer.as_slice()
+        };
 
         // Now that we have the value's encoded bytes, we can encode its header
         // and write it to the output stream.
@@ -98,7 +107,7 @@ where
 
         // Now that we've written the header to the output stream, we can write
         // the value's encoded bytes.
-        self.write_all(&encoded[..])?;
+        self.write_all(encoded)?;
         bytes_written += encoded.len();
 
         Ok(bytes_written)
@@ -153,7 +162,7 @@ mod binary_decimal_tests {
     #[case::foo(Decimal::new(i128::MIN + 1, i32::MIN))]
     fn roundtrip_decimals_with_extreme_values(#[case] value: Decimal) -> IonResult<()> {
         let mut writer = Writer::new(BinaryEncoding_1_0::default_write_config(), Vec::new())?;
-        writer.write(value)?;
+        writer.write(&value)?;
         let output = writer.close()?;
         let mut reader = Reader::new(AnyEncoding, output)?;
         let after_round_trip = reader.expect_next()?.read()?.expect_decimal()?;
@@ -38,7 +38,10 @@ impl DecodedInt {
     /// Encodes the provided `value` as an Int and writes it to the provided `sink`.
     /// Returns the number of bytes written.
     pub fn write<W: Write>(sink: &mut W, value: impl Into<Int>) -> IonResult<usize> {
-        let value = value.into().data;
+        let value = value.into();
+        let Some(value) = value.as_i128() else {
+            return Self::write_big_int(sink, &value);
+        };
         let magnitude = value.unsigned_abs();
         // Using leading_zeros() to determine how many empty bytes we can ignore.
         // We subtract one from the number of leading bits to leave space for a sign bit
@@ -70,6 +73,23 @@ impl DecodedInt {
         Ok(bytes_written)
     }
 
+    /// Encodes an `Int` that is outside the `i128` range and writes it to the provided `sink`.
+    /// Returns the number of bytes written.
+    #[cold]
+    fn write_big_int<W: Write>(sink: &mut W, value: &Int) -> IonResult<usize> {
+        let mut bytes = value.unsigned_abs().to_be_bytes();
+        // If the magnitude's highest bit is already in use, we need to add a leading byte to
+        // make room for the sign bit.
+        if bytes[0] & 0b1000_0000 != 0 {
+            bytes.insert(0, 0);
+        }
+        if value.is_negative() {
+            bytes[0] |= 0b1000_0000;
+        }
+        sink.write_all(&bytes)?;
+        Ok(bytes.len())
+    }
+
     /// Encodes a negative zero as an `Int` and writes it to the provided `sink`.
     /// Returns the number of bytes written.
     ///
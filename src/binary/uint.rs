@@ -1,8 +1,9 @@
 use std::io::Write;
 use std::mem;
 
-use crate::result::{IonFailure, IonResult};
+use crate::result::IonResult;
 use crate::{Int, IonError, UInt};
+use num_bigint::BigUint;
 
 /// Represents a fixed-length unsigned integer. See the
 /// [UInt and Int Fields](https://amazon-ion.github.io/ion-docs/docs/binary.html#uint-and-int-fields)
@@ -22,16 +23,15 @@ impl DecodedUInt {
     }
 
     /// Interprets all of the bytes in the provided slice as big-endian unsigned integer bytes.
-    /// If the length of `uint_bytes` is greater than the size of a `u128`, returns `Err`.
+    /// If the length of `uint_bytes` is greater than the size of a `u128`, the resulting `UInt`
+    /// will be heap-allocated.
     #[inline]
-    pub(crate) fn uint_from_slice(uint_bytes: &[u8]) -> IonResult<u128> {
+    pub(crate) fn uint_from_slice(uint_bytes: &[u8]) -> UInt {
         if uint_bytes.len() > mem::size_of::<u128>() {
-            return IonResult::decoding_error(
-                "integer size is currently limited to the range of an i128",
-            );
+            return UInt::from(BigUint::from_bytes_be(uint_bytes));
         }
 
-        Ok(Self::uint_from_slice_unchecked(uint_bytes))
+        UInt::from(Self::uint_from_slice_unchecked(uint_bytes))
     }
 
     /// Interprets all of the bytes in the provided slice as big-endian unsigned integer bytes.
@@ -1,5 +1,10 @@
+use crate::result::IonFailure;
 use crate::shared_symbol_table::SharedSymbolTable;
-use std::collections::{BTreeMap, HashMap};
+use crate::{Element, Int, IonError, IonResult, ReadLimits, Symbol};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 /// A Catalog is a collection of Shared Symbol Tables.
 /// For more information about the concept of a catalog,
@@ -13,6 +18,14 @@ pub trait Catalog {
     /// Returns the Shared Symbol Table with given table name and version
     /// If a table with given name and version doesn't exists then it returns None
     fn get_table_with_version(&self, name: &str, version: usize) -> Option<&SharedSymbolTable>;
+    /// Returns the Shared Symbol Table with given table name and version if it exists.
+    /// Otherwise, returns the highest version of the table with the given name, if any.
+    /// This is the table that the Ion specification says a reader should fall back to when an
+    /// import's exact version is not available.
+    fn get_best_match(&self, name: &str, version: usize) -> Option<&SharedSymbolTable> {
+        self.get_table_with_version(name, version)
+            .or_else(|| self.get_table(name))
+    }
 }
 
 #[derive(Default)]
@@ -67,6 +80,296 @@ impl MapCatalog {
     }
 }
 
+/// A [`Catalog`] containing the shared symbol tables defined in the Ion files found in a
+/// directory and its subdirectories.
+///
+/// Files with the extension `.ion` (text) or `.10n` (binary) are read; other files are ignored.
+/// Each top-level struct annotated with `$ion_shared_symbol_table` is loaded as a shared symbol
+/// table, and any other values are ignored. If a table declares `imports`, the symbols of the
+/// imported tables (which must also be defined in the directory) precede its own symbols.
+///
+/// ```no_run
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{Catalog, FileSystemCatalog};
+///
+/// let catalog = FileSystemCatalog::new("/etc/my_app/symbol_tables")?;
+/// if let Some(table) = catalog.get_table_with_version("com.example.telemetry", 2) {
+///     println!("{} symbols", table.symbols().len());
+/// }
+///# Ok(())
+///# }
+/// ```
+pub struct FileSystemCatalog {
+    root: PathBuf,
+    tables: MapCatalog,
+    file_versions: BTreeMap<PathBuf, FileVersion>,
+}
+
+impl FileSystemCatalog {
+    /// Loads all of the shared symbol tables defined in the files under `root`.
+    ///
+    /// Returns an error if a file cannot be read or parsed, if a table definition is invalid,
+    /// if more than one file defines the same version of a table, or if a table's imports
+    /// cannot be resolved.
+    pub fn new<P: AsRef<Path>>(root: P) -> IonResult<Self> {
+        let root = root.as_ref().to_path_buf();
+        let file_versions = Self::scan(&root)?;
+        let tables = Self::load(&file_versions)?;
+        Ok(Self {
+            root,
+            tables,
+            file_versions,
+        })
+    }
+
+    /// Returns the directory from which this catalog's tables were loaded.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Rescans the catalog's directory and, if any Ion files were added, removed, or modified
+    /// since they were last loaded, reloads all of its tables. Returns `true` if the tables were
+    /// reloaded.
+    ///
+    /// If reloading fails, the error is returned and the catalog continues to serve the tables
+    /// it had already loaded.
+    pub fn reload(&mut self) -> IonResult<bool> {
+        let file_versions = Self::scan(&self.root)?;
+        if file_versions == self.file_versions {
+            return Ok(false);
+        }
+        self.tables = Self::load(&file_versions)?;
+        self.file_versions = file_versions;
+        Ok(true)
+    }
+
+    /// Finds the Ion files under `root` and records when each one was last modified and its size.
+    fn scan(root: &Path) -> IonResult<BTreeMap<PathBuf, FileVersion>> {
+        let mut file_versions = BTreeMap::new();
+        let mut directories = vec![root.to_path_buf()];
+        while let Some(directory) = directories.pop() {
+            for entry in fs::read_dir(&directory)? {
+                let entry = entry?;
+                let path = entry.path();
+                let metadata = entry.metadata()?;
+                if metadata.is_dir() {
+                    directories.push(path);
+                } else if matches!(
+                    path.extension().and_then(|e| e.to_str()),
+                    Some("ion" | "10n")
+                ) {
+                    file_versions.insert(path, (metadata.modified()?, metadata.len()));
+                }
+            }
+        }
+        Ok(file_versions)
+    }
+
+    /// Reads the table definitions in each of the provided files and resolves their imports.
+    fn load(files: &BTreeMap<PathBuf, FileVersion>) -> IonResult<MapCatalog> {
+        let mut definitions: HashMap<String, BTreeMap<usize, TableDefinition>> = HashMap::new();
+        for path in files.keys() {
+            let bytes = fs::read(path)?;
+            let elements = Element::read_all(bytes).map_err(|e| {
+                IonError::decoding_error(format!("failed to read {}: {e}", path.display()))
+            })?;
+            for element in elements {
+                if element.annotations().first() != Some("$ion_shared_symbol_table") {
+                    continue;
+                }
+                let definition = TableDefinition::try_from(element).map_err(|e| {
+                    IonError::decoding_error(format!(
+                        "invalid shared symbol table in {}: {e}",
+                        path.display()
+                    ))
+                })?;
+                let versions = definitions.entry(definition.name.clone()).or_default();
+                if versions.contains_key(&definition.version) {
+                    return IonResult::decoding_error(format!(
+                        "found more than one definition of shared symbol table name='{}' version={} (second in {})",
+                        definition.name,
+                        definition.version,
+                        path.display()
+                    ));
+                }
+                versions.insert(definition.version, definition);
+            }
+        }
+
+        let mut resolver = ImportResolver {
+            definitions: &definitions,
+            tables: MapCatalog::new(),
+            in_progress: HashSet::new(),
+        };
+        for (name, versions) in &definitions {
+            for version in versions.keys() {
+                resolver.resolve(name, *version)?;
+            }
+        }
+        Ok(resolver.tables)
+    }
+}
+
+impl Catalog for FileSystemCatalog {
+    fn get_table(&self, name: &str) -> Option<&SharedSymbolTable> {
+        self.tables.get_table(name)
+    }
+
+    fn get_table_with_version(&self, name: &str, version: usize) -> Option<&SharedSymbolTable> {
+        self.tables.get_table_with_version(name, version)
+    }
+}
+
+/// The modification time and size of a file, used to detect whether it has changed.
+type FileVersion = (SystemTime, u64);
+
+/// A shared symbol table as it appears in its serialized form, before its imports are resolved.
+struct TableDefinition {
+    name: String,
+    version: usize,
+    imports: Vec<ImportDefinition>,
+    symbols: Vec<Symbol>,
+}
+
+struct ImportDefinition {
+    name: String,
+    version: usize,
+    max_id: Option<usize>,
+}
+
+impl TryFrom<Element> for TableDefinition {
+    type Error = IonError;
+
+    fn try_from(element: Element) -> Result<Self, Self::Error> {
+        let imports = match element.expect_struct()?.get("imports") {
+            Some(imports) if imports.is_null() => Vec::new(),
+            Some(imports) => imports
+                .as_list()
+                .ok_or_else(|| {
+                    IonError::decoding_error(format!(
+                        "expected the 'imports' field to be a list, but found {imports}"
+                    ))
+                })?
+                .iter()
+                .map(ImportDefinition::try_from)
+                .collect::<IonResult<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+        let table = SharedSymbolTable::try_from(element)?;
+        Ok(Self {
+            name: table.name().to_owned(),
+            version: table.version(),
+            imports,
+            symbols: table.symbols().to_vec(),
+        })
+    }
+}
+
+impl TryFrom<&Element> for ImportDefinition {
+    type Error = IonError;
+
+    fn try_from(element: &Element) -> Result<Self, Self::Error> {
+        let import = element.expect_struct()?;
+        let name = match import.get("name").and_then(Element::as_string) {
+            Some(name) if !name.is_empty() => name.to_owned(),
+            _ => return IonResult::decoding_error("found an import without a 'name'"),
+        };
+        // As in a local symbol table, a missing or non-positive version is treated as version 1
+        // and a missing or negative `max_id` means that all of the table's symbols are imported.
+        let version = match import.get("version").and_then(Element::as_int) {
+            Some(version) if *version > Int::ZERO => version.as_usize().ok_or_else(|| {
+                IonError::decoding_error(format!(
+                    "import of '{name}' has a version that is too large: {version}"
+                ))
+            })?,
+            _ => 1,
+        };
+        let max_id = match import.get("max_id").and_then(Element::as_int) {
+            Some(max_id) if *max_id >= Int::ZERO => Some(max_id.as_usize().ok_or_else(|| {
+                IonError::decoding_error(format!(
+                    "import of '{name}' has a max_id that is too large: {max_id}"
+                ))
+            })?),
+            _ => None,
+        };
+        Ok(Self {
+            name,
+            version,
+            max_id,
+        })
+    }
+}
+
+/// Converts [`TableDefinition`]s into [`SharedSymbolTable`]s, resolving each table's imports
+/// before the table itself.
+struct ImportResolver<'a> {
+    definitions: &'a HashMap<String, BTreeMap<usize, TableDefinition>>,
+    tables: MapCatalog,
+    // Tables whose imports are currently being resolved; used to detect import cycles.
+    in_progress: HashSet<(&'a str, usize)>,
+}
+
+impl<'a> ImportResolver<'a> {
+    fn resolve(&mut self, name: &'a str, version: usize) -> IonResult<()> {
+        if self.tables.get_table_with_version(name, version).is_some() {
+            return Ok(());
+        }
+        if !self.in_progress.insert((name, version)) {
+            return IonResult::decoding_error(format!(
+                "shared symbol table name='{name}' version={version} imports itself"
+            ));
+        }
+        let definition = &self.definitions[name][&version];
+        let mut symbols = Vec::new();
+        for import in &definition.imports {
+            // The system symbol table is implicitly imported by every local symbol table.
+            if import.name == "$ion" {
+                continue;
+            }
+            let imported = self.resolve_import(definition, import)?;
+            let max_id = import.max_id.unwrap_or(imported.symbols().len());
+            let num_symbols = ReadLimits::new().check_import(symbols.len(), max_id)?;
+            let num_symbols_to_import = imported.symbols().len().min(max_id);
+            symbols.extend_from_slice(&imported.symbols()[..num_symbols_to_import]);
+            symbols.resize(num_symbols, Symbol::unknown_text());
+        }
+        symbols.extend_from_slice(&definition.symbols);
+        self.in_progress.remove(&(name, version));
+        self.tables
+            .insert_table(SharedSymbolTable::new(name, version, symbols)?);
+        Ok(())
+    }
+
+    /// Finds the table that satisfies `import`. If the requested version is not defined, the
+    /// highest available version is used instead, which requires `import` to specify a `max_id`.
+    fn resolve_import(
+        &mut self,
+        importer: &TableDefinition,
+        import: &ImportDefinition,
+    ) -> IonResult<&SharedSymbolTable> {
+        let Some((name, versions)) = self.definitions.get_key_value(&import.name) else {
+            return IonResult::decoding_error(format!(
+                "shared symbol table name='{}' version={} imports '{}', which was not found",
+                importer.name, importer.version, import.name
+            ));
+        };
+        let version = if versions.contains_key(&import.version) {
+            import.version
+        } else if import.max_id.is_some() {
+            // `versions` is never empty, so there is always a highest version.
+            *versions.keys().next_back().unwrap()
+        } else {
+            return IonResult::decoding_error(format!(
+                "shared symbol table name='{}' version={} imports '{}' version={}, which was not found, without specifying a max_id",
+                importer.name, importer.version, import.name, import.version
+            ));
+        };
+        self.resolve(name, version)?;
+        Ok(self.tables.get_table_with_version(name, version).unwrap())
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct EmptyCatalog;
 
@@ -82,9 +385,15 @@ impl Catalog for EmptyCatalog {
 
 #[cfg(test)]
 mod tests {
-    use crate::catalog::{Catalog, MapCatalog};
+    use std::fs;
+    use std::path::Path;
+
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use crate::catalog::{Catalog, FileSystemCatalog, MapCatalog};
     use crate::shared_symbol_table::SharedSymbolTable;
-    use crate::{IonResult, Symbol};
+    use crate::{AnyEncoding, Decoder, Element, ElementReader, IonResult, Reader, Symbol};
 
     #[test]
     fn get_table_with_name_test() -> IonResult<()> {
@@ -121,4 +430,204 @@ mod tests {
         assert!(catalog.get_table_with_version("T", 2).is_none());
         Ok(())
     }
+
+    /// Creates a temporary directory containing each of the provided `(path, contents)` pairs.
+    fn directory_with(files: &[(&str, &str)]) -> TempDir {
+        let directory = TempDir::new().unwrap();
+        for (path, contents) in files {
+            write_file(directory.path(), path, contents);
+        }
+        directory
+    }
+
+    fn write_file(directory: &Path, path: &str, contents: &str) {
+        let path = directory.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    fn symbols_of<'a>(
+        catalog: &'a FileSystemCatalog,
+        name: &str,
+        version: usize,
+    ) -> Vec<Option<&'a str>> {
+        catalog
+            .get_table_with_version(name, version)
+            .unwrap()
+            .symbols()
+            .iter()
+            .map(Symbol::text)
+            .collect()
+    }
+
+    #[test]
+    fn load_tables_from_directory() -> IonResult<()> {
+        let directory = directory_with(&[
+            (
+                "colors.ion",
+                r#"$ion_shared_symbol_table::{name: "colors", version: 1, symbols: ["red", "green"]}
+                   $ion_shared_symbol_table::{name: "colors", version: 2, symbols: ["red", "green", "blue"]}"#,
+            ),
+            (
+                "nested/shapes.ion",
+                r#"$ion_shared_symbol_table::{name: "shapes", symbols: ["circle", null, "square"]}
+                   // Values that are not shared symbol tables are ignored
+                   {name: "not_a_table", symbols: ["ignored"]}"#,
+            ),
+            ("README.md", "Files without an Ion extension are ignored."),
+        ]);
+        let catalog = FileSystemCatalog::new(directory.path())?;
+        assert_eq!(catalog.root(), directory.path());
+        assert_eq!(
+            symbols_of(&catalog, "colors", 1),
+            [Some("red"), Some("green")]
+        );
+        assert_eq!(catalog.get_table("colors").unwrap().version(), 2);
+        assert_eq!(
+            symbols_of(&catalog, "shapes", 1),
+            [Some("circle"), None, Some("square")]
+        );
+        assert!(catalog.get_table("not_a_table").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_imports() -> IonResult<()> {
+        let directory = directory_with(&[
+            (
+                "base.ion",
+                r#"$ion_shared_symbol_table::{name: "base", version: 1, symbols: ["a", "b"]}
+                   $ion_shared_symbol_table::{name: "base", version: 3, symbols: ["a", "b", "c"]}"#,
+            ),
+            (
+                "derived.ion",
+                r#"$ion_shared_symbol_table::{
+                       name: "derived",
+                       version: 1,
+                       imports: [
+                           {name: "$ion", version: 1, max_id: 9},
+                           {name: "base", version: 1},
+                           // Version 2 is missing, so the highest version is truncated to `max_id`
+                           {name: "base", version: 2, max_id: 2},
+                           // An exact match is padded to `max_id`
+                           {name: "base", version: 3, max_id: 4},
+                       ],
+                       symbols: ["d"]
+                   }
+                   $ion_shared_symbol_table::{
+                       name: "derived_again",
+                       imports: [{name: "derived", version: 1, max_id: 10}],
+                       symbols: ["e"]
+                   }"#,
+            ),
+        ]);
+        let catalog = FileSystemCatalog::new(directory.path())?;
+        let expected_derived = [
+            Some("a"),
+            Some("b"),
+            Some("a"),
+            Some("b"),
+            Some("a"),
+            Some("b"),
+            Some("c"),
+            None,
+            Some("d"),
+        ];
+        assert_eq!(symbols_of(&catalog, "derived", 1), expected_derived);
+        let mut expected_derived_again = expected_derived.to_vec();
+        expected_derived_again.extend([None, Some("e")]);
+        assert_eq!(
+            symbols_of(&catalog, "derived_again", 1),
+            expected_derived_again
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::missing_name(r#"$ion_shared_symbol_table::{version: 1, symbols: ["a"]}"#)]
+    #[case::not_a_struct(r#"$ion_shared_symbol_table::["a"]"#)]
+    #[case::malformed_imports(r#"$ion_shared_symbol_table::{name: "t", imports: "other"}"#)]
+    #[case::missing_import(r#"$ion_shared_symbol_table::{name: "t", imports: [{name: "other"}]}"#)]
+    #[case::inexact_import_without_max_id(
+        r#"$ion_shared_symbol_table::{name: "t", symbols: ["a"]}
+           $ion_shared_symbol_table::{name: "u", imports: [{name: "t", version: 2}]}"#
+    )]
+    #[case::duplicate_version(
+        r#"$ion_shared_symbol_table::{name: "t", version: 1, symbols: ["a"]}
+           $ion_shared_symbol_table::{name: "t", version: 1, symbols: ["b"]}"#
+    )]
+    #[case::import_cycle(
+        r#"$ion_shared_symbol_table::{name: "t", imports: [{name: "u"}]}
+           $ion_shared_symbol_table::{name: "u", imports: [{name: "t"}]}"#
+    )]
+    #[case::invalid_ion(r#"$ion_shared_symbol_table::{name: "t", symbols: ["a""#)]
+    #[case::oversized_max_id(
+        r#"$ion_shared_symbol_table::{name: "t", symbols: ["a"]}
+           $ion_shared_symbol_table::{name: "u", imports: [{name: "t", max_id: 4000000000}]}"#
+    )]
+    #[case::overflowing_max_id(
+        r#"$ion_shared_symbol_table::{name: "t", symbols: ["a"]}
+           $ion_shared_symbol_table::{
+               name: "u",
+               imports: [{name: "t", max_id: 1}, {name: "t", max_id: 18446744073709551615}]
+           }"#
+    )]
+    fn invalid_tables(#[case] contents: &str) {
+        let directory = directory_with(&[("tables.ion", contents)]);
+        let result = FileSystemCatalog::new(directory.path());
+        assert!(result.is_err(), "expected an error for {contents}");
+    }
+
+    #[test]
+    fn get_best_match() -> IonResult<()> {
+        let directory = directory_with(&[(
+            "tables.ion",
+            r#"$ion_shared_symbol_table::{name: "t", version: 1, symbols: ["a"]}
+               $ion_shared_symbol_table::{name: "t", version: 3, symbols: ["a", "b"]}"#,
+        )]);
+        let catalog = FileSystemCatalog::new(directory.path())?;
+        assert_eq!(catalog.get_best_match("t", 1).unwrap().version(), 1);
+        assert_eq!(catalog.get_best_match("t", 2).unwrap().version(), 3);
+        assert_eq!(catalog.get_best_match("t", 4).unwrap().version(), 3);
+        assert!(catalog.get_best_match("u", 1).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn reload_when_files_change() -> IonResult<()> {
+        let directory = directory_with(&[(
+            "t.ion",
+            r#"$ion_shared_symbol_table::{name: "t", symbols: ["a"]}"#,
+        )]);
+        let mut catalog = FileSystemCatalog::new(directory.path())?;
+        assert!(!catalog.reload()?);
+
+        write_file(
+            directory.path(),
+            "u.ion",
+            r#"$ion_shared_symbol_table::{name: "u", symbols: ["b"]}"#,
+        );
+        assert!(catalog.reload()?);
+        assert!(catalog.get_table("u").is_some());
+
+        // A failed reload leaves the previously loaded tables in place.
+        write_file(directory.path(), "u.ion", "$ion_shared_symbol_table::{");
+        assert!(catalog.reload().is_err());
+        assert!(catalog.get_table("t").is_some());
+        assert!(catalog.get_table("u").is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn read_with_file_system_catalog() -> IonResult<()> {
+        let directory = directory_with(&[(
+            "t.ion",
+            r#"$ion_shared_symbol_table::{name: "t", version: 1, symbols: ["foo", "bar"]}"#,
+        )]);
+        let catalog = FileSystemCatalog::new(directory.path())?;
+        let data = r#"$ion_symbol_table::{imports: [{name: "t", version: 1, max_id: 2}]} $10 $11"#;
+        let mut reader = Reader::new(AnyEncoding.with_catalog(catalog), data)?;
+        assert_eq!(reader.read_all_elements()?, Element::read_all("foo bar")?);
+        Ok(())
+    }
 }
@@ -0,0 +1,1016 @@
+//! Structural differences between two [`Element`]s.
+//!
+//! [`diff`] compares two values and produces a [`Patch`]: the list of [`Change`]s that, applied in
+//! order, turn the first value into the second. Each change carries the [`ValuePath`] of the value
+//! it affects.
+//!
+//! Structs are compared according to Ion's data model, in which field order is not significant and
+//! repeated field names form a multiset. A field whose name is unique in both structs is compared
+//! recursively. Fields whose names are repeated are matched by value instead; any that cannot be
+//! matched are reported as whole-value changes, additions or removals.
+//!
+//! Lists and s-expressions are compared element-by-element using a longest-common-subsequence
+//! alignment. Computing the alignment takes time and memory proportional to the product of the
+//! sequences' lengths, so when the differing parts of two sequences are very long, their elements
+//! are instead paired up by position. The index in each sequence change accounts for any earlier
+//! changes to the same sequence, so that applying the changes in order always produces the
+//! expected result.
+//!
+//! ```
+//!# use ion_rs::IonResult;
+//!# fn main() -> IonResult<()> {
+//! use ion_rs::Element;
+//! use ion_rs::diff::{diff, Patch};
+//!
+//! let old = Element::read_one(r#"{name: "widget", sizes: [1, 2, 3], color: red}"#)?;
+//! let new = Element::read_one(r#"{name: "widget", sizes: [1, 3, 4], color: rgb::blue}"#)?;
+//!
+//! let patch = diff(&old, &new);
+//! let summary: Vec<String> = patch.changes().iter().map(|c| c.to_string()).collect();
+//! assert_eq!(
+//!     summary,
+//!     vec![
+//!         "- $.sizes[1]: 2",
+//!         "+ $.sizes[2]: 4",
+//!         "~ $.color: red -> rgb::blue",
+//!     ]
+//! );
+//!
+//! // Patches can be written as Ion...
+//! let patch_element = patch.to_element();
+//! // ...read back...
+//! let patch = Patch::try_from(&patch_element)?;
+//! // ...and applied to a copy of the original value.
+//! let mut patched = old.clone();
+//! patch.apply(&mut patched)?;
+//! assert_eq!(patched, new);
+//!# Ok(())
+//!# }
+//! ```
+//!
+//! ## Patch format
+//!
+//! [`Patch::to_element`] encodes a patch as an Ion list of structs, one per change. Each struct has
+//! an `op` field (one of the symbols `add`, `remove`, `change` or `annotate`) and a `path` field.
+//! The path is a list in which symbols are field names and integers are sequence indexes; `[]`
+//! is the root value. An `old` field holds the value being removed or replaced, and a `new` field
+//! holds the value being added or the replacement. For `annotate`, both hold lists of annotation
+//! symbols.
+//!
+//! ```ion
+//! [
+//!   {op: remove, path: [sizes, 1], old: 2},
+//!   {op: add, path: [sizes, 2], new: 4},
+//!   {op: change, path: [color], old: red, new: rgb::blue},
+//! ]
+//! ```
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Write};
+use std::io::IsTerminal;
+
+use crate::element::{Annotations, Element, Sequence};
+use crate::ion_data::IonEq;
+use crate::path::{PathComponent, ValuePath};
+use crate::result::IonFailure;
+use crate::{ion_struct, IonError, IonResult, List, Struct, Symbol};
+
+/// Compares `old` to `new`, returning the [`Patch`] that transforms the former into the latter.
+/// If the two values are Ion-equivalent, the patch is empty.
+pub fn diff(old: &Element, new: &Element) -> Patch {
+    let mut changes = Vec::new();
+    diff_elements(&mut changes, ValuePath::root(), old, new);
+    Patch { changes }
+}
+
+/// A single difference between two values.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    /// A struct field or sequence element was added.
+    Added { path: ValuePath, new: Element },
+    /// A struct field or sequence element was removed.
+    Removed { path: ValuePath, old: Element },
+    /// A value was replaced by a different value. This is used for scalars, for values whose
+    /// type changed, and for values in a struct whose field name is repeated.
+    Changed {
+        path: ValuePath,
+        old: Element,
+        new: Element,
+    },
+    /// A container's annotations changed while its contents were compared separately.
+    AnnotationsChanged {
+        path: ValuePath,
+        old: Annotations,
+        new: Annotations,
+    },
+}
+
+impl Change {
+    /// Returns the location of the value this change affects.
+    pub fn path(&self) -> &ValuePath {
+        match self {
+            Change::Added { path, .. }
+            | Change::Removed { path, .. }
+            | Change::Changed { path, .. }
+            | Change::AnnotationsChanged { path, .. } => path,
+        }
+    }
+
+    fn to_element(&self) -> Element {
+        let path: Vec<Element> = self
+            .path()
+            .components()
+            .iter()
+            .map(|component| match component {
+                PathComponent::Field(name) => Element::symbol(name.clone()),
+                PathComponent::Index(index) => Element::int(*index as i64),
+            })
+            .collect();
+        let path = Element::from(List::from(Sequence::new(path)));
+        match self {
+            Change::Added { new, .. } => ion_struct! {"op": Symbol::from("add"), "path": path, "new": new.clone()},
+            Change::Removed { old, .. } => ion_struct! {"op": Symbol::from("remove"), "path": path, "old": old.clone()},
+            Change::Changed { old, new, .. } => {
+                ion_struct! {"op": Symbol::from("change"), "path": path, "old": old.clone(), "new": new.clone()}
+            }
+            Change::AnnotationsChanged { old, new, .. } => {
+                ion_struct! {"op": Symbol::from("annotate"), "path": path, "old": annotations_to_element(old), "new": annotations_to_element(new)}
+            }
+        }
+        .into()
+    }
+
+    fn try_from_element(element: &Element) -> IonResult<Self> {
+        let fields = element.expect_struct()?;
+        let op = required_field(fields, "op")?.expect_symbol()?;
+        let mut path = ValuePath::root();
+        for component in required_field(fields, "path")?.expect_list()? {
+            let component = if let Some(name) = component.as_symbol() {
+                PathComponent::Field(name.clone())
+            } else if let Some(index) = component.as_int().and_then(|i| i.as_usize()) {
+                PathComponent::Index(index)
+            } else {
+                return IonResult::decoding_error(format!(
+                    "patch path components must be symbols or non-negative ints, found {component}"
+                ));
+            };
+            path.push(component);
+        }
+        let change = match op.text() {
+            Some("add") => Change::Added {
+                path,
+                new: required_field(fields, "new")?.clone(),
+            },
+            Some("remove") => Change::Removed {
+                path,
+                old: required_field(fields, "old")?.clone(),
+            },
+            Some("change") => Change::Changed {
+                path,
+                old: required_field(fields, "old")?.clone(),
+                new: required_field(fields, "new")?.clone(),
+            },
+            Some("annotate") => Change::AnnotationsChanged {
+                path,
+                old: annotations_from_element(required_field(fields, "old")?)?,
+                new: annotations_from_element(required_field(fields, "new")?)?,
+            },
+            _ => return IonResult::decoding_error(format!("unrecognized patch op: {op}")),
+        };
+        Ok(change)
+    }
+
+    fn apply(&self, root: &mut Element) -> IonResult<()> {
+        let Some((last, parents)) = self.path().components().split_last() else {
+            // The change applies to the root value itself.
+            return match self {
+                Change::Changed { old, new, .. } => {
+                    expect_matches(root, old, self)?;
+                    *root = new.clone();
+                    Ok(())
+                }
+                Change::AnnotationsChanged { old, new, .. } => {
+                    expect_annotations(root, old, self)?;
+                    *root.annotations_mut() = new.clone();
+                    Ok(())
+                }
+                _ => IonResult::illegal_operation(format!(
+                    "cannot apply '{self}': the root value cannot be added or removed"
+                )),
+            };
+        };
+
+        let mut parent = root;
+        for component in parents {
+            parent = match component {
+                PathComponent::Field(name) => parent.as_struct_mut().and_then(|s| s.get_mut(name)),
+                PathComponent::Index(index) => {
+                    parent.as_sequence_mut().and_then(|s| s.get_mut(*index))
+                }
+            }
+            .ok_or_else(|| missing_path(self))?;
+        }
+
+        match last {
+            PathComponent::Field(name) => {
+                let fields = parent.as_struct_mut().ok_or_else(|| missing_path(self))?;
+                self.apply_to_field(fields, name)
+            }
+            PathComponent::Index(index) => {
+                let sequence = parent.as_sequence_mut().ok_or_else(|| missing_path(self))?;
+                self.apply_to_index(sequence, *index)
+            }
+        }
+    }
+
+    fn apply_to_field(&self, fields: &mut Struct, name: &Symbol) -> IonResult<()> {
+        match self {
+            Change::Added { new, .. } => {
+                fields.insert(name.clone(), new.clone());
+                return Ok(());
+            }
+            Change::Removed { old, .. } => {
+                // Repeated field names are allowed, so the field to remove is identified by its
+                // value as well as its name.
+                let mut removed = false;
+                fields.retain(|field_name, value| {
+                    if !removed && field_name == name && Element::ion_eq(value, old) {
+                        removed = true;
+                        return false;
+                    }
+                    true
+                });
+                if removed {
+                    return Ok(());
+                }
+            }
+            Change::Changed { old, new, .. } => {
+                if let Some((_, value)) = fields
+                    .iter_mut()
+                    .find(|(field_name, value)| *field_name == name && Element::ion_eq(value, old))
+                {
+                    *value = new.clone();
+                    return Ok(());
+                }
+            }
+            Change::AnnotationsChanged { old, new, .. } => {
+                if let Some((_, value)) = fields
+                    .iter_mut()
+                    .find(|(field_name, value)| *field_name == name && value.annotations() == old)
+                {
+                    *value.annotations_mut() = new.clone();
+                    return Ok(());
+                }
+            }
+        }
+        IonResult::illegal_operation(format!(
+            "cannot apply '{self}': no matching field was found"
+        ))
+    }
+
+    fn apply_to_index(&self, sequence: &mut Sequence, index: usize) -> IonResult<()> {
+        if let Change::Added { new, .. } = self {
+            if index > sequence.len() {
+                return Err(missing_path(self));
+            }
+            sequence.insert(index, new.clone());
+            return Ok(());
+        }
+        let value = sequence.get_mut(index).ok_or_else(|| missing_path(self))?;
+        match self {
+            Change::Removed { old, .. } => {
+                expect_matches(value, old, self)?;
+                sequence.remove(index);
+            }
+            Change::Changed { old, new, .. } => {
+                expect_matches(value, old, self)?;
+                *value = new.clone();
+            }
+            Change::AnnotationsChanged { old, new, .. } => {
+                expect_annotations(value, old, self)?;
+                *value.annotations_mut() = new.clone();
+            }
+            Change::Added { .. } => unreachable!("handled above"),
+        }
+        Ok(())
+    }
+}
+
+/// Formats the change as a single line: `+` for additions, `-` for removals, `~` for changes, and
+/// `@` for annotation changes.
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::Added { path, new } => write!(f, "+ {path}: {new}"),
+            Change::Removed { path, old } => write!(f, "- {path}: {old}"),
+            Change::Changed { path, old, new } => write!(f, "~ {path}: {old} -> {new}"),
+            Change::AnnotationsChanged { path, old, new } => {
+                write!(f, "@ {path}: ")?;
+                write_annotations(f, old)?;
+                write!(f, " -> ")?;
+                write_annotations(f, new)
+            }
+        }
+    }
+}
+
+fn write_annotations(f: &mut Formatter<'_>, annotations: &Annotations) -> std::fmt::Result {
+    write!(f, "[")?;
+    for (index, annotation) in annotations.iter().enumerate() {
+        if index > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{annotation}")?;
+    }
+    write!(f, "]")
+}
+
+/// The ordered list of [`Change`]s that transforms one value into another. See [`diff`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Patch {
+    changes: Vec<Change>,
+}
+
+impl Patch {
+    /// Constructs a patch from a list of changes, which will be applied in order.
+    pub fn new<I: IntoIterator<Item = Change>>(changes: I) -> Self {
+        Self {
+            changes: changes.into_iter().collect(),
+        }
+    }
+
+    pub fn changes(&self) -> &[Change] {
+        &self.changes
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns `true` if the patch contains no changes; that is, if the values it was computed
+    /// from are Ion-equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Applies each of this patch's changes to `element` in order.
+    ///
+    /// Removals and replacements check that the value being removed or replaced is the one
+    /// recorded in the change. If a change cannot be applied, an error is returned and `element`
+    /// is left with the changes before it applied.
+    pub fn apply(&self, element: &mut Element) -> IonResult<()> {
+        for change in &self.changes {
+            change.apply(element)?;
+        }
+        Ok(())
+    }
+
+    /// Encodes this patch as an Ion list. See the [module documentation](self) for the format.
+    pub fn to_element(&self) -> Element {
+        Element::from(List::from(Sequence::new(
+            self.changes.iter().map(Change::to_element),
+        )))
+    }
+}
+
+impl TryFrom<&Element> for Patch {
+    type Error = IonError;
+
+    /// Reads a patch from the Ion list produced by [`Patch::to_element`].
+    fn try_from(element: &Element) -> Result<Self, Self::Error> {
+        let changes = element
+            .expect_list()?
+            .iter()
+            .map(Change::try_from_element)
+            .collect::<IonResult<Vec<_>>>()?;
+        Ok(Patch { changes })
+    }
+}
+
+impl TryFrom<Element> for Patch {
+    type Error = IonError;
+
+    fn try_from(element: Element) -> Result<Self, Self::Error> {
+        Patch::try_from(&element)
+    }
+}
+
+impl IntoIterator for Patch {
+    type Item = Change;
+    type IntoIter = std::vec::IntoIter<Change>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Patch {
+    type Item = &'a Change;
+    type IntoIter = std::slice::Iter<'a, Change>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.iter()
+    }
+}
+
+impl Display for Patch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+fn diff_elements(changes: &mut Vec<Change>, path: ValuePath, old: &Element, new: &Element) {
+    if old.ion_eq(new) {
+        return;
+    }
+    // Only non-null containers of the same type are compared recursively. Anything else is
+    // reported as a replacement of the whole value.
+    if old.ion_type() != new.ion_type() || old.is_null() || new.is_null() {
+        changes.push(Change::Changed {
+            path,
+            old: old.clone(),
+            new: new.clone(),
+        });
+        return;
+    }
+    match (
+        old.as_struct(),
+        new.as_struct(),
+        old.as_sequence(),
+        new.as_sequence(),
+    ) {
+        (Some(old_fields), Some(new_fields), _, _) => {
+            diff_annotations(changes, &path, old, new);
+            diff_structs(changes, &path, old_fields, new_fields);
+        }
+        (_, _, Some(old_elements), Some(new_elements)) => {
+            diff_annotations(changes, &path, old, new);
+            diff_sequences(changes, &path, old_elements, new_elements);
+        }
+        _ => changes.push(Change::Changed {
+            path,
+            old: old.clone(),
+            new: new.clone(),
+        }),
+    }
+}
+
+fn diff_annotations(changes: &mut Vec<Change>, path: &ValuePath, old: &Element, new: &Element) {
+    if old.annotations() != new.annotations() {
+        changes.push(Change::AnnotationsChanged {
+            path: path.clone(),
+            old: old.annotations().clone(),
+            new: new.annotations().clone(),
+        });
+    }
+}
+
+fn diff_structs(changes: &mut Vec<Change>, path: &ValuePath, old: &Struct, new: &Struct) {
+    // Group each struct's values by field name, remembering the order in which names first
+    // appeared so that the output is deterministic.
+    let mut names: Vec<&Symbol> = Vec::new();
+    let mut groups: HashMap<&Symbol, (Vec<&Element>, Vec<&Element>)> = HashMap::new();
+    for (name, value) in old.fields() {
+        groups
+            .entry(name)
+            .or_insert_with(|| {
+                names.push(name);
+                Default::default()
+            })
+            .0
+            .push(value);
+    }
+    for (name, value) in new.fields() {
+        groups
+            .entry(name)
+            .or_insert_with(|| {
+                names.push(name);
+                Default::default()
+            })
+            .1
+            .push(value);
+    }
+
+    for name in names {
+        let (old_values, new_values) = &groups[name];
+        let field_path = path.child(PathComponent::Field(name.clone()));
+        if let ([old_value], [new_value]) = (old_values.as_slice(), new_values.as_slice()) {
+            diff_elements(changes, field_path, old_value, new_value);
+            continue;
+        }
+        // The name is repeated or missing on at least one side. Values are matched as a multiset;
+        // the unmatched values on each side are paired up in order as replacements, and any that
+        // remain are removals or additions.
+        let mut unmatched_new: Vec<&Element> = new_values.clone();
+        let mut unmatched_old: Vec<&Element> = Vec::new();
+        for old_value in old_values {
+            match unmatched_new.iter().position(|v| v.ion_eq(old_value)) {
+                Some(index) => {
+                    unmatched_new.remove(index);
+                }
+                None => unmatched_old.push(old_value),
+            }
+        }
+        let paired = unmatched_old.len().min(unmatched_new.len());
+        for (old_value, new_value) in unmatched_old.iter().zip(unmatched_new.iter()) {
+            changes.push(Change::Changed {
+                path: field_path.clone(),
+                old: (*old_value).clone(),
+                new: (*new_value).clone(),
+            });
+        }
+        for old_value in &unmatched_old[paired..] {
+            changes.push(Change::Removed {
+                path: field_path.clone(),
+                old: (*old_value).clone(),
+            });
+        }
+        for new_value in &unmatched_new[paired..] {
+            changes.push(Change::Added {
+                path: field_path.clone(),
+                new: (*new_value).clone(),
+            });
+        }
+    }
+}
+
+/// The largest longest-common-subsequence table that [`diff_sequences`] will build, in cells.
+/// This bounds its memory use to a few megabytes.
+const MAX_ALIGNMENT_CELLS: usize = 1 << 20;
+
+fn diff_sequences(changes: &mut Vec<Change>, path: &ValuePath, old: &Sequence, new: &Sequence) {
+    let old: Vec<&Element> = old.iter().collect();
+    let new: Vec<&Element> = new.iter().collect();
+
+    // Elements shared by both sequences at the start and end do not need to be aligned.
+    let prefix = old
+        .iter()
+        .zip(new.iter())
+        .take_while(|(o, n)| o.ion_eq(n))
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(o, n)| o.ion_eq(n))
+        .count();
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    // `index` tracks the position in the sequence as it is being patched: removals leave it
+    // unchanged while everything else advances it.
+    let mut index = prefix;
+    let alignment_cells = (old_middle.len() + 1).saturating_mul(new_middle.len() + 1);
+    if alignment_cells > MAX_ALIGNMENT_CELLS {
+        // Too large to align; pair the elements up by position instead.
+        let mut removed = old_middle.to_vec();
+        let mut added = new_middle.to_vec();
+        flush_sequence_run(changes, path, &mut index, &mut removed, &mut added);
+        return;
+    }
+
+    // `lcs[i][j]` is the length of the longest common subsequence of `old_middle[i..]` and
+    // `new_middle[j..]`.
+    let mut lcs = vec![vec![0usize; new_middle.len() + 1]; old_middle.len() + 1];
+    for i in (0..old_middle.len()).rev() {
+        for j in (0..new_middle.len()).rev() {
+            lcs[i][j] = if old_middle[i].ion_eq(new_middle[j]) {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    // Walk the alignment.
+    let (mut i, mut j) = (0, 0);
+    let mut removed: Vec<&Element> = Vec::new();
+    let mut added: Vec<&Element> = Vec::new();
+    loop {
+        let at_end = i == old_middle.len() && j == new_middle.len();
+        let is_match = !at_end
+            && i < old_middle.len()
+            && j < new_middle.len()
+            && old_middle[i].ion_eq(new_middle[j]);
+        if at_end || is_match {
+            flush_sequence_run(changes, path, &mut index, &mut removed, &mut added);
+            if at_end {
+                break;
+            }
+            index += 1;
+            i += 1;
+            j += 1;
+        } else if j == new_middle.len() || (i < old_middle.len() && lcs[i + 1][j] >= lcs[i][j + 1])
+        {
+            removed.push(old_middle[i]);
+            i += 1;
+        } else {
+            added.push(new_middle[j]);
+            j += 1;
+        }
+    }
+}
+
+/// Reports a run of unaligned sequence elements. Removed and added elements are paired up in order
+/// and compared recursively; any that remain are reported as removals or additions.
+fn flush_sequence_run(
+    changes: &mut Vec<Change>,
+    path: &ValuePath,
+    index: &mut usize,
+    removed: &mut Vec<&Element>,
+    added: &mut Vec<&Element>,
+) {
+    let paired = removed.len().min(added.len());
+    for (old_value, new_value) in removed.iter().zip(added.iter()) {
+        diff_elements(
+            changes,
+            path.child(PathComponent::Index(*index)),
+            old_value,
+            new_value,
+        );
+        *index += 1;
+    }
+    for old_value in &removed[paired..] {
+        changes.push(Change::Removed {
+            path: path.child(PathComponent::Index(*index)),
+            old: (*old_value).clone(),
+        });
+    }
+    for new_value in &added[paired..] {
+        changes.push(Change::Added {
+            path: path.child(PathComponent::Index(*index)),
+            new: (*new_value).clone(),
+        });
+        *index += 1;
+    }
+    removed.clear();
+    added.clear();
+}
+
+fn required_field<'a>(fields: &'a Struct, name: &str) -> IonResult<&'a Element> {
+    match fields.get(name) {
+        Some(value) => Ok(value),
+        None => IonResult::decoding_error(format!("patch change is missing the '{name}' field")),
+    }
+}
+
+fn annotations_to_element(annotations: &Annotations) -> Element {
+    Element::from(List::from(Sequence::new(
+        annotations.iter().map(|a| Element::symbol(a.clone())),
+    )))
+}
+
+fn annotations_from_element(element: &Element) -> IonResult<Annotations> {
+    element
+        .expect_list()?
+        .iter()
+        .map(|annotation| annotation.expect_symbol().cloned())
+        .collect::<IonResult<Vec<Symbol>>>()
+        .map(Annotations::from)
+}
+
+fn expect_matches(value: &Element, expected: &Element, change: &Change) -> IonResult<()> {
+    if value.ion_eq(expected) {
+        return Ok(());
+    }
+    IonResult::illegal_operation(format!("cannot apply '{change}': found {value} instead"))
+}
+
+fn expect_annotations(value: &Element, expected: &Annotations, change: &Change) -> IonResult<()> {
+    if value.annotations() == expected {
+        return Ok(());
+    }
+    IonResult::illegal_operation(format!("cannot apply '{change}': found {value} instead"))
+}
+
+fn missing_path(change: &Change) -> IonError {
+    IonError::illegal_operation(format!("cannot apply '{change}': the path does not exist"))
+}
+
+/// A value that [`assert_ion_eq!`](crate::assert_ion_eq) can compare: an [`Element`] or Ion text
+/// containing exactly one value.
+pub trait IonEqOperand {
+    /// Returns the value to compare, parsing it if necessary.
+    fn to_element(&self) -> IonResult<Cow<'_, Element>>;
+}
+
+impl IonEqOperand for Element {
+    fn to_element(&self) -> IonResult<Cow<'_, Element>> {
+        Ok(Cow::Borrowed(self))
+    }
+}
+
+impl IonEqOperand for str {
+    fn to_element(&self) -> IonResult<Cow<'_, Element>> {
+        Element::read_one(self).map(Cow::Owned)
+    }
+}
+
+impl IonEqOperand for String {
+    fn to_element(&self) -> IonResult<Cow<'_, Element>> {
+        self.as_str().to_element()
+    }
+}
+
+impl<T: IonEqOperand + ?Sized> IonEqOperand for &T {
+    fn to_element(&self) -> IonResult<Cow<'_, Element>> {
+        (**self).to_element()
+    }
+}
+
+/// Panics if `left` and `right` are not Ion-equivalent (see [`IonData`](crate::IonData)),
+/// describing where they differ. This is the function behind
+/// [`assert_ion_eq!`](crate::assert_ion_eq), which should usually be preferred.
+///
+/// The panic message lists each difference with its path; values only found on the left are
+/// prefixed with `-`, and values only found on the right with `+`. The output is only colorized
+/// if standard error is a terminal and the `NO_COLOR` environment variable is not set.
+#[track_caller]
+pub fn assert_ion_eq<L: IonEqOperand + ?Sized, R: IonEqOperand + ?Sized>(left: &L, right: &R) {
+    assert_ion_eq_impl(left, right, None)
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_ion_eq_with_message<L: IonEqOperand + ?Sized, R: IonEqOperand + ?Sized>(
+    left: &L,
+    right: &R,
+    message: std::fmt::Arguments<'_>,
+) {
+    assert_ion_eq_impl(left, right, Some(message))
+}
+
+#[track_caller]
+fn assert_ion_eq_impl<L: IonEqOperand + ?Sized, R: IonEqOperand + ?Sized>(
+    left: &L,
+    right: &R,
+    message: Option<std::fmt::Arguments<'_>>,
+) {
+    let left = left
+        .to_element()
+        .unwrap_or_else(|e| panic!("left operand is not a single Ion value: {e}"));
+    let right = right
+        .to_element()
+        .unwrap_or_else(|e| panic!("right operand is not a single Ion value: {e}"));
+    let use_color = std::io::stderr().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+    if let Some(differences) = describe_differences(&left, &right, use_color) {
+        match message {
+            Some(message) => {
+                panic!("assertion `left ion_eq right` failed: {message}\n{differences}")
+            }
+            None => panic!("assertion `left ion_eq right` failed\n{differences}"),
+        }
+    }
+}
+
+/// Describes each difference between `left` and `right`, or returns `None` if they are
+/// Ion-equivalent.
+fn describe_differences(left: &Element, right: &Element, use_color: bool) -> Option<String> {
+    let patch = diff(left, right);
+    if patch.is_empty() {
+        return None;
+    }
+    let paint = |ansi_code: &str, text: &dyn Display| {
+        if use_color {
+            format!("\x1b[{ansi_code}m{text}\x1b[0m")
+        } else {
+            text.to_string()
+        }
+    };
+    let removed = |text: &dyn Display| paint("31", &format_args!("- {text}"));
+    let added = |text: &dyn Display| paint("32", &format_args!("+ {text}"));
+
+    let mut output = String::new();
+    let count = patch.len();
+    let noun = if count == 1 {
+        "difference"
+    } else {
+        "differences"
+    };
+    // Writing to a String cannot fail.
+    let _ = writeln!(output, "{count} {noun} (- left, + right):");
+    for change in &patch {
+        let path = paint("1", change.path());
+        let _ = match change {
+            Change::Added { new, .. } => writeln!(output, "  at {path}:\n    {}", added(new)),
+            Change::Removed { old, .. } => writeln!(output, "  at {path}:\n    {}", removed(old)),
+            Change::Changed { old, new, .. } => writeln!(
+                output,
+                "  at {path}:\n    {}\n    {}",
+                removed(old),
+                added(new)
+            ),
+            Change::AnnotationsChanged { old, new, .. } => writeln!(
+                output,
+                "  at {path} (annotations):\n    {}\n    {}",
+                removed(&DisplayAnnotations(old)),
+                added(&DisplayAnnotations(new))
+            ),
+        };
+    }
+    Some(output)
+}
+
+struct DisplayAnnotations<'a>(&'a Annotations);
+
+impl Display for DisplayAnnotations<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_annotations(f, self.0)
+    }
+}
+
+/// Asserts that two values are Ion-equivalent (see [`IonData`](crate::IonData)). Each operand may
+/// be an [`Element`](crate::Element) or Ion text containing a single value.
+///
+/// Unlike `assert_eq!`, a failure does not print both values in full. Instead, the panic message
+/// lists only the places where they differ, each annotated with its path. As with `assert_eq!`,
+/// a custom message can follow the operands.
+///
+/// ```
+/// use ion_rs::{assert_ion_eq, Element};
+///
+/// let element = Element::read_one("{name: \"widget\", sizes: [1, 2]}").unwrap();
+/// // Field order does not matter in Ion structs.
+/// assert_ion_eq!(element, r#"{sizes: [1, 2], name: "widget"}"#);
+/// assert_ion_eq!("[1, 2]", element.as_struct().unwrap().get("sizes").unwrap());
+/// ```
+///
+/// ```should_panic
+/// use ion_rs::assert_ion_eq;
+/// // Panics with:
+/// //   assertion `left ion_eq right` failed: sizes changed
+/// //   1 difference (- left, + right):
+/// //     at $.sizes[1]:
+/// //       - 2
+/// //       + 3
+/// assert_ion_eq!("{sizes: [1, 2]}", "{sizes: [1, 3]}", "sizes changed");
+/// ```
+#[macro_export]
+macro_rules! assert_ion_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::diff::assert_ion_eq(&$left, &$right)
+    };
+    ($left:expr, $right:expr, $($message:tt)+) => {
+        $crate::diff::assert_ion_eq_with_message(&$left, &$right, format_args!($($message)+))
+    };
+}
+
+#[cfg(test)]
+mod diff_tests {
+    use rstest::*;
+
+    use super::*;
+
+    fn element(text: &str) -> Element {
+        Element::read_one(text).unwrap()
+    }
+
+    #[rstest]
+    #[case::equal("{a: 1, b: [2, 3]}", "{b: [2, 3], a: 1}", &[])]
+    #[case::scalar("5", "6", &["~ $: 5 -> 6"])]
+    #[case::type_change("{a: 1}", r#"{a: "1"}"#, &[r#"~ $.a: 1 -> "1""#])]
+    #[case::null_container("{a: null.list}", "{a: []}", &["~ $.a: null.list -> []"])]
+    #[case::scalar_annotations("a::1", "b::1", &["~ $: a::1 -> b::1"])]
+    #[case::container_annotations("a::[1]", "b::[2]", &["@ $: ['a'] -> ['b']", "~ $[0]: 1 -> 2"])]
+    #[case::field_added("{a: 1}", "{a: 1, b: 2}", &["+ $.b: 2"])]
+    #[case::field_removed("{a: 1, b: 2}", "{a: 1}", &["- $.b: 2"])]
+    #[case::nested("{a: {b: {c: 1, d: 2}}}", "{a: {b: {c: 1, d: 3}}}", &["~ $.a.b.d: 2 -> 3"])]
+    #[case::repeated_name_reordered("{a: 1, a: 2}", "{a: 2, a: 1}", &[])]
+    #[case::repeated_name_changed("{a: 1, a: 2, a: 3}", "{a: 3, a: 4}", &["~ $.a: 1 -> 4", "- $.a: 2"])]
+    #[case::repeated_name_added("{a: 1}", "{a: 1, a: 1}", &["+ $.a: 1"])]
+    #[case::sequence_insert("[1, 2, 3]", "[0, 1, 2, 2.5, 3]", &["+ $[0]: 0", "+ $[3]: 2.5"])]
+    #[case::sequence_remove("[1, 2, 3, 4]", "[2, 4]", &["- $[0]: 1", "- $[1]: 3"])]
+    #[case::sequence_change("(a {x: 1} c)", "(a {x: 2} c)", &["~ $[1].x: 1 -> 2"])]
+    #[case::sequence_mixed("[1, 2, 3]", "[1, 3, 4]", &["- $[1]: 2", "+ $[2]: 4"])]
+    #[case::list_to_sexp("[1]", "(1)", &["~ $: [1] -> (1)"])]
+    fn diff_changes(#[case] old: &str, #[case] new: &str, #[case] expected: &[&str]) {
+        let (old, new) = (element(old), element(new));
+        let patch = diff(&old, &new);
+        let actual: Vec<String> = patch.changes().iter().map(Change::to_string).collect();
+        assert_eq!(actual, expected);
+        assert_eq!(patch.is_empty(), old.ion_eq(&new));
+
+        // Applying the patch must produce the new value...
+        let mut patched = old.clone();
+        patch.apply(&mut patched).unwrap();
+        assert!(patched.ion_eq(&new), "{patched} != {new}");
+
+        // ...and so must applying the patch after a round trip through its Ion encoding.
+        let patch = Patch::try_from(&patch.to_element()).unwrap();
+        let mut patched = old;
+        patch.apply(&mut patched).unwrap();
+        assert!(patched.ion_eq(&new), "{patched} != {new}");
+    }
+
+    #[test]
+    fn large_sequences_are_paired_by_position() {
+        // These lists share no elements, so aligning them would need a table with ~10^10 cells.
+        let list = |values: std::ops::Range<i64>| {
+            Element::from(List::from(Sequence::new(values.map(Element::from))))
+        };
+        let old = list(0..100_000);
+        let new = list(-100_001..0);
+        let patch = diff(&old, &new);
+        assert_eq!(patch.changes().len(), 100_001);
+        assert_eq!(patch.changes()[1].to_string(), "~ $[1]: 1 -> -100000");
+        assert_eq!(patch.changes()[100_000].to_string(), "+ $[100000]: -1");
+
+        let mut patched = old;
+        patch.apply(&mut patched).unwrap();
+        assert!(patched.ion_eq(&new));
+    }
+
+    #[test]
+    fn patch_format() -> IonResult<()> {
+        let patch = diff(
+            &element("{a: x::[1, 2], b: 1, c: 3}"),
+            &element("{a: y::[1], b: 2, d: 4}"),
+        );
+        let expected = element(
+            r#"[
+                {op: annotate, path: [a], old: [x], new: [y]},
+                {op: remove, path: [a, 1], old: 2},
+                {op: change, path: [b], old: 1, new: 2},
+                {op: remove, path: [c], old: 3},
+                {op: add, path: [d], new: 4},
+            ]"#,
+        );
+        assert_eq!(patch.to_element(), expected);
+        assert_eq!(Patch::try_from(expected)?, patch);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::not_a_list("{op: add, path: [], new: 1}")]
+    #[case::unknown_op("[{op: move, path: [a], new: 1}]")]
+    #[case::missing_value("[{op: add, path: [a]}]")]
+    #[case::bad_path("[{op: add, path: [-1], new: 1}]")]
+    #[case::bad_annotations("[{op: annotate, path: [], old: [], new: [\"a\"]}]")]
+    fn invalid_patches(#[case] text: &str) {
+        assert!(Patch::try_from(element(text)).is_err());
+    }
+
+    #[rstest]
+    #[case::stale_value("{a: 2}", "[{op: change, path: [a], old: 1, new: 3}]")]
+    #[case::stale_removal("[1, 2]", "[{op: remove, path: [1], old: 3}]")]
+    #[case::missing_parent("{a: 1}", "[{op: add, path: [b, c], new: 1}]")]
+    #[case::index_out_of_bounds("[1]", "[{op: add, path: [2], new: 1}]")]
+    #[case::add_root("1", "[{op: add, path: [], new: 1}]")]
+    #[case::stale_annotations("a::[]", "[{op: annotate, path: [], old: [b], new: [c]}]")]
+    fn inapplicable_patches(#[case] target: &str, #[case] patch: &str) -> IonResult<()> {
+        let patch = Patch::try_from(element(patch))?;
+        assert!(patch.apply(&mut element(target)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn describe_differences_output() {
+        let left = element("{a: x::[1, 2], b: 1, c: 3}");
+        let right = element("{a: y::[1, 2], b: 2, d: 4}");
+        let expected = "\
+4 differences (- left, + right):
+  at $.a (annotations):
+    - ['x']
+    + ['y']
+  at $.b:
+    - 1
+    + 2
+  at $.c:
+    - 3
+  at $.d:
+    + 4
+";
+        assert_eq!(
+            describe_differences(&left, &right, false).unwrap(),
+            expected
+        );
+        assert_eq!(describe_differences(&left, &left, false), None);
+
+        let colorized = describe_differences(&left, &right, true).unwrap();
+        assert!(colorized
+            .contains("at \x1b[1m$.b\x1b[0m:\n    \x1b[31m- 1\x1b[0m\n    \x1b[32m+ 2\x1b[0m"));
+    }
+
+    #[test]
+    fn assert_ion_eq_accepts_text_and_elements() {
+        let value = element("{a: 1, b: [true]}");
+        crate::assert_ion_eq!(value, "{b: [true], a: 1}");
+        crate::assert_ion_eq!("{b: [true], a: 1}".to_string(), &value);
+        crate::assert_ion_eq!(value.clone(), value, "comparing {} to itself", "value");
+    }
+
+    #[rstest]
+    #[case::different_values("[1]", "[2]", "1 difference")]
+    #[case::invalid_left("{", "1", "left operand is not a single Ion value")]
+    #[case::multiple_values("1", "1 2", "right operand is not a single Ion value")]
+    fn assert_ion_eq_failures(#[case] left: &str, #[case] right: &str, #[case] message: &str) {
+        let result = std::panic::catch_unwind(|| assert_ion_eq(left, right));
+        let panic = result.expect_err("assertion should have failed");
+        let panic_message = panic.downcast_ref::<String>().unwrap();
+        assert!(panic_message.contains(message), "{panic_message}");
+    }
+}
@@ -103,6 +103,59 @@ impl Annotations {
     pub fn first(&self) -> Option<&str> {
         self.iter().next().and_then(|a| a.text())
     }
+
+    /// Appends an annotation to the end of this sequence.
+    /// ```
+    /// use ion_rs::{Annotations, IntoAnnotations};
+    /// let mut annotations: Annotations = ["foo", "bar"].into_annotations();
+    /// annotations.push("baz");
+    /// assert_eq!(annotations, ["foo", "bar", "baz"].into_annotations());
+    /// ```
+    pub fn push<S: Into<Symbol>>(&mut self, annotation: S) {
+        self.symbols.push(annotation.into())
+    }
+
+    /// Inserts an annotation at position `index`, shifting all of the annotations after it to
+    /// the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    /// ```
+    /// use ion_rs::{Annotations, IntoAnnotations};
+    /// let mut annotations: Annotations = ["bar", "baz"].into_annotations();
+    /// annotations.insert(0, "foo");
+    /// assert_eq!(annotations, ["foo", "bar", "baz"].into_annotations());
+    /// ```
+    pub fn insert<S: Into<Symbol>>(&mut self, index: usize, annotation: S) {
+        self.symbols.insert(index, annotation.into())
+    }
+
+    /// Removes and returns the annotation at position `index`, shifting all of the annotations
+    /// after it to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Symbol {
+        self.symbols.remove(index)
+    }
+
+    /// Retains only the annotations for which `predicate` returns `true`.
+    /// ```
+    /// use ion_rs::{Annotations, IntoAnnotations};
+    /// let mut annotations: Annotations = ["foo", "bar", "foo"].into_annotations();
+    /// annotations.retain(|annotation| annotation.text() != Some("foo"));
+    /// assert_eq!(annotations, ["bar"].into_annotations());
+    /// ```
+    pub fn retain<F: FnMut(&Symbol) -> bool>(&mut self, predicate: F) {
+        self.symbols.retain(predicate)
+    }
+
+    /// Removes all of the annotations from this sequence.
+    pub fn clear(&mut self) {
+        self.symbols.clear()
+    }
 }
 
 impl AsRef<[Symbol]> for Annotations {
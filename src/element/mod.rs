@@ -26,10 +26,12 @@ use crate::element::builders::{SequenceBuilder, StructBuilder};
 use crate::element::reader::ElementReader;
 use crate::ion_data::{IonEq, IonOrd};
 use crate::lazy::any_encoding::AnyEncoding;
-use crate::lazy::encoding::Encoding;
+use crate::lazy::encoder::Encoder;
+use crate::lazy::encoding::TextEncoding_1_0;
 use crate::lazy::reader::Reader;
 use crate::lazy::streaming_raw_reader::{IonInput, IonSlice};
 use crate::result::IonFailure;
+use crate::text::text_format_config::TextFormatConfig;
 use crate::text::text_formatter::FmtValueFormatter;
 use crate::write_config::WriteConfig;
 
@@ -149,23 +151,9 @@ impl Value {
 
 impl Display for Value {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut ivf = FmtValueFormatter { output: f };
-        match &self {
-            Value::Null(ion_type) => ivf.format_null(*ion_type),
-            Value::Bool(bool) => ivf.format_bool(*bool),
-            Value::Int(integer) => ivf.format_integer(integer),
-            Value::Float(float) => ivf.format_float(*float),
-            Value::Decimal(decimal) => ivf.format_decimal(decimal),
-            Value::Timestamp(timestamp) => ivf.format_timestamp(timestamp),
-            Value::Symbol(symbol) => ivf.format_symbol(symbol),
-            Value::String(string) => ivf.format_string(string),
-            Value::Clob(clob) => ivf.format_clob(clob),
-            Value::Blob(blob) => ivf.format_blob(blob),
-            Value::List(sequence) => ivf.format_list(sequence),
-            Value::SExp(sequence) => ivf.format_sexp(sequence),
-            Value::Struct(struct_) => ivf.format_struct(struct_),
-        }
-        .map_err(|_| std::fmt::Error)?;
+        FmtValueFormatter::new(f)
+            .format_value(self)
+            .map_err(|_| std::fmt::Error)?;
 
         Ok(())
     }
@@ -379,6 +367,20 @@ impl Element {
         &self.value
     }
 
+    /// Returns a mutable reference to this [Element]'s [Value]. This can be used to modify the
+    /// value in place or to replace it with a value of a different type. The [Element]'s
+    /// annotations are unaffected.
+    ///
+    /// ```
+    /// use ion_rs::{Element, Value};
+    /// let mut element = Element::read_one("foo::5").unwrap();
+    /// *element.value_mut() = Value::String("hello".into());
+    /// assert_eq!(element, Element::read_one(r#"foo::"hello""#).unwrap());
+    /// ```
+    pub fn value_mut(&mut self) -> &mut Value {
+        &mut self.value
+    }
+
     pub fn null(null_type: IonType) -> Element {
         null_type.into()
     }
@@ -440,10 +442,42 @@ impl Element {
         &self.annotations
     }
 
+    /// Returns a mutable reference to this [Element]'s [Annotations].
+    ///
+    /// ```
+    /// use ion_rs::Element;
+    /// let mut element = Element::read_one("foo::bar::5").unwrap();
+    /// element.annotations_mut().remove(0);
+    /// element.annotations_mut().push("baz");
+    /// assert_eq!(element, Element::read_one("bar::baz::5").unwrap());
+    /// ```
+    pub fn annotations_mut(&mut self) -> &mut Annotations {
+        &mut self.annotations
+    }
+
     pub fn with_annotations<I: IntoAnnotations>(self, annotations: I) -> Self {
         Element::new(annotations.into_annotations(), self.value)
     }
 
+    /// Returns the [`Patch`](crate::diff::Patch) that transforms this [Element] into `other`. See
+    /// [`diff`](crate::diff::diff) for details.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Element;
+    /// let old = Element::read_one("{a: 1, b: [true]}")?;
+    /// let new = Element::read_one("{a: 1, b: [true, false]}")?;
+    /// let patch = old.diff(&new);
+    /// assert_eq!(patch.len(), 1);
+    /// assert_eq!(patch.changes()[0].to_string(), "+ $.b[1]: false");
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn diff(&self, other: &Element) -> crate::diff::Patch {
+        crate::diff::diff(self, other)
+    }
+
     pub fn is_null(&self) -> bool {
         matches!(&self.value, Value::Null(_))
     }
@@ -486,7 +520,7 @@ impl Element {
 
     pub fn as_decimal(&self) -> Option<Decimal> {
         match &self.value {
-            Value::Decimal(d) => Some(*d),
+            Value::Decimal(d) => Some(d.clone()),
             _ => None,
         }
     }
@@ -498,7 +532,7 @@ impl Element {
 
     pub fn as_timestamp(&self) -> Option<Timestamp> {
         match &self.value {
-            Value::Timestamp(t) => Some(*t),
+            Value::Timestamp(t) => Some(t.clone()),
             _ => None,
         }
     }
@@ -605,6 +639,13 @@ impl Element {
         }
     }
 
+    pub fn as_sequence_mut(&mut self) -> Option<&mut Sequence> {
+        match &mut self.value {
+            Value::SExp(s) | Value::List(s) => Some(s),
+            _ => None,
+        }
+    }
+
     pub fn expect_sequence(&self) -> IonResult<&Sequence> {
         self.as_sequence().ok_or_else(|| {
             IonError::decoding_error(format!(
@@ -643,6 +684,13 @@ impl Element {
         }
     }
 
+    pub fn as_struct_mut(&mut self) -> Option<&mut Struct> {
+        match &mut self.value {
+            Value::Struct(structure) => Some(structure),
+            _ => None,
+        }
+    }
+
     pub fn expect_struct(&self) -> IonResult<&Struct> {
         self.as_struct()
             .ok_or_else(|| self.expected(IonType::Struct))
@@ -709,7 +757,7 @@ impl Element {
     ///# Ok(())
     ///# }
     /// ```
-    pub fn encode_as<E: Encoding, C: Into<WriteConfig<E>>>(
+    pub fn encode_as<E: Encoder, C: Into<WriteConfig<E>>>(
         &self,
         config: C,
     ) -> IonResult<E::Output> {
@@ -740,24 +788,70 @@ impl Element {
     ///# Ok(())
     ///# }
     /// ```
-    pub fn encode_to<E: Encoding, C: Into<WriteConfig<E>>, W: io::Write>(
+    pub fn encode_to<E: Encoder, C: Into<WriteConfig<E>>, W: io::Write>(
         &self,
         output: W,
         config: C,
     ) -> IonResult<W> {
         config.into().encode_to(self, output)
     }
+
+    /// Returns a value that implements [`Display`] by writing this element as Ion text using the
+    /// layout and styles described by `config`. (`Element`'s own `Display` implementation
+    /// always writes the element on a single line.)
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Element, TextFormat, TextFormatConfig};
+    ///
+    /// let element = Element::read_one(r#"{name: "Ion", tags: [fast, "self-describing"]}"#)?;
+    /// let config = TextFormatConfig::new(TextFormat::Pretty)
+    ///     .with_indentation_width(4)
+    ///     .with_trailing_commas(false);
+    /// assert_eq!(
+    ///     element.display_with(config).to_string(),
+    ///     "{\n    name: \"Ion\",\n    tags: [\n        fast,\n        \"self-describing\"\n    ]\n}"
+    /// );
+    ///
+    /// // Short containers can be kept on a single line.
+    /// let config = config.with_max_line_width(40);
+    /// assert_eq!(
+    ///     element.display_with(config).to_string(),
+    ///     "{\n    name: \"Ion\",\n    tags: [fast, \"self-describing\"]\n}"
+    /// );
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn display_with(&self, config: impl Into<TextFormatConfig>) -> impl Display + '_ {
+        ElementDisplay {
+            element: self,
+            config: config.into(),
+        }
+    }
 }
 
-impl Display for Element {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        let mut ivf = FmtValueFormatter { output: f };
+/// Formats an [`Element`] using a [`TextFormatConfig`]. See [`Element::display_with`].
+struct ElementDisplay<'a> {
+    element: &'a Element,
+    config: TextFormatConfig,
+}
 
-        // display for annotations of this element
-        ivf.format_annotations(&self.annotations)
+impl<'a> Display for ElementDisplay<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let text = WriteConfig::<TextEncoding_1_0>::new(self.config)
+            .encode(self.element)
             .map_err(|_| std::fmt::Error)?;
+        // The writer follows each top-level value with whitespace, which we don't need here.
+        f.write_str(text.trim_end())
+    }
+}
 
-        self.value.fmt(f)
+impl Display for Element {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        FmtValueFormatter::new(f)
+            .format_element(self)
+            .map_err(|_| std::fmt::Error)
     }
 }
 
@@ -1478,7 +1572,7 @@ mod value_tests {
         I: TryInto<Int, Error = E>,
     {
         let int: Int = source_int.try_into().unwrap();
-        let element: Element = int.into();
+        let element: Element = int.clone().into();
         assert_eq!(element.expect_i64(), int.expect_i64())
     }
 
@@ -1503,4 +1597,30 @@ mod value_tests {
         assert_eq!(actual_text, input);
         Ok(())
     }
+
+    #[test]
+    fn edit_element_tree_in_place() -> IonResult<()> {
+        let mut element = Element::read_one(r#"{items: [1, 2, 3], meta: tag::{count: 3}}"#)?;
+        let root = element.as_struct_mut().unwrap();
+
+        let items = root.get_mut("items").unwrap().as_sequence_mut().unwrap();
+        items.remove(0);
+        items.push(4);
+        items.insert(0, "first");
+        *items.get_mut(1).unwrap().value_mut() = Value::Bool(true);
+
+        let meta = root.get_mut("meta").unwrap();
+        meta.annotations_mut().clear();
+        meta.annotations_mut().push("edited");
+        meta.as_struct_mut().unwrap().insert("updated", true);
+
+        let expected = Element::read_one(
+            r#"{items: ["first", true, 3, 4], meta: edited::{count: 3, updated: true}}"#,
+        )?;
+        assert_eq!(element, expected);
+        assert_eq!(element.as_struct_mut().map(|s| s.len()), Some(2));
+        assert!(Element::int(5).as_struct_mut().is_none());
+        assert!(Element::int(5).as_sequence_mut().is_none());
+        Ok(())
+    }
 }
@@ -75,6 +75,12 @@ pub struct ElementIterator<'a, R: ElementReader + ?Sized> {
     reader: &'a mut R,
 }
 
+impl<'a, R: ElementReader + ?Sized> ElementIterator<'a, R> {
+    pub(crate) fn new(reader: &'a mut R) -> Self {
+        Self { reader }
+    }
+}
+
 impl<'a, R: ElementReader + ?Sized> Iterator for ElementIterator<'a, R> {
     type Item = IonResult<Element>;
 
@@ -2,7 +2,7 @@ use crate::element::builders::SequenceBuilder;
 use crate::element::iterators::SequenceIterator;
 use crate::element::Element;
 use crate::ion_data::{IonEq, IonOrd};
-use crate::lazy::encoding::Encoding;
+use crate::lazy::encoder::Encoder;
 use crate::write_config::WriteConfig;
 use crate::IonResult;
 use std::cmp::Ordering;
@@ -68,6 +68,68 @@ impl Sequence {
         self.elements()
     }
 
+    /// Returns a mutable reference to the element at `index`, or `None` if `index` is out of
+    /// bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Element> {
+        self.elements.get_mut(index)
+    }
+
+    /// Returns an iterator that allows each of the elements in this sequence to be modified.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Element> {
+        self.elements.iter_mut()
+    }
+
+    /// Appends an element to the end of this sequence.
+    ///
+    /// ```
+    /// use ion_rs::{ion_list, Element};
+    /// let mut list = ion_list![1, 2];
+    /// list.push(3);
+    /// assert_eq!(list, ion_list![1, 2, 3]);
+    /// ```
+    pub fn push<E: Into<Element>>(&mut self, element: E) {
+        self.elements.push(element.into())
+    }
+
+    /// Inserts an element at position `index`, shifting all of the elements after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// ```
+    /// use ion_rs::{ion_list, Element};
+    /// let mut list = ion_list![1, 3];
+    /// list.insert(1, 2);
+    /// assert_eq!(list, ion_list![1, 2, 3]);
+    /// ```
+    pub fn insert<E: Into<Element>>(&mut self, index: usize, element: E) {
+        self.elements.insert(index, element.into())
+    }
+
+    /// Removes and returns the element at position `index`, shifting all of the elements after it
+    /// to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// ```
+    /// use ion_rs::{ion_list, Element};
+    /// let mut list = ion_list![1, 2, 3];
+    /// assert_eq!(list.remove(0), Element::int(1));
+    /// assert_eq!(list, ion_list![2, 3]);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Element {
+        self.elements.remove(index)
+    }
+
+    /// Retains only the elements for which `predicate` returns `true`. The predicate may modify
+    /// the elements that it visits.
+    pub fn retain<F: FnMut(&mut Element) -> bool>(&mut self, predicate: F) {
+        self.elements.retain_mut(predicate)
+    }
+
     /// ```
     ///# use ion_rs::IonResult;
     ///# fn main() -> IonResult<()> {
@@ -88,7 +150,7 @@ impl Sequence {
     ///# Ok(())
     ///# }
     /// ```
-    pub fn encode_as<E: Encoding, C: Into<WriteConfig<E>>>(
+    pub fn encode_as<E: Encoder, C: Into<WriteConfig<E>>>(
         &self,
         config: C,
     ) -> IonResult<E::Output> {
@@ -116,7 +178,7 @@ impl Sequence {
     ///# Ok(())
     ///# }
     /// ```
-    pub fn encode_to<E: Encoding, C: Into<WriteConfig<E>>, W: io::Write>(
+    pub fn encode_to<E: Encoder, C: Into<WriteConfig<E>>, W: io::Write>(
         &self,
         output: W,
         config: C,
@@ -7,30 +7,29 @@ use std::io;
 
 use crate::element::Element;
 use crate::IonResult;
-use digest::{FixedOutput, Output, Reset, Update};
 
 use crate::ion_hash::representation::RepresentationEncoder;
 use crate::ion_hash::type_qualifier::TypeQualifier;
-use crate::ion_hash::Markers;
+use crate::ion_hash::{IonDigest, Markers};
 
 pub(crate) struct ElementHasher<D>
 where
-    D: Update + FixedOutput + Reset + Clone + Default,
+    D: IonDigest,
 {
     pub(crate) digest: D,
 }
 
 impl<D> ElementHasher<D>
 where
-    D: Update + FixedOutput + Reset + Clone + Default,
+    D: IonDigest,
 {
     pub(crate) fn new(digest: D) -> ElementHasher<D> {
         ElementHasher { digest }
     }
 
-    pub(crate) fn hash_element(mut self, elem: &Element) -> IonResult<Output<D>> {
+    pub(crate) fn hash_element(mut self, elem: &Element) -> IonResult<D::Output> {
         self.update_serialized_bytes(elem)?;
-        Ok(self.digest.finalize_fixed())
+        Ok(self.digest.finalize())
     }
 
     /// Implements the "serialized bytes" transform as described in the spec. The
@@ -41,12 +40,12 @@ where
         if has_annotations {
             // s(annotated value) → B || TQ || s(annotation) || s(annotation) || ... || s(annotation) || s(value) || E
             self.mark_begin();
-            self.digest.update([0xE0]);
+            self.digest.update(&[0xE0]);
             for ann in elem.annotations() {
                 self.mark_begin();
                 self.digest.update(match ann.text() {
-                    None => [0x71],
-                    Some(_) => [0x70],
+                    None => &[0x71],
+                    Some(_) => &[0x70],
                 });
                 self.write_repr_symbol(Some(ann))?;
                 self.mark_end();
@@ -66,12 +65,12 @@ where
 
     #[inline]
     pub(crate) fn mark_begin(&mut self) {
-        self.digest.update([Markers::B]);
+        self.digest.update(&[Markers::B]);
     }
 
     #[inline]
     pub(crate) fn mark_end(&mut self) {
-        self.digest.update([Markers::E]);
+        self.digest.update(&[Markers::E]);
     }
 
     pub(crate) fn update_type_qualifier_and_representation(
@@ -96,17 +95,16 @@ where
             }
         }
 
-        self.digest.update(escaped);
+        self.digest.update(&escaped);
     }
 }
 
 /// The ion-rust crate uses the `io::Write` trait as a sink for writing
 /// representations. This implementation provides compatibility with the
-/// `Digest` trait (represented as a set of "sub"-traits). We have no need of an
-/// intermediate buffer!
+/// [`IonDigest`] trait. We have no need of an intermediate buffer!
 impl<D> io::Write for ElementHasher<D>
 where
-    D: Update + FixedOutput + Reset + Clone + Default,
+    D: IonDigest,
 {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         self.update_escaping(buf);
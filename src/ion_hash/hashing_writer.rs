@@ -0,0 +1,1005 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+//! Provides [`HashingWriter`], which computes the Ion Hash of each value as it
+//! is written rather than re-reading the encoded output.
+
+use crate::ion_hash::element_hasher::ElementHasher;
+use crate::ion_hash::representation::RepresentationEncoder;
+use crate::ion_hash::type_qualifier::{
+    type_qualifier_boolean, type_qualifier_integer, type_qualifier_not_null,
+    type_qualifier_null_of, type_qualifier_symbol, TypeQualifier,
+};
+use crate::ion_hash::IonDigest;
+use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsVec};
+use crate::lazy::encoder::value_writer::internal::{FieldEncoder, MakeValueWriter};
+use crate::lazy::encoder::value_writer::{
+    AnnotatableWriter, SequenceWriter, StructWriter, ValueWriter,
+};
+use crate::lazy::encoder::value_writer_config::ValueWriterConfig;
+use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
+use crate::path::{IonPath, PathComponent, PathStep, ValuePath};
+use crate::raw_symbol_ref::AsRawSymbolRef;
+use crate::result::IonFailure;
+use crate::{Decimal, Int, IonResult, IonType, RawSymbolRef, Symbol, SymbolTable, Timestamp};
+
+/// Wraps a [`SequenceWriter`] (typically a [`Writer`](crate::Writer)) and computes the Ion Hash
+/// of each top-level value as it is written. Once a top-level value has been written, its digest
+/// is available from [`digest`](Self::digest).
+///
+/// Digests can also be computed for nested values by registering an [`IonPath`] with
+/// [`with_path`](Self::with_path). The digest of each nested value that the path matches is
+/// available from [`path_digests`](Self::path_digests) until the next top-level value is written.
+///
+/// Every digest is the same one that [`IonHasher::hash_element`](crate::ion_hash::IonHasher) would
+/// produce for the equivalent [`Element`](crate::Element). Because a hash depends on the text of
+/// each symbol, symbols, field names, and annotations are normally written as text. Symbol IDs
+/// other than `$0` can only be hashed if a [`SymbolTable`] that defines them has been provided with
+/// [`with_symbol_table`](Self::with_symbol_table). E-expressions cannot be hashed.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "ion-hash-sha2")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::ion_hash::{HashingWriter, IonHasher};
+/// use ion_rs::{v1_0, Element, IonPath, SequenceWriter, Writer};
+/// use sha2::Sha256;
+///
+/// let record = Element::read_one(r#"audit::{id: 7, event: {user: "alice", action: login}}"#)?;
+///
+/// let writer = Writer::new(v1_0::Binary, Vec::new())?;
+/// let mut writer = HashingWriter::<_, Sha256>::new(writer).with_path("$.event".parse()?);
+/// writer.write(&record)?;
+///
+/// assert_eq!(writer.digest(), Some(&Sha256::hash_element(&record)?));
+/// let (location, event_digest) = &writer.path_digests()[0];
+/// assert_eq!(location.to_string(), "$.event");
+/// let event = record.as_struct().unwrap().get("event").unwrap();
+/// assert_eq!(event_digest, &Sha256::hash_element(event)?);
+///
+/// let encoded: Vec<u8> = writer.close()?;
+/// assert_eq!(Element::read_one(encoded)?, record);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "ion-hash-sha2"))]
+///# fn main() {}
+/// ```
+pub struct HashingWriter<W, D>
+where
+    D: IonDigest,
+{
+    writer: W,
+    state: HashState<D>,
+}
+
+impl<W: SequenceWriter, D> HashingWriter<W, D>
+where
+    D: IonDigest,
+{
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            state: HashState::new(),
+        }
+    }
+
+    /// Also computes the digest of each nested value that `path` matches. Paths are evaluated
+    /// relative to each top-level value.
+    pub fn with_path(mut self, path: IonPath) -> Self {
+        self.state.paths.push(path);
+        self
+    }
+
+    /// Resolves the symbol IDs written to this writer using `symbol_table`, which should match the
+    /// wrapped writer's. A symbol ID that `symbol_table` maps to unknown text (for example, one that
+    /// belongs to an imported shared table that was not available) is hashed as a symbol with
+    /// unknown text, as is `$0`.
+    pub fn with_symbol_table(mut self, symbol_table: SymbolTable) -> Self {
+        self.state.symbol_table = Some(symbol_table);
+        self
+    }
+
+    /// Returns the digest of the most recently written top-level value, or `None` if no value has
+    /// been written yet.
+    pub fn digest(&self) -> Option<&D::Output> {
+        self.state.digest.as_ref()
+    }
+
+    /// Returns the location and digest of each nested value in the most recently written
+    /// top-level value that was matched by one of the paths registered with
+    /// [`with_path`](Self::with_path). Values appear in the order in which they were completed, so
+    /// a container follows the values nested inside of it.
+    pub fn path_digests(&self) -> &[(ValuePath, D::Output)] {
+        &self.state.path_digests
+    }
+
+    /// Returns a reference to the wrapped writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+}
+
+impl<W: SequenceWriter, D> MakeValueWriter for HashingWriter<W, D>
+where
+    D: IonDigest,
+{
+    type ValueWriter<'a>
+        = HashingValueWriter<'a, W::ValueWriter<'a>, D>
+    where
+        Self: 'a;
+
+    fn make_value_writer(&mut self) -> Self::ValueWriter<'_> {
+        HashingValueWriter::new(&mut self.state, self.writer.make_value_writer())
+    }
+}
+
+impl<W: SequenceWriter, D> SequenceWriter for HashingWriter<W, D>
+where
+    D: IonDigest,
+{
+    type Resources = W::Resources;
+
+    fn close(self) -> IonResult<Self::Resources> {
+        self.writer.close()
+    }
+}
+
+/// A [`ValueWriter`] that feeds the value it writes to the digests of a [`HashingWriter`].
+pub struct HashingValueWriter<'value, V, D>
+where
+    D: IonDigest,
+{
+    state: &'value mut HashState<D>,
+    value_writer: V,
+    annotations: AnnotationsVec<'value>,
+}
+
+impl<'value, V: ValueWriter, D> HashingValueWriter<'value, V, D>
+where
+    D: IonDigest,
+{
+    fn new(state: &'value mut HashState<D>, value_writer: V) -> Self {
+        Self {
+            state,
+            value_writer,
+            annotations: AnnotationsVec::new(),
+        }
+    }
+
+    /// Hashes a scalar value with the given type qualifier and representation.
+    fn hash_scalar(
+        &mut self,
+        tq: TypeQualifier,
+        mut write_representation: impl FnMut(&mut ElementHasher<D>) -> IonResult<()>,
+    ) -> IonResult<()> {
+        self.state.begin_value(&self.annotations, tq, None)?;
+        self.state
+            .each_hasher(|hasher| write_representation(hasher))?;
+        self.state.end_value();
+        Ok(())
+    }
+}
+
+impl<'value, V: ValueWriter, D> AnnotatableWriter for HashingValueWriter<'value, V, D>
+where
+    D: IonDigest,
+{
+    type AnnotatedValueWriter<'a>
+        = HashingValueWriter<'a, V::AnnotatedValueWriter<'a>, D>
+    where
+        Self: 'a;
+
+    fn with_annotations<'a>(
+        self,
+        annotations: impl AnnotationSeq<'a>,
+    ) -> IonResult<Self::AnnotatedValueWriter<'a>>
+    where
+        Self: 'a,
+    {
+        let annotations = annotations.into_annotations_vec();
+        Ok(HashingValueWriter {
+            state: self.state,
+            value_writer: self.value_writer.with_annotations(annotations.clone())?,
+            annotations,
+        })
+    }
+}
+
+impl<'value, V: ValueWriter, D> ValueWriter for HashingValueWriter<'value, V, D>
+where
+    D: IonDigest,
+{
+    type ListWriter = HashingSequenceWriter<'value, V::ListWriter, D>;
+    type SExpWriter = HashingSequenceWriter<'value, V::SExpWriter, D>;
+    type StructWriter = HashingStructWriter<'value, V::StructWriter, D>;
+    type EExpWriter = V::EExpWriter;
+
+    fn write_null(mut self, ion_type: IonType) -> IonResult<()> {
+        self.hash_scalar(type_qualifier_null_of(ion_type), |_| Ok(()))?;
+        self.value_writer.write_null(ion_type)
+    }
+
+    fn write_bool(mut self, value: bool) -> IonResult<()> {
+        self.hash_scalar(type_qualifier_boolean(Some(value)), |_| Ok(()))?;
+        self.value_writer.write_bool(value)
+    }
+
+    fn write_i64(self, value: i64) -> IonResult<()> {
+        self.write_int(&Int::from(value))
+    }
+
+    fn write_int(mut self, value: &Int) -> IonResult<()> {
+        self.hash_scalar(type_qualifier_integer(Some(value)), |hasher| {
+            hasher.write_repr_integer(Some(value))
+        })?;
+        self.value_writer.write_int(value)
+    }
+
+    fn write_f32(mut self, value: f32) -> IonResult<()> {
+        // Readers materialize 32-bit floats as `f64`s, so that is the value that gets hashed.
+        self.hash_scalar(type_qualifier_not_null(IonType::Float), |hasher| {
+            hasher.write_repr_float(Some(value as f64))
+        })?;
+        self.value_writer.write_f32(value)
+    }
+
+    fn write_f64(mut self, value: f64) -> IonResult<()> {
+        self.hash_scalar(type_qualifier_not_null(IonType::Float), |hasher| {
+            hasher.write_repr_float(Some(value))
+        })?;
+        self.value_writer.write_f64(value)
+    }
+
+    fn write_decimal(mut self, value: &Decimal) -> IonResult<()> {
+        self.hash_scalar(type_qualifier_not_null(IonType::Decimal), |hasher| {
+            hasher.write_repr_decimal(Some(value.clone()))
+        })?;
+        self.value_writer.write_decimal(value)
+    }
+
+    fn write_timestamp(mut self, value: &Timestamp) -> IonResult<()> {
+        self.hash_scalar(type_qualifier_not_null(IonType::Timestamp), |hasher| {
+            hasher.write_repr_timestamp(Some(value.clone()))
+        })?;
+        self.value_writer.write_timestamp(value)
+    }
+
+    fn write_string(mut self, value: impl AsRef<str>) -> IonResult<()> {
+        let text = value.as_ref();
+        self.hash_scalar(type_qualifier_not_null(IonType::String), |hasher| {
+            hasher.write_repr_string(Some(text))
+        })?;
+        self.value_writer.write_string(text)
+    }
+
+    fn write_symbol(mut self, value: impl AsRawSymbolRef) -> IonResult<()> {
+        let token = value.as_raw_symbol_ref();
+        let symbol = self.state.resolve_symbol(token)?;
+        self.hash_scalar(type_qualifier_symbol(Some(&symbol)), |hasher| {
+            hasher.write_repr_symbol(Some(&symbol))
+        })?;
+        self.value_writer.write_symbol(token)
+    }
+
+    fn write_clob(mut self, value: impl AsRef<[u8]>) -> IonResult<()> {
+        let bytes = value.as_ref();
+        self.hash_scalar(type_qualifier_not_null(IonType::Clob), |hasher| {
+            hasher.write_repr_blob(Some(bytes))
+        })?;
+        self.value_writer.write_clob(bytes)
+    }
+
+    fn write_blob(mut self, value: impl AsRef<[u8]>) -> IonResult<()> {
+        let bytes = value.as_ref();
+        self.hash_scalar(type_qualifier_not_null(IonType::Blob), |hasher| {
+            hasher.write_repr_blob(Some(bytes))
+        })?;
+        self.value_writer.write_blob(bytes)
+    }
+
+    fn list_writer(self) -> IonResult<Self::ListWriter> {
+        let list_writer = self.value_writer.list_writer()?;
+        HashingSequenceWriter::begin(self.state, &self.annotations, IonType::List, list_writer)
+    }
+
+    fn sexp_writer(self) -> IonResult<Self::SExpWriter> {
+        let sexp_writer = self.value_writer.sexp_writer()?;
+        HashingSequenceWriter::begin(self.state, &self.annotations, IonType::SExp, sexp_writer)
+    }
+
+    fn struct_writer(self) -> IonResult<Self::StructWriter> {
+        let struct_writer = self.value_writer.struct_writer()?;
+        HashingStructWriter::begin(self.state, &self.annotations, struct_writer)
+    }
+
+    fn eexp_writer<'a>(self, _macro_id: impl Into<MacroIdRef<'a>>) -> IonResult<Self::EExpWriter> {
+        IonResult::illegal_operation(
+            "cannot compute the Ion Hash of an e-expression; write its expansion instead",
+        )
+    }
+}
+
+/// A list or s-expression writer that feeds its child values to the digests of a
+/// [`HashingWriter`].
+pub struct HashingSequenceWriter<'value, S, D>
+where
+    D: IonDigest,
+{
+    state: &'value mut HashState<D>,
+    sequence_writer: S,
+}
+
+impl<'value, S: SequenceWriter<Resources = ()>, D> HashingSequenceWriter<'value, S, D>
+where
+    D: IonDigest,
+{
+    fn begin(
+        state: &'value mut HashState<D>,
+        annotations: &[RawSymbolRef<'_>],
+        ion_type: IonType,
+        sequence_writer: S,
+    ) -> IonResult<Self> {
+        state.begin_value(
+            annotations,
+            type_qualifier_not_null(ion_type),
+            Some(Container::Sequence),
+        )?;
+        Ok(Self {
+            state,
+            sequence_writer,
+        })
+    }
+}
+
+impl<'value, S: SequenceWriter<Resources = ()>, D> MakeValueWriter
+    for HashingSequenceWriter<'value, S, D>
+where
+    D: IonDigest,
+{
+    type ValueWriter<'a>
+        = HashingValueWriter<'a, S::ValueWriter<'a>, D>
+    where
+        Self: 'a;
+
+    fn make_value_writer(&mut self) -> Self::ValueWriter<'_> {
+        HashingValueWriter::new(self.state, self.sequence_writer.make_value_writer())
+    }
+}
+
+impl<'value, S: SequenceWriter<Resources = ()>, D> SequenceWriter
+    for HashingSequenceWriter<'value, S, D>
+where
+    D: IonDigest,
+{
+    type Resources = ();
+
+    fn close(self) -> IonResult<Self::Resources> {
+        self.sequence_writer.close()?;
+        self.state.end_value();
+        Ok(())
+    }
+}
+
+/// A struct writer that feeds its fields to the digests of a [`HashingWriter`].
+pub struct HashingStructWriter<'value, S, D>
+where
+    D: IonDigest,
+{
+    state: &'value mut HashState<D>,
+    struct_writer: S,
+}
+
+impl<'value, S: StructWriter, D> HashingStructWriter<'value, S, D>
+where
+    D: IonDigest,
+{
+    fn begin(
+        state: &'value mut HashState<D>,
+        annotations: &[RawSymbolRef<'_>],
+        struct_writer: S,
+    ) -> IonResult<Self> {
+        state.begin_value(
+            annotations,
+            type_qualifier_not_null(IonType::Struct),
+            Some(Container::Struct(Vec::new())),
+        )?;
+        Ok(Self {
+            state,
+            struct_writer,
+        })
+    }
+}
+
+impl<'value, S: StructWriter, D> MakeValueWriter for HashingStructWriter<'value, S, D>
+where
+    D: IonDigest,
+{
+    type ValueWriter<'a>
+        = HashingValueWriter<'a, S::ValueWriter<'a>, D>
+    where
+        Self: 'a;
+
+    fn make_value_writer(&mut self) -> Self::ValueWriter<'_> {
+        HashingValueWriter::new(self.state, self.struct_writer.make_value_writer())
+    }
+}
+
+impl<'value, S: StructWriter, D> FieldEncoder for HashingStructWriter<'value, S, D>
+where
+    D: IonDigest,
+{
+    fn encode_field_name(&mut self, name: impl AsRawSymbolRef) -> IonResult<()> {
+        let token = name.as_raw_symbol_ref();
+        let name = self.state.resolve_symbol(token)?;
+        self.state.begin_field(name)?;
+        self.struct_writer.encode_field_name(token)
+    }
+}
+
+impl<'value, S: StructWriter, D> StructWriter for HashingStructWriter<'value, S, D>
+where
+    D: IonDigest,
+{
+    fn close(self) -> IonResult<()> {
+        self.struct_writer.close()?;
+        self.state.end_value();
+        Ok(())
+    }
+
+    fn config(&self) -> ValueWriterConfig {
+        self.struct_writer.config()
+    }
+}
+
+/// The kind of container a [`HashingWriter`] is writing.
+enum Container<D>
+where
+    D: IonDigest,
+{
+    Sequence,
+    /// A struct and the hashes of the fields that have been written to it so far. Field hashes
+    /// are sorted and added to the struct's representation when it is closed.
+    Struct(Vec<D::Output>),
+}
+
+/// A value that has been started but not yet finished. Scalars are finished as soon as they're
+/// started; containers remain open until they are closed.
+struct OpenValue<D>
+where
+    D: IonDigest,
+{
+    has_annotations: bool,
+    is_field: bool,
+    /// Whether this value is matched by one of the paths and has its own digest.
+    is_selected: bool,
+    /// The path steps that remain to be applied to this value's children, as pairs of
+    /// `(path index, step index)`.
+    pending_steps: Vec<(usize, usize)>,
+    container: Option<Container<D>>,
+    /// The number of child values that have been started so far.
+    num_children: usize,
+}
+
+/// The digests that a [`HashingWriter`] is computing, and the position of the value currently
+/// being written.
+struct HashState<D>
+where
+    D: IonDigest,
+{
+    paths: Vec<IonPath>,
+    /// Each level holds the hashers that the serialized bytes of the current value are written
+    /// to. The first hasher in a level computes the hash of a top-level value or of a struct
+    /// field; any that follow compute the hashes of values selected by a path. Because each
+    /// struct field is hashed on its own, every field value begins a new level.
+    levels: Vec<Vec<ElementHasher<D>>>,
+    open_values: Vec<OpenValue<D>>,
+    location: ValuePath,
+    pending_field_name: Option<Symbol>,
+    symbol_table: Option<SymbolTable>,
+    digest: Option<D::Output>,
+    path_digests: Vec<(ValuePath, D::Output)>,
+}
+
+impl<D> HashState<D>
+where
+    D: IonDigest,
+{
+    fn new() -> Self {
+        Self {
+            paths: Vec::new(),
+            levels: Vec::new(),
+            open_values: Vec::new(),
+            location: ValuePath::root(),
+            pending_field_name: None,
+            symbol_table: None,
+            digest: None,
+            path_digests: Vec::new(),
+        }
+    }
+
+    /// Resolves a symbol token to the [`Symbol`] whose text will be hashed.
+    fn resolve_symbol(&self, token: RawSymbolRef<'_>) -> IonResult<Symbol> {
+        match token {
+            RawSymbolRef::Text(text) => Ok(Symbol::owned(text)),
+            RawSymbolRef::SystemSymbol_1_1(symbol) => Ok(Symbol::static_text(symbol.text())),
+            RawSymbolRef::SymbolId(0) => Ok(Symbol::unknown_text()),
+            RawSymbolRef::SymbolId(sid) => {
+                match self.symbol_table.as_ref().and_then(|t| t.symbol_for(sid)) {
+                    Some(symbol) => Ok(symbol.clone()),
+                    None => IonResult::illegal_operation(format!(
+                        "cannot compute the Ion Hash of symbol ID ${sid} without its text"
+                    )),
+                }
+            }
+        }
+    }
+
+    /// Calls `f` with each of the hashers that the current value's bytes are written to.
+    fn each_hasher(
+        &mut self,
+        mut f: impl FnMut(&mut ElementHasher<D>) -> IonResult<()>,
+    ) -> IonResult<()> {
+        let level = self
+            .levels
+            .last_mut()
+            .expect("a value is being written, so there is at least one level");
+        for hasher in level {
+            f(hasher)?;
+        }
+        Ok(())
+    }
+
+    /// Begins a new struct field, hashing its name.
+    fn begin_field(&mut self, name: Symbol) -> IonResult<()> {
+        // s(fieldname), as in `struct_field_hash`.
+        let mut hasher = ElementHasher::new(D::default());
+        hasher.mark_begin();
+        hasher
+            .digest
+            .update(type_qualifier_symbol(Some(&name)).as_bytes());
+        hasher.write_repr_symbol(Some(&name))?;
+        hasher.mark_end();
+        self.levels.push(vec![hasher]);
+        self.pending_field_name = Some(name);
+        Ok(())
+    }
+
+    /// Begins a value, writing its annotations and type qualifier. If the value is a container,
+    /// it remains open until [`end_value`](Self::end_value) is called for it.
+    fn begin_value(
+        &mut self,
+        annotations: &[RawSymbolRef<'_>],
+        tq: TypeQualifier,
+        container: Option<Container<D>>,
+    ) -> IonResult<()> {
+        let annotations = annotations
+            .iter()
+            .map(|token| self.resolve_symbol(*token))
+            .collect::<IonResult<Vec<_>>>()?;
+
+        let (is_field, mut steps) = match self.open_values.last_mut() {
+            None => {
+                // This is a new top-level value.
+                self.levels.push(vec![ElementHasher::new(D::default())]);
+                self.path_digests.clear();
+                (false, (0..self.paths.len()).map(|path| (path, 0)).collect())
+            }
+            Some(parent) => {
+                let component = match parent.container {
+                    Some(Container::Struct(_)) => PathComponent::Field(
+                        self.pending_field_name
+                            .take()
+                            .expect("struct field values follow a field name"),
+                    ),
+                    _ => PathComponent::Index(parent.num_children),
+                };
+                parent.num_children += 1;
+                let steps = child_steps(&self.paths, &parent.pending_steps, &component);
+                let is_field = matches!(component, PathComponent::Field(_));
+                self.location.push(component);
+                (is_field, steps)
+            }
+        };
+        apply_annotation_steps(&self.paths, &mut steps, &annotations);
+        let is_selected = steps
+            .iter()
+            .any(|(path, step)| *step == self.paths[*path].steps().len());
+        if is_selected {
+            self.levels
+                .last_mut()
+                .expect("there is at least one level")
+                .push(ElementHasher::new(D::default()));
+        }
+
+        let has_annotations = !annotations.is_empty();
+        self.each_hasher(|hasher| {
+            if has_annotations {
+                // See `ElementHasher::update_serialized_bytes`.
+                hasher.mark_begin();
+                hasher.digest.update(&[0xE0]);
+                for annotation in &annotations {
+                    hasher.mark_begin();
+                    hasher.digest.update(match annotation.text() {
+                        None => &[0x71],
+                        Some(_) => &[0x70],
+                    });
+                    hasher.write_repr_symbol(Some(annotation))?;
+                    hasher.mark_end();
+                }
+            }
+            hasher.mark_begin();
+            hasher.digest.update(tq.as_bytes());
+            Ok(())
+        })?;
+
+        self.open_values.push(OpenValue {
+            has_annotations,
+            is_field,
+            is_selected,
+            pending_steps: steps,
+            container,
+            num_children: 0,
+        });
+        Ok(())
+    }
+
+    /// Finishes the most recently begun value and records any digests that it completes.
+    fn end_value(&mut self) {
+        let value = self
+            .open_values
+            .pop()
+            .expect("end_value is only called for a value that was begun");
+        let level = self.levels.last_mut().expect("there is at least one level");
+
+        if let Some(Container::Struct(mut field_hashes)) = value.container {
+            // See `RepresentationEncoder::write_repr_struct`.
+            field_hashes.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+            for hasher in level.iter_mut() {
+                for hash in &field_hashes {
+                    hasher.update_escaping(hash);
+                }
+            }
+        }
+        for hasher in level.iter_mut() {
+            hasher.mark_end();
+            if value.has_annotations {
+                hasher.mark_end();
+            }
+        }
+
+        if value.is_selected {
+            let hasher = level.pop().expect("selected values have their own hasher");
+            self.path_digests
+                .push((self.location.clone(), hasher.digest.finalize()));
+        }
+
+        if self.open_values.is_empty() {
+            let mut level = self.levels.pop().expect("there is at least one level");
+            let hasher = level.pop().expect("each level has a hasher");
+            self.digest = Some(hasher.digest.finalize());
+            return;
+        }
+
+        self.location.pop();
+        if value.is_field {
+            let mut level = self
+                .levels
+                .pop()
+                .expect("field values have their own level");
+            let hasher = level.pop().expect("each level has a hasher");
+            let parent = self
+                .open_values
+                .last_mut()
+                .expect("field values have a parent");
+            if let Some(Container::Struct(field_hashes)) = &mut parent.container {
+                field_hashes.push(hasher.digest.finalize());
+            }
+        }
+    }
+}
+
+/// Given the steps pending at a container, returns the steps pending at its child value that is
+/// found at `component`.
+fn child_steps(
+    paths: &[IonPath],
+    parent_steps: &[(usize, usize)],
+    component: &PathComponent,
+) -> Vec<(usize, usize)> {
+    let mut steps = Vec::new();
+    for &(path, step_index) in parent_steps {
+        let Some(step) = paths[path].steps().get(step_index) else {
+            continue;
+        };
+        let next_step = match (step, component) {
+            (PathStep::Field(name), PathComponent::Field(field_name))
+                if field_name.text() == Some(name.as_str()) =>
+            {
+                step_index + 1
+            }
+            (PathStep::Index(index), PathComponent::Index(child_index)) if index == child_index => {
+                step_index + 1
+            }
+            (PathStep::Wildcard, _) => step_index + 1,
+            // Descendants are matched with the recursive descent step still pending.
+            (PathStep::RecursiveDescent, _) => step_index,
+            _ => continue,
+        };
+        if !steps.contains(&(path, next_step)) {
+            steps.push((path, next_step));
+        }
+    }
+    steps
+}
+
+/// Applies the steps that filter or select the current value itself (annotation filters and
+/// recursive descent) to `steps`.
+fn apply_annotation_steps(
+    paths: &[IonPath],
+    steps: &mut Vec<(usize, usize)>,
+    annotations: &[Symbol],
+) {
+    let mut index = 0;
+    while index < steps.len() {
+        let (path, step_index) = steps[index];
+        let next_step = match paths[path].steps().get(step_index) {
+            Some(PathStep::Annotated(annotation))
+                if annotations
+                    .iter()
+                    .any(|a| a.text() == Some(annotation.as_str())) =>
+            {
+                Some(step_index + 1)
+            }
+            // Recursive descent also matches the current value.
+            Some(PathStep::RecursiveDescent) => Some(step_index + 1),
+            _ => None,
+        };
+        if let Some(next_step) = next_step {
+            if !steps.contains(&(path, next_step)) {
+                steps.push((path, next_step));
+            }
+        }
+        index += 1;
+    }
+}
+
+#[cfg(all(test, feature = "ion-hash-sha2"))]
+mod tests {
+    use rstest::rstest;
+    use sha2::Sha256;
+
+    use crate::ion_hash::{HashingWriter, IonHasher, IonValueHasher};
+    use crate::lazy::encoder::value_writer::{
+        AnnotatableWriter, SequenceWriter, StructWriter, ValueWriter,
+    };
+    use crate::lazy::encoder::write_as_ion::WriteAsIon;
+    use crate::{
+        v1_0, v1_1, AnyEncoding, Decoder, Element, ElementReader, ImportResolution, IonData,
+        IonPath, IonResult, MapCatalog, Reader, SharedSymbolTable, WriteConfig, Writer,
+    };
+
+    const RECORDS: &str = r#"
+        null.struct 0 -7 1.5e0 -0e0 nan 1.23 2024-02-29T12:30Z "text" sym '' {{aGVsbG8=}} {{"clob"}}
+        audit::{id: 1, user: "alice", events: [login::{at: 2024T}, logout::{at: 2025T}], tags: (a b)}
+        {a: 1, a: 1, a: 2, b: {c: [1, [2, (3)]], d: null.list}}
+        a::b::[1, c::{d: e::f}]
+        {'': '', '\x0B\x0C': "\x0B\x0C", clob: {{ "\x0B\x0E\x0C" }}}
+    "#;
+
+    /// A top-level value and the digests that the writer reported after writing it.
+    struct WrittenValue {
+        element: Element,
+        digest: Vec<u8>,
+        // The location and digest of each value that matched one of the writer's paths.
+        path_digests: Vec<(String, Vec<u8>)>,
+    }
+
+    fn write_with_paths(paths: &[&str]) -> IonResult<Vec<WrittenValue>> {
+        let mut writer = HashingWriter::<_, Sha256>::new(Writer::new(v1_0::Text, Vec::new())?);
+        for path in paths {
+            writer = writer.with_path(path.parse()?);
+        }
+        let mut results = Vec::new();
+        for element in Element::read_all(RECORDS)? {
+            writer.write(&element)?;
+            let digest = writer.digest().expect("a value was written").to_vec();
+            let path_digests = writer
+                .path_digests()
+                .iter()
+                .map(|(location, digest)| (location.to_string(), digest.to_vec()))
+                .collect();
+            results.push(WrittenValue {
+                element,
+                digest,
+                path_digests,
+            });
+        }
+        let output = writer.close()?;
+        assert!(IonData::eq(
+            &Element::read_all(output)?,
+            &Element::read_all(RECORDS)?
+        ));
+        Ok(results)
+    }
+
+    #[test]
+    fn top_level_digests_match_element_hash() -> IonResult<()> {
+        for WrittenValue {
+            element,
+            digest,
+            path_digests,
+        } in write_with_paths(&[])?
+        {
+            assert_eq!(
+                digest,
+                Sha256::hash_element(&element)?.to_vec(),
+                "{element}"
+            );
+            assert!(path_digests.is_empty());
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::root("$")]
+    #[case::field("$.user")]
+    #[case::index("[1]")]
+    #[case::wildcard("$.*")]
+    #[case::nested("$.events[*].at")]
+    #[case::annotated("$.events[*]::logout")]
+    #[case::recursive_descent("$..")]
+    #[case::recursive_field("$..a")]
+    #[case::recursive_annotated("$..::c")]
+    #[case::no_matches("$.missing")]
+    fn path_digests_match_element_hash(#[case] path: &str) -> IonResult<()> {
+        let ion_path: IonPath = path.parse()?;
+        for WrittenValue {
+            element,
+            path_digests,
+            ..
+        } in write_with_paths(&[path])?
+        {
+            let expected = ion_path
+                .query(&element)
+                .map(|(location, value)| {
+                    Ok((location.to_string(), Sha256::hash_element(value)?.to_vec()))
+                })
+                .collect::<IonResult<Vec<_>>>()?;
+            let mut actual = path_digests;
+            // Digests are reported in the order that the values were completed.
+            actual.sort();
+            let mut expected = expected;
+            expected.sort();
+            assert_eq!(actual, expected, "{path} in {element}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn values_written_with_value_writer_methods() -> IonResult<()> {
+        let mut writer = HashingWriter::<_, Sha256>::new(Writer::new(v1_0::Binary, Vec::new())?)
+            .with_path("$.nested".parse()?);
+        let mut strukt = writer
+            .value_writer()
+            .with_annotations("a")?
+            .struct_writer()?;
+        strukt.write("int", 5)?.write("float", 2.5f32)?;
+        strukt.field_writer("symbol").write_symbol("foo")?;
+        strukt
+            .field_writer("nested")
+            .with_annotations(["b", "c"])?
+            .write_list([1, 2, 3])?;
+        let mut sexp = strukt.field_writer("sexp").sexp_writer()?;
+        sexp.write_null(crate::IonType::Int)?.write_bool(true)?;
+        sexp.close()?;
+        strukt.close()?;
+
+        let expected = Element::read_one(
+            "a::{int: 5, float: 2.5e0, symbol: foo, nested: b::c::[1, 2, 3], sexp: (null.int true)}",
+        )?;
+        assert_eq!(writer.digest(), Some(&Sha256::hash_element(&expected)?));
+        let nested = expected.as_struct().unwrap().get("nested").unwrap();
+        assert_eq!(writer.path_digests().len(), 1);
+        assert_eq!(writer.path_digests()[0].1, Sha256::hash_element(nested)?);
+
+        let output = writer.close()?;
+        assert_eq!(Element::read_one(output)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn path_digests_are_reset_for_each_top_level_value() -> IonResult<()> {
+        let mut writer = HashingWriter::<_, Sha256>::new(Writer::new(v1_0::Text, Vec::new())?)
+            .with_path("[0]".parse()?);
+        writer.write_list([1, 2])?;
+        assert_eq!(writer.path_digests().len(), 1);
+        writer.write(3)?;
+        assert!(writer.path_digests().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_ids_without_text_cannot_be_hashed() -> IonResult<()> {
+        let mut writer = HashingWriter::<_, Sha256>::new(Writer::new(v1_0::Binary, Vec::new())?);
+        assert!(writer.write_symbol(4).is_err());
+        assert!(writer.write_symbol(0).is_ok());
+        assert_eq!(
+            writer.digest(),
+            Some(&Sha256::hash_element(&Element::read_one("$0")?)?)
+        );
+        Ok(())
+    }
+
+    /// Writes one of three values whose symbols are written as symbol IDs.
+    fn write_symbol_id_value<W: SequenceWriter>(writer: &mut W, index: usize) -> IonResult<()> {
+        match index {
+            0 => {
+                writer.write_symbol(4)?; // name
+            }
+            1 => {
+                writer.write_symbol(11)?;
+            }
+            _ => {
+                let mut strukt = writer
+                    .value_writer()
+                    .with_annotations(11)?
+                    .struct_writer()?;
+                strukt.field_writer(10).write_symbol(11)?;
+                strukt.close()?;
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_ids_are_resolved_using_the_symbol_table() -> IonResult<()> {
+        // Symbol IDs 10 and 11 belong to an import that the reader's catalog does not contain.
+        let import = SharedSymbolTable::new("missing", 1, ["a"])?;
+        let config = WriteConfig::<v1_0::Binary>::new().with_import_max_id(import, 2);
+        let mut writer = Writer::new(config.clone(), Vec::new())?;
+        for index in 0..3 {
+            write_symbol_id_value(&mut writer, index)?;
+        }
+        let encoded = writer.close()?;
+
+        let read_config = AnyEncoding
+            .with_catalog(MapCatalog::new())
+            .with_import_resolution(ImportResolution::Lenient);
+        let mut reader = Reader::new(read_config, encoded)?;
+        let mut writer = HashingWriter::<_, Sha256>::new(Writer::new(config, Vec::new())?);
+        let expected = Element::read_all("name $0 $0::{$0: $0}")?;
+        for (index, element) in expected.iter().enumerate() {
+            let value = reader.expect_next()?;
+            if index == 0 {
+                writer = writer.with_symbol_table(value.symbol_table().clone());
+            }
+            write_symbol_id_value(&mut writer, index)?;
+            let digest = Sha256::hash_element(element)?;
+            assert_eq!(Sha256::hash_value(value)?, digest, "{element}");
+            assert_eq!(writer.digest(), Some(&digest), "{element}");
+        }
+        assert!(writer.write_symbol(12).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn eexps_cannot_be_hashed() -> IonResult<()> {
+        let mut writer = HashingWriter::<_, Sha256>::new(Writer::new(v1_1::Binary, Vec::new())?);
+        assert!(writer.eexp_writer(0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn lazy_values_can_be_copied_into_a_hashing_writer() -> IonResult<()> {
+        let mut reader = Reader::new(v1_0::Text, RECORDS)?;
+        let mut writer = HashingWriter::<_, Sha256>::new(Writer::new(v1_0::Binary, Vec::new())?);
+        while let Some(value) = reader.next()? {
+            value.write_as_ion(writer.value_writer())?;
+            assert_eq!(writer.digest(), Some(&Sha256::hash_value(value)?));
+        }
+        let output = writer.close()?;
+        assert!(IonData::eq(
+            &Reader::new(v1_0::Binary, output)?.read_all_elements()?,
+            &Element::read_all(RECORDS)?
+        ));
+        Ok(())
+    }
+}
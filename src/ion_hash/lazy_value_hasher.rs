@@ -0,0 +1,266 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+//! Provides [`LazyValueHasher`], a single-use IonHash implementation for a
+//! [`LazyValue`] that reads the value incrementally instead of materializing
+//! it as an [`Element`](crate::Element).
+
+use crate::ion_hash::element_hasher::ElementHasher;
+use crate::ion_hash::representation::RepresentationEncoder;
+use crate::ion_hash::type_qualifier::{type_qualifier_symbol_ref, TypeQualifier};
+use crate::ion_hash::IonDigest;
+use crate::lazy::decoder::Decoder;
+use crate::lazy::encoding::RawValueLiteral;
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
+use crate::{IonResult, SymbolRef};
+
+pub(crate) struct LazyValueHasher<D>
+where
+    D: IonDigest,
+{
+    // The markers, escaping, and scalar representations are shared with `ElementHasher`.
+    hasher: ElementHasher<D>,
+}
+
+impl<D> LazyValueHasher<D>
+where
+    D: IonDigest,
+{
+    pub(crate) fn new(digest: D) -> LazyValueHasher<D> {
+        LazyValueHasher {
+            hasher: ElementHasher::new(digest),
+        }
+    }
+
+    pub(crate) fn hash_value<E: Decoder>(
+        mut self,
+        value: LazyValue<'_, E>,
+    ) -> IonResult<D::Output> {
+        self.update_serialized_bytes(value)?;
+        Ok(self.hasher.digest.finalize())
+    }
+
+    /// Implements the "serialized bytes" transform as described in the spec.
+    /// See [`ElementHasher::update_serialized_bytes`].
+    fn update_serialized_bytes<E: Decoder>(&mut self, value: LazyValue<'_, E>) -> IonResult<()> {
+        let has_annotations = value.has_annotations();
+        if has_annotations {
+            // s(annotated value) → B || TQ || s(annotation) || s(annotation) || ... || s(annotation) || s(value) || E
+            self.hasher.mark_begin();
+            self.hasher.digest.update(&[0xE0]);
+            for annotation in value.annotations() {
+                self.update_symbol(annotation?)?;
+            }
+        }
+
+        self.hasher.mark_begin();
+        let value_ref = value.read()?;
+        let tq = TypeQualifier::from_value_ref(&value_ref);
+        self.hasher.digest.update(tq.as_bytes());
+        self.update_with_representation(value, value_ref)?;
+        self.hasher.mark_end();
+
+        if has_annotations {
+            self.hasher.mark_end();
+        }
+
+        Ok(())
+    }
+
+    /// Writes the serialized bytes of a non-null symbol, as used for annotations and field names.
+    fn update_symbol(&mut self, symbol: SymbolRef<'_>) -> IonResult<()> {
+        self.hasher.mark_begin();
+        self.hasher
+            .digest
+            .update(type_qualifier_symbol_ref(&symbol).as_bytes());
+        // There are no representation bytes for unknown-text symbols.
+        self.hasher.write_repr_string(symbol.text())?;
+        self.hasher.mark_end();
+        Ok(())
+    }
+
+    fn update_with_representation<E: Decoder>(
+        &mut self,
+        value: LazyValue<'_, E>,
+        value_ref: ValueRef<'_, E>,
+    ) -> IonResult<()> {
+        if let Some(raw_value) = value.raw() {
+            if let Some(body) = canonical_representation(&raw_value, &value_ref) {
+                self.hasher.update_escaping(body);
+                return Ok(());
+            }
+        }
+
+        match value_ref {
+            ValueRef::Null(_) | ValueRef::Bool(_) => {} // these have no representation
+            ValueRef::Int(int) => self.hasher.write_repr_integer(Some(&int))?,
+            ValueRef::Float(float) => self.hasher.write_repr_float(Some(float))?,
+            ValueRef::Decimal(decimal) => self.hasher.write_repr_decimal(Some(decimal))?,
+            ValueRef::Timestamp(timestamp) => self.hasher.write_repr_timestamp(Some(timestamp))?,
+            ValueRef::Symbol(symbol) => self.hasher.write_repr_string(symbol.text())?,
+            ValueRef::String(text) => self.hasher.write_repr_string(Some(text.text()))?,
+            ValueRef::Clob(bytes) | ValueRef::Blob(bytes) => {
+                self.hasher.write_repr_blob(Some(bytes.data()))?
+            }
+            ValueRef::List(list) => {
+                for child in list {
+                    self.update_serialized_bytes(child?)?;
+                }
+            }
+            ValueRef::SExp(sexp) => {
+                for child in sexp {
+                    self.update_serialized_bytes(child?)?;
+                }
+            }
+            ValueRef::Struct(strukt) => {
+                // See `RepresentationEncoder::write_repr_struct`.
+                let mut hashes = Vec::new();
+                for field in strukt {
+                    let field = field?;
+                    hashes.push(struct_field_hash::<D, E>(field.name()?, field.value())?);
+                }
+
+                hashes.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+                for hash in hashes {
+                    self.hasher.update_escaping(hash);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// If `raw_value` is a binary Ion 1.0 scalar whose encoded body is identical to its Ion Hash
+/// representation, returns that body so it can be fed to the digest without decoding it.
+///
+/// Ion Hash representations are based on the binary Ion 1.0 encoding, so this holds for strings,
+/// clobs, blobs, and for ints and 64-bit floats that are encoded canonically. Other values
+/// (including decimals and timestamps, whose encodings can be padded) are re-encoded from their
+/// decoded form instead.
+fn canonical_representation<'a, E: Decoder>(
+    raw_value: &'a impl RawValueLiteral,
+    value_ref: &ValueRef<'_, E>,
+) -> Option<&'a [u8]> {
+    let body = raw_value.binary_1_0_body()?;
+    let is_canonical = match value_ref {
+        // Zero has no representation bytes; any other magnitude must not have leading zeros.
+        ValueRef::Int(_) => body.first() != Some(&0),
+        // Positive zero has no representation bytes and NaN has a single representation.
+        ValueRef::Float(float) => {
+            body.is_empty() || (body.len() == 8 && !float.is_nan() && float.to_bits() != 0)
+        }
+        ValueRef::String(_) | ValueRef::Clob(_) | ValueRef::Blob(_) => true,
+        _ => false,
+    };
+    is_canonical.then_some(body)
+}
+
+/// Computes the field hash of a lazily read struct field. See the [`Element`](crate::Element)
+/// equivalent in `representation.rs`.
+fn struct_field_hash<D, E>(name: SymbolRef<'_>, value: LazyValue<'_, E>) -> IonResult<D::Output>
+where
+    D: IonDigest,
+    E: Decoder,
+{
+    let mut hasher = LazyValueHasher::new(D::default());
+    hasher.update_symbol(name)?;
+    hasher.update_serialized_bytes(value)?;
+    Ok(hasher.hasher.digest.finalize())
+}
+
+#[cfg(all(test, feature = "ion-hash-sha2"))]
+mod tests {
+    use rstest::rstest;
+    use sha2::Sha256;
+
+    use crate::ion_hash::{IonHasher, IonValueHasher};
+    use crate::{
+        v1_0, AnyEncoding, Decoder, Element, ImportResolution, IonResult, MapCatalog, Reader,
+    };
+
+    const VALUES: &str = r#"
+        null null.bool null.int null.float null.decimal null.timestamp null.symbol null.string
+        null.clob null.blob null.list null.sexp null.struct
+        true false
+        0 1 -1 255 -256 0x0B0E0C 123456789012345678901234567890 -98765432109876543210
+        0e0 -0e0 1.5e0 -2.25e-10 +inf -inf nan
+        0. -0. 0d-3 1.23 -45.600 12345678901234567890.123
+        2024T 2024-02-29T 2024-02-29T12:30Z 2024-02-29T12:30:45.678-08:00
+        foo 'bar baz' '' "" "hello" "\x0B\x0E\x0C"
+        {{}} {{aGVsbG8=}} {{"clob"}} {{ "\x0B\x0E\x0C" }}
+        [] [1, [2, [3]]] () (a b (c)) {} {a: 1, b: "two", c: [3]} {a: 1, a: 1, a: 2}
+        a::b::1 a::{x: b::[c::3]} '\x0B'::"escaped annotation"
+    "#;
+
+    fn hash_lazily(data: impl AsRef<[u8]>) -> IonResult<Vec<Vec<u8>>> {
+        let mut reader = Reader::new(AnyEncoding, data.as_ref())?;
+        let mut digests = Vec::new();
+        while let Some(value) = reader.next()? {
+            digests.push(Sha256::hash_value(value)?.to_vec());
+        }
+        Ok(digests)
+    }
+
+    fn hash_elements(data: impl AsRef<[u8]>) -> IonResult<Vec<Vec<u8>>> {
+        Element::read_all(data.as_ref())?
+            .iter()
+            .map(|element| Ok(Sha256::hash_element(element)?.to_vec()))
+            .collect()
+    }
+
+    #[test]
+    fn lazy_hash_matches_element_hash() -> IonResult<()> {
+        let expected = hash_elements(VALUES)?;
+        let elements = Element::read_all(VALUES)?;
+        assert_eq!(hash_lazily(VALUES)?, expected);
+        assert_eq!(hash_lazily(elements.encode_as(v1_0::Binary)?)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn symbols_from_missing_imports_have_unknown_text() -> IonResult<()> {
+        let data = r#"
+            $ion_symbol_table::{imports: [{name: "missing", version: 1, max_id: 2}]}
+            $10 {$11: $10} $10::$11
+        "#;
+        let config = AnyEncoding
+            .with_catalog(MapCatalog::new())
+            .with_import_resolution(ImportResolution::Lenient);
+        let mut reader = Reader::new(config, data)?;
+        // Symbols with unknown text all hash like `$0`, no matter which symbol ID they have.
+        for expected in Element::read_all("$0 {$0: $0} $0::$0")? {
+            let value = reader.expect_next()?;
+            let digest = Sha256::hash_element(&expected)?;
+            assert_eq!(Sha256::hash_value(value)?, digest, "{expected}");
+            assert_eq!(
+                Sha256::hash_element(&value.try_into()?)?,
+                digest,
+                "{expected}"
+            );
+        }
+        // A symbol whose text is "$0" is not a symbol with unknown text.
+        assert_ne!(hash_elements("$0")?, hash_elements("'$0'")?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::int_with_leading_zeros(&[0x22, 0x00, 0x01], "1")]
+    #[case::zero_with_padding(&[0x21, 0x00], "0")]
+    #[case::negative_int_with_leading_zeros(&[0x33, 0x00, 0x00, 0x0B], "-11")]
+    #[case::float_32(&[0x44, 0x3F, 0xC0, 0x00, 0x00], "1.5e0")]
+    #[case::positive_zero_float(&[0x48, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], "0e0")]
+    #[case::negative_zero_float(&[0x48, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], "-0e0")]
+    #[case::non_canonical_nan(&[0x48, 0x7F, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01], "nan")]
+    #[case::padded_decimal(&[0x53, 0xC2, 0x00, 0x05], "0.05")]
+    #[case::padded_timestamp(&[0x65, 0x80, 0x00, 0x0F, 0xE8, 0x81], "2024-01T")]
+    #[case::annotated_string(&[0xE7, 0x81, 0x84, 0x84, 0x0B, 0x0E, 0x0C, 0x2E], r#"name::"\x0B\x0E\x0C.""#)]
+    #[case::struct_with_padding(&[0xD6, 0x84, 0x20, 0x81, 0x00, 0x85, 0x20], "{name: 0, version: 0}")]
+    fn non_canonical_binary_encodings(#[case] body: &[u8], #[case] text: &str) -> IonResult<()> {
+        let mut data = vec![0xE0, 0x01, 0x00, 0xEA];
+        data.extend_from_slice(body);
+        assert_eq!(hash_lazily(&data)?, hash_elements(text)?);
+        Ok(())
+    }
+}
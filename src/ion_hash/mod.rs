@@ -2,41 +2,90 @@
 
 //! Implements the [Ion Hash specification](https://amazon-ion.github.io/ion-hash/docs/spec.html)
 //!
+//! Ion Hash can be computed with any hash function that implements [`IonDigest`]. Enabling the
+//! `ion-hash-digest` feature implements `IonDigest` for every hash function that implements
+//! version 0.10 of the RustCrypto [`digest`](https://docs.rs/digest/0.10) crate's `Digest` trait.
+//! The `ion-hash-sha2` and `ion-hash-blake2` features also provide convenience functions like
+//! `sha256` and `blake2b512`.
+//!
 //! ## Examples
 //! ```rust
 //! use ion_rs::{Element, IonResult};
 //! use ion_rs::ion_hash;
 //!
 //! # // XXX this doc test requires an optional feature--so this makes sure we can still run tests
-//! # #[cfg(feature = "sha2")]
+//! # #[cfg(feature = "ion-hash-sha2")]
 //! # fn main() -> IonResult<()> {
 //!   let elem = Element::read_one(b"\"hello world\"")?;
-//!   let digest = ion_hash::sha256(&elem);
+//!   let digest: [u8; 32] = ion_hash::sha256(&elem)?;
 //!   println!("{:?}", digest);
 //! # Ok(())
 //! # }
-//! # #[cfg(not(feature = "sha2"))]
+//! # #[cfg(not(feature = "ion-hash-sha2"))]
 //! # fn main() {}
 //! ```
-
-use digest::{self, FixedOutput, Reset, Update};
+//!
+//! ## Symbols with unknown text
+//! The spec hashes every symbol token whose text is unknown the same way, regardless of its symbol
+//! ID: a type qualifier of `0x71` with no representation. This includes `$0` and symbol IDs that
+//! belong to an imported shared symbol table that was not found in the reader's catalog (see
+//! [`ImportResolution::Lenient`](crate::ImportResolution::Lenient)). A symbol whose text is `"$0"`
+//! is not a symbol with unknown text and is hashed like any other text.
 
 use crate::element::Element;
+#[cfg(feature = "experimental-reader-writer")]
+use crate::lazy::decoder::Decoder;
+#[cfg(feature = "experimental-reader-writer")]
+use crate::lazy::value::LazyValue;
 use crate::IonResult;
 use element_hasher::ElementHasher;
+#[cfg(feature = "experimental-reader-writer")]
+use lazy_value_hasher::LazyValueHasher;
 
 mod element_hasher;
+#[cfg(feature = "experimental-reader-writer")]
+mod hashing_writer;
+#[cfg(feature = "experimental-reader-writer")]
+mod lazy_value_hasher;
 mod representation;
 mod type_qualifier;
 
-#[cfg(feature = "sha2")]
-use digest::Output;
-#[cfg(feature = "sha2")]
-use sha2::Sha256;
+#[cfg(feature = "experimental-reader-writer")]
+pub use hashing_writer::{
+    HashingSequenceWriter, HashingStructWriter, HashingValueWriter, HashingWriter,
+};
+
 /// Utility to hash an [`Element`] using SHA-256 as the hash function.
-#[cfg(feature = "sha2")]
-pub fn sha256(elem: &Element) -> IonResult<Output<Sha256>> {
-    Sha256::hash_element(elem)
+#[cfg(feature = "ion-hash-sha2")]
+pub fn sha256(elem: &Element) -> IonResult<[u8; 32]> {
+    hash_element_to_array::<sha2::Sha256, 32>(elem)
+}
+
+/// Utility to hash an [`Element`] using SHA-512 as the hash function.
+#[cfg(feature = "ion-hash-sha2")]
+pub fn sha512(elem: &Element) -> IonResult<[u8; 64]> {
+    hash_element_to_array::<sha2::Sha512, 64>(elem)
+}
+
+/// Utility to hash an [`Element`] using BLAKE2b-512 as the hash function.
+#[cfg(feature = "ion-hash-blake2")]
+pub fn blake2b512(elem: &Element) -> IonResult<[u8; 64]> {
+    hash_element_to_array::<blake2::Blake2b512, 64>(elem)
+}
+
+/// Utility to hash an [`Element`] using BLAKE2s-256 as the hash function.
+#[cfg(feature = "ion-hash-blake2")]
+pub fn blake2s256(elem: &Element) -> IonResult<[u8; 32]> {
+    hash_element_to_array::<blake2::Blake2s256, 32>(elem)
+}
+
+#[cfg(any(feature = "ion-hash-sha2", feature = "ion-hash-blake2"))]
+fn hash_element_to_array<D: IonDigest, const N: usize>(elem: &Element) -> IonResult<[u8; N]> {
+    let digest = D::hash_element(elem)?;
+    Ok(digest
+        .as_ref()
+        .try_into()
+        .expect("the digest has the hash function's output size"))
 }
 
 /// Bytes markers as per the spec.
@@ -50,7 +99,69 @@ impl Markers {
     const ESC: u8 = 0x0C;
 }
 
-/// This trait mostly exists to extend the [`Digest`](digest::Digest) trait to support Ion Hash.
+/// A hash function that can be used to compute Ion Hash digests.
+///
+/// Ion Hash feeds the serialized bytes of a value to the hash function and, for each struct field,
+/// to a new instance of the hash function created with [`Default`]. Implementations are provided
+/// for the RustCrypto hash functions when the `ion-hash-digest` feature is enabled; other hash
+/// functions can implement this trait directly.
+///
+/// ```
+/// use ion_rs::ion_hash::{IonDigest, IonHasher};
+/// use ion_rs::{Element, IonResult};
+///
+/// /// Collects the bytes that Ion Hash would feed to a real hash function.
+/// #[derive(Default)]
+/// struct Identity(Vec<u8>);
+///
+/// impl IonDigest for Identity {
+///     type Output = Vec<u8>;
+///
+///     fn update(&mut self, data: &[u8]) {
+///         self.0.extend_from_slice(data);
+///     }
+///
+///     fn finalize(self) -> Vec<u8> {
+///         self.0
+///     }
+/// }
+///
+///# fn main() -> IonResult<()> {
+/// let digest = Identity::hash_element(&Element::read_one("\"hi\"")?)?;
+/// assert_eq!(digest, [0x0B, 0x80, b'h', b'i', 0x0E]);
+///# Ok(())
+///# }
+/// ```
+pub trait IonDigest: Default {
+    /// The digest produced by the hash function, typically a fixed-size array of bytes.
+    type Output: AsRef<[u8]>;
+
+    /// Adds `data` to the input of the hash function.
+    fn update(&mut self, data: &[u8]);
+
+    /// Consumes the hash function and returns the digest of its input.
+    fn finalize(self) -> Self::Output;
+}
+
+/// Adapts the hash functions that implement version 0.10 of the `digest` crate's
+/// [`Digest`](digest::Digest) trait, including those in the `sha2` and `blake2` crates.
+#[cfg(feature = "ion-hash-digest")]
+impl<D> IonDigest for D
+where
+    D: digest::Digest + Default,
+{
+    type Output = digest::Output<D>;
+
+    fn update(&mut self, data: &[u8]) {
+        digest::Digest::update(self, data)
+    }
+
+    fn finalize(self) -> Self::Output {
+        digest::Digest::finalize(self)
+    }
+}
+
+/// This trait mostly exists to extend the [`IonDigest`] trait to support Ion Hash.
 /// See [`hash_element`](Self::hash_element).
 pub trait IonHasher {
     type Output;
@@ -59,22 +170,75 @@ pub trait IonHasher {
     fn hash_element(elem: &Element) -> IonResult<Self::Output>;
 }
 
-/// Implements [`IonHasher`] for any type that implements [`Digest`](digest::Digest).
-///
-/// Note: the `Digest` trait is implemented for types that implement a set of
-/// traits. You should read the `D` generic as `Digest`. The reason we list the
-/// subtraits here is that implementors have much less work to do if they
-/// implement the subtraits only, as the blanket impls in the `digest` crate take
-/// care of assembly.
+/// Implements [`IonHasher`] for any type that implements [`IonDigest`].
 impl<D> IonHasher for D
 where
-    D: Update + FixedOutput + Reset + Clone + Default,
+    D: IonDigest,
 {
-    type Output = digest::Output<D>;
+    type Output = D::Output;
 
     /// Provides Ion hash over arbitrary [`Element`] instances with a given
-    /// [`Digest`](digest::Digest) algorithm.
+    /// [`IonDigest`] algorithm.
     fn hash_element(elem: &Element) -> IonResult<Self::Output> {
         ElementHasher::new(D::default()).hash_element(elem)
     }
 }
+
+/// Extends [`IonHasher`] to support hashing [`LazyValue`]s, which are part of the experimental
+/// streaming reader API.
+#[cfg(feature = "experimental-reader-writer")]
+pub trait IonValueHasher: IonHasher {
+    /// Returns the Ion Hash of the given [`LazyValue`]. The value is read incrementally rather
+    /// than being materialized as an [`Element`]; the resulting digest is the same as the one
+    /// [`hash_element`](IonHasher::hash_element) would produce for the equivalent `Element`.
+    fn hash_value<E: Decoder>(value: LazyValue<'_, E>) -> IonResult<Self::Output>;
+}
+
+/// Implements [`IonValueHasher`] for any type that implements [`IonDigest`].
+#[cfg(feature = "experimental-reader-writer")]
+impl<D> IonValueHasher for D
+where
+    D: IonDigest,
+{
+    fn hash_value<E: Decoder>(value: LazyValue<'_, E>) -> IonResult<Self::Output> {
+        LazyValueHasher::new(D::default()).hash_value(value)
+    }
+}
+
+#[cfg(all(test, feature = "ion-hash-sha2", feature = "ion-hash-blake2"))]
+mod tests {
+    use crate::ion_hash::{blake2b512, blake2s256, sha256, sha512, IonHasher};
+    use crate::{Element, IonResult};
+
+    // The serialized bytes of "hello" are `0B 80 68 65 6C 6C 6F 0E`. The expected digest is the
+    // one that `sha256sum` computes for those bytes.
+    #[test]
+    fn convenience_functions() -> IonResult<()> {
+        let hello = Element::from("hello");
+        assert_eq!(
+            sha256(&hello)?,
+            [
+                0x2b, 0x04, 0xb4, 0x82, 0x83, 0x41, 0x28, 0x19, 0x78, 0xfe, 0x1e, 0x2e, 0x82, 0x91,
+                0x5b, 0x79, 0x7a, 0x66, 0x4f, 0xf0, 0x0b, 0x8d, 0xf7, 0xeb, 0xf5, 0x57, 0xcd, 0xf4,
+                0x95, 0xc2, 0xbf, 0xa8,
+            ]
+        );
+        assert_eq!(
+            sha256(&hello)?.as_slice(),
+            sha2::Sha256::hash_element(&hello)?.as_slice()
+        );
+        assert_eq!(
+            sha512(&hello)?.as_slice(),
+            sha2::Sha512::hash_element(&hello)?.as_slice()
+        );
+        assert_eq!(
+            blake2b512(&hello)?.as_slice(),
+            blake2::Blake2b512::hash_element(&hello)?.as_slice()
+        );
+        assert_eq!(
+            blake2s256(&hello)?.as_slice(),
+            blake2::Blake2s256::hash_element(&hello)?.as_slice()
+        );
+        Ok(())
+    }
+}
@@ -8,13 +8,13 @@
 
 use crate::binary::decimal::DecimalBinaryEncoder;
 use crate::binary::timestamp::TimestampBinaryEncoder;
-use crate::binary::{self};
 use crate::ion_hash::element_hasher::ElementHasher;
 use crate::ion_hash::type_qualifier::type_qualifier_symbol;
+use crate::ion_hash::IonDigest;
 use crate::result::IonResult;
 use crate::{Decimal, Int, IonType, Struct, Symbol, Timestamp};
 use crate::{Element, Sequence};
-use digest::{FixedOutput, Output, Reset, Update};
+use num_traits::Zero;
 
 pub(crate) trait RepresentationEncoder {
     fn update_with_representation(&mut self, elem: &Element) -> IonResult<()> {
@@ -49,17 +49,14 @@ pub(crate) trait RepresentationEncoder {
 
 impl<D> RepresentationEncoder for ElementHasher<D>
 where
-    D: Update + FixedOutput + Reset + Clone + Default,
+    D: IonDigest,
 {
     fn write_repr_integer(&mut self, value: Option<&Int>) -> IonResult<()> {
         if let Some(int) = value {
-            match int.expect_i128()? {
-                0 => {}
-                i => {
-                    let magnitude = i.unsigned_abs();
-                    let encoded = binary::uint::encode(magnitude);
-                    self.update_escaping(encoded.as_bytes());
-                }
+            // Zero has no representation bytes.
+            if !int.is_zero() {
+                let magnitude = int.unsigned_abs();
+                self.update_escaping(magnitude.to_be_bytes());
             }
         }
 
@@ -189,7 +186,7 @@ where
                 .map(|(key, value)| struct_field_hash::<D>(key, value))
                 .collect::<IonResult<_>>()?;
 
-            hashes.sort();
+            hashes.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
 
             for hash in hashes {
                 self.update_escaping(hash);
@@ -200,9 +197,9 @@ where
     }
 }
 
-fn struct_field_hash<D>(key: &Symbol, value: &Element) -> IonResult<Output<D>>
+fn struct_field_hash<D>(key: &Symbol, value: &Element) -> IonResult<D::Output>
 where
-    D: Update + FixedOutput + Reset + Clone + Default,
+    D: IonDigest,
 {
     let mut hasher = ElementHasher::new(D::default());
 
@@ -216,5 +213,5 @@ where
     // value
     hasher.update_serialized_bytes(value)?;
 
-    Ok(hasher.digest.finalize_fixed())
+    Ok(hasher.digest.finalize())
 }
@@ -3,7 +3,9 @@
 //!
 //! [spec]: https://amazon-ion.github.io/ion-hash/docs/spec.html.
 use crate::binary::IonTypeCode;
-use crate::{Decimal, Int, IonType, Struct, Symbol, Timestamp};
+use crate::lazy::decoder::Decoder;
+use crate::lazy::value_ref::ValueRef;
+use crate::{Decimal, Int, IonType, Struct, Symbol, SymbolRef, Timestamp};
 use crate::{Element, Sequence};
 
 use std::slice;
@@ -51,6 +53,27 @@ impl TypeQualifier {
         }
     }
 
+    /// Computes a [`TypeQualifier`] from a [`ValueRef`] read from a lazy value. This produces the
+    /// same qualifier that [`from_element`](Self::from_element) would for the equivalent
+    /// [`Element`].
+    pub(crate) fn from_value_ref<D: Decoder>(value: &ValueRef<'_, D>) -> TypeQualifier {
+        match value {
+            ValueRef::Null(ion_type) => type_qualifier_null_of(*ion_type),
+            ValueRef::Bool(b) => type_qualifier_boolean(Some(*b)),
+            ValueRef::Int(i) => type_qualifier_integer(Some(i)),
+            ValueRef::Float(f) => type_qualifier_float(Some(*f)),
+            ValueRef::Decimal(_) => type_qualifier_not_null(IonType::Decimal),
+            ValueRef::Timestamp(_) => type_qualifier_not_null(IonType::Timestamp),
+            ValueRef::Symbol(symbol) => type_qualifier_symbol_ref(symbol),
+            ValueRef::String(_) => type_qualifier_not_null(IonType::String),
+            ValueRef::Clob(_) => type_qualifier_not_null(IonType::Clob),
+            ValueRef::Blob(_) => type_qualifier_not_null(IonType::Blob),
+            ValueRef::List(_) => type_qualifier_not_null(IonType::List),
+            ValueRef::SExp(_) => type_qualifier_not_null(IonType::SExp),
+            ValueRef::Struct(_) => type_qualifier_not_null(IonType::Struct),
+        }
+    }
+
     /// Convenient transform to feed to a `Digest`.
     pub(crate) fn as_bytes(&self) -> &[u8] {
         slice::from_ref(&self.0)
@@ -138,3 +161,52 @@ pub(crate) fn type_qualifier_symbol(sym: Option<&Symbol>) -> TypeQualifier {
 pub(crate) fn type_qualifier_struct(value: Option<&Struct>) -> TypeQualifier {
     combine(IonTypeCode::Struct, qualify_nullness(value))
 }
+
+/// Returns the qualifier of a null value of the given type, e.g. `null.int`.
+pub(crate) fn type_qualifier_null_of(ion_type: IonType) -> TypeQualifier {
+    match ion_type {
+        IonType::Null => type_qualifier_null(),
+        IonType::Bool => type_qualifier_boolean(None),
+        IonType::Int => type_qualifier_integer(None),
+        IonType::Float => type_qualifier_float(None),
+        IonType::Decimal => type_qualifier_decimal(None),
+        IonType::Timestamp => type_qualifier_timestamp(None),
+        IonType::Symbol => type_qualifier_symbol(None),
+        IonType::String => type_qualifier_string(None),
+        IonType::Clob => type_qualifier_clob(None),
+        IonType::Blob => type_qualifier_blob(None),
+        IonType::List => type_qualifier_list(None),
+        IonType::SExp => type_qualifier_sexp(None),
+        IonType::Struct => type_qualifier_struct(None),
+    }
+}
+
+/// Returns the qualifier of a non-null value of the given type. This is only meaningful for
+/// types whose qualifier does not depend on the value itself; the qualifiers of bools, ints, and
+/// symbols must be computed from the value.
+pub(crate) fn type_qualifier_not_null(ion_type: IonType) -> TypeQualifier {
+    let type_code = match ion_type {
+        IonType::Float => IonTypeCode::Float,
+        IonType::Decimal => IonTypeCode::Decimal,
+        IonType::Timestamp => IonTypeCode::Timestamp,
+        IonType::String => IonTypeCode::String,
+        IonType::Clob => IonTypeCode::Clob,
+        IonType::Blob => IonTypeCode::Blob,
+        IonType::List => IonTypeCode::List,
+        IonType::SExp => IonTypeCode::SExpression,
+        IonType::Struct => IonTypeCode::Struct,
+        IonType::Null | IonType::Bool | IonType::Int | IonType::Symbol => {
+            unreachable!("the qualifier of a non-null {ion_type} depends on its value")
+        }
+    };
+    combine(type_code, QUALIFIER_NOT_NULL)
+}
+
+/// Like [`type_qualifier_symbol`], but for a non-null [`SymbolRef`] such as a lazily read field
+/// name or annotation.
+pub(crate) fn type_qualifier_symbol_ref(symbol: &SymbolRef<'_>) -> TypeQualifier {
+    if symbol.text().is_none() {
+        return TypeQualifier(0x71);
+    }
+    combine(IonTypeCode::Symbol, QUALIFIER_NOT_NULL)
+}
@@ -33,7 +33,7 @@ use crate::lazy::decoder::{
     RawVersionMarker,
 };
 use crate::lazy::encoding::{
-    BinaryEncoding_1_0, BinaryEncoding_1_1, TextEncoding_1_0, TextEncoding_1_1,
+    BinaryEncoding_1_0, BinaryEncoding_1_1, RawValueLiteral, TextEncoding_1_0, TextEncoding_1_1,
 };
 use crate::lazy::expanded::e_expression::EExpArgGroup;
 use crate::lazy::expanded::macro_evaluator::{
@@ -452,6 +452,9 @@ impl<'data> LazyRawAnyReader<'data> {
         match *data {
             [0xE0, 0x01, 0x00, 0xEA, ..] => IonEncoding::Binary_1_0,
             [0xE0, 0x01, 0x01, 0xEA, ..] => IonEncoding::Binary_1_1,
+            // The input ends partway through a binary IVM. Text Ion cannot begin with this byte
+            // sequence, so treat it as binary; the reader will report that the IVM is incomplete.
+            [0xE0] | [0xE0, 0x01] | [0xE0, 0x01, 0x00 | 0x01] => IonEncoding::Binary_1_0,
             _ => IonEncoding::Text_1_0,
         }
     }
@@ -736,6 +739,15 @@ impl<'top> LazyRawAnyValue<'top> {
     }
 }
 
+impl<'top> RawValueLiteral for LazyRawAnyValue<'top> {
+    fn binary_1_0_body(&self) -> Option<&[u8]> {
+        match &self.encoding {
+            LazyRawValueKind::Binary_1_0(value) => Some(value.value_body()),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub enum LazyRawValueKind<'top> {
     Text_1_0(LazyRawTextValue_1_0<'top>),
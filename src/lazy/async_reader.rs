@@ -0,0 +1,341 @@
+use std::io;
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::element::Element;
+use crate::lazy::decoder::Decoder;
+use crate::lazy::reader::Reader;
+use crate::lazy::streaming_raw_reader::{IonDataSource, IonInput};
+use crate::lazy::value::LazyValue;
+use crate::read_config::{ReadConfig, ReadLimit};
+use crate::result::IonFailure;
+use crate::{IonError, IonResult, Sequence};
+
+/// A buffered data source for implementations of tokio's [`AsyncRead`] trait; it is the
+/// asynchronous counterpart of [`IonStream`](crate::IonStream).
+///
+/// Its synchronous `IonDataSource` methods never perform I/O. If the reader needs more data
+/// than the buffer holds, `fill_buffer` returns an [`io::ErrorKind::WouldBlock`] error, which
+/// signals the [`AsyncReader`] to await [`fill_buffer_async`](Self::fill_buffer_async) and try
+/// again. Once `fill_buffer_async` has
+/// reached the end of the input, `fill_buffer` returns `Ok(0)`.
+pub struct AsyncIonStream<R: AsyncRead + Unpin> {
+    // The input source
+    input: R,
+    // A buffer containing a sliding window of data from `input`.
+    buffer: Vec<u8>,
+    // The index of the first occupied byte in the buffer. If position==limit, no bytes
+    // are occupied.
+    position: usize,
+    // The index of the first unoccupied byte in the buffer *at or after* `position`.
+    limit: usize,
+    // The size beyond which the buffer will not grow.
+    max_buffer_size: usize,
+    // Whether `input` has reported the end of the stream.
+    is_exhausted: bool,
+}
+
+impl<R: AsyncRead + Unpin> AsyncIonStream<R> {
+    const DEFAULT_IO_BUFFER_SIZE: usize = 4 * 1024;
+
+    pub fn new(input: R) -> Self {
+        AsyncIonStream {
+            input,
+            buffer: vec![0u8; Self::DEFAULT_IO_BUFFER_SIZE],
+            position: 0,
+            limit: 0,
+            max_buffer_size: usize::MAX,
+            is_exhausted: false,
+        }
+    }
+
+    /// Moves all of the bytes in the range `self.position..self.limit` to the beginning of the buffer,
+    /// reclaiming space that was previously occupied by bytes that have since been consumed.
+    fn shift_remaining_bytes_to_index_zero(&mut self) {
+        let remaining_data_range = self.position..self.limit;
+        self.limit = remaining_data_range.len();
+        self.position = 0;
+        self.buffer.copy_within(remaining_data_range, 0);
+    }
+
+    /// Awaits more input from the data source and appends it to the buffer of available bytes.
+    /// A return value of `Ok(0)` indicates that the input has been exhausted.
+    pub async fn fill_buffer_async(&mut self) -> IonResult<usize> {
+        if self.position > 0 {
+            self.shift_remaining_bytes_to_index_zero();
+        }
+        if self.buffer.len() == self.limit {
+            if self.buffer.len() >= self.max_buffer_size {
+                return IonResult::limit_exceeded(ReadLimit::MaxBufferSize, self.max_buffer_size);
+            }
+            let new_size = (self.buffer.len() * 2).min(self.max_buffer_size);
+            self.buffer.resize(new_size, 0);
+        }
+        let bytes_read = self.input.read(&mut self.buffer[self.limit..]).await?;
+        if bytes_read == 0 {
+            self.is_exhausted = true;
+        }
+        self.limit += bytes_read;
+        Ok(bytes_read)
+    }
+}
+
+impl<R: AsyncRead + Unpin> IonDataSource for AsyncIonStream<R> {
+    fn buffer(&self) -> &[u8] {
+        &self.buffer[self.position..self.limit]
+    }
+
+    fn fill_buffer(&mut self) -> IonResult<usize> {
+        if self.is_exhausted {
+            return Ok(0);
+        }
+        Err(io::Error::from(io::ErrorKind::WouldBlock).into())
+    }
+
+    fn consume(&mut self, number_of_bytes: usize) {
+        self.position += number_of_bytes;
+        debug_assert!(self.position <= self.limit);
+    }
+
+    fn set_max_buffer_size(&mut self, max_buffer_size: usize) {
+        self.max_buffer_size = max_buffer_size;
+        let new_size = max_buffer_size.max(self.limit);
+        if new_size < self.buffer.len() {
+            self.buffer.truncate(new_size);
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> IonInput for AsyncIonStream<R> {
+    type DataSource = Self;
+
+    fn into_data_source(self) -> Self::DataSource {
+        self
+    }
+}
+
+/// A [`Reader`] that awaits more data from an [`AsyncRead`] implementation whenever the next
+/// top-level value is not yet complete.
+///
+/// Decoding is performed synchronously by the same machinery that [`Reader`] uses. Each time
+/// the input buffer runs out before a value can be read in full, the reader awaits more input
+/// and tries again; values that have been returned are always complete.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[tokio::main(flavor = "current_thread")]
+///# async fn main() -> IonResult<()> {
+/// use ion_rs::{AsyncReader, Element};
+/// use ion_rs::v1_0::Text;
+/// use tokio::io::AsyncWriteExt;
+///
+/// let (mut client, server) = tokio::io::duplex(64);
+/// tokio::spawn(async move {
+///     client.write_all(b"1 2 ").await?;
+///     client.write_all(b"3").await
+/// });
+///
+/// let mut reader = AsyncReader::new(Text, server)?;
+/// let mut sum = 0;
+/// while let Some(value) = reader.next().await? {
+///     sum += value.read()?.expect_i64()?;
+/// }
+/// assert_eq!(sum, 6);
+///# Ok(())
+///# }
+/// ```
+pub struct AsyncReader<Encoding: Decoder, Input: AsyncRead + Unpin> {
+    reader: Reader<Encoding, AsyncIonStream<Input>>,
+}
+
+impl<Encoding: Decoder, Input: AsyncRead + Unpin> AsyncReader<Encoding, Input> {
+    pub fn new(
+        config: impl Into<ReadConfig<Encoding>>,
+        input: Input,
+    ) -> IonResult<AsyncReader<Encoding, Input>> {
+        let reader = Reader::new(config, AsyncIonStream::new(input))?;
+        Ok(AsyncReader { reader })
+    }
+
+    /// Returns the next top-level value in the input stream as `Ok(Some(lazy_value))`, awaiting
+    /// more input as needed. If the input is exhausted, returns `Ok(None)`.
+    ///
+    /// If the input ends partway through a value or contains invalid data, returns `Err(ion_error)`.
+    #[allow(clippy::should_implement_trait)]
+    // ^-- Clippy objects that the method name `next` will be confused for `Iterator::next()`
+    pub async fn next(&mut self) -> IonResult<Option<LazyValue<'_, Encoding>>> {
+        loop {
+            {
+                // XXX: Returning the result conditionally from inside this loop runs into the same
+                //      borrow checker limitation described on the `StreamingRawReader` type. A
+                //      raw pointer lets us decouple the result's lifetime from the loop.
+                //
+                //      See: https://github.com/rust-lang/rust/issues/70255
+                //
+                // SAFETY: The result either escapes the loop via `return` or is dropped at the end
+                //         of this block, before `self.reader` is modified below.
+                let reader = unsafe { &mut *(&mut self.reader as *mut Reader<_, _>) };
+                match reader.next() {
+                    Err(IonError::Io(e)) if e.source().kind() == io::ErrorKind::WouldBlock => {}
+                    result => return result,
+                }
+            }
+            // The reader ran out of data; wait for more to arrive and try again.
+            self.reader.data_source_mut().fill_buffer_async().await?;
+        }
+    }
+
+    /// Like [`Self::next`], but returns an `IonError` if there are no more values in the stream.
+    pub async fn expect_next(&mut self) -> IonResult<LazyValue<'_, Encoding>> {
+        self.next()
+            .await?
+            .ok_or_else(|| IonError::decoding_error("expected another top-level value"))
+    }
+
+    /// Reads the next top-level value in the input stream and materializes it as an [`Element`].
+    pub async fn read_next_element(&mut self) -> IonResult<Option<Element>> {
+        match self.next().await? {
+            None => Ok(None),
+            Some(lazy_value) => Ok(Some(lazy_value.try_into()?)),
+        }
+    }
+
+    /// Reads all of the remaining top-level values in the input stream as [`Element`]s.
+    pub async fn read_all_elements(&mut self) -> IonResult<Sequence> {
+        let mut elements = Vec::new();
+        while let Some(element) = self.read_next_element().await? {
+            elements.push(element);
+        }
+        Ok(elements.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use tokio::io::{duplex, AsyncWriteExt};
+
+    use crate::lazy::decoder::Decoder;
+    use crate::lazy::encoding::{BinaryEncoding_1_0, Encoding, TextEncoding_1_0};
+    use crate::read_config::ReadConfig;
+    use crate::{
+        v1_0, AnyEncoding, AsyncReader, Element, IonData, IonError, IonResult, ReadLimit,
+        ReadLimits, Sequence,
+    };
+
+    const TEXT: &str = r#"
+        $ion_1_0
+        foo::bar::1000 75 1.20 null null.struct 2024-03-14T12:00:30.000Z
+        $ion_symbol_table::{symbols: ["baz", "quux"]}
+        $10 $11::{a: [1, 2, 3], "b": (c d e)} "hello" '''world''' {{aGVsbG8=}}
+    "#;
+
+    /// Writes `data` to the reader's input in pieces of `chunk_size` bytes while the reader
+    /// collects the values it contains.
+    async fn read_in_chunks<D: Decoder>(
+        config: impl Into<ReadConfig<D>>,
+        data: &[u8],
+        chunk_size: usize,
+    ) -> IonResult<Sequence> {
+        let (mut client, server) = duplex(chunk_size);
+        let mut reader = AsyncReader::new(config, server)?;
+        let write = async move {
+            for chunk in data.chunks(chunk_size) {
+                client.write_all(chunk).await?;
+            }
+            // Dropping the client closes the pipe.
+            Ok::<_, IonError>(())
+        };
+        let ((), elements) = tokio::try_join!(write, reader.read_all_elements())?;
+        Ok(elements)
+    }
+
+    #[rstest]
+    #[case::one_byte_at_a_time(1)]
+    #[case::small_chunks(7)]
+    #[case::large_chunks(1024)]
+    #[tokio::test]
+    async fn read_text_in_chunks(#[case] chunk_size: usize) -> IonResult<()> {
+        let expected = Element::read_all(TEXT)?;
+        let actual = read_in_chunks(TextEncoding_1_0, TEXT.as_bytes(), chunk_size).await?;
+        assert!(IonData::eq(&expected, &actual));
+        let actual = read_in_chunks(AnyEncoding, TEXT.as_bytes(), chunk_size).await?;
+        assert!(IonData::eq(&expected, &actual));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::one_byte_at_a_time(1)]
+    #[case::small_chunks(7)]
+    #[case::large_chunks(1024)]
+    #[tokio::test]
+    async fn read_binary_in_chunks(#[case] chunk_size: usize) -> IonResult<()> {
+        let expected = Element::read_all(TEXT)?;
+        let binary = v1_0::Binary::encode_all(&expected)?;
+        let actual = read_in_chunks(BinaryEncoding_1_0, &binary, chunk_size).await?;
+        assert!(IonData::eq(&expected, &actual));
+        let actual = read_in_chunks(AnyEncoding, &binary, chunk_size).await?;
+        assert!(IonData::eq(&expected, &actual));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn text_scalar_split_across_writes() -> IonResult<()> {
+        let (mut client, server) = duplex(64);
+        let mut reader = AsyncReader::new(v1_0::Text, server)?;
+        client.write_all(b"12").await?;
+        let write = async move {
+            // Give the reader an opportunity to see the partial value before the rest arrives.
+            tokio::task::yield_now().await;
+            client.write_all(b"34 true").await?;
+            client.write_all(b"_teeth").await?;
+            Ok::<_, IonError>(())
+        };
+        let (write_result, read_result) = tokio::join!(write, reader.read_all_elements());
+        write_result?;
+        assert_eq!(read_result?, Element::read_all("1234 true_teeth")?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn incomplete_value_at_end_of_input() -> IonResult<()> {
+        let binary = v1_0::Binary::encode_all(&Element::read_all("\"hello\"")?)?;
+        let (mut client, server) = duplex(64);
+        client.write_all(&binary[..binary.len() - 1]).await?;
+        drop(client);
+        let mut reader = AsyncReader::new(v1_0::Binary, server)?;
+        assert!(matches!(reader.next().await, Err(IonError::Incomplete(_))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn buffer_size_limit() -> IonResult<()> {
+        let (mut client, server) = duplex(64);
+        client
+            .write_all(b"\"a string that will not fit\" 1")
+            .await?;
+        drop(client);
+        let limits = ReadLimits::new().with_max_buffer_size(8);
+        let mut reader = AsyncReader::new(v1_0::Text.with_limits(limits), server)?;
+        match reader.next().await {
+            Err(IonError::LimitExceeded(e)) => assert_eq!(e.limit(), ReadLimit::MaxBufferSize),
+            other => panic!("expected a limit exceeded error, found {other:?}"),
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_in_spawned_task() -> IonResult<()> {
+        let (mut client, server) = duplex(64);
+        // `tokio::spawn` requires the reader (and its futures) to be `Send`.
+        let task = tokio::spawn(async move {
+            let mut reader = AsyncReader::new(AnyEncoding, server)?;
+            reader.read_all_elements().await
+        });
+        client.write_all(b"1 foo::2 [3]").await?;
+        drop(client);
+        let elements = task.await.expect("reader task panicked")?;
+        assert_eq!(elements, Element::read_all("1 foo::2 [3]")?);
+        Ok(())
+    }
+}
@@ -19,6 +19,7 @@ use crate::lazy::encoder::binary::v1_1::flex_uint::FlexUInt;
 use crate::lazy::encoding::BinaryEncoding_1_0;
 use crate::result::IonFailure;
 use crate::{Int, IonError, IonResult, IonType};
+use num_bigint::{BigInt, BigUint};
 
 const MAX_INT_SIZE_IN_BYTES: usize = mem::size_of::<i128>();
 
@@ -340,33 +341,29 @@ impl<'a> BinaryBuffer<'a> {
         const BUFFER_SIZE: usize = MAX_INT_SIZE_IN_BYTES;
         if length == 0 {
             return Ok((DecodedInt::new(0, false, 0), self.consume(0)));
-        } else if length > BUFFER_SIZE + 1 {
-            //                         ^^^
-            // We support reading i128::MIN, which requires 17 bytes to encode. We reject anything
-            // 18 bytes or larger here; later on, we reject 17-byte encodings of values other than
-            // i128::MIN.
-            return cold_path! {
-            IonResult::decoding_error(format!(
-                "Found a {length}-byte Int. Max supported size is {MAX_INT_SIZE_IN_BYTES} bytes."
-            ))};
         }
 
         let int_bytes = self
             .peek_n_bytes(length)
             .ok_or_else(|| IonError::incomplete("an Int encoding primitive", self.offset()))?;
 
-        // i128::MIN is a special case; it's the only 17-byte encoding we accept.
-        const INT_MIN_ENCODING: &[u8] = &[0x80, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
-        if length == INT_MIN_ENCODING.len() {
+        let is_negative: bool = int_bytes[0] & 0b1000_0000 != 0;
+
+        if length > BUFFER_SIZE {
+            // The value may not fit in an i128; decode it as a BigInt. (If it does fit, as
+            // i128::MIN's 17-byte encoding does, `Int::from` will store it inline.)
             return cold_path! {{
-                if int_bytes != INT_MIN_ENCODING {
-                    IonResult::decoding_error("Found an int encoding primitive outside the supported range")
-                } else {
-                    Ok((
-                        DecodedInt::new(Int::from(i128::MIN), true, length),
-                        self.consume(length),
-                    ))
+                let mut magnitude_bytes = int_bytes.to_vec();
+                // Unset the sign bit
+                magnitude_bytes[0] &= 0b0111_1111;
+                let mut value = BigInt::from(BigUint::from_bytes_be(&magnitude_bytes));
+                if is_negative {
+                    value = value.neg();
                 }
+                Ok((
+                    DecodedInt::new(Int::from(value), is_negative, length),
+                    self.consume(length),
+                ))
             }};
         }
 
@@ -375,7 +372,6 @@ impl<'a> BinaryBuffer<'a> {
         let first_occupied_byte_index = buffer.len() - int_bytes.len();
         buffer[first_occupied_byte_index..].copy_from_slice(int_bytes);
 
-        let is_negative: bool = int_bytes[0] & 0b1000_0000 != 0;
         // Unset the sign bit in the buffer
         buffer[first_occupied_byte_index] &= 0b0111_1111;
 
@@ -993,12 +989,38 @@ mod tests {
     }
 
     #[test]
-    fn read_int_overflow() -> IonResult<()> {
+    fn read_int_larger_than_i128() -> IonResult<()> {
         let data = vec![1; MAX_INT_SIZE_IN_BYTES + 1];
-        let buffer = BinaryBuffer::new(&data); // Negative zero
-        buffer
-            .read_int(buffer.len())
-            .expect_err("This exceeded the configured max Int size.");
+        let buffer = BinaryBuffer::new(&data);
+        let int = buffer.read_int(buffer.len())?.0;
+        assert_eq!(int.size_in_bytes(), MAX_INT_SIZE_IN_BYTES + 1);
+        assert_eq!(int.value().as_i128(), None);
+        let expected = BigInt::from_bytes_be(num_bigint::Sign::Plus, &data);
+        assert_eq!(int.value(), &Int::from(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn read_negative_int_larger_than_i128() -> IonResult<()> {
+        let mut data = vec![0xFF; MAX_INT_SIZE_IN_BYTES + 2];
+        let buffer = BinaryBuffer::new(&data);
+        let int = buffer.read_int(buffer.len())?.0;
+        data[0] = 0x7F;
+        let expected = BigInt::from_bytes_be(num_bigint::Sign::Minus, &data);
+        assert!(int.value().is_negative());
+        assert_eq!(int.value(), &Int::from(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn read_i128_min() -> IonResult<()> {
+        // i128::MIN's magnitude needs all 128 bits, so its encoding requires a 17th byte.
+        let mut data = vec![0u8; MAX_INT_SIZE_IN_BYTES + 1];
+        data[0] = 0x80;
+        data[1] = 0x80;
+        let buffer = BinaryBuffer::new(&data);
+        let int = buffer.read_int(buffer.len())?.0;
+        assert_eq!(int.value().as_i128(), Some(i128::MIN));
         Ok(())
     }
 }
@@ -46,6 +46,9 @@ pub struct BinaryBuffer<'a> {
     //                          offset: 6
     data: &'a [u8],
     offset: usize,
+    // The number of delimited containers (and e-expressions) being scanned that enclose `data`.
+    // This is checked against the reader's `max_container_depth` before each one is entered.
+    depth: usize,
     context: EncodingContextRef<'a>,
 }
 
@@ -93,6 +96,7 @@ impl<'a> BinaryBuffer<'a> {
         BinaryBuffer {
             data,
             offset,
+            depth: 0,
             context,
         }
     }
@@ -119,6 +123,7 @@ impl<'a> BinaryBuffer<'a> {
         BinaryBuffer {
             data: self.bytes_range(offset, length),
             offset: self.offset + offset,
+            depth: self.depth,
             context: self.context,
         }
     }
@@ -164,10 +169,21 @@ impl<'a> BinaryBuffer<'a> {
         Self {
             data: &self.data[num_bytes_to_consume..],
             offset: self.offset + num_bytes_to_consume,
+            depth: self.depth,
             context: self.context,
         }
     }
 
+    /// Returns a copy of this buffer whose data is nested inside one more container. If that would
+    /// exceed the reader's container depth limit, returns an `IonError::LimitExceeded` instead.
+    /// Checking the limit before scanning a container's contents keeps deeply nested input from
+    /// exhausting the stack.
+    fn enter_container(self) -> IonResult<Self> {
+        let depth = self.depth + 1;
+        self.context.read_limits().check_container_depth(depth)?;
+        Ok(Self { depth, ..self })
+    }
+
     /// Reads the first byte in the buffer and returns it as an [Opcode]. If the buffer is empty,
     /// returns an `IonError::Incomplete`.
     #[inline]
@@ -259,6 +275,7 @@ impl<'a> BinaryBuffer<'a> {
             data: &self.data[offset..],
             // stream offset + local offset
             offset: self.offset + offset,
+            depth: self.depth,
             context: self.context,
         }
     }
@@ -466,7 +483,7 @@ impl<'a> BinaryBuffer<'a> {
     pub(crate) fn peek_delimited_sequence(
         self,
     ) -> IonResult<(DelimitedContents<'a>, BinaryBuffer<'a>)> {
-        let mut input = self.consume(1);
+        let mut input = self.consume(1).enter_container()?;
         let mut values =
             BumpVec::<LazyRawValueExpr<'a, v1_1::Binary>>::new_in(self.context.allocator());
 
@@ -485,7 +502,15 @@ impl<'a> BinaryBuffer<'a> {
             }
         }
 
-        Ok((DelimitedContents::Values(values.into_bump_slice()), input))
+        // The input that follows the container is at the same depth as the container itself.
+        let remaining = Self {
+            depth: self.depth,
+            ..input
+        };
+        Ok((
+            DelimitedContents::Values(values.into_bump_slice()),
+            remaining,
+        ))
     }
 
     /// Reads the value for a delimited struct field, consuming NOPs if present.
@@ -570,7 +595,7 @@ impl<'a> BinaryBuffer<'a> {
     pub(crate) fn peek_delimited_struct(
         self,
     ) -> IonResult<(DelimitedContents<'a>, BinaryBuffer<'a>)> {
-        let mut input = self.consume(1);
+        let mut input = self.consume(1).enter_container()?;
         let mut values =
             BumpVec::<LazyRawFieldExpr<'a, v1_1::Binary>>::new_in(self.context.allocator());
 
@@ -590,7 +615,15 @@ impl<'a> BinaryBuffer<'a> {
             }
         }
 
-        Ok((DelimitedContents::Fields(values.into_bump_slice()), input))
+        // The input that follows the container is at the same depth as the container itself.
+        let remaining = Self {
+            depth: self.depth,
+            ..input
+        };
+        Ok((
+            DelimitedContents::Fields(values.into_bump_slice()),
+            remaining,
+        ))
     }
 
     /// Reads a value from the buffer. The caller must confirm that the buffer is not empty and that
@@ -925,8 +958,10 @@ impl<'a> BinaryBuffer<'a> {
         };
 
         let bitmap = ArgGroupingBitmap::new(signature.num_variadic_params(), bitmap_bits);
+        // Arguments are nested inside the e-expression, so they count toward the depth limit.
+        let args_input = input_after_bitmap.enter_container()?;
         let mut args_iter =
-            BinaryEExpArgsIterator_1_1::for_input(bitmap.iter(), input_after_bitmap, signature);
+            BinaryEExpArgsIterator_1_1::for_input(bitmap.iter(), args_input, signature);
         let mut cache =
             BumpVec::with_capacity_in(args_iter.size_hint().0, self.context.allocator());
 
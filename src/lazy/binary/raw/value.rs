@@ -530,7 +530,7 @@ impl<'top> LazyRawBinaryValue_1_0<'top> {
         debug_assert!(self.encoded_value.ion_type() == IonType::Int);
         // `value_body()` returns a buffer starting at the body of the value.
         let uint_bytes = self.value_body();
-        let magnitude: Int = DecodedUInt::uint_from_slice(uint_bytes)?.try_into()?;
+        let magnitude: Int = DecodedUInt::uint_from_slice(uint_bytes).try_into()?;
 
         use crate::binary::type_code::IonTypeCode::*;
         use num_traits::Zero;
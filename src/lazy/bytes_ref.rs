@@ -82,7 +82,7 @@ impl<'a, 'b> PartialEq<BytesRef<'a>> for BytesRef<'b> {
 
 impl<'data> Display for BytesRef<'data> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut formatter = FmtValueFormatter { output: f };
+        let mut formatter = FmtValueFormatter::new(f);
         formatter
             .format_blob(self.data())
             .map_err(|_| std::fmt::Error)
@@ -2,6 +2,7 @@ use std::fmt::Debug;
 use std::io::Write;
 use std::ops::Range;
 
+use crate::catalog::EmptyCatalog;
 use crate::lazy::any_encoding::{IonEncoding, IonVersion};
 use crate::lazy::encoder::text::v1_0::writer::LazyRawTextWriter_1_0;
 use crate::lazy::encoder::text::v1_1::writer::LazyRawTextWriter_1_1;
@@ -15,7 +16,7 @@ use crate::lazy::raw_stream_item::LazyRawStreamItem;
 use crate::lazy::raw_value_ref::RawValueRef;
 use crate::lazy::span::Span;
 use crate::lazy::streaming_raw_reader::RawReaderState;
-use crate::read_config::ReadConfig;
+use crate::read_config::{ReadConfig, ReadLimits};
 use crate::result::IonFailure;
 use crate::{
     v1_0, v1_1, Catalog, Encoding, IonResult, IonType, LazyExpandedFieldName, LazyExpandedValue,
@@ -80,9 +81,15 @@ pub trait Decoder: 'static + Sized + Debug + Clone + Copy {
 
     type VersionMarker<'top>: RawVersionMarker<'top>;
 
-    fn with_catalog(self, catalog: impl Catalog + 'static) -> ReadConfig<Self> {
+    /// Creates a [`ReadConfig`] whose readers will resolve shared symbol table imports using
+    /// `catalog`. The catalog moves with the reader, so it must be `Send`.
+    fn with_catalog(self, catalog: impl Catalog + Send + 'static) -> ReadConfig<Self> {
         ReadConfig::new_with_catalog(self, catalog)
     }
+
+    fn with_limits(self, limits: ReadLimits) -> ReadConfig<Self> {
+        ReadConfig::new_with_catalog(self, EmptyCatalog).with_limits(limits)
+    }
 }
 
 pub trait RawVersionMarker<'top>: Debug + Copy + Clone + HasSpan<'top> {
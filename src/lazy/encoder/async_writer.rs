@@ -0,0 +1,309 @@
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::lazy::encoder::value_writer::internal::MakeValueWriter;
+use crate::lazy::encoder::value_writer::SequenceWriter;
+use crate::lazy::encoder::write_as_ion::WriteAsIon;
+use crate::lazy::encoder::writer::Writer;
+use crate::lazy::encoding::Encoding;
+use crate::write_config::WriteConfig;
+use crate::{Element, ElementWriter, IonResult};
+
+/// An Ion writer that emits its output to an implementation of tokio's [`AsyncWrite`] trait.
+///
+/// Encoding is performed synchronously by a [`Writer`] whose output is an in-memory buffer.
+/// Only [`flush`](Self::flush) and [`close`](Self::close) perform I/O; they write any pending
+/// symbol table or encoding directive to the sink followed by the data that depends on it,
+/// producing the same bytes that a [`Writer`] would.
+///
+/// `flush` is cancel-safe: if its future is dropped before it completes, the bytes that were not
+/// yet accepted by the sink will be written by the next call to `flush` or `close`.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[tokio::main(flavor = "current_thread")]
+///# async fn main() -> IonResult<()> {
+/// use ion_rs::{AsyncWriter, Element};
+/// use ion_rs::v1_0::Binary;
+///
+/// let mut writer = AsyncWriter::new(Binary, Vec::new())?;
+/// writer.write(1)?.write("foo")?;
+/// let output = writer.close().await?;
+///
+/// assert_eq!(Element::read_all(output)?, Element::read_all("1 \"foo\"")?);
+///# Ok(())
+///# }
+/// ```
+pub struct AsyncWriter<E: Encoding, Output: AsyncWrite + Unpin> {
+    writer: Writer<E, Vec<u8>>,
+    // The number of bytes at the head of the writer's buffer that have already been written to
+    // `output`. This is only nonzero if a call to `flush` was cancelled partway through.
+    bytes_flushed: usize,
+    output: Output,
+}
+
+impl<E: Encoding, Output: AsyncWrite + Unpin> AsyncWriter<E, Output> {
+    /// Constructs a writer for the requested encoding using the provided configuration.
+    ///
+    /// Nothing is written to `output` until the writer is flushed or closed.
+    pub fn new(config: impl Into<WriteConfig<E>>, output: Output) -> IonResult<Self> {
+        let writer = Writer::new(config, Vec::new())?;
+        Ok(AsyncWriter {
+            writer,
+            bytes_flushed: 0,
+            output,
+        })
+    }
+
+    pub fn output(&self) -> &Output {
+        &self.output
+    }
+
+    pub fn output_mut(&mut self) -> &mut Output {
+        &mut self.output
+    }
+
+    /// Returns a value writer that can be used to write a single top-level value, including
+    /// containers and annotated values.
+    pub fn value_writer(&mut self) -> <Writer<E, Vec<u8>> as MakeValueWriter>::ValueWriter<'_> {
+        self.writer.value_writer()
+    }
+
+    pub fn write<V: WriteAsIon>(&mut self, value: V) -> IonResult<&mut Self> {
+        self.writer.write(value)?;
+        Ok(self)
+    }
+
+    pub fn write_all<V: WriteAsIon, I: IntoIterator<Item = V>>(
+        &mut self,
+        values: I,
+    ) -> IonResult<&mut Self> {
+        self.writer.write_all(values)?;
+        Ok(self)
+    }
+
+    pub fn write_element(&mut self, element: &Element) -> IonResult<()> {
+        self.writer.write_element(element)
+    }
+
+    pub fn write_elements<'a, I: IntoIterator<Item = &'a Element>>(
+        &mut self,
+        elements: I,
+    ) -> IonResult<()> {
+        for element in elements {
+            self.writer.write_element(element)?;
+        }
+        Ok(())
+    }
+
+    /// Writes bytes of previously encoded values to the output sink and flushes it.
+    pub async fn flush(&mut self) -> IonResult<()> {
+        // Encode any pending symbol table or directive ahead of the data in the writer's buffer.
+        self.writer.flush()?;
+        let buffer = self.writer.output_mut();
+        // Unlike `write_all`, recording our progress after each write means that a cancelled
+        // flush neither loses nor repeats any bytes when it is retried.
+        while self.bytes_flushed < buffer.len() {
+            let bytes_written = self.output.write(&buffer[self.bytes_flushed..]).await?;
+            if bytes_written == 0 {
+                return Err(std::io::Error::from(std::io::ErrorKind::WriteZero).into());
+            }
+            self.bytes_flushed += bytes_written;
+        }
+        buffer.clear();
+        self.bytes_flushed = 0;
+        self.output.flush().await?;
+        Ok(())
+    }
+
+    /// Flushes any remaining data to the output sink, shuts it down, and returns it.
+    pub async fn close(mut self) -> IonResult<Output> {
+        self.flush().await?;
+        self.output.shutdown().await?;
+        Ok(self.output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use rstest::rstest;
+    use tokio::io::{duplex, AsyncReadExt, AsyncWrite};
+
+    use crate::lazy::encoder::value_writer::{
+        AnnotatableWriter, SequenceWriter, StructWriter, ValueWriter,
+    };
+    use crate::lazy::encoding::{BinaryEncoding_1_0, Encoding, TextEncoding_1_0};
+    use crate::{
+        v1_0, AsyncWriter, Element, IonError, IonResult, Symbol, TextFormat, WriteConfig, Writer,
+    };
+
+    /// Writes two batches of values that each introduce new symbols, flushing after each batch.
+    fn write_batches<W: SequenceWriter>(writer: &mut W, batch: usize) -> IonResult<()> {
+        let symbol = Symbol::from(format!("symbol_{batch}"));
+        writer.write(1)?.write(&symbol)?;
+        writer
+            .value_writer()
+            .with_annotations(format!("annotation_{batch}").as_str())?
+            .write("text")?;
+        let mut strukt = writer.struct_writer()?;
+        strukt.write(format!("field_{batch}").as_str(), &symbol)?;
+        strukt.close()
+    }
+
+    /// Writes the same values as [`write_batches`] using only `AsyncWriter`'s own methods.
+    async fn async_output<E: Encoding>(config: impl Into<WriteConfig<E>>) -> IonResult<Vec<u8>> {
+        let mut writer = AsyncWriter::new(config, Vec::new())?;
+        for batch in 0..2 {
+            let symbol = Symbol::from(format!("symbol_{batch}"));
+            writer.write(1)?.write(&symbol)?;
+            writer
+                .value_writer()
+                .with_annotations(format!("annotation_{batch}").as_str())?
+                .write("text")?;
+            let mut strukt = writer.value_writer().struct_writer()?;
+            strukt.write(format!("field_{batch}").as_str(), &symbol)?;
+            strukt.close()?;
+            writer.flush().await?;
+        }
+        writer.close().await
+    }
+
+    fn sync_output<E: Encoding>(config: impl Into<WriteConfig<E>>) -> IonResult<Vec<u8>> {
+        let mut writer = Writer::new(config, Vec::new())?;
+        for batch in 0..2 {
+            write_batches(&mut writer, batch)?;
+            writer.flush()?;
+        }
+        writer.close()
+    }
+
+    #[rstest]
+    #[case::text(WriteConfig::<TextEncoding_1_0>::new(TextFormat::Compact))]
+    #[case::binary(WriteConfig::<BinaryEncoding_1_0>::new())]
+    #[tokio::test]
+    async fn same_output_as_sync_writer<E: Encoding>(
+        #[case] config: WriteConfig<E>,
+    ) -> IonResult<()> {
+        let expected = sync_output(config.clone())?;
+        let actual = async_output(config).await?;
+        assert_eq!(actual, expected);
+        let elements = Element::read_all(&actual)?;
+        assert_eq!(
+            elements,
+            Element::read_all(
+                r#"1 symbol_0 annotation_0::"text" {field_0: symbol_0}
+                   1 symbol_1 annotation_1::"text" {field_1: symbol_1}"#
+            )?
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nothing_is_written_until_flush() -> IonResult<()> {
+        let mut writer = AsyncWriter::new(v1_0::Text, Vec::new())?;
+        writer.write_element(&Element::from(Symbol::from("foo")))?;
+        assert!(writer.output().is_empty());
+        writer.flush().await?;
+        assert_eq!(
+            Element::read_all(writer.output())?,
+            Element::read_all("foo")?
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_to_pipe() -> IonResult<()> {
+        let elements = Element::read_all("foo::{bar: [1, 2.5, \"three\"]} baz")?;
+        let (client, mut server) = duplex(8);
+        let mut writer = AsyncWriter::new(v1_0::Binary, client)?;
+        writer.write_elements(&elements)?;
+        // The client is still alive when the read finishes, so the reader can only have seen
+        // the end of the stream because closing the writer shut the client down.
+        let write = writer.close();
+        let read = async move {
+            let mut output = Vec::new();
+            server.read_to_end(&mut output).await?;
+            Ok::<_, IonError>(output)
+        };
+        let (_client, output) = tokio::try_join!(write, read)?;
+        assert_eq!(Element::read_all(output)?, elements);
+        Ok(())
+    }
+
+    /// A sink that accepts one byte per write and is only ready on every other poll.
+    #[derive(Default)]
+    struct TrickleSink {
+        bytes: Vec<u8>,
+        ready: bool,
+        is_shut_down: bool,
+    }
+
+    impl AsyncWrite for TrickleSink {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<std::io::Result<usize>> {
+            if !self.ready {
+                self.ready = true;
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            self.ready = false;
+            self.bytes.extend_from_slice(&buf[..1]);
+            Poll::Ready(Ok(1))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::io::Result<()>> {
+            self.is_shut_down = true;
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn cancelled_flush_resumes_where_it_stopped() -> IonResult<()> {
+        let elements = Element::read_all("foo::{bar: [1, 2.5, \"three\"]} baz")?;
+        let mut writer = AsyncWriter::new(v1_0::Binary, TrickleSink::default())?;
+        writer.write_elements(&elements)?;
+        // Let the flush write a few bytes, then drop its future.
+        tokio::select! {
+            biased;
+            _ = writer.flush() => panic!("the flush should not have completed"),
+            _ = tokio::task::yield_now() => {}
+        }
+        let bytes_written = writer.output().bytes.len();
+        assert!(bytes_written > 0);
+        writer.write(Symbol::from("quux"))?;
+        let sink = writer.close().await?;
+        assert!(sink.bytes.len() > bytes_written);
+        assert!(sink.is_shut_down);
+        let mut expected = elements;
+        expected.push(Element::from(Symbol::from("quux")));
+        assert_eq!(Element::read_all(sink.bytes)?, expected);
+        Ok(())
+    }
+
+    // Reading the output back requires Ion 1.1 support.
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[tokio::test]
+    async fn write_in_spawned_task() -> IonResult<()> {
+        // `tokio::spawn` requires the writer (and its futures) to be `Send`.
+        let task = tokio::spawn(async move {
+            let mut writer = AsyncWriter::new(crate::v1_1::Binary, Vec::new())?;
+            writer.write(Symbol::from("foo"))?.write(2)?;
+            writer.close().await
+        });
+        let output = task.await.expect("writer task panicked")?;
+        assert_eq!(Element::read_all(output)?, Element::read_all("foo 2")?);
+        Ok(())
+    }
+}
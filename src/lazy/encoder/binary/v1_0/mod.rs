@@ -1,6 +1,10 @@
 use crate::lazy::encoder::binary::v1_0::writer::LazyRawBinaryWriter_1_0;
 use crate::lazy::encoder::{Encoder, SymbolCreationPolicy};
 use crate::lazy::encoding::BinaryEncoding_1_0;
+use crate::{
+    AnnotationsEncoding, ContainerEncoding, FieldNameEncoding, IonVersion, SymbolValueEncoding,
+    ValueWriterConfig,
+};
 use std::io::Write;
 
 mod container_writers;
@@ -12,5 +16,18 @@ impl Encoder for BinaryEncoding_1_0 {
     const DEFAULT_SYMBOL_CREATION_POLICY: SymbolCreationPolicy =
         SymbolCreationPolicy::RequireSymbolId;
 
+    type Output = Vec<u8>;
     type Writer<W: Write> = LazyRawBinaryWriter_1_0<W>;
+
+    fn ion_version() -> IonVersion {
+        IonVersion::v1_0
+    }
+
+    fn default_value_writer_config() -> ValueWriterConfig {
+        ValueWriterConfig::binary()
+            .with_field_name_encoding(FieldNameEncoding::WriteAsSymbolIds)
+            .with_annotations_encoding(AnnotationsEncoding::WriteAsSymbolIds)
+            .with_container_encoding(ContainerEncoding::LengthPrefixed)
+            .with_symbol_value_encoding(SymbolValueEncoding::WriteAsSymbolIds)
+    }
 }
@@ -143,9 +143,19 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
     }
 
     pub fn write_int(mut self, value: &Int) -> IonResult<()> {
-        let magnitude: u128 = value.unsigned_abs().data;
-        let encoded = uint::encode(magnitude);
-        let bytes_to_write = encoded.as_bytes();
+        let magnitude = value.unsigned_abs();
+        let encoded;
+        let big_magnitude_bytes;
+        let bytes_to_write: &[u8] = match magnitude.as_u128() {
+            Some(small_magnitude) => {
+                encoded = uint::encode(small_magnitude);
+                encoded.as_bytes()
+            }
+            None => {
+                big_magnitude_bytes = magnitude.to_be_bytes();
+                big_magnitude_bytes.as_slice()
+            }
+        };
 
         let encoded_length = bytes_to_write.len();
         let mut type_descriptor: u8 = if value.is_negative() { 0x30 } else { 0x20 };
@@ -265,7 +275,10 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
 impl<'value, 'top> Sealed for BinaryValueWriter_1_0<'value, 'top> {}
 
 impl<'value, 'top> AnnotatableWriter for BinaryValueWriter_1_0<'value, 'top> {
-    type AnnotatedValueWriter<'a> = BinaryAnnotatedValueWriter_1_0<'a, 'top> where Self: 'a;
+    type AnnotatedValueWriter<'a>
+        = BinaryAnnotatedValueWriter_1_0<'a, 'top>
+    where
+        Self: 'a;
 
     fn with_annotations<'a>(
         self,
@@ -389,7 +402,10 @@ impl<'value, 'top> Sealed for BinaryAnnotatedValueWriter_1_0<'value, 'top> {
 }
 
 impl<'value, 'top> AnnotatableWriter for BinaryAnnotatedValueWriter_1_0<'value, 'top> {
-    type AnnotatedValueWriter<'a> = BinaryAnnotatedValueWriter_1_0<'a, 'top> where Self: 'a;
+    type AnnotatedValueWriter<'a>
+        = BinaryAnnotatedValueWriter_1_0<'a, 'top>
+    where
+        Self: 'a;
 
     fn with_annotations<'a>(
         self,
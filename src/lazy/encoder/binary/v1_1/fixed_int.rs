@@ -3,6 +3,7 @@ use std::io::Write;
 use crate::decimal::coefficient::Coefficient;
 use crate::result::IonFailure;
 use crate::{Int, IonResult};
+use num_bigint::BigInt;
 
 /// An Ion 1.1 encoding primitive that represents a fixed-length signed integer.
 #[derive(Debug)]
@@ -45,11 +46,7 @@ impl FixedInt {
             1 => Self::read_const::<1>(input.try_into().unwrap()),
             2 => Self::read_const::<2>(input.try_into().unwrap()),
             n if n <= MAX_INT_SIZE_IN_BYTES => Self::read_general_case(input, n),
-            _ => {
-                return IonResult::decoding_error(
-                    "found a FixedInt that was larger than the supported maximum",
-                )
-            }
+            n => Self::read_big_int(input, n),
         };
         Ok(fixed_int)
     }
@@ -80,6 +77,13 @@ impl FixedInt {
         FixedInt::new(size_in_bytes, value)
     }
 
+    /// Reads a `FixedInt` whose encoding is too large to be read directly into an `i128`.
+    #[cold]
+    fn read_big_int(input: &[u8], size_in_bytes: usize) -> FixedInt {
+        let value = BigInt::from_signed_bytes_le(&input[..size_in_bytes]);
+        FixedInt::new(size_in_bytes, value)
+    }
+
     #[inline]
     fn write_i128<W: Write>(output: &mut W, value: i128) -> IonResult<usize> {
         let num_encoded_bytes = Self::encoded_size(value);
@@ -92,16 +96,26 @@ impl FixedInt {
     }
 
     pub fn write(output: &mut impl Write, value: &Int) -> IonResult<usize> {
-        Self::write_i128(output, value.data)
+        match value.as_i128() {
+            Some(value) => Self::write_i128(output, value),
+            None => {
+                let encoded_bytes = value.to_signed_le_bytes();
+                output.write_all(&encoded_bytes)?;
+                Ok(encoded_bytes.len())
+            }
+        }
     }
 
     #[inline]
     pub fn encoded_size(value: impl Into<Int>) -> usize {
         let value = value.into();
+        let Some(value) = value.as_i128() else {
+            return value.to_signed_le_bytes().len();
+        };
         let num_sign_bits = if value.is_negative() {
-            value.data.leading_ones()
+            value.leading_ones()
         } else {
-            value.data.leading_zeros()
+            value.leading_zeros()
         };
         let num_magnitude_bits = 128 - num_sign_bits;
         (num_magnitude_bits as usize / 8) + 1
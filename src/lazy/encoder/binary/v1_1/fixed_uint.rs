@@ -3,11 +3,10 @@ use std::io::Write;
 use ice_code::ice as cold_path;
 
 use crate::decimal::coefficient::Coefficient;
-use crate::lazy::encoder::binary::v1_1::fixed_int::{
-    MAX_INT_SIZE_IN_BYTES, MAX_UINT_SIZE_IN_BYTES,
-};
+use crate::lazy::encoder::binary::v1_1::fixed_int::MAX_UINT_SIZE_IN_BYTES;
 use crate::result::IonFailure;
 use crate::{IonError, IonResult, UInt};
+use num_bigint::BigUint;
 
 /// An Ion 1.1 encoding primitive that represents a fixed-length unsigned integer.
 #[derive(Debug)]
@@ -36,8 +35,11 @@ impl FixedUInt {
             return IonResult::incomplete("reading a FixedUInt", offset);
         }
 
-        if size_in_bytes > MAX_INT_SIZE_IN_BYTES {
-            return cold_path! {{IonResult::decoding_error("found a FixedUInt that was larger than the supported maximum")}};
+        if size_in_bytes > MAX_UINT_SIZE_IN_BYTES {
+            return cold_path! {{
+                let value = BigUint::from_bytes_le(&input[..size_in_bytes]);
+                Ok(FixedUInt::new(size_in_bytes, value))
+            }};
         }
 
         const BUFFER_SIZE: usize = MAX_UINT_SIZE_IN_BYTES;
@@ -49,7 +51,12 @@ impl FixedUInt {
 
     #[inline]
     pub(crate) fn write<W: Write>(output: &mut W, value: impl Into<UInt>) -> IonResult<usize> {
-        let value = value.into().data;
+        let value = value.into();
+        let Some(value) = value.as_u128() else {
+            let encoded_bytes = BigUint::from(value).to_bytes_le();
+            output.write_all(&encoded_bytes)?;
+            return Ok(encoded_bytes.len());
+        };
         let encoded_bytes = value.to_le_bytes();
         let leading_zeros = value.leading_zeros();
         let num_encoded_bytes = (16 - (leading_zeros as usize / 8)).max(1);
@@ -202,7 +209,7 @@ mod tests {
 
         for (value, expected_encoding) in test_cases {
             let mut buffer = Vec::new();
-            FixedUInt::write(&mut buffer, value)?;
+            FixedUInt::write(&mut buffer, value.clone())?;
             let encoding = buffer.as_slice();
             assert_eq!(encoding, expected_encoding, "actual encoding {encoding:x?} was != expected encoding {expected_encoding:x?} for value {value}");
         }
@@ -2,7 +2,8 @@ use std::io::Write;
 
 use crate::lazy::encoder::binary::v1_1::writer::LazyRawBinaryWriter_1_1;
 use crate::lazy::encoder::{Encoder, SymbolCreationPolicy};
-use crate::lazy::encoding::BinaryEncoding_1_1;
+use crate::lazy::encoding::{BinaryEncoding_1_0, BinaryEncoding_1_1};
+use crate::{IonVersion, ValueWriterConfig};
 
 pub mod container_writers;
 pub mod fixed_int;
@@ -18,5 +19,15 @@ impl Encoder for BinaryEncoding_1_1 {
     const DEFAULT_SYMBOL_CREATION_POLICY: SymbolCreationPolicy =
         SymbolCreationPolicy::RequireSymbolId;
 
+    type Output = Vec<u8>;
     type Writer<W: Write> = LazyRawBinaryWriter_1_1<W>;
+
+    fn ion_version() -> IonVersion {
+        IonVersion::v1_1
+    }
+
+    fn default_value_writer_config() -> ValueWriterConfig {
+        // By default, use the same settings as binary 1.0
+        BinaryEncoding_1_0::default_value_writer_config()
+    }
 }
@@ -173,23 +173,15 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
             return self.write_i64(small_value);
         }
         cold_path! {{
-            let value: i128 = value.data;
             // Because we've ruled out numbers small enough to fit in an i64, its encoded length
             // must be greater than 8. Write the opcode for an integer with a FlexUInt length.
             self.push_byte(0xF6);
-            let num_sign_bits = if value < 0 {
-                value.leading_ones()
-            } else {
-                value.leading_zeros()
-            };
-            let num_magnitude_bits = 128 - num_sign_bits;
-            let num_encoded_bytes = (num_magnitude_bits as usize / 8) + 1;
-            let le_bytes = value.to_le_bytes();
-            let encoded_bytes = &le_bytes[..num_encoded_bytes];
+            let encoded_bytes = value.to_signed_le_bytes();
+            let num_encoded_bytes = encoded_bytes.len();
             // Write the length as a FlexUInt.
             FlexUInt::write(self.encoding_buffer, num_encoded_bytes as u64)?;
             // Write the little endian bytes of the integer.
-            self.push_bytes(encoded_bytes);
+            self.push_bytes(&encoded_bytes);
             Ok(())
         }}
     }
@@ -739,7 +731,9 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
 impl<'value, 'top> Sealed for BinaryValueWriter_1_1<'value, 'top> {}
 
 impl<'value, 'top> AnnotatableWriter for BinaryValueWriter_1_1<'value, 'top> {
-    type AnnotatedValueWriter<'a> = BinaryAnnotatedValueWriter_1_1<'a, 'top> where
+    type AnnotatedValueWriter<'a>
+        = BinaryAnnotatedValueWriter_1_1<'a, 'top>
+    where
         Self: 'a;
 
     fn with_annotations<'a>(
@@ -850,7 +844,10 @@ impl<'value, 'top> Sealed for BinaryAnnotatedValueWriter_1_1<'value, 'top> {
 }
 
 impl<'value, 'top> AnnotatableWriter for BinaryAnnotatedValueWriter_1_1<'value, 'top> {
-    type AnnotatedValueWriter<'a> = BinaryAnnotatedValueWriter_1_1<'a, 'top> where Self: 'a;
+    type AnnotatedValueWriter<'a>
+        = BinaryAnnotatedValueWriter_1_1<'a, 'top>
+    where
+        Self: 'a;
 
     fn with_annotations<'a>(
         self,
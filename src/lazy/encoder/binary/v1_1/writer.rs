@@ -10,8 +10,7 @@ use crate::lazy::encoder::value_writer::internal::MakeValueWriter;
 use crate::lazy::encoder::value_writer::SequenceWriter;
 use crate::lazy::encoder::value_writer_config::ValueWriterConfig;
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
-use crate::lazy::encoder::LazyRawWriter;
-use crate::lazy::encoding::Encoding;
+use crate::lazy::encoder::{Encoder, LazyRawWriter};
 use crate::unsafe_helpers::{mut_ref_to_ptr, ptr_to_mut_ref, ptr_to_ref};
 use crate::write_config::{WriteConfig, WriteConfigKind};
 use crate::{IonEncoding, IonResult};
@@ -37,6 +36,10 @@ pub struct LazyRawBinaryWriter_1_1<W: Write> {
     encoding_buffer_ptr: Option<*mut ()>,
 }
 
+// SAFETY: `encoding_buffer_ptr` is the only field that is not `Send` on its own. It points into
+//         memory owned by `allocator`, which moves to the new thread along with the writer.
+unsafe impl<W: Write + Send> Send for LazyRawBinaryWriter_1_1<W> {}
+
 /// The initial size of the backing array for the writer's bump allocator.
 // This value was chosen somewhat arbitrarily and can be changed as needed.
 const DEFAULT_BUMP_SIZE: usize = 16 * 1024;
@@ -126,7 +129,7 @@ impl<W: Write> LazyRawWriter<W> for LazyRawBinaryWriter_1_1<W> {
         Self::new(output)
     }
 
-    fn build<E: Encoding>(config: WriteConfig<E>, output: W) -> IonResult<Self>
+    fn build<E: Encoder>(config: WriteConfig<E>, output: W) -> IonResult<Self>
     where
         Self: Sized,
     {
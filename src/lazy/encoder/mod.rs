@@ -5,12 +5,14 @@ use std::io::Write;
 
 use value_writer::SequenceWriter;
 
-use crate::lazy::encoding::Encoding;
+use crate::lazy::encoding::OutputFromBytes;
 use crate::write_config::WriteConfig;
-use crate::{IonEncoding, IonResult};
+use crate::{IonEncoding, IonResult, IonVersion, ValueWriterConfig};
 
 pub mod annotate;
 pub mod annotation_seq;
+#[cfg(feature = "experimental-async")]
+pub mod async_writer;
 pub mod binary;
 pub mod text;
 pub mod value_writer;
@@ -29,9 +31,18 @@ pub trait Encoder: 'static + Sized + Debug + Clone + Copy {
     const SUPPORTS_TEXT_TOKENS: bool;
     const DEFAULT_SYMBOL_CREATION_POLICY: SymbolCreationPolicy;
 
+    /// The type produced when values are encoded in memory: `Vec<u8>` for binary encodings and
+    /// `String` for text encodings.
+    type Output: 'static + OutputFromBytes + AsRef<[u8]>;
+
     /// A writer that serializes Rust values as Ion, emitting the resulting data to an implementation
     /// of [`Write`].
     type Writer<W: Write>: LazyRawWriter<W>;
+
+    /// The version of Ion whose encoding context (symbol table, macro table) the writer maintains.
+    fn ion_version() -> IonVersion;
+
+    fn default_value_writer_config() -> ValueWriterConfig;
 }
 
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -59,7 +70,7 @@ pub trait LazyRawWriter<W: Write>: SequenceWriter<Resources = W> {
     where
         Self: Sized;
 
-    fn build<E: Encoding>(config: WriteConfig<E>, output: W) -> IonResult<Self>
+    fn build<E: Encoder>(config: WriteConfig<E>, output: W) -> IonResult<Self>
     where
         Self: Sized;
     fn flush(&mut self) -> IonResult<()>;
@@ -0,0 +1,26 @@
+pub mod value_writer;
+pub mod writer;
+
+use crate::lazy::encoder::text::json::writer::LazyRawJsonWriter;
+use crate::lazy::encoder::{Encoder, SymbolCreationPolicy};
+use crate::lazy::encoding::{JsonEncoding, TextEncoding_1_0};
+use crate::{IonVersion, ValueWriterConfig};
+use std::io::Write;
+
+impl Encoder for JsonEncoding {
+    const SUPPORTS_TEXT_TOKENS: bool = true;
+    const DEFAULT_SYMBOL_CREATION_POLICY: SymbolCreationPolicy =
+        SymbolCreationPolicy::WriteProvidedToken;
+    type Output = String;
+    type Writer<W: Write> = LazyRawJsonWriter<W>;
+
+    fn ion_version() -> IonVersion {
+        // JSON is a subset of text Ion 1.0.
+        IonVersion::v1_0
+    }
+
+    fn default_value_writer_config() -> ValueWriterConfig {
+        // Symbols are written as strings, so they must always be written as text.
+        TextEncoding_1_0::default_value_writer_config()
+    }
+}
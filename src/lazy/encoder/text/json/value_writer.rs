@@ -0,0 +1,260 @@
+use std::io::Write;
+
+use crate::lazy::encoder::annotation_seq::AnnotationSeq;
+use crate::lazy::encoder::text::v1_0::value_writer::{
+    TextListWriter_1_0, TextStructWriter_1_0, TextValueWriter_1_0,
+};
+use crate::lazy::encoder::text::v1_0::writer::TextOutput;
+use crate::lazy::encoder::value_writer::internal::{FieldEncoder, MakeValueWriter};
+use crate::lazy::encoder::value_writer::{
+    AnnotatableWriter, SequenceWriter, StructWriter, ValueWriter,
+};
+use crate::lazy::encoding::JsonEncoding;
+use crate::lazy::never::Never;
+use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
+use crate::raw_symbol_ref::AsRawSymbolRef;
+use crate::result::IonFailure;
+use crate::text::text_formatter::ShortestDecimal;
+use crate::{
+    Decimal, Encoder, Int, IonResult, IonType, RawSymbolRef, Timestamp, ValueWriterConfig,
+};
+use delegate::delegate;
+
+/// Writes `text` as a JSON string, escaping the characters that JSON requires to be escaped.
+pub(crate) fn write_json_string<O: Write>(output: &mut O, text: &str) -> IonResult<()> {
+    output.write_all(b"\"")?;
+    let mut start = 0usize;
+    for (byte_index, character) in text.char_indices() {
+        let escaped = match character {
+            '"' => r#"\""#,
+            '\\' => r"\\",
+            '\n' => r"\n",
+            '\r' => r"\r",
+            '\t' => r"\t",
+            '\x08' => r"\b",
+            '\x0C' => r"\f",
+            // Other control characters must be written as a unicode escape.
+            '\x00'..='\x1F' => {
+                write!(
+                    output,
+                    "{}\\u{:04x}",
+                    &text[start..byte_index],
+                    character as u32
+                )?;
+                start = byte_index + 1;
+                continue;
+            }
+            _ => {
+                // Other characters can be left as-is
+                continue;
+            }
+        };
+        write!(output, "{}{}", &text[start..byte_index], escaped)?;
+        start = byte_index + 1;
+    }
+    write!(output, "{}\"", &text[start..])?;
+    Ok(())
+}
+
+/// Writes the text of a symbol token as a JSON string. Symbols with unknown text cannot be
+/// represented in JSON.
+fn write_json_symbol_token<O: Write>(output: &mut O, token: RawSymbolRef<'_>) -> IonResult<()> {
+    match token {
+        RawSymbolRef::Text(text) => write_json_string(output, text),
+        RawSymbolRef::SystemSymbol_1_1(symbol) => write_json_string(output, symbol.text()),
+        RawSymbolRef::SymbolId(sid) => IonResult::encoding_error(format!(
+            "cannot write symbol ${sid} as JSON; its text is not known"
+        )),
+    }
+}
+
+/// Writes a decimal as a JSON number. Decimals with a zero exponent are written as integers.
+/// Other decimals are written with a decimal point or an `e` exponent, whichever is shorter.
+fn write_json_decimal<O: Write>(output: &mut O, value: &Decimal) -> IonResult<()> {
+    if value.exponent() == 0 {
+        // JSON numbers cannot end with a decimal point.
+        write!(output, "{}", value.coefficient())?;
+    } else {
+        write!(output, "{}", ShortestDecimal::new(value, 'e'))?;
+    }
+    Ok(())
+}
+
+pub struct JsonValueWriter<'value, W: Write + 'value> {
+    pub(crate) value_writer_1_0: TextValueWriter_1_0<'value, W>,
+}
+
+impl<'value, W: Write + 'value> JsonValueWriter<'value, W> {
+    /// Writes the value's JSON text using `write_text`, surrounded by the indentation and
+    /// delimiters that the text writer would use for any other value.
+    fn write_json<F>(mut self, write_text: F) -> IonResult<()>
+    where
+        F: FnOnce(&mut TextOutput<W>) -> IonResult<()>,
+    {
+        self.value_writer_1_0.write_indentation()?;
+        write_text(self.value_writer_1_0.output())?;
+        self.value_writer_1_0.write_delimiter_text()
+    }
+}
+
+impl<'value, W: Write + 'value> AnnotatableWriter for JsonValueWriter<'value, W> {
+    type AnnotatedValueWriter<'a> = JsonValueWriter<'a, W> where Self: 'a;
+
+    fn with_annotations<'a>(
+        self,
+        _annotations: impl AnnotationSeq<'a>,
+    ) -> IonResult<Self::AnnotatedValueWriter<'a>>
+    where
+        Self: 'a,
+    {
+        // JSON does not have annotations; they are dropped.
+        Ok(self)
+    }
+}
+
+impl<'value, W: Write + 'value> ValueWriter for JsonValueWriter<'value, W> {
+    type ListWriter = JsonListWriter<'value, W>;
+    type SExpWriter = JsonListWriter<'value, W>;
+    type StructWriter = JsonStructWriter<'value, W>;
+    // JSON does not support macros
+    type EExpWriter = Never;
+
+    // Booleans and integers are spelled the same way in JSON as in Ion.
+    delegate! {
+        to self.value_writer_1_0 {
+            fn write_bool(self, value: bool) -> IonResult<()>;
+            fn write_i64(self, value: i64) -> IonResult<()>;
+            fn write_int(self, value: &Int) -> IonResult<()>;
+        }
+    }
+
+    fn write_null(self, _ion_type: IonType) -> IonResult<()> {
+        // JSON only has an untyped null.
+        self.value_writer_1_0.write_null(IonType::Null)
+    }
+
+    fn write_f32(self, value: f32) -> IonResult<()> {
+        self.write_f64(value as f64)
+    }
+
+    fn write_f64(self, value: f64) -> IonResult<()> {
+        if !value.is_finite() {
+            // JSON numbers cannot represent `nan`, `+inf`, or `-inf`.
+            return self.write_null(IonType::Float);
+        }
+        // Ion's float notation is also a valid JSON number.
+        self.value_writer_1_0.write_f64(value)
+    }
+
+    fn write_decimal(self, value: &Decimal) -> IonResult<()> {
+        self.write_json(|output| write_json_decimal(output, value))
+    }
+
+    fn write_timestamp(mut self, value: &Timestamp) -> IonResult<()> {
+        self.value_writer_1_0.write_indentation()?;
+        // Timestamp text never contains characters that would need to be escaped.
+        write!(self.value_writer_1_0.output(), "\"")?;
+        self.value_writer_1_0
+            .write_formatted(|formatter| formatter.format_timestamp(value))?;
+        write!(self.value_writer_1_0.output(), "\"")?;
+        self.value_writer_1_0.write_delimiter_text()
+    }
+
+    fn write_string(self, value: impl AsRef<str>) -> IonResult<()> {
+        self.write_json(|output| write_json_string(output, value.as_ref()))
+    }
+
+    fn write_symbol(self, value: impl AsRawSymbolRef) -> IonResult<()> {
+        let token = value.as_raw_symbol_ref();
+        self.write_json(|output| write_json_symbol_token(output, token))
+    }
+
+    fn write_clob(self, value: impl AsRef<[u8]>) -> IonResult<()> {
+        // Each byte of the clob becomes the character with the same code point.
+        let text: String = value.as_ref().iter().map(|byte| *byte as char).collect();
+        self.write_string(text)
+    }
+
+    fn write_blob(self, value: impl AsRef<[u8]>) -> IonResult<()> {
+        self.write_string(base64::encode(value))
+    }
+
+    fn list_writer(self) -> IonResult<Self::ListWriter> {
+        Ok(JsonListWriter {
+            writer_1_0: self.value_writer_1_0.list_writer()?,
+        })
+    }
+
+    fn sexp_writer(self) -> IonResult<Self::SExpWriter> {
+        // S-expressions are written as JSON arrays.
+        self.list_writer()
+    }
+
+    fn struct_writer(self) -> IonResult<Self::StructWriter> {
+        Ok(JsonStructWriter {
+            writer_1_0: self.value_writer_1_0.struct_writer()?,
+        })
+    }
+
+    fn eexp_writer<'a>(self, _macro_id: impl Into<MacroIdRef<'a>>) -> IonResult<Self::EExpWriter> {
+        IonResult::encoding_error("macros are not supported in JSON")
+    }
+}
+
+/// Writes JSON arrays. Both Ion lists and s-expressions are written as arrays.
+pub struct JsonListWriter<'value, W: Write> {
+    writer_1_0: TextListWriter_1_0<'value, W>,
+}
+
+impl<'value, W: Write> MakeValueWriter for JsonListWriter<'value, W> {
+    type ValueWriter<'a> = JsonValueWriter<'a, W> where Self: 'a;
+
+    fn make_value_writer(&mut self) -> Self::ValueWriter<'_> {
+        JsonValueWriter {
+            value_writer_1_0: self.writer_1_0.make_value_writer(),
+        }
+    }
+}
+
+impl<'value, W: Write> SequenceWriter for JsonListWriter<'value, W> {
+    type Resources = ();
+
+    fn close(self) -> IonResult<Self::Resources> {
+        self.writer_1_0.close()
+    }
+}
+
+/// Writes JSON objects.
+pub struct JsonStructWriter<'value, W: Write> {
+    writer_1_0: TextStructWriter_1_0<'value, W>,
+}
+
+impl<'value, W: Write> FieldEncoder for JsonStructWriter<'value, W> {
+    fn encode_field_name(&mut self, name: impl AsRawSymbolRef) -> IonResult<()> {
+        let name = name.as_raw_symbol_ref();
+        self.writer_1_0
+            .encode_field_name_with(name, |output, _config| {
+                write_json_symbol_token(output, name)
+            })
+    }
+}
+
+impl<'value, W: Write> MakeValueWriter for JsonStructWriter<'value, W> {
+    type ValueWriter<'a> = JsonValueWriter<'a, W> where Self: 'a;
+
+    fn make_value_writer(&mut self) -> Self::ValueWriter<'_> {
+        JsonValueWriter {
+            value_writer_1_0: self.writer_1_0.make_value_writer(),
+        }
+    }
+}
+
+impl<'value, W: Write> StructWriter for JsonStructWriter<'value, W> {
+    fn close(self) -> IonResult<()> {
+        self.writer_1_0.close()
+    }
+
+    fn config(&self) -> ValueWriterConfig {
+        JsonEncoding::default_value_writer_config()
+    }
+}
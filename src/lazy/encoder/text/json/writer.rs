@@ -0,0 +1,237 @@
+use std::io::Write;
+
+use crate::lazy::encoder::text::json::value_writer::JsonValueWriter;
+use crate::lazy::encoder::text::v1_0::writer::LazyRawTextWriter_1_0;
+use crate::lazy::encoder::value_writer::internal::MakeValueWriter;
+use crate::lazy::encoder::value_writer::SequenceWriter;
+use crate::lazy::encoder::{Encoder, LazyRawWriter};
+use crate::lazy::encoding::JsonEncoding;
+use crate::write_config::WriteConfigKind;
+use crate::{IonEncoding, IonResult, TextFormat, WriteConfig};
+
+// JSON is a syntactic subset of text Ion 1.0. The types comprising this writer implementation
+// convert each value to its JSON equivalent and then delegate to the 1.0 text writer.
+
+/// A raw JSON writer. Values are converted to JSON following the rules described in
+/// [`JsonEncoding`].
+pub struct LazyRawJsonWriter<W: Write> {
+    pub(crate) writer_1_0: LazyRawTextWriter_1_0<W>,
+}
+
+impl<W: Write> SequenceWriter for LazyRawJsonWriter<W> {
+    type Resources = W;
+
+    fn close(self) -> IonResult<Self::Resources> {
+        self.writer_1_0.close()
+    }
+}
+
+impl<W: Write> MakeValueWriter for LazyRawJsonWriter<W> {
+    type ValueWriter<'a> = JsonValueWriter<'a, W>
+    where
+        Self: 'a;
+
+    fn make_value_writer(&mut self) -> Self::ValueWriter<'_> {
+        let value_writer_1_0 = self.writer_1_0.make_value_writer();
+        JsonValueWriter { value_writer_1_0 }
+    }
+}
+
+impl<W: Write> LazyRawWriter<W> for LazyRawJsonWriter<W> {
+    fn new(output: W) -> IonResult<Self>
+    where
+        Self: Sized,
+    {
+        Self::build(
+            WriteConfig::<JsonEncoding>::new(TextFormat::default()),
+            output,
+        )
+    }
+
+    fn build<E: Encoder>(config: WriteConfig<E>, output: W) -> IonResult<Self>
+    where
+        Self: Sized,
+    {
+        match &config.kind {
+            WriteConfigKind::Text(text_config) => Ok(LazyRawJsonWriter {
+                writer_1_0: LazyRawTextWriter_1_0::with_format_config(
+                    output,
+                    // JSON does not permit trailing commas.
+                    text_config.format_config.with_trailing_commas(false),
+                ),
+            }),
+            WriteConfigKind::Binary(_) => {
+                unreachable!("Binary writer can not be created from text encoding")
+            }
+        }
+    }
+
+    fn flush(&mut self) -> IonResult<()> {
+        self.writer_1_0.flush()
+    }
+
+    fn output(&self) -> &W {
+        self.writer_1_0.output()
+    }
+
+    fn output_mut(&mut self) -> &mut W {
+        self.writer_1_0.output_mut()
+    }
+
+    fn write_version_marker(&mut self) -> IonResult<()> {
+        // JSON does not have a version marker.
+        Ok(())
+    }
+
+    fn encoding(&self) -> IonEncoding {
+        IonEncoding::Text_1_0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::lazy::encoder::text::json::writer::LazyRawJsonWriter;
+    use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter};
+    use crate::lazy::encoder::LazyRawWriter;
+    use crate::lazy::encoding::{Encoding, JsonEncoding};
+    use crate::{
+        v1_0, Element, IonData, IonResult, Reader, Symbol, TextFormat, TextFormatConfig,
+        WriteConfig,
+    };
+
+    #[rstest]
+    #[case::null("null", "null")]
+    #[case::typed_null("null.struct", "null")]
+    #[case::bool("true", "true")]
+    #[case::int("-12345", "-12345")]
+    #[case::big_int("123456789012345678901234567890", "123456789012345678901234567890")]
+    #[case::float("1.5e0", "1.5e0")]
+    #[case::float_nan("nan", "null")]
+    #[case::float_inf("+inf", "null")]
+    #[case::float_neg_inf("-inf", "null")]
+    #[case::decimal("12.30", "12.30")]
+    #[case::decimal_integer("5.", "5")]
+    #[case::decimal_negative_zero("-0.", "-0")]
+    #[case::decimal_positive_exponent("5d3", "5e3")]
+    #[case::decimal_small("0.125", "0.125")]
+    #[case::decimal_shorter_exponent("0.005", "5e-3")]
+    #[case::decimal_tiny("5d-20", "5e-20")]
+    #[case::decimal_huge_negative_exponent("1d-1000000000", "1e-1000000000")]
+    #[case::timestamp("2024-05-01T12:30:00Z", r#""2024-05-01T12:30:00+00:00""#)]
+    #[case::symbol("foo", r#""foo""#)]
+    #[case::quoted_symbol("'hello world'", r#""hello world""#)]
+    #[case::string(r#""foo\nbar""#, r#""foo\nbar""#)]
+    #[case::string_escapes(r#""'?\0\a\v\x01""#, r#""'?\u0000\u0007\u000b\u0001""#)]
+    #[case::string_unicode(r#""αβγ""#, r#""αβγ""#)]
+    #[case::clob(r#"{{"hi\x00\xff"}}"#, r#""hi\u0000ÿ""#)]
+    #[case::blob("{{aGVsbG8=}}", r#""aGVsbG8=""#)]
+    #[case::annotations("foo::bar::5", "5")]
+    #[case::list("[1, a::2, three]", r#"[1, 2, "three"]"#)]
+    #[case::sexp("(+ 1 2)", r#"["+", 1, 2]"#)]
+    #[case::structure(
+        "{a: 1, 'b c': (d), \"e\": null.int}",
+        r#"{"a": 1, "b c": ["d"], "e": null}"#
+    )]
+    #[case::nested("a::{b: [c::{d: e::f}]}", r#"{"b": [{"d": "f"}]}"#)]
+    fn write_json(#[case] ion: &str, #[case] expected: &str) -> IonResult<()> {
+        let element = Element::read_one(ion)?;
+        let json = element.encode_as(JsonEncoding)?;
+        assert_eq!(json.trim_end(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn write_json_stream() -> IonResult<()> {
+        let mut writer = LazyRawJsonWriter::new(Vec::new())?;
+        writer.write(1)?.write("foo")?.write(Symbol::from("bar"))?;
+        let mut list = writer.list_writer()?;
+        list.write(true)?.write(2.5f64)?;
+        list.close()?;
+        let mut strukt = writer.struct_writer()?;
+        strukt.write("a", 1)?.write("b", "two")?;
+        strukt.close()?;
+        let output = writer.close()?;
+        let expected = r#"1
+"foo"
+"bar"
+[
+  true,
+  2.5e0
+]
+{
+  "a": 1,
+  "b": "two"
+}
+"#;
+        assert_eq!(String::from_utf8_lossy(&output), expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::compact(TextFormat::Compact, r#"{"a": [1, 2], "b": {}}"#)]
+    #[case::lines(TextFormat::Lines, r#"{"a": [1, 2], "b": {}}"#)]
+    #[case::pretty(
+        TextFormat::Pretty,
+        "{\n  \"a\": [\n    1,\n    2\n  ],\n  \"b\": {\n  }\n}"
+    )]
+    fn write_json_formats(#[case] format: TextFormat, #[case] expected: &str) -> IonResult<()> {
+        let element = Element::read_one("{a: [1, 2], b: {}}")?;
+        // Trailing commas are not valid JSON, so requesting them has no effect.
+        let config = TextFormatConfig::new(format).with_trailing_commas(true);
+        let json = element.encode_as(JsonEncoding.with_format(config))?;
+        assert_eq!(json.trim_end(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn write_canonical_json() -> IonResult<()> {
+        let element = Element::read_one("{b: 2, a: foo::1, c: [2021-01-01T00:00Z]}")?;
+        let config = TextFormatConfig::new(TextFormat::Compact).with_canonical(true);
+        let json = element.encode_as(WriteConfig::<JsonEncoding>::new(config))?;
+        assert_eq!(
+            json.trim_end(),
+            r#"{"a": 1, "b": 2, "c": ["2021-01-01T00:00Z"]}"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_with_unknown_text_is_an_error() -> IonResult<()> {
+        let mut writer = LazyRawJsonWriter::new(Vec::new())?;
+        assert!(writer.write(Symbol::unknown_text()).is_err());
+        let mut strukt = writer.struct_writer()?;
+        assert!(strukt.write(Symbol::unknown_text(), 1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn json_output_is_ion() -> IonResult<()> {
+        // Values that survive the conversion to JSON unchanged read back as the same Ion data.
+        let elements = Element::read_all(r#"null 1 true -2.5e0 "hi" [1, [2]] {a: {b: 0.25}}"#)?;
+        let json = elements.encode_as(JsonEncoding)?;
+        let actual = Element::read_all(json)?;
+        assert!(IonData::eq(&elements, &actual));
+        Ok(())
+    }
+
+    #[test]
+    fn transcode_lazy_values() -> IonResult<()> {
+        let elements = Element::read_all(r#"a::{b: (c d), e: {{aGVsbG8=}}, f: 2024T} 7 "g""#)?;
+        let ion = v1_0::Binary::encode_all(&elements)?;
+        let mut reader = Reader::new(v1_0::Binary, ion)?;
+        let mut json = String::new();
+        while let Some(value) = reader.next()? {
+            json.push_str(&value.to_json()?);
+        }
+        assert_eq!(
+            json,
+            r#"{"b": ["c", "d"], "e": "aGVsbG8=", "f": "2024T"} 7 "g" "#
+        );
+        let mut reader = Reader::new(v1_0::Text, "[1, 2]")?;
+        let output = reader.expect_next()?.write_json_to(Vec::new())?;
+        assert_eq!(output, b"[1, 2] ");
+        Ok(())
+    }
+}
@@ -1,2 +1,3 @@
+pub mod json;
 pub mod v1_0;
 pub mod v1_1;
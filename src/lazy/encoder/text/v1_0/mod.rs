@@ -3,6 +3,10 @@ use std::io::Write;
 use crate::lazy::encoder::text::v1_0::writer::LazyRawTextWriter_1_0;
 use crate::lazy::encoder::{Encoder, SymbolCreationPolicy};
 use crate::lazy::encoding::TextEncoding_1_0;
+use crate::{
+    AnnotationsEncoding, ContainerEncoding, FieldNameEncoding, IonVersion, SymbolValueEncoding,
+    ValueWriterConfig,
+};
 
 pub mod value_writer;
 pub mod writer;
@@ -12,5 +16,18 @@ impl Encoder for TextEncoding_1_0 {
     const DEFAULT_SYMBOL_CREATION_POLICY: SymbolCreationPolicy =
         SymbolCreationPolicy::WriteProvidedToken;
 
+    type Output = String;
     type Writer<W: Write> = LazyRawTextWriter_1_0<W>;
+
+    fn ion_version() -> IonVersion {
+        IonVersion::v1_0
+    }
+
+    fn default_value_writer_config() -> ValueWriterConfig {
+        ValueWriterConfig::text()
+            .with_field_name_encoding(FieldNameEncoding::WriteAsInlineText)
+            .with_annotations_encoding(AnnotationsEncoding::WriteAsInlineText)
+            .with_container_encoding(ContainerEncoding::Delimited)
+            .with_symbol_value_encoding(SymbolValueEncoding::WriteAsInlineText)
+    }
 }
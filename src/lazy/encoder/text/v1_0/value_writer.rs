@@ -1,11 +1,12 @@
-use std::fmt::Formatter;
+use std::cmp::Ordering;
 use std::io::Write;
 
 use delegate::delegate;
 
+use crate::ion_data::IonOrd;
 use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsVec};
 use crate::lazy::encoder::private::Sealed;
-use crate::lazy::encoder::text::v1_0::writer::LazyRawTextWriter_1_0;
+use crate::lazy::encoder::text::v1_0::writer::{LazyRawTextWriter_1_0, TextOutput};
 use crate::lazy::encoder::value_writer::internal::{FieldEncoder, MakeValueWriter};
 use crate::lazy::encoder::value_writer::{
     delegate_value_writer_to, AnnotatableWriter, SequenceWriter, StructWriter, ValueWriter,
@@ -15,11 +16,13 @@ use crate::lazy::never::Never;
 use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
 use crate::raw_symbol_ref::AsRawSymbolRef;
 use crate::result::IonFailure;
+use crate::text::text_format_config::TextFormatConfig;
 use crate::text::text_formatter::{FmtValueFormatter, IoValueFormatter};
 use crate::text::whitespace_config::WhitespaceConfig;
 use crate::types::{ContainerType, ParentType};
 use crate::{
-    v1_0, Decimal, Encoding, Int, IonResult, IonType, RawSymbolRef, Timestamp, ValueWriterConfig,
+    v1_0, Decimal, Element, Encoder, Int, IonResult, IonType, RawSymbolRef, Symbol, Timestamp,
+    ValueWriterConfig,
 };
 
 pub struct TextValueWriter_1_0<'value, W: Write + 'value> {
@@ -36,11 +39,25 @@ pub struct TextValueWriter_1_0<'value, W: Write + 'value> {
 
 pub(crate) fn write_symbol_token<O: Write, A: AsRawSymbolRef>(
     output: &mut O,
+    config: TextFormatConfig,
     token: A,
+) -> IonResult<()> {
+    write_formatted(output, config, |formatter| {
+        formatter.format_symbol_token(token)
+    })
+}
+
+/// Uses a [`FmtValueFormatter`] configured with `config` to write text to `output`.
+pub(crate) fn write_formatted<O: Write>(
+    output: &mut O,
+    config: TextFormatConfig,
+    format: impl FnOnce(&mut FmtValueFormatter<'_, IoValueFormatter<&mut O>>) -> IonResult<()>,
 ) -> IonResult<()> {
     let mut io_shim = IoValueFormatter::new(output);
-    let _ = io_shim.value_formatter().format_symbol_token(token);
-    io_shim.into_result()
+    let format_result = format(&mut io_shim.value_formatter_with_config(config));
+    // If an I/O error occurred, it will be more informative than the formatter's result.
+    io_shim.into_result()?;
+    format_result
 }
 
 /// Writes the body (i.e. no start or end delimiters) of a string or symbol with any illegal
@@ -70,9 +87,10 @@ impl<'value, W: Write + 'value> TextValueWriter_1_0<'value, W> {
         }
     }
 
-    /// Writes the `indentation` string set in the whitespace config to output `depth` times.
-    fn write_indentation(&mut self) -> IonResult<()> {
-        let indentation = self.whitespace_config().indentation;
+    /// Writes the separator following the previous value (if any) and then the configured
+    /// indentation for this value's depth.
+    pub(crate) fn write_indentation(&mut self) -> IonResult<()> {
+        self.writer.write_pending_separator()?;
         if self.parent_type == ParentType::Struct || self.has_annotations {
             // If this value is part of a struct field, the indentation was written before the
             // field name.
@@ -80,22 +98,32 @@ impl<'value, W: Write + 'value> TextValueWriter_1_0<'value, W> {
             // Either way, there's nothing to do here.
             return Ok(());
         }
-        if !indentation.is_empty() {
-            for _ in 0..self.depth {
-                write!(self.output(), "{indentation}")?;
-            }
-        }
-        Ok(())
+        self.writer.write_indentation(self.depth)
     }
 }
 
 impl<'value, W: Write> TextValueWriter_1_0<'value, W> {
-    fn output(&mut self) -> &mut W {
+    pub(crate) fn output(&mut self) -> &mut TextOutput<W> {
         &mut self.writer.output
     }
 
     fn whitespace_config(&self) -> &WhitespaceConfig {
-        self.writer.whitespace_config
+        &self.writer.whitespace_config
+    }
+
+    fn format_config(&self) -> TextFormatConfig {
+        self.writer.format_config
+    }
+
+    /// Writes the value's text using a [`FmtValueFormatter`] that honors the format config.
+    pub(crate) fn write_formatted<F>(&mut self, format: F) -> IonResult<()>
+    where
+        F: FnOnce(
+            &mut FmtValueFormatter<'_, IoValueFormatter<&mut TextOutput<W>>>,
+        ) -> IonResult<()>,
+    {
+        let config = self.format_config();
+        write_formatted(self.output(), config, format)
     }
 
     pub fn delimiter(&self) -> &'static str {
@@ -103,13 +131,18 @@ impl<'value, W: Write> TextValueWriter_1_0<'value, W> {
     }
 
     #[inline]
-    fn write_delimiter_text(&mut self) -> IonResult<()> {
-        let space_between = match self.depth {
-            0 => self.whitespace_config().space_between_top_level_values,
-            _ => self.whitespace_config().space_between_nested_values,
-        };
+    pub(crate) fn write_delimiter_text(&mut self) -> IonResult<()> {
         let value_delimiter = self.value_delimiter;
-        write!(self.output(), "{value_delimiter}{space_between}")?;
+        if self.depth == 0 {
+            let space_between = self.whitespace_config().space_between_top_level_values;
+            write!(self.output(), "{value_delimiter}{space_between}")?;
+            return self.writer.end_top_level_value();
+        }
+        // Nested values' delimiters are written when the next value begins, making it possible
+        // to omit the delimiter after the last value in the container.
+        let space_between = self.whitespace_config().space_between_nested_values;
+        self.writer
+            .set_pending_separator(value_delimiter, space_between);
         Ok(())
     }
 }
@@ -127,11 +160,12 @@ impl<'value, W: Write> TextAnnotatedValueWriter_1_0<'value, W> {
         // After indenting, we set the `has_annotations` flag to `true` so the value won't write
         // indentation a second time.
         self.value_writer.has_annotations = !self.annotations.is_empty();
-        let output = &mut self.value_writer.writer.output;
+        let config = self.value_writer.format_config();
+        let output = self.value_writer.output();
         for annotation in self.annotations {
             match annotation.as_raw_symbol_ref() {
                 RawSymbolRef::Text(token) => {
-                    write_symbol_token(output, token)?;
+                    write_symbol_token(output, config, token)?;
                     write!(output, "::")
                 }
                 RawSymbolRef::SymbolId(sid) => write!(output, "${sid}::"),
@@ -169,6 +203,8 @@ pub(crate) struct TextContainerWriter_1_0<'a, W: Write> {
     container_type: ContainerType,
     value_delimiter: &'static str,
     trailing_delimiter: &'static str,
+    // The offset in the writer's buffer at which this container's opening delimiter was written.
+    start: usize,
 }
 
 impl<'a, W: Write> TextContainerWriter_1_0<'a, W> {
@@ -181,6 +217,8 @@ impl<'a, W: Write> TextContainerWriter_1_0<'a, W> {
         value_delimiter: &'static str,
         trailing_delimiter: &'static str,
     ) -> IonResult<Self> {
+        // Any leading indentation was written by the value writer that created this container.
+        let start = writer.output.buffer().len();
         let mut container_writer = Self {
             writer,
             depth,
@@ -189,62 +227,58 @@ impl<'a, W: Write> TextContainerWriter_1_0<'a, W> {
             has_been_closed: false,
             value_delimiter,
             trailing_delimiter,
+            start,
         };
-        if parent_type != ParentType::Struct {
-            container_writer.write_indentation(depth)?;
-        }
         let space_after_container_start = container_writer
-            .writer
-            .whitespace_config
+            .whitespace_config()
             .space_after_container_start;
         write!(
-            container_writer.writer.output,
+            container_writer.output(),
             "{opening_delimiter}{space_after_container_start}"
         )?;
         Ok(container_writer)
     }
 
-    /// Writes the `indentation` string set in the whitespace config to output `depth` times.
+    /// Writes the separator following the previous value (if any) and then the configured
+    /// indentation for the specified `depth`.
     fn write_indentation(&mut self, depth: usize) -> IonResult<()> {
-        let indentation = self.whitespace_config().indentation;
-        if !indentation.is_empty() {
-            for _ in 0..depth {
-                write!(self.output(), "{indentation}")?;
-            }
-        }
-        Ok(())
+        self.writer.write_pending_separator()?;
+        self.writer.write_indentation(depth)
     }
 
-    /// Writes the provided value to output using its implementation of `WriteAsIon`, then writes
-    /// the whitespace config's `space_between_nested_values`.
+    /// Writes the provided value to output using its implementation of `WriteAsIon`. The value's
+    /// delimiter is written before the next value or when the container is closed.
     fn write_value<V: WriteAsIon>(&mut self, value: V) -> IonResult<&mut Self> {
-        self.write_indentation(self.depth)?;
         value.write_as_ion(self.value_writer())?;
         Ok(self)
     }
 
     /// Finalizes the container, preventing further values from being written.
     pub fn close(mut self, closing_delimiter: &str) -> IonResult<()> {
-        let space_between = match self.depth {
-            0 => self.whitespace_config().space_between_top_level_values,
-            _ => self.whitespace_config().space_between_nested_values,
-        };
         let trailing_delimiter = self.trailing_delimiter;
-        self.write_indentation(self.depth)?;
-        write!(
-            self.output(),
-            "{closing_delimiter}{trailing_delimiter}{space_between}"
-        )?;
+        self.writer.end_container()?;
+        self.writer.write_indentation(self.depth)?;
+        write!(self.output(), "{closing_delimiter}")?;
+        self.writer.reflow_container(self.start, trailing_delimiter);
+        if self.depth == 0 {
+            let space_between = self.whitespace_config().space_between_top_level_values;
+            write!(self.output(), "{trailing_delimiter}{space_between}")?;
+            self.writer.end_top_level_value()?;
+        } else {
+            let space_between = self.whitespace_config().space_between_nested_values;
+            self.writer
+                .set_pending_separator(trailing_delimiter, space_between);
+        }
         self.has_been_closed = true;
         Ok(())
     }
 
-    fn output(&mut self) -> &mut W {
+    fn output(&mut self) -> &mut TextOutput<W> {
         &mut self.writer.output
     }
 
     fn whitespace_config(&self) -> &WhitespaceConfig {
-        self.writer.whitespace_config
+        &self.writer.whitespace_config
     }
 
     #[inline]
@@ -378,6 +412,21 @@ impl<'a, W: Write> SequenceWriter for TextSExpWriter_1_0<'a, W> {
 /// Incrementally encodes an Ion struct.
 pub struct TextStructWriter_1_0<'a, W: Write> {
     container_writer: TextContainerWriter_1_0<'a, W>,
+    // In canonical mode, the location of each field's text in the writer's buffer. When the struct
+    // is closed, these are used to rewrite the fields in sorted order.
+    fields: Vec<FieldSpan>,
+}
+
+/// The location of a struct field's text in the writer's buffer.
+#[derive(Clone)]
+struct FieldSpan {
+    name: Symbol,
+    // The offset of the field's indentation
+    start: usize,
+    // The offset of the first byte after the field name and its trailing whitespace
+    value_start: usize,
+    // The offset of the first byte after the field's value, not including its delimiter
+    end: usize,
 }
 
 impl<'a, W: Write> TextStructWriter_1_0<'a, W> {
@@ -396,28 +445,154 @@ impl<'a, W: Write> TextStructWriter_1_0<'a, W> {
             ",",
             trailing_delimiter,
         )?;
-        Ok(Self { container_writer })
+        Ok(Self {
+            container_writer,
+            fields: Vec::new(),
+        })
     }
 
-    pub fn end(self) -> IonResult<()> {
+    pub fn end(mut self) -> IonResult<()> {
+        self.sort_fields();
         self.container_writer.close("}")?;
         Ok(())
     }
+
+    fn is_canonical(&self) -> bool {
+        self.container_writer.writer.format_config.is_canonical()
+    }
+
+    /// Records the end of the most recently written field, if any.
+    fn end_current_field(&mut self) {
+        let buffer_length = self.container_writer.writer.output.buffer().len();
+        if let Some(field) = self.fields.last_mut() {
+            field.end = buffer_length;
+        }
+    }
+
+    /// In canonical mode, rewrites the struct's fields in the writer's buffer in sorted order.
+    /// Fields are ordered by name; fields with the same name are ordered by their values.
+    fn sort_fields(&mut self) {
+        if self.fields.len() < 2 {
+            return;
+        }
+        self.end_current_field();
+        let buffer = self.container_writer.writer.output.buffer_mut();
+        // Every field is separated from the next by the same delimiter and whitespace.
+        let separator = buffer[self.fields[0].end..self.fields[1].start].to_vec();
+        let fields_start = self.fields[0].start;
+        self.fields
+            .sort_by(|field1, field2| field1.name.ion_cmp(&field2.name));
+        // Values only need to be compared when their fields share a name, so each run of
+        // same-named fields is sorted separately.
+        let mut run_start = 0;
+        while run_start < self.fields.len() {
+            let name = &self.fields[run_start].name;
+            let run_length = self.fields[run_start..]
+                .iter()
+                .take_while(|field| field.name.ion_cmp(name).is_eq())
+                .count();
+            let run = &mut self.fields[run_start..run_start + run_length];
+            if run.len() > 1 {
+                Self::sort_fields_by_value(buffer, run);
+            }
+            run_start += run_length;
+        }
+        let mut sorted_text = Vec::with_capacity(buffer.len() - fields_start);
+        for (index, field) in self.fields.iter().enumerate() {
+            if index > 0 {
+                sorted_text.extend_from_slice(&separator);
+            }
+            sorted_text.extend_from_slice(&buffer[field.start..field.end]);
+        }
+        buffer.truncate(fields_start);
+        buffer.extend_from_slice(&sorted_text);
+    }
+
+    /// Sorts `fields` by the Ion ordering ([`IonOrd`]) of their values, which are read back from
+    /// `buffer`.
+    ///
+    /// Some values cannot be read without context that the raw writer does not have, like a
+    /// symbol ID that is not in the system symbol table. Those values are sorted after the
+    /// readable ones and ordered by their text.
+    fn sort_fields_by_value(buffer: &[u8], fields: &mut [FieldSpan]) {
+        let mut fields_and_values: Vec<(FieldSpan, Option<Element>)> = fields
+            .iter()
+            .map(|field| {
+                let value = Element::read_one(&buffer[field.value_start..field.end]).ok();
+                (field.clone(), value)
+            })
+            .collect();
+        fields_and_values.sort_by(|(field1, value1), (field2, value2)| {
+            let text1 = &buffer[field1.value_start..field1.end];
+            let text2 = &buffer[field2.value_start..field2.end];
+            match (value1, value2) {
+                (Some(value1), Some(value2)) => value1.ion_cmp(value2),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+            .then_with(|| text1.cmp(text2))
+        });
+        for (field, (sorted_field, _)) in fields.iter_mut().zip(fields_and_values) {
+            *field = sorted_field;
+        }
+    }
 }
 
 impl<'a, W: Write> FieldEncoder for TextStructWriter_1_0<'a, W> {
     fn encode_field_name(&mut self, name: impl AsRawSymbolRef) -> IonResult<()> {
+        let name = name.as_raw_symbol_ref();
+        self.encode_field_name_with(name, |output, config| {
+            write_symbol_token(output, config, name)
+        })
+    }
+}
+
+impl<'a, W: Write> TextStructWriter_1_0<'a, W> {
+    /// Writes the indentation, field name, and `:` that precede a field's value. The field name's
+    /// text is written by `write_name`, allowing other text formats to spell field names their
+    /// own way.
+    pub(crate) fn encode_field_name_with<F>(
+        &mut self,
+        name: RawSymbolRef<'_>,
+        write_name: F,
+    ) -> IonResult<()>
+    where
+        F: FnOnce(&mut TextOutput<W>, TextFormatConfig) -> IonResult<()>,
+    {
+        if self.is_canonical() {
+            self.end_current_field();
+        }
+        // The separator following the previous field is not part of either field's text.
+        self.container_writer.writer.write_pending_separator()?;
+        let field_start = self.container_writer.writer.output.buffer().len();
         // Leading indentation for the current depth
         self.container_writer
             .write_indentation(self.container_writer.depth + 1)?;
         // Write the field name
-        write_symbol_token(self.container_writer.output(), name)?;
+        let config = self.container_writer.writer.format_config;
+        write_name(self.container_writer.output(), config)?;
         let space_after_field_name = self
             .container_writer
             .whitespace_config()
             .space_after_field_name;
         // Write a `:` and configured trailing whitespace
         write!(self.container_writer.output(), ":{space_after_field_name}",)?;
+        if self.is_canonical() {
+            let name = match name {
+                RawSymbolRef::Text(text) => Symbol::owned(text),
+                RawSymbolRef::SystemSymbol_1_1(symbol) => Symbol::owned(symbol.text()),
+                // Without a symbol table, symbol IDs are treated as having unknown text.
+                RawSymbolRef::SymbolId(_) => Symbol::unknown_text(),
+            };
+            let value_start = self.container_writer.writer.output.buffer().len();
+            self.fields.push(FieldSpan {
+                name,
+                start: field_start,
+                value_start,
+                end: value_start,
+            });
+        }
         Ok(())
     }
 }
@@ -551,41 +726,19 @@ impl<'value, W: Write> ValueWriter for TextValueWriter_1_0<'value, W> {
 
     fn write_f64(mut self, value: f64) -> IonResult<()> {
         self.write_indentation()?;
-        if value.is_nan() {
-            write!(self.output(), "nan")?;
-            return self.write_delimiter_text();
-        }
-
-        if value.is_infinite() {
-            if value.is_sign_positive() {
-                write!(self.output(), "+inf")?;
-            } else {
-                write!(self.output(), "-inf")?;
-            }
-            return self.write_delimiter_text();
-        }
-
-        // The {:e} formatter provided by the Display trait writes floats using scientific
-        // notation. It works for all floating point values except -0.0 (it drops the sign).
-        // See: https://github.com/rust-lang/rust/issues/20596
-        if value == 0.0f64 && value.is_sign_negative() {
-            write!(self.output(), "-0e0")?;
-            return self.write_delimiter_text();
-        }
-
-        write!(self.output(), "{value:e}")?;
+        self.write_formatted(|formatter| formatter.format_float(value))?;
         self.write_delimiter_text()
     }
 
     fn write_decimal(mut self, value: &Decimal) -> IonResult<()> {
         self.write_indentation()?;
-        write!(self.output(), "{value}")?;
+        self.write_formatted(|formatter| formatter.format_decimal(value))?;
         self.write_delimiter_text()
     }
 
     fn write_timestamp(mut self, value: &Timestamp) -> IonResult<()> {
         self.write_indentation()?;
-        write!(self.output(), "{value}")?;
+        self.write_formatted(|formatter| formatter.format_timestamp(value))?;
         self.write_delimiter_text()
     }
 
@@ -599,39 +752,28 @@ impl<'value, W: Write> ValueWriter for TextValueWriter_1_0<'value, W> {
 
     fn write_symbol(mut self, value: impl AsRawSymbolRef) -> IonResult<()> {
         self.write_indentation()?;
-        write_symbol_token(self.output(), value)?;
+        self.write_formatted(|formatter| formatter.format_symbol_token(value))?;
         self.write_delimiter_text()
     }
 
     fn write_clob(mut self, value: impl AsRef<[u8]>) -> IonResult<()> {
-        // This type exists solely to enable using the IonValueFormatter (which operates on
-        // `std::fmt::Write`) to write to a `std::io::Write`.
-        struct ClobShim<'a>(&'a [u8]);
-        impl<'a> std::fmt::Display for ClobShim<'a> {
-            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-                let mut formatter = FmtValueFormatter { output: f };
-                formatter.format_clob(self.0)?;
-                Ok(())
-            }
-        }
-
         self.write_indentation()?;
-        write!(self.output(), "{}", ClobShim(value.as_ref()))?;
+        self.write_formatted(|formatter| formatter.format_clob(value))?;
         self.write_delimiter_text()
     }
 
     fn write_blob(mut self, value: impl AsRef<[u8]>) -> IonResult<()> {
         self.write_indentation()?;
-        // Rust format strings escape curly braces by doubling them. The following string is:
-        // * The opening {{ from a text Ion blob, with each brace doubled to escape it.
-        // * A {} pair used by the format string to indicate where the base64-encoded bytes
-        //   should be inserted.
-        // * The closing }} from a text Ion blob, with each brace doubled to escape it.
-        write!(self.output(), "{{{{{}}}}}", base64::encode(value))?;
+        // If the blob is wrapped, its continuation lines are indented one level deeper than
+        // the blob itself.
+        let indentation_width = self.whitespace_config().indentation_width * (self.depth + 1);
+        let line_prefix = " ".repeat(indentation_width);
+        self.write_formatted(|formatter| formatter.format_wrapped_blob(value, &line_prefix))?;
         self.write_delimiter_text()
     }
 
-    fn list_writer(self) -> IonResult<Self::ListWriter> {
+    fn list_writer(mut self) -> IonResult<Self::ListWriter> {
+        self.write_indentation()?;
         TextListWriter_1_0::new(
             self.writer,
             self.depth,
@@ -639,7 +781,8 @@ impl<'value, W: Write> ValueWriter for TextValueWriter_1_0<'value, W> {
             self.value_delimiter,
         )
     }
-    fn sexp_writer(self) -> IonResult<Self::SExpWriter> {
+    fn sexp_writer(mut self) -> IonResult<Self::SExpWriter> {
+        self.write_indentation()?;
         TextSExpWriter_1_0::new(
             self.writer,
             self.depth,
@@ -647,7 +790,8 @@ impl<'value, W: Write> ValueWriter for TextValueWriter_1_0<'value, W> {
             self.value_delimiter,
         )
     }
-    fn struct_writer(self) -> IonResult<Self::StructWriter> {
+    fn struct_writer(mut self) -> IonResult<Self::StructWriter> {
+        self.write_indentation()?;
         TextStructWriter_1_0::new(
             self.writer,
             self.depth,
@@ -6,19 +6,95 @@ use crate::lazy::encoder::text::v1_0::value_writer::TextValueWriter_1_0;
 use crate::lazy::encoder::value_writer::internal::MakeValueWriter;
 use crate::lazy::encoder::value_writer::SequenceWriter;
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
-use crate::lazy::encoder::LazyRawWriter;
-use crate::lazy::encoding::{Encoding, TextEncoding_1_0};
-use crate::text::whitespace_config::{
-    WhitespaceConfig, COMPACT_WHITESPACE_CONFIG, LINES_WHITESPACE_CONFIG, PRETTY_WHITESPACE_CONFIG,
-};
+use crate::lazy::encoder::{Encoder, LazyRawWriter};
+use crate::lazy::encoding::TextEncoding_1_0;
+use crate::text::text_format_config::TextFormatConfig;
+use crate::text::whitespace_config::WhitespaceConfig;
 use crate::types::ParentType;
 use crate::write_config::WriteConfigKind;
 use crate::{IonEncoding, IonResult, TextFormat, WriteConfig};
 
 /// A raw text Ion 1.0 writer.
 pub struct LazyRawTextWriter_1_0<W: Write> {
-    pub(crate) output: W,
-    pub(crate) whitespace_config: &'static WhitespaceConfig,
+    pub(crate) output: TextOutput<W>,
+    pub(crate) whitespace_config: WhitespaceConfig,
+    pub(crate) format_config: TextFormatConfig,
+    // The delimiter and whitespace that follow the most recently written nested value. These are
+    // written when the next value in the container begins. If the container is closed instead,
+    // the delimiter is only written if the config calls for trailing commas.
+    pending_separator: Option<(&'static str, &'static str)>,
+}
+
+/// The destination of a text writer's encoded text.
+///
+/// Reflowing a container to fit `max_line_width` or sorting a struct's fields in canonical mode
+/// requires rewriting text after it has been encoded. When the format config calls for either,
+/// the text of the top-level value currently being written is collected in a buffer and written
+/// to the output once the value is complete. Otherwise, text is written directly to the output.
+pub(crate) struct TextOutput<W: Write> {
+    output: W,
+    buffer: Vec<u8>,
+    is_buffered: bool,
+    // When buffering, the column in `output` at which the buffer's contents will begin.
+    output_column: usize,
+}
+
+impl<W: Write> TextOutput<W> {
+    fn new(output: W, format_config: &TextFormatConfig) -> Self {
+        Self {
+            output,
+            buffer: Vec::new(),
+            is_buffered: format_config.max_line_width().is_some() || format_config.is_canonical(),
+            output_column: 0,
+        }
+    }
+
+    /// Returns the text of the top-level value currently being written. This is always empty if
+    /// the output is not buffered.
+    pub(crate) fn buffer(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Returns a mutable reference to the text of the top-level value currently being written.
+    pub(crate) fn buffer_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.buffer
+    }
+
+    /// Writes the buffered text of the most recently completed top-level value to the output.
+    fn end_top_level_value(&mut self) -> IonResult<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        self.output.write_all(&self.buffer)?;
+        self.output_column = match self.buffer.iter().rposition(|byte| *byte == b'\n') {
+            Some(newline_index) => text_width(&self.buffer[newline_index + 1..]),
+            None => self.output_column + text_width(&self.buffer),
+        };
+        self.buffer.clear();
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for TextOutput<W> {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        if self.is_buffered {
+            self.buffer.write(bytes)
+        } else {
+            self.output.write(bytes)
+        }
+    }
+
+    fn write_all(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+        if self.is_buffered {
+            self.buffer.write_all(bytes)
+        } else {
+            self.output.write_all(bytes)
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.output.flush()
+    }
 }
 
 impl<W: Write> LazyRawTextWriter_1_0<W> {
@@ -27,6 +103,17 @@ impl<W: Write> LazyRawTextWriter_1_0<W> {
         <Self as LazyRawWriter<W>>::new(output)
     }
 
+    /// Constructs a new writer that will emit encoded data to the specified `output` using the
+    /// layout and styles described by `format_config`.
+    pub(crate) fn with_format_config(output: W, format_config: TextFormatConfig) -> Self {
+        LazyRawTextWriter_1_0 {
+            output: TextOutput::new(output, &format_config),
+            whitespace_config: WhitespaceConfig::for_format_config(&format_config),
+            format_config,
+            pending_separator: None,
+        }
+    }
+
     /// Writes the provided data as a top-level value.
     pub fn write<V: WriteAsIon>(&mut self, value: V) -> IonResult<&mut Self> {
         value.write_as_ion(self.value_writer())?;
@@ -35,10 +122,81 @@ impl<W: Write> LazyRawTextWriter_1_0<W> {
 
     /// Writes any pending data to the output stream and then calls [`Write::flush`] on it.
     pub fn flush(&mut self) -> IonResult<()> {
+        self.end_top_level_value()?;
         self.output.flush()?;
         Ok(())
     }
 
+    /// Writes the buffered text of the most recently completed top-level value, if any, to the
+    /// output.
+    pub(crate) fn end_top_level_value(&mut self) -> IonResult<()> {
+        self.output.end_top_level_value()
+    }
+
+    /// Writes the separator that follows the previous nested value, if any. This must be called
+    /// before any text belonging to the next value is written.
+    pub(crate) fn write_pending_separator(&mut self) -> IonResult<()> {
+        if let Some((delimiter, space_between)) = self.pending_separator.take() {
+            write!(self.output, "{delimiter}{space_between}")?;
+        }
+        Ok(())
+    }
+
+    /// Records the separator that follows a nested value. It will be written by
+    /// [`Self::write_pending_separator`] or [`Self::end_container`].
+    pub(crate) fn set_pending_separator(
+        &mut self,
+        delimiter: &'static str,
+        space_between: &'static str,
+    ) {
+        self.pending_separator = Some((delimiter, space_between));
+    }
+
+    /// Writes whatever should appear between the last value in a container and its closing
+    /// delimiter. If the config disables trailing commas, the last value's delimiter is dropped.
+    pub(crate) fn end_container(&mut self) -> IonResult<()> {
+        if self.format_config.trailing_commas() {
+            return self.write_pending_separator();
+        }
+        if self.pending_separator.take().is_some() {
+            let space_before_end = self.whitespace_config.space_before_container_end;
+            write!(self.output, "{space_before_end}")?;
+        }
+        Ok(())
+    }
+
+    /// Writes the configured indentation for the specified nesting `depth`.
+    pub(crate) fn write_indentation(&mut self, depth: usize) -> IonResult<()> {
+        let width = self.whitespace_config.indentation_width * depth;
+        write!(self.output, "{:width$}", "")?;
+        Ok(())
+    }
+
+    /// If the config specifies a maximum line width, tries to rewrite the container whose text
+    /// begins at `start` in the buffer on a single line. The container is left as-is if the
+    /// resulting line (including the `trailing_delimiter` that will follow it) would be too long.
+    pub(crate) fn reflow_container(&mut self, start: usize, trailing_delimiter: &str) {
+        let Some(max_line_width) = self.format_config.max_line_width() else {
+            return;
+        };
+        let buffer = &mut self.output.buffer;
+        let container_text = &buffer[start..];
+        if !container_text.contains(&b'\n') {
+            // It's already on a single line.
+            return;
+        }
+        let single_line = collapse_lines(container_text, self.format_config.trailing_commas());
+        let start_column = match buffer[..start].iter().rposition(|byte| *byte == b'\n') {
+            Some(newline_index) => text_width(&buffer[newline_index + 1..start]),
+            None => self.output.output_column + text_width(&buffer[..start]),
+        };
+        let line_width = start_column + text_width(&single_line) + trailing_delimiter.len();
+        if line_width <= max_line_width {
+            buffer.truncate(start);
+            buffer.extend_from_slice(&single_line);
+        }
+    }
+
     /// Helper method to construct this format's `ValueWriter` implementation.
     #[inline]
     fn value_writer(&mut self) -> TextValueWriter_1_0<'_, W> {
@@ -56,7 +214,7 @@ impl<W: Write> SequenceWriter for LazyRawTextWriter_1_0<W> {
 
     fn close(mut self) -> IonResult<Self::Resources> {
         self.flush()?;
-        Ok(self.output)
+        Ok(self.output.output)
     }
 }
 
@@ -79,19 +237,12 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_0<W> {
     }
 
     /// Build text writer based on given writer configuration
-    fn build<E: Encoding>(config: WriteConfig<E>, output: W) -> IonResult<Self> {
+    fn build<E: Encoder>(config: WriteConfig<E>, output: W) -> IonResult<Self> {
         match &config.kind {
-            WriteConfigKind::Text(text_config) => {
-                let whitespace_config = match text_config.text_kind {
-                    TextFormat::Compact => &COMPACT_WHITESPACE_CONFIG,
-                    TextFormat::Lines => &LINES_WHITESPACE_CONFIG,
-                    TextFormat::Pretty => &PRETTY_WHITESPACE_CONFIG,
-                };
-                Ok(LazyRawTextWriter_1_0 {
-                    output,
-                    whitespace_config,
-                })
-            }
+            WriteConfigKind::Text(text_config) => Ok(LazyRawTextWriter_1_0::with_format_config(
+                output,
+                text_config.format_config,
+            )),
             WriteConfigKind::Binary(_) => {
                 unreachable!("Binary writer can not be created from text encoding")
             }
@@ -107,17 +258,17 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_0<W> {
     }
 
     fn output(&self) -> &W {
-        &self.output
+        &self.output.output
     }
 
     fn output_mut(&mut self) -> &mut W {
-        &mut self.output
+        &mut self.output.output
     }
 
     fn write_version_marker(&mut self) -> IonResult<()> {
         let space_between = self.whitespace_config.space_between_top_level_values;
         write!(self.output, "$ion_1_0{space_between}")?;
-        Ok(())
+        self.end_top_level_value()
     }
 
     fn encoding(&self) -> IonEncoding {
@@ -125,10 +276,93 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_0<W> {
     }
 }
 
+/// Returns the number of characters in the provided UTF-8 text.
+fn text_width(text: &[u8]) -> usize {
+    // Count every byte that is not a UTF-8 continuation byte.
+    text.iter().filter(|byte| (**byte & 0xC0) != 0x80).count()
+}
+
+/// Joins the lines of a container's text into a single line, removing their indentation. The
+/// first value follows the opening delimiter directly. The closing delimiter follows the last
+/// value directly unless the last value was followed by a trailing comma.
+fn collapse_lines(container_text: &[u8], trailing_commas: bool) -> Vec<u8> {
+    let mut lines = container_text.split(|byte| *byte == b'\n');
+    // The first line holds the opening delimiter.
+    let mut single_line = lines.next().unwrap_or_default().to_vec();
+    // The first value follows an opening `[`, `(`, or `{` directly. An e-expression's first line
+    // ends with its macro name (`(:name`) instead, so a space separates the name from the first
+    // argument.
+    let follows_delimiter = matches!(single_line.last(), Some(b'[' | b'(' | b'{'));
+    let lines: Vec<&[u8]> = lines
+        .map(|line| {
+            let indentation = line.iter().take_while(|byte| **byte == b' ').count();
+            &line[indentation..]
+        })
+        .filter(|line| !line.is_empty())
+        .collect();
+    for (index, line) in lines.iter().enumerate() {
+        let is_first = index == 0;
+        let is_closing_delimiter = index == lines.len() - 1;
+        let needs_space = if is_first {
+            !follows_delimiter
+        } else {
+            !is_closing_delimiter || trailing_commas
+        };
+        if needs_space {
+            single_line.push(b' ');
+        }
+        single_line.extend_from_slice(line);
+    }
+    single_line
+}
+
 #[cfg(test)]
 mod tests {
+    use std::cell::RefCell;
+    use std::io::Write;
+    use std::rc::Rc;
+
     use crate::lazy::encoder::text::v1_0::writer::LazyRawTextWriter_1_0;
-    use crate::{v1_1, Annotatable, ElementReader, IonData, IonResult, Reader, SequenceWriter};
+    use crate::{
+        v1_0, v1_1, Annotatable, Element, ElementReader, IonData, IonResult, RawSymbolRef, Reader,
+        SequenceWriter, StructWriter, TextFormat, TextFormatConfig,
+    };
+    use rstest::rstest;
+
+    /// An output whose contents can be inspected while a writer owns it.
+    #[derive(Clone, Default)]
+    struct SharedOutput(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedOutput {
+        fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().write(bytes)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[rstest]
+    #[case::plain(TextFormatConfig::new(TextFormat::Pretty), true)]
+    #[case::max_line_width(TextFormatConfig::new(TextFormat::Pretty).with_max_line_width(80), false)]
+    #[case::canonical(TextFormatConfig::new(TextFormat::Pretty).with_canonical(true), false)]
+    fn text_is_only_buffered_when_it_may_be_rewritten(
+        #[case] config: TextFormatConfig,
+        #[case] writes_nested_values_immediately: bool,
+    ) -> IonResult<()> {
+        let output = SharedOutput::default();
+        let mut writer = LazyRawTextWriter_1_0::with_format_config(output.clone(), config);
+        let mut list_writer = writer.list_writer()?;
+        list_writer.write(1)?;
+        let has_written_text = !output.0.borrow().is_empty();
+        assert_eq!(has_written_text, writes_nested_values_immediately);
+        list_writer.close()?;
+        writer.flush()?;
+        let text = output.0.borrow().clone();
+        assert_eq!(Element::read_one(text)?, Element::read_one("[1]")?);
+        Ok(())
+    }
 
     #[test]
     fn write_annotated_values() -> IonResult<()> {
@@ -160,4 +394,96 @@ mod tests {
         assert!(IonData::eq(&expected, &actual));
         Ok(())
     }
+
+    #[test]
+    fn write_annotated_containers_with_indentation() -> IonResult<()> {
+        let element = Element::read_one("{foo: bar::[1]}")?;
+        let text = element.encode_as(v1_0::Text.with_format(TextFormat::Pretty))?;
+        assert_eq!(text, "{\n  foo: bar::[\n    1,\n  ],\n}\n");
+        let element = Element::read_one("[baz::[2]]")?;
+        let text = element.encode_as(v1_0::Text.with_format(TextFormat::Pretty))?;
+        assert_eq!(text, "[\n  baz::[\n    2,\n  ],\n]\n");
+        Ok(())
+    }
+
+    #[test]
+    fn max_line_width_only_collapses_containers_that_fit() -> IonResult<()> {
+        let element = Element::read_one(
+            r#"{short: [1, 2, 3], long: ["a somewhat long string", "and another one"], nested: {a: (b c)}}"#,
+        )?;
+        let config = TextFormatConfig::new(TextFormat::Pretty)
+            .with_max_line_width(24)
+            .with_trailing_commas(false);
+        let text = element.encode_as(v1_0::Text.with_format(config))?;
+        let expected = r#"{
+  short: [1, 2, 3],
+  long: [
+    "a somewhat long string",
+    "and another one"
+  ],
+  nested: {a: (b c)}
+}
+"#;
+        assert_eq!(text, expected);
+        assert!(IonData::eq(&element, &Element::read_one(text)?));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::compact(TextFormat::Compact)]
+    #[case::lines(TextFormat::Lines)]
+    #[case::pretty(TextFormat::Pretty)]
+    fn canonical_text_round_trips(#[case] format: TextFormat) -> IonResult<()> {
+        let element = Element::read_one(
+            r#"
+            {
+                zeta: 0b101,
+                alpha: [0x10, 1.50, 0.000012, 12d3, -0d0, 2.5e0, -0e0, nan, -inf],
+                alpha: null.struct,
+                alpha: 'two words'::{ y: 1, x: 2, x: 1 },
+                mid: ('+' a 'null' '$7' "text"),
+                when: [2024T, 2024-03-05T06:07:08.090+00:00, 2024-03-05T06:07-08:00],
+                data: {{aGVsbG8=}},
+            }
+            "#,
+        )?;
+        let config = TextFormatConfig::new(format).with_canonical(true);
+        let first = element.encode_as(v1_0::Text.with_format(config))?;
+        let second = Element::read_one(&first)?.encode_as(v1_0::Text.with_format(config))?;
+        assert_eq!(first, second);
+        assert!(IonData::eq(&element, &Element::read_one(&second)?));
+        Ok(())
+    }
+
+    #[test]
+    fn canonical_text_sorts_struct_fields() -> IonResult<()> {
+        let element = Element::read_one(r#"{b: {d: 4, c: 3}, a: "x", a: 10, a: 9, a: foo::2}"#)?;
+        let config = TextFormatConfig::new(TextFormat::Compact)
+            .with_canonical(true)
+            .with_trailing_commas(false);
+        let text = element.encode_as(v1_1::Text.with_format(config))?;
+        assert_eq!(
+            text,
+            r#"$ion_1_1 {a: 9, a: 10, a: foo::2, a: "x", b: {c: 3, d: 4}} "#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn canonical_text_sorts_fields_written_as_symbol_ids() -> IonResult<()> {
+        let config = TextFormatConfig::new(TextFormat::Compact)
+            .with_canonical(true)
+            .with_trailing_commas(false);
+        let mut writer = LazyRawTextWriter_1_0::with_format_config(vec![], config);
+        // Values that cannot be read back without a symbol table are ordered by their text.
+        let mut struct_writer = writer.struct_writer()?;
+        struct_writer
+            .write(RawSymbolRef::SymbolId(12), 0)?
+            .write("a", RawSymbolRef::SymbolId(11))?
+            .write("a", RawSymbolRef::SymbolId(10))?;
+        struct_writer.close()?;
+        let text = String::from_utf8(writer.close()?).unwrap();
+        assert_eq!(text, "{$12: 0, a: $10, a: $11} ");
+        Ok(())
+    }
 }
@@ -3,12 +3,23 @@ pub(crate) mod writer;
 
 use crate::lazy::encoder::text::v1_1::writer::LazyRawTextWriter_1_1;
 use crate::lazy::encoder::{Encoder, SymbolCreationPolicy};
-use crate::lazy::encoding::TextEncoding_1_1;
+use crate::lazy::encoding::{TextEncoding_1_0, TextEncoding_1_1};
+use crate::{IonVersion, ValueWriterConfig};
 use std::io::Write;
 
 impl Encoder for TextEncoding_1_1 {
     const SUPPORTS_TEXT_TOKENS: bool = true;
     const DEFAULT_SYMBOL_CREATION_POLICY: SymbolCreationPolicy =
         SymbolCreationPolicy::WriteProvidedToken;
+    type Output = String;
     type Writer<W: Write> = LazyRawTextWriter_1_1<W>;
+
+    fn ion_version() -> IonVersion {
+        IonVersion::v1_1
+    }
+
+    fn default_value_writer_config() -> ValueWriterConfig {
+        // By default, use the same settings as text 1.0
+        TextEncoding_1_0::default_value_writer_config()
+    }
 }
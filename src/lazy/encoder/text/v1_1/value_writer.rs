@@ -12,7 +12,7 @@ use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
 use crate::raw_symbol_ref::AsRawSymbolRef;
 use crate::result::IonFailure;
 use crate::types::{ContainerType, ParentType};
-use crate::{v1_1, Decimal, Encoding, Int, IonResult, IonType, Timestamp, ValueWriterConfig};
+use crate::{v1_1, Decimal, Encoder, Int, IonResult, IonType, Timestamp, ValueWriterConfig};
 use delegate::delegate;
 use std::io::Write;
 
@@ -82,7 +82,11 @@ impl<'value, W: Write + 'value> ValueWriter for TextValueWriter_1_1<'value, W> {
         })
     }
 
-    fn eexp_writer<'a>(self, macro_id: impl Into<MacroIdRef<'a>>) -> IonResult<Self::EExpWriter> {
+    fn eexp_writer<'a>(
+        mut self,
+        macro_id: impl Into<MacroIdRef<'a>>,
+    ) -> IonResult<Self::EExpWriter> {
+        self.value_writer_1_0.write_indentation()?;
         TextEExpWriter_1_1::new(
             self.value_writer_1_0.writer,
             self.value_writer_1_0.depth,
@@ -274,9 +278,7 @@ impl<'value, W: Write + 'value> SequenceWriter for TextEExpWriter_1_1<'value, W>
 }
 
 impl<'value, W: Write + 'value> MakeValueWriter for TextEExpWriter_1_1<'value, W> {
-    type ValueWriter<'a> = TextValueWriter_1_1<'a, W>
-    where
-        Self: 'a,;
+    type ValueWriter<'a> = TextValueWriter_1_1<'a, W> where Self: 'a;
 
     fn make_value_writer(&mut self) -> Self::ValueWriter<'_> {
         TextValueWriter_1_1 {
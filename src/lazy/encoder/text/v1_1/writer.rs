@@ -4,11 +4,8 @@ use crate::lazy::encoder::text::v1_0::writer::LazyRawTextWriter_1_0;
 use crate::lazy::encoder::text::v1_1::value_writer::TextValueWriter_1_1;
 use crate::lazy::encoder::value_writer::internal::MakeValueWriter;
 use crate::lazy::encoder::value_writer::SequenceWriter;
-use crate::lazy::encoder::LazyRawWriter;
-use crate::lazy::encoding::{Encoding, TextEncoding_1_1};
-use crate::text::whitespace_config::{
-    COMPACT_WHITESPACE_CONFIG, LINES_WHITESPACE_CONFIG, PRETTY_WHITESPACE_CONFIG,
-};
+use crate::lazy::encoder::{Encoder, LazyRawWriter};
+use crate::lazy::encoding::TextEncoding_1_1;
 use crate::write_config::WriteConfigKind;
 use crate::{IonEncoding, IonResult, TextFormat, WriteConfig};
 
@@ -50,28 +47,20 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_1<W> {
         )
     }
 
-    fn build<E: Encoding>(config: WriteConfig<E>, mut output: W) -> IonResult<Self>
+    fn build<E: Encoder>(config: WriteConfig<E>, output: W) -> IonResult<Self>
     where
         Self: Sized,
     {
         match &config.kind {
             WriteConfigKind::Text(text_config) => {
-                let whitespace_config = match text_config.text_kind {
-                    TextFormat::Compact => &COMPACT_WHITESPACE_CONFIG,
-                    TextFormat::Lines => &LINES_WHITESPACE_CONFIG,
-                    TextFormat::Pretty => &PRETTY_WHITESPACE_CONFIG,
-                };
-                write!(
-                    output,
-                    "$ion_1_1{}",
-                    whitespace_config.space_between_top_level_values
-                )?;
-                Ok(LazyRawTextWriter_1_1 {
-                    writer_1_0: LazyRawTextWriter_1_0 {
+                let mut writer = LazyRawTextWriter_1_1 {
+                    writer_1_0: LazyRawTextWriter_1_0::with_format_config(
                         output,
-                        whitespace_config,
-                    },
-                })
+                        text_config.format_config,
+                    ),
+                };
+                writer.write_version_marker()?;
+                Ok(writer)
             }
             WriteConfigKind::Binary(_) => {
                 unreachable!("Binary writer can not be created from text encoding")
@@ -97,7 +86,7 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_1<W> {
             .whitespace_config
             .space_between_top_level_values;
         write!(self.writer_1_0.output, "$ion_1_1{space_between}")?;
-        Ok(())
+        self.writer_1_0.end_top_level_value()
     }
 
     fn encoding(&self) -> IonEncoding {
@@ -20,7 +20,7 @@ use std::marker::PhantomData;
 use crate::lazy::decoder::{Decoder, LazyRawValueExpr, RawValueExpr};
 use crate::lazy::encoder::annotation_seq::AnnotationsVec;
 use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter, ValueWriter};
-use crate::lazy::encoding::Encoding;
+use crate::lazy::encoder::Encoder;
 use crate::lazy::expanded::macro_evaluator::RawEExpression;
 use crate::lazy::text::raw::v1_1::arg_group::{EExpArg, EExpArgExpr};
 use crate::lazy::value::LazyValue;
@@ -56,7 +56,7 @@ pub trait WriteAsIon {
     ///# #[cfg(not(feature = "experimental-reader-writer"))]
     ///# fn main() -> IonResult<()> { Ok(()) }
     /// ```
-    fn encode_as<E: Encoding, C: Into<WriteConfig<E>>>(&self, config: C) -> IonResult<E::Output>
+    fn encode_as<E: Encoder, C: Into<WriteConfig<E>>>(&self, config: C) -> IonResult<E::Output>
     where
         for<'a> &'a Self: WriteAsIon,
     {
@@ -65,7 +65,7 @@ pub trait WriteAsIon {
 
     /// Encodes this value as an Ion stream with `self` as the single top-level value, writing
     /// the resulting stream to the specified `output`.
-    fn encode_to<E: Encoding, C: Into<WriteConfig<E>>, W: io::Write>(
+    fn encode_to<E: Encoder, C: Into<WriteConfig<E>>, W: io::Write>(
         &self,
         config: C,
         output: W,
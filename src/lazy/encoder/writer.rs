@@ -16,17 +16,17 @@ use crate::lazy::encoder::value_writer_config::{
     ValueWriterConfig,
 };
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
-use crate::lazy::encoder::LazyRawWriter;
+use crate::lazy::encoder::{Encoder, LazyRawWriter};
 use crate::lazy::encoding::{
-    BinaryEncoding_1_0, BinaryEncoding_1_1, Encoding, TextEncoding_1_0, TextEncoding_1_1,
+    BinaryEncoding_1_0, BinaryEncoding_1_1, JsonEncoding, TextEncoding_1_0, TextEncoding_1_1,
 };
 use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
 use crate::raw_symbol_ref::AsRawSymbolRef;
 use crate::result::IonFailure;
-use crate::write_config::WriteConfig;
+use crate::write_config::{SymbolTableImport, WriteConfig};
 use crate::{
     Decimal, Element, ElementWriter, Int, IonResult, IonType, IonVersion, MacroTable, RawSymbolRef,
-    Symbol, SymbolTable, Timestamp, UInt, Value,
+    ReadLimits, Symbol, SymbolTable, Timestamp, UInt, Value,
 };
 
 pub(crate) struct WriterContext {
@@ -46,7 +46,7 @@ impl WriterContext {
 }
 
 /// An Ion writer that maintains a symbol table and creates new entries as needed.
-pub struct Writer<E: Encoding, Output: Write> {
+pub struct Writer<E: Encoder, Output: Write> {
     context: WriterContext,
     data_writer: E::Writer<Vec<u8>>,
     directive_writer: E::Writer<Vec<u8>>,
@@ -58,18 +58,40 @@ pub type TextWriter_1_0<Output> = Writer<TextEncoding_1_0, Output>;
 pub type BinaryWriter_1_0<Output> = Writer<BinaryEncoding_1_0, Output>;
 pub type TextWriter_1_1<Output> = Writer<TextEncoding_1_1, Output>;
 pub type BinaryWriter_1_1<Output> = Writer<BinaryEncoding_1_1, Output>;
+pub type JsonWriter<Output> = Writer<JsonEncoding, Output>;
 
-impl<E: Encoding, Output: Write> Writer<E, Output> {
+impl<E: Encoder, Output: Write> Writer<E, Output> {
     /// Constructs a writer for the requested encoding using the provided configuration.
     pub fn new(config: impl Into<WriteConfig<E>>, output: Output) -> IonResult<Self> {
-        let config = config.into();
+        let mut config = config.into();
+        let imports = std::mem::take(&mut config.imports);
         let directive_writer = E::Writer::build(config.clone(), vec![])?;
         let mut data_writer = E::Writer::build(config, vec![])?;
         // Erase the IVM that's created by default
         data_writer.output_mut().clear();
         // TODO: LazyEncoder should define a method to construct a new symtab and/or macro table
         let ion_version = E::ion_version();
-        let symbol_table = SymbolTable::new(ion_version);
+        let mut symbol_table = SymbolTable::new(ion_version);
+        for import in &imports {
+            // Each symbol ID past the end of the imported table is reserved with a placeholder,
+            // so `max_id` is bounded the same way that readers bound it by default.
+            let max_size = ReadLimits::DEFAULT_MAX_IMPORTED_SYMBOL_TABLE_SIZE;
+            if import.max_id > max_size.saturating_sub(symbol_table.len()) {
+                return IonResult::encoding_error(format!(
+                    "importing {} symbols from shared symbol table '{}' would grow the symbol \
+                     table beyond {max_size} symbols",
+                    import.max_id,
+                    import.table.name()
+                ));
+            }
+            let symbols = import.table.symbols().iter().take(import.max_id);
+            for symbol in symbols {
+                symbol_table.add_symbol_or_placeholder(symbol.text());
+            }
+            for _ in import.table.symbols().len()..import.max_id {
+                symbol_table.add_placeholder();
+            }
+        }
         let macro_table = MacroTable::with_system_macros();
         let context = WriterContext::new(symbol_table, macro_table);
         let mut writer = Writer {
@@ -79,6 +101,9 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
             output,
             value_writer_config: E::default_value_writer_config(),
         };
+        if !imports.is_empty() {
+            writer.write_lst_imports(&imports)?;
+        }
         writer.flush()?;
         Ok(writer)
     }
@@ -125,6 +150,28 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
         Ok(self.output)
     }
 
+    /// Helper method to encode an LST that imports the configured shared symbol tables.
+    fn write_lst_imports(&mut self, imports: &[SymbolTableImport]) -> IonResult<()> {
+        let mut lst = self
+            .directive_writer
+            .value_writer()
+            .with_annotations(system_symbol_ids::ION_SYMBOL_TABLE)?
+            .struct_writer()?;
+
+        let mut import_list = lst.field_writer(system_symbol_ids::IMPORTS).list_writer()?;
+        for import in imports {
+            let mut import_struct = import_list.struct_writer()?;
+            import_struct
+                .write(system_symbol_ids::NAME, import.table.name())?
+                .write(system_symbol_ids::VERSION, import.table.version())?
+                .write(system_symbol_ids::MAX_ID, import.max_id)?;
+            import_struct.close()?;
+        }
+        import_list.close()?;
+
+        lst.close()
+    }
+
     /// Helper method to encode an LST append containing pending symbols.
     fn write_lst_append(&mut self) -> IonResult<()> {
         let Self {
@@ -184,7 +231,7 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
     }
 }
 
-impl<E: Encoding, Output: Write> MakeValueWriter for Writer<E, Output> {
+impl<E: Encoder, Output: Write> MakeValueWriter for Writer<E, Output> {
     type ValueWriter<'a> = ApplicationValueWriter<'a, <E::Writer<Vec<u8>> as MakeValueWriter>::ValueWriter<'a>>
     where
         Self: 'a;
@@ -200,7 +247,7 @@ impl<E: Encoding, Output: Write> MakeValueWriter for Writer<E, Output> {
     }
 }
 
-impl<E: Encoding, Output: Write> SequenceWriter for Writer<E, Output> {
+impl<E: Encoder, Output: Write> SequenceWriter for Writer<E, Output> {
     type Resources = Output;
 
     fn close(mut self) -> IonResult<Self::Resources> {
@@ -756,12 +803,17 @@ impl<S: SequenceWriter> ElementWriter for S {
 #[cfg(feature = "experimental-ion-1-1")]
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use crate::lazy::encoder::value_writer::AnnotatableWriter;
     use crate::lazy::encoder::value_writer_config::{AnnotationsEncoding, SymbolValueEncoding};
+    use crate::lazy::encoding::{BinaryEncoding_1_0, Encoding, TextEncoding_1_0};
     use crate::raw_symbol_ref::AsRawSymbolRef;
     use crate::{
-        v1_1, FieldNameEncoding, HasSpan, IonResult, LazyRawValue, RawSymbolRef, SequenceWriter,
-        StructWriter, SystemReader, ValueWriter, Writer,
+        v1_0, v1_1, AnyEncoding, Decoder, Element, ElementReader, FieldNameEncoding, HasSpan,
+        IonError, IonResult, LazyRawValue, MapCatalog, RawSymbolRef, ReadLimits, Reader,
+        SequenceWriter, SharedSymbolTable, StructWriter, Symbol, SystemReader, TextFormat,
+        ValueWriter, WriteConfig, Writer,
     };
 
     fn symbol_value_encoding_test<const N: usize, A: AsRawSymbolRef>(
@@ -1021,4 +1073,125 @@ mod tests {
             ],
         )
     }
+
+    fn write_with_imports<E: Encoding>(config: WriteConfig<E>) -> IonResult<Vec<u8>> {
+        let mut writer = Writer::new(config, Vec::new())?;
+        writer
+            .write(Symbol::from("device_id"))?
+            .write(Symbol::from("region"))?
+            .write(Symbol::from("truncated"))?;
+        writer.flush()?;
+        // Symbols added after the imports are declared must not discard them.
+        let mut strukt = writer.struct_writer()?;
+        strukt
+            .write("device_id", Symbol::from("local_symbol"))?
+            .write("region", Symbol::from("us-east-1"))?;
+        strukt.close()?;
+        writer.close()
+    }
+
+    fn telemetry_table() -> SharedSymbolTable {
+        SharedSymbolTable::new("telemetry", 2, ["device_id", "timestamp", "truncated"]).unwrap()
+    }
+
+    fn regions_table() -> SharedSymbolTable {
+        SharedSymbolTable::new("regions", 1, ["region", "us-east-1"]).unwrap()
+    }
+
+    #[rstest]
+    #[case::text(
+        WriteConfig::<TextEncoding_1_0>::new(TextFormat::Compact)
+            .with_import_max_id(telemetry_table(), 2)
+            .with_import(regions_table())
+    )]
+    #[case::binary(
+        WriteConfig::<BinaryEncoding_1_0>::new()
+            .with_import_max_id(telemetry_table(), 2)
+            .with_import(regions_table())
+    )]
+    fn shared_symbol_table_imports<E: Encoding>(#[case] config: WriteConfig<E>) -> IonResult<()> {
+        let mut catalog = MapCatalog::new();
+        catalog.insert_table(telemetry_table());
+        catalog.insert_table(regions_table());
+        // Truncating `telemetry` means that `truncated` must be defined locally.
+        let bytes = write_with_imports(config)?;
+        let mut reader = Reader::new(AnyEncoding.with_catalog(catalog), bytes.as_slice())?;
+        assert_eq!(
+            reader.read_all_elements()?,
+            Element::read_all(
+                "device_id region truncated {device_id: local_symbol, region: 'us-east-1'}"
+            )?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn padded_import() -> IonResult<()> {
+        // Padding `telemetry` to 4 symbols reserves SID 13, so `regions` begins at SID 14.
+        let config = WriteConfig::<BinaryEncoding_1_0>::new()
+            .with_import_max_id(telemetry_table(), 4)
+            .with_import(regions_table());
+        let mut writer = Writer::new(config, Vec::new())?;
+        writer
+            .write(Symbol::from("timestamp"))?
+            .write(Symbol::from("us-east-1"))?;
+        let mut catalog = MapCatalog::new();
+        catalog.insert_table(telemetry_table());
+        catalog.insert_table(regions_table());
+        let mut reader = SystemReader::new(v1_0::Binary.with_catalog(catalog), writer.close()?);
+        // Imported symbols are encoded as SIDs rather than being defined locally.
+        for (text, sid) in [("timestamp", 11u8), ("us-east-1", 15)] {
+            let value = reader.expect_next_value()?;
+            assert_eq!(value.read()?.expect_symbol()?, text);
+            assert_eq!(value.raw().unwrap().span().bytes(), &[0x71, sid]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn oversized_import_max_id_is_rejected() {
+        let max_size = ReadLimits::DEFAULT_MAX_IMPORTED_SYMBOL_TABLE_SIZE;
+        let config = WriteConfig::<BinaryEncoding_1_0>::new()
+            .with_import_max_id(telemetry_table(), 4)
+            .with_import_max_id(regions_table(), max_size);
+        let Err(IonError::Encoding(error)) = Writer::new(config, Vec::new()) else {
+            panic!("expected an encoding error for an import that exceeds the symbol table limit");
+        };
+        assert!(error.to_string().contains("'regions'"));
+        let config = WriteConfig::<BinaryEncoding_1_0>::new()
+            .with_import_max_id(telemetry_table(), usize::MAX);
+        assert!(Writer::new(config, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn imported_symbols_are_not_redefined() -> IonResult<()> {
+        let config = WriteConfig::<BinaryEncoding_1_0>::new()
+            .with_import_max_id(telemetry_table(), 2)
+            .with_import(regions_table());
+        let bytes = write_with_imports(config)?;
+        let contains = |text: &str| bytes.windows(text.len()).any(|w| w == text.as_bytes());
+        // The shared tables' names appear in the header, but their symbols do not.
+        assert!(contains("telemetry"));
+        assert!(!contains("device_id"));
+        assert!(!contains("us-east-1"));
+        // `truncated` is beyond the import's `max_id`, so it is added to the local symbol table.
+        assert!(contains("truncated"));
+        Ok(())
+    }
+
+    /// Fails to compile if the provided type cannot be moved to another thread.
+    fn assert_send<T: Send>() {}
+
+    #[test]
+    fn writers_are_send() {
+        use crate::{v1_0, JsonWriter, MacroTable};
+        use std::fs::File;
+
+        assert_send::<v1_0::BinaryWriter<File>>();
+        assert_send::<v1_0::TextWriter<Vec<u8>>>();
+        assert_send::<v1_1::BinaryWriter<File>>();
+        assert_send::<v1_1::TextWriter<Vec<u8>>>();
+        assert_send::<JsonWriter<Vec<u8>>>();
+        assert_send::<MacroTable>();
+    }
 }
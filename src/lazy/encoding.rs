@@ -3,7 +3,7 @@
 use std::fmt::Debug;
 use std::io;
 
-use crate::lazy::any_encoding::{IonEncoding, IonVersion, LazyRawAnyValue};
+use crate::lazy::any_encoding::IonEncoding;
 use crate::lazy::binary::raw::annotations_iterator::RawBinaryAnnotationsIterator;
 use crate::lazy::binary::raw::r#struct::{LazyRawBinaryFieldName_1_0, LazyRawBinaryStruct_1_0};
 use crate::lazy::binary::raw::reader::LazyRawBinaryReader_1_0;
@@ -35,15 +35,11 @@ use crate::lazy::text::value::{
 };
 
 use crate::lazy::binary::raw::v1_1::e_expression::BinaryEExpression_1_1;
-use crate::{
-    AnnotationsEncoding, ContainerEncoding, FieldNameEncoding, IonResult, SymbolValueEncoding,
-    TextFormat, ValueWriterConfig, WriteConfig,
-};
+use crate::read_config::{JsonDecoding, ReadConfig};
+use crate::{IonResult, TextFormatConfig, WriteConfig};
 
 /// Marker trait for types that represent an Ion encoding.
 pub trait Encoding: Encoder + Decoder {
-    type Output: 'static + OutputFromBytes + AsRef<[u8]>;
-
     fn encode<V: WriteAsIon>(value: V) -> IonResult<Self::Output> {
         let bytes = Self::encode_to(value, Vec::new())?;
         Ok(Self::Output::from_bytes(bytes))
@@ -77,12 +73,7 @@ pub trait Encoding: Encoder + Decoder {
         Self::instance().encoding().is_text()
     }
 
-    fn ion_version() -> IonVersion {
-        Self::instance().encoding().version()
-    }
-
     fn default_write_config() -> WriteConfig<Self>;
-    fn default_value_writer_config() -> ValueWriterConfig;
 }
 
 // Similar to a simple `From` implementation, but can be defined for both String and Vec<u8> because
@@ -122,9 +113,14 @@ impl<'top> BinaryEncoding<'top> for BinaryEncoding_1_1 {}
 pub struct TextEncoding_1_0;
 
 impl TextEncoding_1_0 {
-    pub fn with_format(self, format: TextFormat) -> WriteConfig<Self> {
+    pub fn with_format(self, format: impl Into<TextFormatConfig>) -> WriteConfig<Self> {
         WriteConfig::<Self>::new(format)
     }
+
+    /// Creates a [`ReadConfig`] whose readers only accept JSON. See [`JsonDecoding`] for details.
+    pub fn with_json_decoding(self, json_decoding: impl Into<JsonDecoding>) -> ReadConfig<Self> {
+        ReadConfig::from(self).with_json_decoding(json_decoding)
+    }
 }
 
 /// The Ion 1.1 text encoding.
@@ -132,14 +128,39 @@ impl TextEncoding_1_0 {
 pub struct TextEncoding_1_1;
 
 impl TextEncoding_1_1 {
-    pub fn with_format(self, format: TextFormat) -> WriteConfig<Self> {
+    pub fn with_format(self, format: impl Into<TextFormatConfig>) -> WriteConfig<Self> {
         WriteConfig::<Self>::new(format)
     }
 }
 
-impl Encoding for BinaryEncoding_1_0 {
-    type Output = Vec<u8>;
+/// JSON, written by down-converting Ion data.
+///
+/// Ion's data model is a superset of JSON's, so some information is lost when values are
+/// written as JSON. The conversion follows the standard Ion-to-JSON rules:
+/// * annotations are dropped
+/// * typed nulls (`null.int`, `null.struct`, ...) are written as `null`
+/// * symbols, timestamps, and clobs are written as strings
+/// * blobs are written as base64-encoded strings
+/// * decimals are written as JSON numbers
+/// * `nan`, `+inf`, and `-inf` floats are written as `null`
+/// * s-expressions are written as arrays
+///
+/// Symbols with unknown text cannot be represented in JSON; attempting to write one is an error.
+///
+/// Because Ion text is a superset of JSON, `JsonEncoding`'s output can be read by any Ion text
+/// reader.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct JsonEncoding;
 
+impl JsonEncoding {
+    /// Returns a write configuration that lays out JSON as specified by `format`. JSON does not
+    /// permit trailing commas, so the config's trailing comma setting is ignored.
+    pub fn with_format(self, format: impl Into<TextFormatConfig>) -> WriteConfig<Self> {
+        WriteConfig::<Self>::new(format)
+    }
+}
+
+impl Encoding for BinaryEncoding_1_0 {
     fn encoding(&self) -> IonEncoding {
         IonEncoding::Binary_1_0
     }
@@ -154,18 +175,8 @@ impl Encoding for BinaryEncoding_1_0 {
     fn default_write_config() -> WriteConfig<Self> {
         WriteConfig::<Self>::new()
     }
-
-    fn default_value_writer_config() -> ValueWriterConfig {
-        ValueWriterConfig::binary()
-            .with_field_name_encoding(FieldNameEncoding::WriteAsSymbolIds)
-            .with_annotations_encoding(AnnotationsEncoding::WriteAsSymbolIds)
-            .with_container_encoding(ContainerEncoding::LengthPrefixed)
-            .with_symbol_value_encoding(SymbolValueEncoding::WriteAsSymbolIds)
-    }
 }
 impl Encoding for BinaryEncoding_1_1 {
-    type Output = Vec<u8>;
-
     fn encoding(&self) -> IonEncoding {
         IonEncoding::Binary_1_1
     }
@@ -181,15 +192,8 @@ impl Encoding for BinaryEncoding_1_1 {
     fn default_write_config() -> WriteConfig<Self> {
         WriteConfig::<Self>::new()
     }
-
-    fn default_value_writer_config() -> ValueWriterConfig {
-        // By default, use the same settings as binary 1.0
-        BinaryEncoding_1_0::default_value_writer_config()
-    }
 }
 impl Encoding for TextEncoding_1_0 {
-    type Output = String;
-
     fn encoding(&self) -> IonEncoding {
         IonEncoding::Text_1_0
     }
@@ -202,19 +206,10 @@ impl Encoding for TextEncoding_1_0 {
         "text Ion v1.0"
     }
     fn default_write_config() -> WriteConfig<Self> {
-        WriteConfig::<Self>::new(<TextFormat as Default>::default())
-    }
-    fn default_value_writer_config() -> ValueWriterConfig {
-        ValueWriterConfig::text()
-            .with_field_name_encoding(FieldNameEncoding::WriteAsInlineText)
-            .with_annotations_encoding(AnnotationsEncoding::WriteAsInlineText)
-            .with_container_encoding(ContainerEncoding::Delimited)
-            .with_symbol_value_encoding(SymbolValueEncoding::WriteAsInlineText)
+        WriteConfig::<Self>::new(TextFormatConfig::default())
     }
 }
 impl Encoding for TextEncoding_1_1 {
-    type Output = String;
-
     fn encoding(&self) -> IonEncoding {
         IonEncoding::Text_1_1
     }
@@ -227,12 +222,7 @@ impl Encoding for TextEncoding_1_1 {
         "text Ion v1.1"
     }
     fn default_write_config() -> WriteConfig<Self> {
-        WriteConfig::<Self>::new(<TextFormat as Default>::default())
-    }
-
-    fn default_value_writer_config() -> ValueWriterConfig {
-        // By default, use the same settings as text 1.0
-        TextEncoding_1_0::default_value_writer_config()
+        WriteConfig::<Self>::new(TextFormatConfig::default())
     }
 }
 
@@ -320,12 +310,21 @@ impl Decoder for BinaryEncoding_1_1 {
 // If we do not confine the implementation to types with a marker trait, rustc complains that
 // someone may someday use `ExpandedValueSource` as a `LazyDecoder::Value`, and then the
 // implementation will conflict with the core `impl<T> From<T> for T` implementation.
-pub trait RawValueLiteral {}
+pub trait RawValueLiteral {
+    /// If this value is encoded in binary Ion 1.0, returns the bytes of its body: the encoded
+    /// data that follows its type descriptor and length. Otherwise, returns `None`.
+    fn binary_1_0_body(&self) -> Option<&[u8]> {
+        None
+    }
+}
 
 impl<'top, E: TextEncoding<'top>> RawValueLiteral for LazyRawTextValue<'top, E> {}
-impl<'top> RawValueLiteral for LazyRawBinaryValue_1_0<'top> {}
+impl<'top> RawValueLiteral for LazyRawBinaryValue_1_0<'top> {
+    fn binary_1_0_body(&self) -> Option<&[u8]> {
+        Some(self.value_body())
+    }
+}
 impl<'top> RawValueLiteral for &'top LazyRawBinaryValue_1_1<'top> {}
-impl<'top> RawValueLiteral for LazyRawAnyValue<'top> {}
 
 #[cfg(test)]
 mod tests {
@@ -334,7 +333,7 @@ mod tests {
     use crate::lazy::encoding::TextEncoding;
     use crate::{
         ion_list, ion_seq, ion_sexp, ion_struct, v1_0, v1_1, IonResult, Sequence, TextFormat,
-        WriteConfig,
+        TextFormatConfig, WriteConfig,
     };
 
     #[rstest]
@@ -362,6 +361,30 @@ mod tests {
         v1_1::Text.with_format(TextFormat::Lines),
         "$ion_1_1\n{foo: 1, bar: 2, }\n[1, 2, ]\n(1 2 )\n"
     )]
+    #[case::pretty_without_trailing_commas(
+        v1_0::Text.with_format(TextFormatConfig::new(TextFormat::Pretty).with_trailing_commas(false)),
+        "{\n  foo: 1,\n  bar: 2\n}\n[\n  1,\n  2\n]\n(\n  1\n  2\n)\n"
+    )]
+    #[case::compact_without_trailing_commas(
+        v1_1::Text.with_format(TextFormatConfig::new(TextFormat::Compact).with_trailing_commas(false)),
+        "$ion_1_1 {foo: 1, bar: 2} [1, 2] (1 2) "
+    )]
+    #[case::pretty_with_indentation_width(
+        v1_0::Text.with_format(TextFormatConfig::new(TextFormat::Pretty).with_indentation_width(4)),
+        "{\n    foo: 1,\n    bar: 2,\n}\n[\n    1,\n    2,\n]\n(\n    1\n    2\n)\n"
+    )]
+    #[case::pretty_with_max_line_width(
+        v1_0::Text.with_format(TextFormatConfig::new(TextFormat::Pretty).with_max_line_width(12)),
+        "{\n  foo: 1,\n  bar: 2,\n}\n[1, 2, ]\n(1 2 )\n"
+    )]
+    #[case::pretty_with_max_line_width_v1_1(
+        v1_1::Text.with_format(
+            TextFormatConfig::new(TextFormat::Pretty)
+                .with_max_line_width(20)
+                .with_trailing_commas(false)
+        ),
+        "$ion_1_1\n{foo: 1, bar: 2}\n[1, 2]\n(1 2)\n"
+    )]
     fn encode_formatted_text<'a, E: TextEncoding<'a>>(
         #[case] config: impl Into<WriteConfig<E>>,
         #[case] expected: &str,
@@ -18,7 +18,7 @@ use crate::{v1_1, IonError, IonResult, IonType, Macro, MacroTable, Reader, Symbo
 use rustc_hash::FxHashMap;
 use smallvec::SmallVec;
 use std::ops::Range;
-use std::rc::Rc;
+use std::sync::Arc;
 
 /// Information inferred about a template's expansion at compile time.
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -379,7 +379,7 @@ impl TemplateCompiler {
         context: EncodingContextRef,
         pending_macros: &'a MacroTable,
         macro_id: impl Into<MacroIdRef<'a>>,
-    ) -> Option<Rc<Macro>> {
+    ) -> Option<Arc<Macro>> {
         let macro_id = macro_id.into();
         // Since this ID is unqualified, it must be in either...
         // ...the local namespace, having just been defined...
@@ -393,7 +393,7 @@ impl TemplateCompiler {
         context: EncodingContextRef,
         module_name: &'a str,
         macro_id: impl Into<MacroIdRef<'a>>,
-    ) -> Option<Rc<Macro>> {
+    ) -> Option<Arc<Macro>> {
         let macro_id = macro_id.into();
         match module_name {
             // If the module is `$ion`, this refers to the system module.
@@ -409,10 +409,10 @@ impl TemplateCompiler {
         }
     }
 
-    pub fn compile_from_sexp<'a: 'b, 'b, Encoding: Decoder>(
+    pub fn compile_from_sexp<'a, Encoding: Decoder>(
         context: EncodingContextRef<'a>,
-        pending_macros: &'b MacroTable,
-        macro_def_sexp: LazySExp<'a, Encoding>,
+        pending_macros: &'a MacroTable,
+        macro_def_sexp: LazySExp<'_, Encoding>,
     ) -> Result<TemplateMacro, IonError> {
         let mut values = macro_def_sexp.iter();
 
@@ -798,7 +798,7 @@ impl TemplateCompiler {
     fn compile_macro<'top, D: Decoder>(
         tdl_context: TdlContext,
         definition: &mut TemplateBody,
-        macro_ref: Rc<Macro>,
+        macro_ref: Arc<Macro>,
         mut arguments: impl Iterator<Item = IonResult<LazyValue<'top, D>>>,
     ) -> IonResult<()> {
         // If this macro doesn't accept any parameters but arg expressions have been passed,
@@ -814,7 +814,7 @@ impl TemplateCompiler {
         let macro_step_index = definition.expressions.len();
         // Assume the macro contains zero argument expressions to start, we'll update
         // this at the end of the function after we've compiled any argument expressions.
-        definition.push_macro_invocation(Rc::clone(&macro_ref), ExprRange::empty());
+        definition.push_macro_invocation(Arc::clone(&macro_ref), ExprRange::empty());
 
         // We'll step through the parameters one at a time, looking for a corresponding argument
         // expression for each. If the ratio of arguments to parameters isn't 1:1, we'll also
@@ -943,7 +943,7 @@ impl TemplateCompiler {
     fn insert_placeholder_none_invocations<D: Decoder>(
         tdl_context: TdlContext,
         definition: &mut TemplateBody,
-        macro_ref: &Rc<Macro>,
+        macro_ref: &Arc<Macro>,
         index: usize,
     ) -> Result<(), IonError> {
         // There are fewer args than parameters. That's ok as long as all of the remaining
@@ -1023,7 +1023,7 @@ impl TemplateCompiler {
     fn resolve_maybe_macro_id_expr<D: Decoder>(
         tdl_context: TdlContext,
         id_expr: Option<IonResult<LazyValue<D>>>,
-    ) -> IonResult<Rc<Macro>> {
+    ) -> IonResult<Arc<Macro>> {
         // Get the name or address from the `Option<IonResult<LazyValue<_>>>` if possible, or
         // surface an appropriate error message.
         let value = match id_expr {
@@ -1043,7 +1043,7 @@ impl TemplateCompiler {
     fn resolve_macro_id_expr<D: Decoder>(
         tdl_context: TdlContext,
         id_expr: LazyValue<D>,
-    ) -> IonResult<Rc<Macro>> {
+    ) -> IonResult<Arc<Macro>> {
         let macro_id = match id_expr.read()? {
             ValueRef::Symbol(s) => {
                 if let Some(name) = s.text() {
@@ -1266,9 +1266,9 @@ enum TdlSExpKind<'a, D: Decoder> {
     QuasiLiteral(SExpIterator<'a, D>),
     /// A macro invocation in the template body.
     ///     (macro_id /*...*/)
-    /// * Associated `Rc<Macro>` is a reference to the macro definition to which the `macro_id` referred.
+    /// * Associated `Arc<Macro>` is a reference to the macro definition to which the `macro_id` referred.
     /// * Associated iterator returns the s-expression's remaining child expressions.
-    MacroInvocation(Rc<Macro>, SExpIterator<'a, D>),
+    MacroInvocation(Arc<Macro>, SExpIterator<'a, D>),
     /// An expression group being passed as an argument to a macro invocation.
     ///     (.. /*...*/)
     /// * Associated `Parameter` is the parameter to which this arg expression group is being passed.
@@ -1374,7 +1374,7 @@ struct TdlContext<'top> {
 #[cfg(test)]
 mod tests {
     use rustc_hash::FxHashMap;
-    use std::rc::Rc;
+    use std::sync::Arc;
 
     use crate::lazy::expanded::compiler::TemplateCompiler;
     use crate::lazy::expanded::template::{
@@ -1409,14 +1409,14 @@ mod tests {
     fn expect_macro(
         definition: &TemplateMacro,
         index: usize,
-        expected_macro: Rc<Macro>,
+        expected_macro: Arc<Macro>,
         expected_num_args: usize,
     ) -> IonResult<()> {
         expect_step(
             definition,
             index,
             TemplateBodyExpr::macro_invocation(
-                Rc::clone(&expected_macro),
+                Arc::clone(&expected_macro),
                 // First arg position to last arg position (exclusive)
                 ExprRange::new(index..index + 1 + expected_num_args),
             ),
@@ -1,20 +1,31 @@
-use crate::lazy::expanded::compiler::{ExpansionAnalysis, ExpansionSingleton};
+use crate::element::{Element, Sequence, Value};
+use crate::lazy::decoder::Decoder;
+use crate::lazy::encoder::value_writer::ValueWriter;
+use crate::lazy::encoder::write_as_ion::WriteAsIon;
+use crate::lazy::expanded::compiler::{ExpansionAnalysis, ExpansionSingleton, TemplateCompiler};
 use crate::lazy::expanded::template::{
     ExprRange, MacroSignature, Parameter, ParameterCardinality, ParameterEncoding,
     RestSyntaxPolicy, TemplateBody, TemplateBodyElement, TemplateMacro, TemplateMacroRef,
     TemplateValue,
 };
+use crate::lazy::expanded::{EncodingContext, EncodingContextRef};
+use crate::lazy::sequence::LazySExp;
 use crate::lazy::text::raw::v1_1::reader::{MacroAddress, MacroIdRef};
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
 use crate::result::IonFailure;
-use crate::{IonResult, IonType, Symbol, TemplateBodyExpr};
+use crate::{
+    v1_0, IonError, IonResult, IonType, IonVersion, Reader, Struct, Symbol, TemplateBodyExpr,
+    TemplateBodyExprKind,
+};
 use delegate::delegate;
 use rustc_hash::{FxBuildHasher, FxHashMap};
 use std::borrow::Cow;
-use std::rc::Rc;
+use std::sync::{Arc, LazyLock};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Macro {
-    name: Option<Rc<str>>,
+    name: Option<Arc<str>>,
     signature: MacroSignature,
     kind: MacroKind,
     // Compile-time heuristics that allow the reader to evaluate e-expressions lazily or using fewer
@@ -38,7 +49,7 @@ pub struct Macro {
 
 impl Macro {
     pub fn named(
-        name: impl Into<Rc<str>>,
+        name: impl Into<Arc<str>>,
         signature: MacroSignature,
         kind: MacroKind,
         expansion_analysis: ExpansionAnalysis,
@@ -64,7 +75,7 @@ impl Macro {
     }
 
     pub fn new(
-        name: Option<Rc<str>>,
+        name: Option<Arc<str>>,
         signature: MacroSignature,
         kind: MacroKind,
         expansion_analysis: ExpansionAnalysis,
@@ -80,8 +91,8 @@ impl Macro {
     pub fn name(&self) -> Option<&str> {
         self.name.as_deref()
     }
-    pub(crate) fn clone_name(&self) -> Option<Rc<str>> {
-        self.name.as_ref().map(Rc::clone)
+    pub(crate) fn clone_name(&self) -> Option<Arc<str>> {
+        self.name.as_ref().map(Arc::clone)
     }
     pub fn signature(&self) -> &MacroSignature {
         &self.signature
@@ -170,24 +181,22 @@ impl<'top> MacroRef<'top> {
 /// its validity and allowing evaluation to begin.
 #[derive(Debug, Clone)]
 pub struct MacroTable {
-    // Stores `Rc` references to the macro definitions to make cloning the table's contents cheaper.
-    macros_by_address: Vec<Rc<Macro>>,
+    // Stores `Arc` references to the macro definitions to make cloning the table's contents cheaper.
+    macros_by_address: Vec<Arc<Macro>>,
     // Maps names to an address that can be used to query the Vec above.
-    macros_by_name: FxHashMap<Rc<str>, usize>,
+    macros_by_name: FxHashMap<Arc<str>, usize>,
 }
 
-thread_local! {
-    /// An instance of the Ion 1.1 system macro table is lazily instantiated once per thread,
-    /// minimizing the number of times macro compilation occurs.
-    ///
-    /// The thread-local instance holds `Rc` references to its macro names and macro definitions,
-    /// making its contents inexpensive to `clone()` and reducing the number of duplicate `String`s
-    /// being allocated over time.
-    ///
-    /// Each time the user constructs a reader, it clones the thread-local copy of the system macro
-    /// table.
-    pub static ION_1_1_SYSTEM_MACROS: MacroTable = MacroTable::construct_system_macro_table();
-}
+/// An instance of the Ion 1.1 system macro table is lazily instantiated once per process,
+/// minimizing the number of times macro compilation occurs.
+///
+/// The shared instance holds `Arc` references to its macro names and macro definitions,
+/// making its contents inexpensive to `clone()` and reducing the number of duplicate `String`s
+/// being allocated over time.
+///
+/// Each time the user constructs a reader, it clones the shared copy of the system macro table.
+pub(crate) static ION_1_1_SYSTEM_MACROS: LazyLock<MacroTable> =
+    LazyLock::new(MacroTable::construct_system_macro_table);
 
 impl Default for MacroTable {
     fn default() -> Self {
@@ -208,12 +217,12 @@ impl MacroTable {
     // is expected to change as development continues. It is currently used in several unit tests.
     pub const FIRST_USER_MACRO_ID: usize = Self::NUM_SYSTEM_MACROS;
 
-    fn compile_system_macros() -> Vec<Rc<Macro>> {
+    fn compile_system_macros() -> Vec<Arc<Macro>> {
         // We cannot compile template macros from source (text Ion) because that would require a Reader,
         // and a Reader would require system macros. To avoid this circular dependency, we manually
         // compile any TemplateMacros ourselves.
         vec![
-            Rc::new(Macro::named(
+            Arc::new(Macro::named(
                 "none",
                 MacroSignature::new(vec![]).unwrap(),
                 MacroKind::None,
@@ -232,7 +241,7 @@ impl MacroTable {
             //    (macro values (x*) x)
             //
             // It is effectively a user-addressable expression group.
-            Rc::new(Macro::from_template_macro(TemplateMacro {
+            Arc::new(Macro::from_template_macro(TemplateMacro {
                 name: Some("values".into()),
                 signature: MacroSignature::new(vec![Parameter::new(
                     "expr_group",
@@ -247,7 +256,7 @@ impl MacroTable {
                 },
                 expansion_analysis: ExpansionAnalysis::default(),
             })),
-            Rc::new(Macro::named(
+            Arc::new(Macro::named(
                 "make_string",
                 MacroSignature::new(vec![Parameter::new(
                     "text_values",
@@ -268,7 +277,7 @@ impl MacroTable {
                     }),
                 },
             )),
-            Rc::new(Macro::named(
+            Arc::new(Macro::named(
                 "make_sexp",
                 MacroSignature::new(vec![Parameter::new(
                     "sequences",
@@ -292,7 +301,7 @@ impl MacroTable {
                     }),
                 },
             )),
-            Rc::new(Macro::named(
+            Arc::new(Macro::named(
                 "annotate",
                 MacroSignature::new(vec![
                     Parameter::new(
@@ -326,7 +335,7 @@ impl MacroTable {
             //        (macro_table $ion_encoding)
             //      )
             //    )
-            Rc::new(Macro::from_template_macro(TemplateMacro {
+            Arc::new(Macro::from_template_macro(TemplateMacro {
                 name: Some("set_symbols".into()),
                 signature: MacroSignature::new(vec![Parameter::new(
                     "symbols",
@@ -411,7 +420,7 @@ impl MacroTable {
             //        (macro_table $ion_encoding)
             //      )
             //    )
-            Rc::new(Macro::from_template_macro(TemplateMacro {
+            Arc::new(Macro::from_template_macro(TemplateMacro {
                 name: Some("add_symbols".into()),
                 signature: MacroSignature::new(vec![Parameter::new(
                     "symbols",
@@ -502,7 +511,7 @@ impl MacroTable {
             //        (macro_table (%macro_definitions))
             //      )
             //    )
-            Rc::new(Macro::from_template_macro(TemplateMacro {
+            Arc::new(Macro::from_template_macro(TemplateMacro {
                 name: Some("set_macros".into()),
                 signature: MacroSignature::new(vec![Parameter::new(
                     "macro_definitions",
@@ -583,7 +592,7 @@ impl MacroTable {
             //        (macro_table $ion_encoding (%macro_definitions))
             //      )
             //    )
-            Rc::new(Macro::from_template_macro(TemplateMacro {
+            Arc::new(Macro::from_template_macro(TemplateMacro {
                 name: Some("add_macros".into()),
                 signature: MacroSignature::new(vec![Parameter::new(
                     "macro_definitions",
@@ -680,7 +689,7 @@ impl MacroTable {
     }
 
     pub fn with_system_macros() -> Self {
-        ION_1_1_SYSTEM_MACROS.with(|system_macros| system_macros.clone())
+        ION_1_1_SYSTEM_MACROS.clone()
     }
 
     pub fn empty() -> Self {
@@ -721,18 +730,18 @@ impl MacroTable {
         Some(MacroRef { address, reference })
     }
 
-    pub(crate) fn clone_macro_with_name(&self, name: &str) -> Option<Rc<Macro>> {
+    pub(crate) fn clone_macro_with_name(&self, name: &str) -> Option<Arc<Macro>> {
         let address = *self.macros_by_name.get(name)?;
         let reference = self.macros_by_address.get(address)?;
-        Some(Rc::clone(reference))
+        Some(Arc::clone(reference))
     }
 
-    pub(crate) fn clone_macro_with_address(&self, address: usize) -> Option<Rc<Macro>> {
+    pub(crate) fn clone_macro_with_address(&self, address: usize) -> Option<Arc<Macro>> {
         let reference = self.macros_by_address.get(address)?;
-        Some(Rc::clone(reference))
+        Some(Arc::clone(reference))
     }
 
-    pub(crate) fn clone_macro_with_id(&self, macro_id: MacroIdRef) -> Option<Rc<Macro>> {
+    pub(crate) fn clone_macro_with_id(&self, macro_id: MacroIdRef) -> Option<Arc<Macro>> {
         use MacroIdRef::*;
         match macro_id {
             LocalName(name) => self.clone_macro_with_name(name),
@@ -747,7 +756,7 @@ impl MacroTable {
             if self.macros_by_name.contains_key(name.as_ref()) {
                 return IonResult::decoding_error(format!("macro named '{name}' already exists"));
             }
-            self.macros_by_name.insert(Rc::clone(name), id);
+            self.macros_by_name.insert(Arc::clone(name), id);
         }
 
         let new_macro = Macro::new(
@@ -757,7 +766,7 @@ impl MacroTable {
             template.expansion_analysis,
         );
 
-        self.macros_by_address.push(Rc::new(new_macro));
+        self.macros_by_address.push(Arc::new(new_macro));
         Ok(id)
     }
 
@@ -772,8 +781,617 @@ impl MacroTable {
                 }
                 self.macros_by_name.insert(name, next_id);
             }
-            self.macros_by_address.push(Rc::clone(macro_ref))
+            self.macros_by_address.push(Arc::clone(macro_ref))
+        }
+        Ok(())
+    }
+}
+
+impl MacroTable {
+    /// Reconstructs the `(macro_table ...)` clause that would define this table's macros.
+    fn to_tdl(&self) -> IonResult<Element> {
+        let system_macros = ION_1_1_SYSTEM_MACROS.macros_by_address.as_slice();
+        let mut clause = vec![Element::symbol("macro_table")];
+        let mut address = 0;
+        while address < self.len() {
+            // A run of the system macros is written as a re-export of the `$ion` module.
+            if self.macros_by_address[address..].starts_with(system_macros) {
+                clause.push(Element::symbol("$ion"));
+                address += system_macros.len();
+                continue;
+            }
+            clause.push(TdlDecompiler::macro_definition(self, address)?);
+            address += 1;
         }
+        Ok(tdl_sexp(clause))
+    }
+
+    /// Compiles each of the macro definitions in a `(macro_table ...)` clause.
+    fn from_tdl<D: Decoder>(
+        context: EncodingContextRef<'_>,
+        clause: LazySExp<'_, D>,
+    ) -> IonResult<Self> {
+        let mut args = clause.iter();
+        let operation = args.next().transpose()?;
+        match operation.map(|operation| operation.read()).transpose()? {
+            Some(ValueRef::Symbol(name)) if name == "macro_table" => {}
+            _ => {
+                return IonResult::decoding_error(format!(
+                    "expected a `(macro_table ...)` clause, but found: {clause:?}"
+                ))
+            }
+        }
+        let mut macro_table = MacroTable::empty();
+        for arg in args {
+            let arg = arg?;
+            match arg.read()? {
+                ValueRef::SExp(macro_def_sexp) => {
+                    let new_macro =
+                        TemplateCompiler::compile_from_sexp(context, &macro_table, macro_def_sexp)?;
+                    macro_table.add_macro(new_macro)?;
+                }
+                ValueRef::Symbol(module_name) if module_name == "$ion" => {
+                    macro_table.append_all_macros_from(&ION_1_1_SYSTEM_MACROS)?;
+                }
+                other => {
+                    return IonResult::decoding_error(format!(
+                        "macro_table was passed an unsupported argument: {other:?}"
+                    ))
+                }
+            }
+        }
+        Ok(macro_table)
+    }
+}
+
+/// Writes this table as a `(macro_table ...)` clause, the form an Ion 1.1 encoding directive uses
+/// to define a module's macros. Each template macro is written as a `(macro ...)` definition that
+/// compiles to an identical template, and each run of the system macros is written as `$ion`.
+///
+/// Macros that are implemented natively rather than as templates cannot be expressed in TDL;
+/// attempting to write a table that contains one (outside of the system macros) is an error.
+impl WriteAsIon for MacroTable {
+    fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+        (&self.to_tdl()?).write_as_ion(writer)
+    }
+}
+
+/// Compiles a `(macro_table ...)` clause, like the one produced by the [`WriteAsIon`]
+/// implementation, into a `MacroTable`.
+impl<D: Decoder> TryFrom<LazyValue<'_, D>> for MacroTable {
+    type Error = IonError;
+
+    fn try_from(value: LazyValue<'_, D>) -> Result<Self, Self::Error> {
+        // Each definition is compiled in a fresh context so that it can only refer to system
+        // macros and to the macros that precede it in the clause.
+        let context = EncodingContext::for_ion_version(IonVersion::v1_1);
+        Self::from_tdl(context.get_ref(), value.read()?.expect_sexp()?)
+    }
+}
+
+/// Compiles a `(macro_table ...)` clause represented as an [`Element`] into a `MacroTable`.
+impl TryFrom<&Element> for MacroTable {
+    type Error = IonError;
+
+    fn try_from(element: &Element) -> Result<Self, Self::Error> {
+        // The compiler reads TDL from a `LazyValue`, so the element is encoded as binary Ion and
+        // read back.
+        let mut reader = Reader::new(v1_0::Binary, element.encode_as(v1_0::Binary)?)?;
+        MacroTable::try_from(reader.expect_next()?)
+    }
+}
+
+impl TryFrom<Element> for MacroTable {
+    type Error = IonError;
+
+    fn try_from(element: Element) -> Result<Self, Self::Error> {
+        MacroTable::try_from(&element)
+    }
+}
+
+fn tdl_sexp(children: Vec<Element>) -> Element {
+    Value::SExp(Sequence::new(children)).into()
+}
+
+/// Reconstructs TDL source for a template macro in a [`MacroTable`]. Compiling the resulting
+/// definition in the context of the macros that precede it in the table yields an equal `Macro`.
+struct TdlDecompiler<'a> {
+    table: &'a MacroTable,
+    // The address of the macro being decompiled. Its template can only refer to macros that were
+    // defined before it.
+    address: usize,
+    signature: &'a MacroSignature,
+    body: &'a TemplateBody,
+}
+
+impl<'a> TdlDecompiler<'a> {
+    fn macro_definition(table: &'a MacroTable, address: usize) -> IonResult<Element> {
+        let definition = table.macros_by_address[address].as_ref();
+        let id_text = MacroRef::new(address, definition).id_text().into_owned();
+        let MacroKind::Template(body) = definition.kind() else {
+            return IonResult::encoding_error(format!(
+                "macro '{id_text}' is not a template macro and cannot be written as TDL"
+            ));
+        };
+        let Some(body_expr) = body.expressions().first() else {
+            return IonResult::encoding_error(format!("macro '{id_text}' has an empty body"));
+        };
+        let decompiler = Self {
+            table,
+            address,
+            signature: definition.signature(),
+            body,
+        };
+
+        let name = match definition.name() {
+            Some(name) => Element::symbol(name),
+            None => Element::null(IonType::Null),
+        };
+
+        let mut parameters = Vec::new();
+        for parameter in definition.signature().parameters() {
+            let encoding: Vec<&str> = match parameter.encoding() {
+                ParameterEncoding::Tagged => Vec::new(),
+                ParameterEncoding::FlexUInt => vec!["flex_uint"],
+                ParameterEncoding::MacroShaped(encoding_macro) => {
+                    match decompiler.macro_id(encoding_macro)? {
+                        (module, MacroIdRef::LocalName(name)) => module.into_iter().chain([name]).collect(),
+                        (_, MacroIdRef::LocalAddress(_)) => {
+                            return IonResult::encoding_error(format!(
+                                "parameter '{}' of macro '{id_text}' is encoded as an anonymous macro, which TDL cannot name",
+                                parameter.name()
+                            ))
+                        }
+                    }
+                }
+            };
+            parameters.push(Element::symbol(parameter.name()).with_annotations(encoding));
+            let cardinality = match parameter.cardinality() {
+                ParameterCardinality::ExactlyOne => continue,
+                ParameterCardinality::ZeroOrOne => "?",
+                ParameterCardinality::ZeroOrMore => "*",
+                ParameterCardinality::OneOrMore => "+",
+            };
+            parameters.push(Element::symbol(cardinality));
+        }
+
+        // The compiler's expansion analysis looks at the body's source rather than its compiled
+        // form. A body of `(.literal foo)` compiles to the same template as `foo`, but is not known
+        // to produce exactly one value. If that's the case, reconstruct the `literal` form.
+        let is_literal_body = !definition.must_produce_exactly_one_value()
+            && matches!(
+                body_expr.kind(),
+                TemplateBodyExprKind::Element(element) if !matches!(element.value(), TemplateValue::SExp)
+            );
+        let body = if is_literal_body {
+            decompiler.literal(std::iter::once(0))?
+        } else {
+            decompiler.expr(0, false)?
+        };
+
+        Ok(tdl_sexp(vec![
+            Element::symbol("macro"),
+            name,
+            tdl_sexp(parameters),
+            body,
+        ]))
+    }
+
+    /// Decompiles the expression at `index`. If `is_literal` is true, the expression is nested
+    /// inside a `(literal ...)` and must be a value.
+    fn expr(&self, index: usize, is_literal: bool) -> IonResult<Element> {
+        let expr = &self.body.expressions()[index];
+        match expr.kind() {
+            TemplateBodyExprKind::Element(element) => {
+                self.element(element, expr.expr_range(), is_literal)
+            }
+            _ if is_literal => {
+                IonResult::encoding_error("found a TDL operation inside of a literal value")
+            }
+            TemplateBodyExprKind::Variable(variable) => Ok(tdl_sexp(vec![
+                Element::symbol("%"),
+                Element::symbol(variable.name(self.signature)),
+            ])),
+            TemplateBodyExprKind::MacroInvocation(invocation) => {
+                self.invocation(&invocation.invoked_macro, expr.expr_range())
+            }
+            TemplateBodyExprKind::ExprGroup(_) => {
+                let mut group = vec![Element::symbol("..")];
+                for child in self.children(expr.expr_range()) {
+                    group.push(self.expr(child, false)?);
+                }
+                Ok(tdl_sexp(group))
+            }
+        }
+    }
+
+    fn element(
+        &self,
+        element: &TemplateBodyElement,
+        expr_range: ExprRange,
+        is_literal: bool,
+    ) -> IonResult<Element> {
+        let value = match element.value() {
+            TemplateValue::Null(ion_type) => Value::Null(*ion_type),
+            TemplateValue::Bool(b) => Value::Bool(*b),
+            TemplateValue::Int(i) => Value::Int(i.clone()),
+            TemplateValue::Float(f) => Value::Float(*f),
+            TemplateValue::Decimal(d) => Value::Decimal(d.clone()),
+            TemplateValue::Timestamp(t) => Value::Timestamp(t.clone()),
+            TemplateValue::Symbol(s) => Value::Symbol(s.clone()),
+            TemplateValue::String(s) => Value::String(s.clone()),
+            TemplateValue::Clob(c) => Value::Clob(c.clone()),
+            TemplateValue::Blob(b) => Value::Blob(b.clone()),
+            TemplateValue::List => {
+                let mut list = Vec::new();
+                for child in self.children(expr_range) {
+                    list.push(self.expr(child, is_literal)?);
+                }
+                Value::List(Sequence::new(list))
+            }
+            TemplateValue::SExp => {
+                let mut sexp = Vec::new();
+                for child in self.children(expr_range) {
+                    // Outside of a literal, an s-expression that begins with one of these symbols
+                    // would be interpreted as a TDL operation. The symbol must be quoted instead.
+                    if sexp.is_empty() && !is_literal && self.is_operator_symbol(child) {
+                        sexp.push(self.literal(std::iter::once(child))?);
+                        continue;
+                    }
+                    sexp.push(self.expr(child, is_literal)?);
+                }
+                Value::SExp(Sequence::new(sexp))
+            }
+            TemplateValue::Struct(_) => {
+                let mut fields = Struct::builder();
+                for field in self.children(expr_range).chunks(2) {
+                    let &[name_index, value_index] = field else {
+                        return IonResult::encoding_error("found a struct field with no value");
+                    };
+                    let TemplateValue::Symbol(name) = self.body.expressions()[name_index]
+                        .kind()
+                        .require_element()
+                        .value()
+                    else {
+                        return IonResult::encoding_error("found a struct field with no name");
+                    };
+                    fields = fields.with_field(name.clone(), self.expr(value_index, is_literal)?);
+                }
+                Value::Struct(fields.build())
+            }
+        };
+        let annotations = &self.body.annotations_storage()[element.annotations_range().ops_range()];
+        Ok(Element::new(annotations.iter().cloned().collect(), value))
+    }
+
+    fn invocation(&self, invoked_macro: &'a Macro, expr_range: ExprRange) -> IonResult<Element> {
+        let arguments = self.children(expr_range);
+        if arguments.len() != invoked_macro.signature().len() {
+            // The compiler turns `(.literal a b c)` into an invocation of `values` whose arguments
+            // are the literal values rather than a single expression group. No other invocation
+            // has one expression per argument, so TDL cannot reproduce it.
+            if Self::is_values_macro(invoked_macro) {
+                return self.literal(arguments.into_iter());
+            }
+            return IonResult::encoding_error(format!(
+                "macro at address {} passes {} arguments to macro '{}', which has {} parameters",
+                self.address,
+                arguments.len(),
+                invoked_macro.name().unwrap_or("<anonymous>"),
+                invoked_macro.signature().len()
+            ));
+        }
+        let id = match self.macro_id(invoked_macro)? {
+            (module, MacroIdRef::LocalName(name)) => {
+                Element::symbol(name).with_annotations(module.into_iter().collect::<Vec<_>>())
+            }
+            (_, MacroIdRef::LocalAddress(address)) => Element::int(address as i64),
+        };
+        let mut invocation = vec![Element::symbol("."), id];
+        for argument in arguments {
+            invocation.push(self.expr(argument, false)?);
+        }
+        Ok(tdl_sexp(invocation))
+    }
+
+    /// Constructs a `(.$ion::literal ...)` containing the values at the specified indexes.
+    fn literal(&self, indexes: impl Iterator<Item = usize>) -> IonResult<Element> {
+        let mut literal = vec![
+            Element::symbol("."),
+            Element::symbol("literal").with_annotations(["$ion"]),
+        ];
+        for index in indexes {
+            literal.push(self.expr(index, true)?);
+        }
+        Ok(tdl_sexp(literal))
+    }
+
+    fn is_values_macro(invoked_macro: &Macro) -> bool {
+        ION_1_1_SYSTEM_MACROS
+            .macro_with_name("values")
+            .is_some_and(|values| values.reference() == invoked_macro)
+    }
+
+    /// Returns the module name (if the ID must be qualified) and the ID that TDL can use to refer
+    /// to `invoked_macro` from the body of the macro being decompiled.
+    fn macro_id(
+        &self,
+        invoked_macro: &'a Macro,
+    ) -> IonResult<(Option<&'static str>, MacroIdRef<'a>)> {
+        let is_invoked_macro = |candidate: &Arc<Macro>| {
+            std::ptr::eq(candidate.as_ref(), invoked_macro) || candidate.as_ref() == invoked_macro
+        };
+        let preceding_macros = &self.table.macros_by_address[..self.address];
+        if let Some(address) = preceding_macros.iter().position(is_invoked_macro) {
+            return Ok(match invoked_macro.name() {
+                Some(name) => (None, MacroIdRef::LocalName(name)),
+                None => (None, MacroIdRef::LocalAddress(address)),
+            });
+        }
+        let is_system_macro = ION_1_1_SYSTEM_MACROS
+            .macros_by_address
+            .iter()
+            .any(is_invoked_macro);
+        match invoked_macro.name() {
+            Some(name) if is_system_macro => Ok((Some("$ion"), MacroIdRef::LocalName(name))),
+            _ => IonResult::encoding_error(format!(
+                "macro at address {} refers to macro '{}', which is not defined before it in the table",
+                self.address,
+                invoked_macro.name().unwrap_or("<anonymous>")
+            )),
+        }
+    }
+
+    /// Returns the indexes of the expressions that are direct children of the expression whose
+    /// range is `expr_range`.
+    fn children(&self, expr_range: ExprRange) -> Vec<usize> {
+        let mut children = Vec::new();
+        let mut index = expr_range.start() + 1;
+        while index < expr_range.end() {
+            children.push(index);
+            index = self.body.expressions()[index]
+                .expr_range()
+                .end()
+                .max(index + 1);
+        }
+        children
+    }
+
+    fn is_operator_symbol(&self, index: usize) -> bool {
+        matches!(
+            self.body.expressions()[index].kind(),
+            TemplateBodyExprKind::Element(element)
+                if matches!(element.value(), TemplateValue::Symbol(s) if matches!(s.text(), Some("." | ".." | "%")))
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use std::sync::Arc;
+
+    use crate::lazy::encoder::write_as_ion::WriteAsIon;
+    use crate::lazy::expanded::compiler::{ExpansionAnalysis, TemplateCompiler};
+    use crate::lazy::expanded::template::{
+        ExprRange, MacroSignature, TemplateBody, TemplateBodyElement, TemplateMacro, TemplateValue,
+    };
+    use crate::lazy::expanded::EncodingContext;
+    use crate::{v1_0, Element, IonInput, IonResult, IonVersion, MacroTable, Reader};
+
+    fn read_table<I: IonInput>(tdl: I) -> IonResult<MacroTable> {
+        let mut reader = Reader::new(v1_0::Text, tdl)?;
+        MacroTable::try_from(reader.expect_next()?)
+    }
+
+    #[rstest]
+    #[case::empty("(macro_table)")]
+    #[case::system_macros("(macro_table $ion)")]
+    #[case::scalars(
+        r#"(macro_table
+             (macro scalars () [null.int, true, 7, 1.5e0, 1.25, 2024-01-01T, "text", sym, $0, {{"clob"}}, {{aGVsbG8=}}])
+        )"#
+    )]
+    #[case::annotations(
+        r#"(macro_table
+             (macro annotated (x) a::b::{c: d::[e::1], $0: (%x), f: g::(h (%x))})
+        )"#
+    )]
+    #[case::parameters(
+        r#"(macro_table
+             $ion
+             (macro pair (flex_uint::x y?) [(%x), (%y)])
+             (macro point (pair::coords $ion::make_string::label? tags*) {coords: (%coords), label: (%label), tags: [(%tags)]})
+             (macro greet (first last+) (.make_string "Hello, " (%first) " " (%last) "!"))
+        )"#
+    )]
+    #[case::invocations(
+        r#"(macro_table
+             (macro twice (x) (.values (%x) (%x)))
+             (macro pair (x y?) [(%x), (%y)])
+             (macro null (x) [(%x)])
+             (macro calls (x*) ((.twice 1) (.pair 1) (.pair 1 (.$ion::none)) (.values (.. 1 2)) (.values) (.1 (%x) (..)) (.2 3)))
+        )"#
+    )]
+    #[case::literals(
+        r#"(macro_table
+             (macro single () (.literal foo))
+             (macro multiple () (.literal (.. a) (% b) [(.c)]))
+             (macro nothing () (.literal))
+             (macro quoted (x) ((.literal .) (%x) ((.literal %) x) (.literal (.. (%x)))))
+             (macro nested (x) [(.literal (. not a macro)), (%x)])
+        )"#
+    )]
+    #[case::shadowed_literal(
+        r#"(macro_table
+             $ion
+             (macro literal (x) (%x))
+             (macro uses_literal () [(.literal 1), (.$ion::literal (.literal 1))])
+        )"#
+    )]
+    fn macro_table_round_trip(#[case] tdl: &str) -> IonResult<()> {
+        let table = read_table(tdl)?;
+        let ion = table.encode_as(v1_0::Binary)?;
+        let element = Element::read_one(&ion)?;
+        let mut reader = Reader::new(v1_0::Binary, ion)?;
+        let round_tripped = MacroTable::try_from(reader.expect_next()?)?;
+        assert_eq!(round_tripped.len(), table.len());
+        for address in 0..table.len() {
+            assert_eq!(
+                round_tripped.macro_at_address(address).unwrap().reference(),
+                table.macro_at_address(address).unwrap().reference(),
+                "macro at address {address} did not round trip; written as:\n{element}"
+            );
+        }
+        // Writing the table again produces an identical definition.
+        assert_eq!(
+            Element::read_one(round_tripped.encode_as(v1_0::Text)?)?,
+            element
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::empty("(macro_table)")]
+    #[case::system_macros("(macro_table $ion)")]
+    #[case::definitions(
+        r#"(macro_table
+             $ion
+             (macro pair (flex_uint::x y?) [(%x), (%y)])
+             (macro twice (x) (.values (%x) (%x)))
+             (macro annotated (x) a::{b: c::[(%x)], $0: (.twice (%x))})
+        )"#
+    )]
+    fn macro_table_element_round_trip(#[case] tdl: &str) -> IonResult<()> {
+        let table = MacroTable::try_from(Element::read_one(tdl)?)?;
+        assert_eq!(table.len(), read_table(tdl)?.len());
+        let element = Element::read_one(table.encode_as(v1_0::Binary)?)?;
+        let round_tripped = MacroTable::try_from(&element)?;
+        assert_eq!(round_tripped.len(), table.len());
+        for address in 0..table.len() {
+            assert_eq!(
+                round_tripped.macro_at_address(address).unwrap().reference(),
+                table.macro_at_address(address).unwrap().reference(),
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn macro_table_from_element_rejects_other_values() {
+        assert!(MacroTable::try_from(Element::from(17)).is_err());
+        assert!(MacroTable::try_from(Element::read_one("(macro_table 17)").unwrap()).is_err());
+    }
+
+    #[test]
+    fn system_macros_are_written_as_module_name() -> IonResult<()> {
+        let table = read_table("(macro_table $ion (macro seventeen () 17))")?;
+        assert_eq!(table.len(), MacroTable::NUM_SYSTEM_MACROS + 1);
+        assert_eq!(
+            Element::read_one(table.encode_as(v1_0::Text)?)?,
+            Element::read_one("(macro_table $ion (macro seventeen () 17))")?
+        );
+        let system_macros = MacroTable::with_system_macros();
+        assert_eq!(
+            Element::read_one(system_macros.encode_as(v1_0::Text)?)?,
+            Element::read_one("(macro_table $ion)")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn references_to_system_macros_are_qualified() -> IonResult<()> {
+        let table = read_table("(macro_table (macro twice (x) (.values (%x) (%x))))")?;
+        assert_eq!(
+            Element::read_one(table.encode_as(v1_0::Text)?)?,
+            Element::read_one("(macro_table (macro twice (x) (. $ion::values (.. (% x) (% x)))))")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn read_table_from_lazy_value() -> IonResult<()> {
+        let tdl = "(macro_table $ion (macro greet (name) (.make_string \"Hello, \" (%name))))";
+        let mut reader = Reader::new(v1_0::Text, tdl)?;
+        let table = MacroTable::try_from(reader.expect_next()?)?;
+        assert_eq!(table.len(), MacroTable::NUM_SYSTEM_MACROS + 1);
+        assert!(table.macro_with_name("greet").is_some());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::not_a_sexp("[macro_table]")]
+    #[case::wrong_operation("(symbol_table [\"foo\"])")]
+    #[case::unknown_module("(macro_table $ion_encoding)")]
+    #[case::invalid_argument("(macro_table 1)")]
+    #[case::duplicate_name("(macro_table (macro foo () 1) (macro foo () 2))")]
+    #[case::undefined_macro("(macro_table (macro foo () (.bar)))")]
+    fn invalid_tables(#[case] tdl: &str) {
+        read_table(tdl).expect_err("table should be invalid");
+    }
+
+    #[test]
+    fn macros_outside_of_the_table_cannot_be_written() -> IonResult<()> {
+        let mut context = EncodingContext::for_ion_version(IonVersion::v1_1);
+        let helper = TemplateCompiler::compile_from_text(context.get_ref(), "(macro helper () 1)")?;
+        context.macro_table_mut().add_macro(helper)?;
+        let caller =
+            TemplateCompiler::compile_from_text(context.get_ref(), "(macro caller () (.helper))")?;
+        let mut table = MacroTable::empty();
+        table.add_macro(caller)?;
+        table
+            .encode_as(v1_0::Text)
+            .expect_err("`helper` is not in the table");
+        Ok(())
+    }
+
+    #[test]
+    fn invocations_that_tdl_cannot_express_cannot_be_written() -> IonResult<()> {
+        let mut table = read_table("(macro_table (macro pair (x y) [(%x), (%y)]))")?;
+        let pair = table.clone_macro_with_name("pair").unwrap();
+        // The compiler always passes `pair` two arguments; this template passes it one.
+        let mut body = TemplateBody {
+            expressions: Vec::new(),
+            annotations_storage: Vec::new(),
+        };
+        body.push_macro_invocation(Arc::clone(&pair), ExprRange::new(0..2));
+        body.push_element(
+            TemplateBodyElement::with_value(TemplateValue::Int(1.into())),
+            ExprRange::new(1..2),
+        );
+        table.add_macro(TemplateMacro {
+            name: Some("caller".into()),
+            signature: MacroSignature::new(vec![])?,
+            body,
+            expansion_analysis: ExpansionAnalysis::default(),
+        })?;
+        table
+            .encode_as(v1_0::Text)
+            .expect_err("`pair` is invoked with too few arguments");
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn written_table_defines_macros_in_a_stream() -> IonResult<()> {
+        use crate::ElementReader;
+
+        let table = read_table(
+            r#"(macro_table
+                 $ion
+                 (macro greet (name) (.make_string "Hello, " (%name) "!"))
+            )"#,
+        )?;
+        let ion = format!(
+            "$ion_1_1 $ion_encoding::({}) (:greet \"World\")",
+            table.encode_as(v1_0::Text)?
+        );
+        let mut reader = Reader::new(crate::AnyEncoding, ion)?;
+        assert_eq!(
+            reader.read_all_elements()?,
+            Element::read_all("\"Hello, World!\"")?
+        );
         Ok(())
     }
 }
@@ -36,7 +36,7 @@ use bumpalo::Bump as BumpAllocator;
 use std::cell::{Cell, UnsafeCell};
 use std::fmt::{Debug, Formatter};
 use std::ops::{Deref, Range};
-use std::rc::Rc;
+use std::sync::Arc;
 
 use sequence::{LazyExpandedList, LazyExpandedSExp};
 
@@ -66,6 +66,7 @@ use crate::lazy::system_stream_item::SystemStreamItem;
 use crate::lazy::text::raw::v1_1::reader::MacroAddress;
 use crate::lazy::value::LazyValue;
 use crate::raw_symbol_ref::AsRawSymbolRef;
+use crate::read_config::{ImportResolution, JsonDecoding, ReadLimits};
 use crate::result::IonFailure;
 use crate::{
     Catalog, Decimal, HasRange, HasSpan, Int, IonResult, IonType, RawStreamItem, RawSymbolRef,
@@ -97,6 +98,9 @@ pub struct EncodingContext {
     pub(crate) macro_table: MacroTable,
     pub(crate) symbol_table: SymbolTable,
     pub(crate) allocator: BumpAllocator,
+    pub(crate) read_limits: ReadLimits,
+    // When set, the text Ion 1.0 parser rejects any syntax that is not JSON.
+    pub(crate) json_decoding: Option<JsonDecoding>,
 }
 
 impl EncodingContext {
@@ -110,6 +114,8 @@ impl EncodingContext {
             macro_table,
             symbol_table,
             allocator,
+            read_limits: ReadLimits::new(),
+            json_decoding: None,
         }
     }
 
@@ -179,6 +185,14 @@ impl<'top> EncodingContextRef<'top> {
         &self.context.symbol_table
     }
 
+    pub(crate) fn read_limits(&self) -> ReadLimits {
+        self.context.read_limits
+    }
+
+    pub(crate) fn json_decoding(&self) -> Option<JsonDecoding> {
+        self.context.json_decoding
+    }
+
     pub fn macro_table(&self) -> &'top MacroTable {
         &self.context.macro_table
     }
@@ -191,13 +205,13 @@ impl<'top> EncodingContextRef<'top> {
         self.context.system_module.macro_table()
     }
 
-    pub(crate) fn none_macro(&self) -> Rc<Macro> {
+    pub(crate) fn none_macro(&self) -> Arc<Macro> {
         self.system_macro_table()
             .clone_macro_with_name("none")
             .expect("`none` macro in system macro table")
     }
 
-    pub(crate) fn values_macro(&self) -> Rc<Macro> {
+    pub(crate) fn values_macro(&self) -> Arc<Macro> {
         self.system_macro_table()
             .clone_macro_with_name("values")
             .expect("`values` macro in system macro table")
@@ -268,21 +282,41 @@ pub struct ExpandingReader<Encoding: Decoder, Input: IonInput> {
     // to the encoding context the next time the reader is between top-level expressions.
     pending_context_changes: UnsafeCell<PendingContextChanges>,
     encoding_context: UnsafeCell<EncodingContext>,
-    catalog: Box<dyn Catalog>,
+    catalog: Box<dyn Catalog + Send>,
+    import_resolution: ImportResolution,
+}
+
+// SAFETY: `evaluator_ptr` is the only field that is not `Send` on its own. It points to a
+//         `MacroEvaluator` in the bump allocator owned by `encoding_context`, which moves to the
+//         new thread along with the reader. The `catalog` is `Send` by its type, and the bounds
+//         below require every other field to be `Send`.
+unsafe impl<Encoding: Decoder, Input: IonInput> Send for ExpandingReader<Encoding, Input>
+where
+    StreamingRawReader<Encoding, Input>: Send,
+    PendingContextChanges: Send,
+    EncodingContext: Send,
+{
 }
 
 impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
     pub(crate) fn new(
         raw_reader: StreamingRawReader<Encoding, Input>,
-        catalog: Box<dyn Catalog>,
+        catalog: Box<dyn Catalog + Send>,
+        read_limits: ReadLimits,
+        import_resolution: ImportResolution,
+        json_decoding: Option<JsonDecoding>,
     ) -> Self {
         let encoding = raw_reader.encoding();
+        let mut encoding_context = EncodingContext::for_ion_version(encoding.version());
+        encoding_context.read_limits = read_limits;
+        encoding_context.json_decoding = json_decoding;
         Self {
             raw_reader: raw_reader.into(),
             evaluator_ptr: None.into(),
-            encoding_context: EncodingContext::for_ion_version(encoding.version()).into(),
+            encoding_context: encoding_context.into(),
             pending_context_changes: PendingContextChanges::new().into(),
             catalog,
+            import_resolution,
         }
     }
 
@@ -427,11 +461,27 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
         if SystemReader::<_, Input>::is_symbol_table_struct(&value)? {
             // ...traverse it and record any new symbols in our `pending_lst`.
             let pending_changes = unsafe { &mut *self.pending_context_changes.get() };
+            let read_limits = self.context().read_limits();
             SystemReader::<_, Input>::process_symbol_table(
                 pending_changes,
                 &*self.catalog,
+                self.import_resolution,
+                read_limits,
                 &value,
             )?;
+            // Confirm that applying the pending changes will not grow the symbol table beyond
+            // the configured limit.
+            let symbol_table = self.context().symbol_table();
+            let retained_symbols = if pending_changes.is_lst_append {
+                symbol_table.len()
+            } else {
+                symbol_table.num_prefix_symbols()
+            };
+            read_limits.check_symbol_table_size(
+                retained_symbols
+                    + pending_changes.imported_symbols.len()
+                    + pending_changes.symbols.len(),
+            )?;
             pending_changes.has_changes = true;
             let lazy_struct = LazyStruct {
                 expanded_struct: value.read()?.expect_struct()?,
@@ -518,6 +568,11 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
         }
     }
 
+    /// Returns a mutable reference to the data source from which the raw reader pulls input.
+    pub(crate) fn data_source_mut(&mut self) -> &mut Input::DataSource {
+        self.raw_reader.get_mut().data_source_mut()
+    }
+
     pub fn detected_encoding(&self) -> IonEncoding {
         // SAFETY: We have an immutable reference to `self`, so it's legal for us to have an immutable
         //         reference to one of its fields.
@@ -982,7 +1037,7 @@ impl<'top, Encoding: Decoder> LazyExpandedValue<'top, Encoding> {
             Template(environment, element) => {
                 Ok(ValueRef::from_template(self.context, *environment, element))
             }
-            Constructed(_annotations, value) => Ok(**value),
+            Constructed(_annotations, value) => Ok((**value).clone()),
             SingletonEExp(ref eexp) => self.read_resolved_singleton_eexp(eexp),
         }
     }
@@ -1099,7 +1154,7 @@ impl<'top, Encoding: Decoder> Iterator for ExpandedAnnotationsIterator<'top, Enc
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub enum ExpandedValueRef<'top, Encoding: Decoder> {
     Null(IonType),
     Bool(bool),
@@ -1312,10 +1367,10 @@ impl<'top, Encoding: Decoder> ExpandedValueRef<'top, Encoding> {
         match element.value() {
             Null(ion_type) => ExpandedValueRef::Null(*ion_type),
             Bool(b) => ExpandedValueRef::Bool(*b),
-            Int(i) => ExpandedValueRef::Int(*i),
+            Int(i) => ExpandedValueRef::Int(i.clone()),
             Float(f) => ExpandedValueRef::Float(*f),
-            Decimal(d) => ExpandedValueRef::Decimal(*d),
-            Timestamp(t) => ExpandedValueRef::Timestamp(*t),
+            Decimal(d) => ExpandedValueRef::Decimal(d.clone()),
+            Timestamp(t) => ExpandedValueRef::Timestamp(t.clone()),
             String(s) => ExpandedValueRef::String(StrRef::from(s.text())),
             Symbol(s) => ExpandedValueRef::Symbol(s.as_raw_symbol_ref()),
             Blob(b) => ExpandedValueRef::Blob(BytesRef::from(b.as_ref())),
@@ -2,6 +2,8 @@
 //! top level value.
 
 pub(crate) mod any_encoding;
+#[cfg(feature = "experimental-async")]
+pub(crate) mod async_reader;
 pub(crate) mod binary;
 pub(crate) mod bytes_ref;
 pub(crate) mod decoder;
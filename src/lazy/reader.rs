@@ -123,6 +123,11 @@ impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
         let system_reader = SystemReader::new(config, ion_data);
         Ok(Reader { system_reader })
     }
+
+    /// Returns a mutable reference to the data source from which the reader pulls input.
+    pub(crate) fn data_source_mut(&mut self) -> &mut Input::DataSource {
+        self.system_reader.expanding_reader.data_source_mut()
+    }
 }
 
 use crate::lazy::{
@@ -389,4 +394,23 @@ mod tests {
             Ok(())
         })
     }
+
+    /// Fails to compile if the provided type cannot be moved to another thread.
+    fn assert_send<T: Send>() {}
+
+    #[test]
+    fn readers_are_send() {
+        use crate::IonStream;
+        use std::fs::File;
+        use std::io::BufReader;
+
+        assert_send::<Reader<AnyEncoding, IonStream<File>>>();
+        assert_send::<Reader<AnyEncoding, IonStream<BufReader<File>>>>();
+        assert_send::<Reader<AnyEncoding, File>>();
+        assert_send::<Reader<AnyEncoding, Vec<u8>>>();
+        assert_send::<Reader<AnyEncoding, &[u8]>>();
+        assert_send::<Reader<v1_0::Binary, &[u8]>>();
+        assert_send::<Reader<v1_0::Text, &[u8]>>();
+        assert_send::<EncodingContext>();
+    }
 }
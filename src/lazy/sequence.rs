@@ -134,19 +134,30 @@ impl<'top, D: Decoder> LazyList<'top, D> {
     }
 }
 
-impl<'top, D: Decoder> TryFrom<LazyList<'top, D>> for Sequence {
-    type Error = IonError;
-
-    fn try_from(lazy_sequence: LazyList<'top, D>) -> Result<Self, Self::Error> {
-        let sequence: Sequence = lazy_sequence
+impl<'top, D: Decoder> LazyList<'top, D> {
+    /// Materializes the list's child values. `depth` is the number of containers enclosing this
+    /// list, which is used to enforce the reader's container depth limit.
+    pub(crate) fn to_sequence_at_depth(self, depth: usize) -> IonResult<Sequence> {
+        let read_limits = self.expanded_list.context.read_limits();
+        read_limits.check_container_depth(depth + 1)?;
+        let sequence: Sequence = self
             .iter()
-            .map(|result| result.and_then(Element::try_from))
+            .map(|result| result?.to_element_at_depth(depth + 1))
             .collect::<IonResult<Vec<_>>>()?
             .into();
         Ok(sequence)
     }
 }
 
+impl<'top, D: Decoder> TryFrom<LazyList<'top, D>> for Sequence {
+    type Error = IonError;
+
+    fn try_from(lazy_sequence: LazyList<'top, D>) -> Result<Self, Self::Error> {
+        // Nesting is counted from this sequence; see `ReadLimits::with_max_container_depth`.
+        lazy_sequence.to_sequence_at_depth(0)
+    }
+}
+
 impl<'top, D: Decoder> TryFrom<LazyList<'top, D>> for Element {
     type Error = IonError;
 
@@ -300,19 +311,30 @@ impl<'top, D: Decoder> LazySExp<'top, D> {
     }
 }
 
-impl<'top, D: Decoder> TryFrom<LazySExp<'top, D>> for Sequence {
-    type Error = IonError;
-
-    fn try_from(lazy_sequence: LazySExp<'top, D>) -> Result<Self, Self::Error> {
-        let sequence: Sequence = lazy_sequence
+impl<'top, D: Decoder> LazySExp<'top, D> {
+    /// Materializes the s-expression's child values. `depth` is the number of containers
+    /// enclosing this s-expression, which is used to enforce the reader's container depth limit.
+    pub(crate) fn to_sequence_at_depth(self, depth: usize) -> IonResult<Sequence> {
+        let read_limits = self.expanded_sexp.context.read_limits();
+        read_limits.check_container_depth(depth + 1)?;
+        let sequence: Sequence = self
             .iter()
-            .map(|v| Element::try_from(v?))
+            .map(|v| v?.to_element_at_depth(depth + 1))
             .collect::<IonResult<Vec<_>>>()?
             .into();
         Ok(sequence)
     }
 }
 
+impl<'top, D: Decoder> TryFrom<LazySExp<'top, D>> for Sequence {
+    type Error = IonError;
+
+    fn try_from(lazy_sequence: LazySExp<'top, D>) -> Result<Self, Self::Error> {
+        // Nesting is counted from this sequence; see `ReadLimits::with_max_container_depth`.
+        lazy_sequence.to_sequence_at_depth(0)
+    }
+}
+
 impl<'top, D: Decoder> TryFrom<LazySExp<'top, D>> for Element {
     type Error = IonError;
 
@@ -53,7 +53,7 @@ impl<'data> PartialEq<StrRef<'data>> for str {
 
 impl<'data> Display for StrRef<'data> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut formatter = FmtValueFormatter { output: f };
+        let mut formatter = FmtValueFormatter::new(f);
         formatter
             .format_string(self.text())
             .map_err(|_| std::fmt::Error)
@@ -8,6 +8,8 @@ use crate::lazy::any_encoding::IonEncoding;
 use crate::lazy::decoder::{Decoder, LazyRawReader};
 use crate::lazy::expanded::EncodingContextRef;
 use crate::lazy::raw_stream_item::LazyRawStreamItem;
+use crate::read_config::{ReadLimit, ReadLimits};
+use crate::result::IonFailure;
 use crate::{IonError, IonResult, LazyRawValue};
 
 /// Wraps an implementation of [`IonDataSource`] and reads one top level value at a time from the input.
@@ -24,6 +26,8 @@ pub struct StreamingRawReader<Encoding: Decoder, Input: IonInput> {
     // The absolute position of the reader within the overall stream. This is the index of the first
     // byte that has not yet been read.
     stream_position: usize,
+    // Bounds the size of each top-level stream item.
+    read_limits: ReadLimits,
     // XXX: The `UnsafeCell` wrappers around the field below is a workaround for a limitation in
     //      rustc's borrow checker that prevents mutable references from being conditionally
     //      returned in a loop.
@@ -74,13 +78,28 @@ impl<'a> RawReaderState<'a> {
 }
 
 impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
-    pub fn new(_encoding: Encoding, input: Input) -> StreamingRawReader<Encoding, Input> {
+    pub fn new(encoding: Encoding, input: Input) -> StreamingRawReader<Encoding, Input> {
+        Self::with_limits(encoding, input, ReadLimits::new())
+    }
+
+    /// Constructs a `StreamingRawReader` that enforces the buffer and value size limits in
+    /// `read_limits`.
+    pub fn with_limits(
+        _encoding: Encoding,
+        input: Input,
+        read_limits: ReadLimits,
+    ) -> StreamingRawReader<Encoding, Input> {
+        let mut input = input.into_data_source();
+        if let Some(max_buffer_size) = read_limits.max_buffer_size() {
+            input.set_max_buffer_size(max_buffer_size);
+        }
         StreamingRawReader {
             decoder: PhantomData,
             // This value will be overwritten if/when the reader detects a new version.
             detected_encoding: Encoding::INITIAL_ENCODING_EXPECTED,
-            input: input.into_data_source().into(),
+            input: input.into(),
             stream_position: 0,
+            read_limits,
         }
     }
 
@@ -158,6 +177,11 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
             let input = unsafe { &mut *self.input.get() };
             // If we ran out of data before we could get a result...
             if matches!(result, Err(IonError::Incomplete(_)) | Ok(LazyRawStreamItem::<Encoding>::EndOfStream(_))) {
+                // If the incomplete item is already larger than the limit, there's no need to
+                // buffer the rest of it.
+                if matches!(result, Err(IonError::Incomplete(_))) {
+                    self.read_limits.check_value_size(available_bytes.len())?;
+                }
                 // ...try to pull more data from the data source. It's ok to modify the buffer in
                 // this case because `result` (which holds a reference to the buffer) will be
                 // discarded.
@@ -217,6 +241,8 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
                     }
                 }
 
+                self.read_limits.check_value_size(bytes_read)?;
+
                 // If this isn't just a peek, update our state to remember what we've already read.
                 if !is_peek {
                     // Mark those input bytes as having been consumed so they are not read again.
@@ -236,6 +262,11 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
     pub fn encoding(&self) -> IonEncoding {
         self.detected_encoding
     }
+
+    /// Returns a mutable reference to the data source from which this reader pulls input.
+    pub(crate) fn data_source_mut(&mut self) -> &mut Input::DataSource {
+        self.input.get_mut()
+    }
 }
 
 /// An input source--typically an implementation of either `AsRef<[u8]>` or `io::Read`--from which
@@ -252,6 +283,16 @@ pub trait IonDataSource {
     /// Marks `number_of_bytes` in the buffer as having been read. The caller is responsible for
     /// confirming that the buffer contains at least `number_of_bytes` bytes.
     fn consume(&mut self, number_of_bytes: usize);
+
+    /// Limits the size to which the buffer may grow. If [`fill_buffer`](Self::fill_buffer) would
+    /// need to grow the buffer beyond `max_buffer_size` bytes, it returns an
+    /// [`IonError::LimitExceeded`] instead.
+    ///
+    /// The default implementation does nothing; it is suitable for data sources that do not
+    /// allocate a buffer.
+    fn set_max_buffer_size(&mut self, max_buffer_size: usize) {
+        let _ = max_buffer_size;
+    }
 }
 
 /// A fixed slice of Ion data that does not grow; it wraps an implementation of `AsRef<[u8]>` such
@@ -321,6 +362,8 @@ pub struct IonStream<R: Read> {
     position: usize,
     // The index of the first unoccupied byte in the buffer *at or after* `position`.
     limit: usize,
+    // The size beyond which the buffer will not grow.
+    max_buffer_size: usize,
 }
 
 impl<R: Read> IonStream<R> {
@@ -334,6 +377,7 @@ impl<R: Read> IonStream<R> {
             position: 0,
             // The index of the first unoccupied byte in the buffer *at or after* `position`.
             limit: 0,
+            max_buffer_size: usize::MAX,
         }
     }
 }
@@ -364,10 +408,15 @@ impl<R: Read> IonDataSource for IonStream<R> {
             self.shift_remaining_bytes_to_index_zero();
         }
         if self.buffer.len() == self.limit {
-            // If we're out of space, double the size of the buffer and fill it with zeros
-            // before proceeding. (The bytes must be set to a value to avoid undefined behavior;
-            // zero is a conventional choice. The value will never be used anyway.)
-            self.buffer.resize(self.buffer.len() * 2, 0);
+            if self.buffer.len() >= self.max_buffer_size {
+                return IonResult::limit_exceeded(ReadLimit::MaxBufferSize, self.max_buffer_size);
+            }
+            // If we're out of space, double the size of the buffer (without exceeding the maximum
+            // size) and fill it with zeros before proceeding. (The bytes must be set to a value to
+            // avoid undefined behavior; zero is a conventional choice. The value will never be
+            // used anyway.)
+            let new_size = (self.buffer.len() * 2).min(self.max_buffer_size);
+            self.buffer.resize(new_size, 0);
         }
         // Attempt to read as many bytes as will fit in the currently allocated capacity beyond
         // `limit`.
@@ -382,6 +431,16 @@ impl<R: Read> IonDataSource for IonStream<R> {
         self.position += number_of_bytes;
         debug_assert!(self.position <= self.limit);
     }
+
+    fn set_max_buffer_size(&mut self, max_buffer_size: usize) {
+        self.max_buffer_size = max_buffer_size;
+        // If the initial buffer is larger than the maximum, shrink it. Bytes that have already
+        // been read into the buffer are kept.
+        let new_size = max_buffer_size.max(self.limit);
+        if new_size < self.buffer.len() {
+            self.buffer.truncate(new_size);
+        }
+    }
 }
 
 /// Types that can be used as a source of Ion data.
@@ -471,17 +530,22 @@ impl IonInput for Box<dyn Read> {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
     use std::io;
     use std::io::{BufReader, Cursor, Read};
 
-    use crate::lazy::any_encoding::AnyEncoding;
+    use crate::lazy::any_encoding::{AnyEncoding, IonEncoding};
     use crate::lazy::decoder::{Decoder, LazyRawValue};
+    use crate::lazy::encoding::Encoding;
     use crate::lazy::expanded::EncodingContext;
     use crate::lazy::raw_stream_item::LazyRawStreamItem;
     use crate::lazy::raw_value_ref::RawValueRef;
     use crate::lazy::streaming_raw_reader::{IonInput, StreamingRawReader};
     use crate::raw_symbol_ref::AsRawSymbolRef;
-    use crate::{v1_0, Decimal, IonError, IonResult, IonStream, RawSymbolRef, RawVersionMarker};
+    use crate::{
+        v1_0, Decimal, Element, IonError, IonResult, IonStream, RawSymbolRef, RawVersionMarker,
+        ReadLimit, ReadLimits,
+    };
 
     fn expect_value<'a, D: Decoder>(
         actual: LazyRawStreamItem<'a, D>,
@@ -563,6 +627,23 @@ mod tests {
         read_example_stream(input)
     }
 
+    #[test]
+    fn read_binary_stream_one_byte_at_a_time() -> IonResult<()> {
+        // The reader must recognize a partial binary IVM as incomplete rather than as text.
+        let elements = Element::read_all(EXAMPLE_STREAM)?;
+        let binary = v1_0::Binary::encode_all(&elements)?;
+        let input = BufReader::with_capacity(1, Cursor::new(binary));
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let mut reader = StreamingRawReader::new(AnyEncoding, input);
+        let _ivm = reader.next(context)?.expect_ivm()?;
+        assert_eq!(reader.encoding(), IonEncoding::Binary_1_0);
+        for text in ["foo", "bar", "baz", "quux", "quuz"] {
+            expect_string(reader.next(context)?, text)?;
+        }
+        expect_end_of_stream(reader.next(context)?)
+    }
+
     const INVALID_EXAMPLE_STREAM: &str = "2024-03-12T16:33.000-05:"; // Missing offset minutes
 
     fn read_invalid_example_stream(input: impl IonInput) -> IonResult<()> {
@@ -643,4 +724,68 @@ mod tests {
 
         Ok(())
     }
+
+    fn expect_limit_exceeded<T: std::fmt::Debug>(result: IonResult<T>, expected: ReadLimit) {
+        match result {
+            Err(IonError::LimitExceeded(error)) => assert_eq!(error.limit(), expected),
+            other => panic!("expected the {expected} limit to be exceeded, found {other:?}"),
+        }
+    }
+
+    #[test]
+    fn buffer_size_limit() -> IonResult<()> {
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let text = format!(r#""{}" 1"#, "a".repeat(100));
+
+        // The string does not fit in a 64 byte buffer.
+        let limits = ReadLimits::new().with_max_buffer_size(64);
+        let mut reader =
+            StreamingRawReader::with_limits(v1_0::Text, tiny_buf_reader(&text), limits);
+        expect_limit_exceeded(reader.next(context), ReadLimit::MaxBufferSize);
+
+        // It does fit in a 128 byte buffer.
+        let limits = ReadLimits::new().with_max_buffer_size(128);
+        let mut reader =
+            StreamingRawReader::with_limits(v1_0::Text, tiny_buf_reader(&text), limits);
+        let value = reader.next(context)?.expect_value()?;
+        assert_eq!(value.read()?.expect_string()?.len(), 100);
+        Ok(())
+    }
+
+    /// Returns a binary Ion stream containing a blob whose header claims that it is hundreds of
+    /// megabytes long, followed by an endless sequence of zeros.
+    fn huge_blob_stream() -> IonStream<Box<dyn Read>> {
+        // IVM, then a blob type descriptor with a VarUInt length of 2^28 - 1.
+        let header: &[u8] = &[0xE0, 0x01, 0x00, 0xEA, 0xAE, 0x7F, 0x7F, 0x7F, 0xFF];
+        let input: Box<dyn Read> = Box::new(Cursor::new(header).chain(io::repeat(0)));
+        IonStream::new(input)
+    }
+
+    #[rstest]
+    #[case::buffer_size(ReadLimits::new().with_max_buffer_size(64 * 1024), ReadLimit::MaxBufferSize)]
+    #[case::value_size(ReadLimits::new().with_max_value_size(1024), ReadLimit::MaxValueSize)]
+    fn huge_length_prefix_is_rejected(
+        #[case] limits: ReadLimits,
+        #[case] expected: ReadLimit,
+    ) -> IonResult<()> {
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let mut reader = StreamingRawReader::with_limits(v1_0::Binary, huge_blob_stream(), limits);
+        assert_eq!(reader.next(context)?.expect_ivm()?.major_minor(), (1, 0));
+        expect_limit_exceeded(reader.next(context), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn value_size_limit() -> IonResult<()> {
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let limits = ReadLimits::new().with_max_value_size(8);
+        let mut reader = StreamingRawReader::with_limits(v1_0::Text, "[1, 2] [1, 2, 3, 4]", limits);
+        let list = reader.next(context)?.expect_value()?;
+        assert_eq!(list.read()?.expect_list()?.iter().count(), 2);
+        expect_limit_exceeded(reader.next(context), ReadLimit::MaxValueSize);
+        Ok(())
+    }
 }
@@ -368,19 +368,31 @@ impl<'top, D: Decoder> StructIterator<'top, D> {
     }
 }
 
-impl<'top, D: Decoder> TryFrom<LazyStruct<'top, D>> for Struct {
-    type Error = IonError;
-
-    fn try_from(lazy_struct: LazyStruct<'top, D>) -> Result<Self, Self::Error> {
+impl<'top, D: Decoder> LazyStruct<'top, D> {
+    /// Materializes the struct's fields. `depth` is the number of containers enclosing this
+    /// struct, which is used to enforce the reader's container depth limit.
+    pub(crate) fn to_struct_at_depth(self, depth: usize) -> IonResult<Struct> {
+        let read_limits = self.expanded_struct.context.read_limits();
+        read_limits.check_container_depth(depth + 1)?;
         let mut builder = StructBuilder::new();
-        for field in &lazy_struct {
+        for field in &self {
             let field = field?;
-            builder = builder.with_field(field.name()?, Element::try_from(field.value())?);
+            builder =
+                builder.with_field(field.name()?, field.value().to_element_at_depth(depth + 1)?);
         }
         Ok(builder.build())
     }
 }
 
+impl<'top, D: Decoder> TryFrom<LazyStruct<'top, D>> for Struct {
+    type Error = IonError;
+
+    fn try_from(lazy_struct: LazyStruct<'top, D>) -> Result<Self, Self::Error> {
+        // Nesting is counted from this struct; see `ReadLimits::with_max_container_depth`.
+        lazy_struct.to_struct_at_depth(0)
+    }
+}
+
 impl<'top, D: Decoder> TryFrom<LazyStruct<'top, D>> for Element {
     type Error = IonError;
 
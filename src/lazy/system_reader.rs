@@ -12,7 +12,7 @@ use crate::lazy::streaming_raw_reader::{IonInput, StreamingRawReader};
 use crate::lazy::system_stream_item::SystemStreamItem;
 use crate::lazy::text::raw::v1_1::reader::MacroAddress;
 use crate::lazy::value::LazyValue;
-use crate::read_config::ReadConfig;
+use crate::read_config::{ImportResolution, ReadConfig, ReadLimits};
 use crate::result::IonFailure;
 use crate::{
     AnyEncoding, Catalog, Int, IonError, IonResult, IonType, LazyField, LazySExp, LazyStruct,
@@ -134,8 +134,14 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
         input: Input,
     ) -> SystemReader<Encoding, Input> {
         let config = config.into();
-        let raw_reader = StreamingRawReader::new(config.encoding(), input);
-        let expanding_reader = ExpandingReader::new(raw_reader, config.catalog);
+        let raw_reader = StreamingRawReader::with_limits(config.encoding(), input, config.limits);
+        let expanding_reader = ExpandingReader::new(
+            raw_reader,
+            config.catalog,
+            config.limits,
+            config.import_resolution,
+            config.json_decoding,
+        );
         SystemReader { expanding_reader }
     }
 
@@ -455,6 +461,8 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
     pub(crate) fn process_symbol_table(
         pending_lst: &mut PendingContextChanges,
         catalog: &dyn Catalog,
+        import_resolution: ImportResolution,
+        read_limits: ReadLimits,
         symbol_table: &LazyExpandedValue<'_, Encoding>,
     ) -> IonResult<()> {
         // We've already confirmed this is an annotated struct
@@ -502,10 +510,16 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
         if let Some(imports_field) = imports_field {
             let lazy_value = imports_field.value();
             Self::clear_pending_lst_if_needed(pending_lst, lazy_value)?;
-            Self::process_imports(pending_lst, catalog, lazy_value)?;
+            Self::process_imports(
+                pending_lst,
+                catalog,
+                import_resolution,
+                read_limits,
+                lazy_value,
+            )?;
         }
         if let Some(symbols_field) = symbols_field {
-            Self::process_symbols(pending_lst, symbols_field.value())?;
+            Self::process_symbols(pending_lst, read_limits, symbols_field.value())?;
         }
 
         Ok(())
@@ -545,10 +559,14 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
     // Store any strings defined in the `symbols` field in the `PendingLst` for future application.
     fn process_symbols(
         pending_lst: &mut PendingContextChanges,
+        read_limits: ReadLimits,
         symbols: LazyValue<'_, Encoding>,
     ) -> IonResult<()> {
         if let ValueRef::List(list) = symbols.read()? {
             for symbol_text_result in list.iter() {
+                read_limits.check_symbol_table_size(
+                    pending_lst.imported_symbols.len() + pending_lst.symbols.len() + 1,
+                )?;
                 if let ValueRef::String(str_ref) = symbol_text_result?.read()? {
                     pending_lst
                         .symbols
@@ -569,6 +587,8 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
     fn process_imports(
         pending_lst: &mut PendingContextChanges,
         catalog: &dyn Catalog,
+        import_resolution: ImportResolution,
+        read_limits: ReadLimits,
         imports: LazyValue<'_, Encoding>,
     ) -> IonResult<()> {
         match imports.read()? {
@@ -589,8 +609,7 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
                         _ => continue,
                     };
                     let version: usize = match import.get("version")? {
-                        Some(ValueRef::Int(i)) if i > Int::ZERO => usize::try_from(i)
-                            .map_err(|_|
+                        Some(ValueRef::Int(i)) if i > Int::ZERO => i.as_usize().ok_or_else(||
                             IonError::decoding_error(format!("found a symbol table import (name='{name}') with a version number too high to support: {i}")),
                         ),
                         // If there's no version, a non-int version, or a version <= 0, we treat it
@@ -598,39 +617,50 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
                         _ => Ok(1),
                     }?;
 
-                    let shared_table = match catalog.get_table_with_version(name.as_ref(), version) {
-                        Some(table) => table,
-                        None => return IonResult::decoding_error(
-                            format!("symbol table import failed, could not find table with name='{name}' and version={version}")
-                        ),
-                    };
-
                     let max_id = match import.get("max_id")? {
                         Some(ValueRef::Int(i)) if i >= Int::ZERO => {
-                            usize::try_from(i).map_err(|_| {
+                            Some(usize::try_from(i).map_err(|_| {
                                 IonError::decoding_error(
                                     "found a `max_id` beyond the range of usize",
                                 )
-                            })?
+                            })?)
                         }
                         // If the max_id is unspecified, negative, or an invalid data type, we'll import all of the symbols from the requested table.
-                        _ => shared_table.symbols().len(),
+                        _ => None,
                     };
 
-                    let num_symbols_to_import = shared_table.symbols().len().min(max_id);
+                    let shared_table = match catalog.get_table_with_version(name.as_ref(), version) {
+                        Some(table) => Some(table),
+                        // If the exact version isn't available, the spec calls for using the best
+                        // match instead. If there's no table by that name at all, each of the
+                        // `max_id` symbol IDs has unknown text. Both require a `max_id`.
+                        None if import_resolution == ImportResolution::Lenient && max_id.is_some() => {
+                            catalog.get_best_match(name.as_ref(), version)
+                        }
+                        None => return IonResult::decoding_error(
+                            format!("symbol table import failed, could not find table with name='{name}' and version={version}")
+                        ),
+                    };
+                    let shared_symbols = shared_table.map(|table| table.symbols()).unwrap_or(&[]);
+                    let max_id = max_id.unwrap_or(shared_symbols.len());
+
+                    // Make sure the import will not grow the symbol table beyond the configured
+                    // limit before allocating space for its symbols. `max_id` comes from the
+                    // data, so this also applies a default limit if none was configured.
+                    read_limits.check_import(
+                        pending_lst.imported_symbols.len() + pending_lst.symbols.len(),
+                        max_id,
+                    )?;
+                    let num_imported_symbols = pending_lst.imported_symbols.len() + max_id;
+                    let num_symbols_to_import = shared_symbols.len().min(max_id);
 
                     pending_lst
                         .imported_symbols
-                        .extend_from_slice(&shared_table.symbols()[..num_symbols_to_import]);
-
-                    if max_id > shared_table.symbols().len() {
-                        let num_pending_symbols = pending_lst.imported_symbols().len();
-                        let num_placeholders = max_id - shared_table.symbols().len();
-                        pending_lst.imported_symbols.resize(
-                            num_pending_symbols + num_placeholders,
-                            Symbol::unknown_text(),
-                        );
-                    }
+                        .extend_from_slice(&shared_symbols[..num_symbols_to_import]);
+                    // Any symbol IDs beyond the end of the shared table have unknown text.
+                    pending_lst
+                        .imported_symbols
+                        .resize(num_imported_symbols, Symbol::unknown_text());
                 }
             }
             _ => {
@@ -650,11 +680,14 @@ impl<Input: IonInput> SystemReader<AnyEncoding, Input> {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use crate::lazy::binary::test_utilities::to_binary_ion;
     use crate::lazy::decoder::RawVersionMarker;
     use crate::lazy::system_stream_item::SystemStreamItem;
     use crate::{
-        v1_0, AnyEncoding, Catalog, IonResult, SequenceWriter, SymbolRef, ValueWriter, Writer,
+        v1_0, AnyEncoding, Catalog, IonResult, ReadLimit, SequenceWriter, SymbolRef, ValueWriter,
+        Writer,
     };
 
     use super::*;
@@ -755,7 +788,7 @@ mod tests {
 
     fn system_reader_with_catalog_for<Input: IonInput>(
         input: Input,
-        catalog: impl Catalog + 'static,
+        catalog: impl Catalog + Send + 'static,
     ) -> SystemReader<AnyEncoding, Input> {
         SystemReader::new(AnyEncoding.with_catalog(catalog), input)
     }
@@ -936,6 +969,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn non_existent_shared_symbol_table_imports_lenient() -> IonResult<()> {
+        let mut map_catalog = MapCatalog::new();
+        map_catalog.insert_table(SharedSymbolTable::new("shared_table_2", 1, ["bar"])?);
+        let config = AnyEncoding
+            .with_catalog(map_catalog)
+            .with_import_resolution(ImportResolution::Lenient);
+        let mut reader = SystemReader::new(
+            config,
+            r#"
+                $ion_symbol_table::{
+                    imports: [ { name:"shared_table_3", version: 1, max_id: 3 }, { name:"shared_table_2", version: 1 }, { name:"shared_table_4", version: 1, max_id: 1 } ],
+                    symbols: [ "local_symbol" ]
+                }
+                $12 // <unknown text>
+                $13 // "bar"
+                $15 // "local_symbol"
+            "#,
+        );
+        // Tables that are missing from the catalog occupy `max_id` symbol IDs with unknown text.
+        assert_eq!(
+            reader.expect_next_value()?.read()?.expect_symbol()?,
+            SymbolRef::with_unknown_text()
+        );
+        assert_eq!(reader.expect_next_value()?.read()?.expect_symbol()?, "bar");
+        assert_eq!(
+            reader.expect_next_value()?.read()?.expect_symbol()?,
+            "local_symbol"
+        );
+        Ok(())
+    }
+
     #[test]
     fn pad_with_max_id() -> IonResult<()> {
         let mut map_catalog = MapCatalog::new();
@@ -1003,6 +1068,110 @@ mod tests {
         Ok(())
     }
 
+    /// Reads `num_symbols` symbol IDs starting at `$10` after a local symbol table with the
+    /// provided `imports` and returns the text of each symbol.
+    fn read_inexact_imports(
+        import_resolution: ImportResolution,
+        imports: &str,
+        num_symbols: usize,
+    ) -> IonResult<Vec<Option<String>>> {
+        let mut map_catalog = MapCatalog::new();
+        map_catalog.insert_table(SharedSymbolTable::new("shared_table", 1, ["foo", "bar"])?);
+        map_catalog.insert_table(SharedSymbolTable::new(
+            "shared_table",
+            3,
+            ["foo", "bar", "baz"],
+        )?);
+        let config = AnyEncoding
+            .with_catalog(map_catalog)
+            .with_import_resolution(import_resolution);
+        let symbol_ids = (10..10 + num_symbols).map(|sid| format!("${sid} "));
+        let ion = format!(
+            r#"$ion_symbol_table::{{imports: [{imports}], symbols: ["local"]}} {}"#,
+            symbol_ids.collect::<String>()
+        );
+        let mut reader = SystemReader::new(config, ion.as_str());
+        let mut symbols = Vec::new();
+        for _ in 0..num_symbols {
+            let value = reader.expect_next_value()?.read()?;
+            symbols.push(value.expect_symbol()?.text().map(str::to_owned));
+        }
+        Ok(symbols)
+    }
+
+    #[rstest]
+    #[case::exact_match_padded(
+        r#"{name: "shared_table", version: 1, max_id: 3}"#,
+        &[Some("foo"), Some("bar"), None, Some("local")]
+    )]
+    #[case::best_match_truncated(
+        r#"{name: "shared_table", version: 2, max_id: 2}"#,
+        &[Some("foo"), Some("bar"), Some("local")]
+    )]
+    #[case::best_match_padded(
+        r#"{name: "shared_table", version: 2, max_id: 4}"#,
+        &[Some("foo"), Some("bar"), Some("baz"), None, Some("local")]
+    )]
+    #[case::missing_table(r#"{name: "other_table", version: 1, max_id: 2}"#, &[None, None, Some("local")])]
+    fn lenient_import_resolution(
+        #[case] imports: &str,
+        #[case] expected: &[Option<&str>],
+    ) -> IonResult<()> {
+        let symbols = read_inexact_imports(ImportResolution::Lenient, imports, expected.len())?;
+        assert_eq!(
+            symbols,
+            expected
+                .iter()
+                .map(|s| s.map(str::to_owned))
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::inexact_without_max_id(
+        ImportResolution::Lenient,
+        r#"{name: "shared_table", version: 2}"#
+    )]
+    #[case::missing_table_without_max_id(ImportResolution::Lenient, r#"{name: "other_table"}"#)]
+    #[case::strict_best_match(
+        ImportResolution::Strict,
+        r#"{name: "shared_table", version: 2, max_id: 2}"#
+    )]
+    #[case::strict_missing_table(ImportResolution::Strict, r#"{name: "other_table", max_id: 2}"#)]
+    fn unresolvable_imports(#[case] import_resolution: ImportResolution, #[case] imports: &str) {
+        assert!(read_inexact_imports(import_resolution, imports, 1).is_err());
+    }
+
+    #[rstest]
+    #[case::oversized_padding(r#"{name: "other_table", max_id: 4000000000}"#)]
+    #[case::overflowing_padding(
+        r#"{name: "other_table", max_id: 1}, {name: "other_table", max_id: 18446744073709551615}"#
+    )]
+    fn lenient_import_padding_is_limited(#[case] imports: &str) {
+        match read_inexact_imports(ImportResolution::Lenient, imports, 1) {
+            Err(IonError::LimitExceeded(error)) => {
+                assert_eq!(error.limit(), ReadLimit::MaxSymbolTableSize)
+            }
+            other => panic!("expected the symbol table size limit to be exceeded, found {other:?}"),
+        }
+    }
+
+    #[test]
+    fn strict_import_resolution_allows_exact_matches() -> IonResult<()> {
+        let imports = r#"{name: "shared_table", version: 3, max_id: 2}"#;
+        let symbols = read_inexact_imports(ImportResolution::Strict, imports, 3)?;
+        assert_eq!(
+            symbols,
+            [
+                Some("foo".to_owned()),
+                Some("bar".to_owned()),
+                Some("local".to_owned())
+            ]
+        );
+        Ok(())
+    }
+
     #[cfg(feature = "experimental-ion-1-1")]
     #[test]
     fn detect_encoding_directive_text() -> IonResult<()> {
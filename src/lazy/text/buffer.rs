@@ -28,10 +28,12 @@ use crate::lazy::raw_stream_item::{EndPosition, LazyRawStreamItem, RawStreamItem
 use crate::lazy::text::encoded_value::EncodedTextValue;
 use crate::lazy::text::matched::{
     MatchedBlob, MatchedClob, MatchedDecimal, MatchedFieldName, MatchedFieldNameSyntax,
-    MatchedFloat, MatchedInt, MatchedString, MatchedSymbol, MatchedTimestamp,
+    MatchedFloat, MatchedInt, MatchedJsonNumber, MatchedString, MatchedSymbol, MatchedTimestamp,
     MatchedTimestampOffset, MatchedValue,
 };
-use crate::lazy::text::parse_result::{fatal_parse_error, InvalidInputError, IonParseError};
+use crate::lazy::text::parse_result::{
+    fatal_parse_error, limit_exceeded_failure, InvalidInputError, IonParseError,
+};
 use crate::lazy::text::parse_result::{IonMatchResult, IonParseResult};
 use crate::lazy::text::raw::r#struct::{LazyRawTextFieldName_1_0, RawTextStructIterator_1_0};
 use crate::lazy::text::raw::sequence::{RawTextListIterator_1_0, RawTextSExpIterator_1_0};
@@ -44,6 +46,7 @@ use crate::lazy::text::raw::v1_1::reader::{
 use crate::lazy::text::value::{
     LazyRawTextValue, LazyRawTextValue_1_0, LazyRawTextValue_1_1, LazyRawTextVersionMarker,
 };
+use crate::read_config::JsonDecoding;
 use crate::result::DecodingError;
 use crate::{
     v1_1, Encoding, HasRange, IonError, IonResult, IonType, RawSymbolRef, TimestampPrecision,
@@ -113,6 +116,9 @@ pub struct TextBuffer<'top> {
     //                          offset: 6
     data: &'top [u8],
     offset: usize,
+    // The number of containers (and e-expressions) that enclose `data`. This is checked against
+    // the reader's `max_container_depth` before each nested container is parsed.
+    depth: usize,
     pub(crate) context: EncodingContextRef<'top>,
 }
 
@@ -142,6 +148,7 @@ impl<'top> TextBuffer<'top> {
             context,
             data,
             offset,
+            depth: 0,
         }
     }
 
@@ -165,6 +172,7 @@ impl<'top> TextBuffer<'top> {
         TextBuffer {
             data: &self.data[offset..offset + length],
             offset: self.offset + offset,
+            depth: self.depth,
             context: self.context,
         }
     }
@@ -178,10 +186,33 @@ impl<'top> TextBuffer<'top> {
         TextBuffer {
             data: &self.data[offset..],
             offset: self.offset + offset,
+            depth: self.depth,
             context: self.context,
         }
     }
 
+    /// Returns a copy of this buffer whose data is nested inside one more container.
+    pub(crate) fn nested(self) -> TextBuffer<'top> {
+        TextBuffer {
+            depth: self.depth + 1,
+            ..self
+        }
+    }
+
+    /// Returns a copy of this buffer whose data is nested inside one more container, or a fatal
+    /// parse error if that would exceed the reader's container depth limit. Checking the limit
+    /// before matching a container's contents keeps deeply nested input from exhausting the stack.
+    fn enter_container(self) -> Result<TextBuffer<'top>, nom::Err<IonParseError<'top>>> {
+        let nested = self.nested();
+        let read_limits = self.context.read_limits();
+        if let Err(IonError::LimitExceeded(limit_exceeded)) =
+            read_limits.check_container_depth(nested.depth)
+        {
+            return Err(limit_exceeded_failure(self, limit_exceeded));
+        }
+        Ok(nested)
+    }
+
     /// Returns a slice containing all of the buffer's bytes.
     pub fn bytes(&self) -> &'top [u8] {
         self.data
@@ -243,12 +274,26 @@ impl<'top> TextBuffer<'top> {
 
     /// Matches any amount of contiguous comments and whitespace, including none.
     pub fn match_optional_comments_and_whitespace(self) -> IonMatchResult<'top> {
+        if self.context.json_decoding().is_some() {
+            return self.match_optional_json_whitespace();
+        }
         recognize(many0_count(alt((
             Self::match_whitespace,
             Self::match_comment,
         ))))(self)
     }
 
+    /// Matches any amount of JSON whitespace, including none. JSON does not have comments; if one
+    /// follows the whitespace, returns a fatal error.
+    fn match_optional_json_whitespace(self) -> IonMatchResult<'top> {
+        let (remaining, whitespace) =
+            complete_take_while(|b: u8| matches!(b, b' ' | b'\t' | b'\r' | b'\n'))(self)?;
+        if remaining.bytes().starts_with(b"//") || remaining.bytes().starts_with(b"/*") {
+            return Err(not_json(remaining, "comments are not valid JSON"));
+        }
+        Ok((remaining, whitespace))
+    }
+
     /// Matches a single
     ///     // Rest-of-the-line
     /// or
@@ -458,14 +503,18 @@ impl<'top> TextBuffer<'top> {
     pub fn match_struct_field_name_and_value(
         self,
     ) -> IonParseResult<'top, (MatchedFieldName<'top>, LazyRawTextValue_1_0<'top>)> {
-        terminated(
-            separated_pair(
-                whitespace_and_then(Self::match_struct_field_name),
-                whitespace_and_then(tag(":")),
-                whitespace_and_then(Self::match_annotated_value),
-            ),
-            whitespace_and_then(alt((tag(","), peek(tag("}"))))),
-        )(self)
+        let (remaining, (field_name, value)) = separated_pair(
+            whitespace_and_then(Self::match_struct_field_name),
+            whitespace_and_then(tag(":")),
+            whitespace_and_then(Self::match_annotated_value),
+        )(self)?;
+        if self.context.json_decoding().is_some() {
+            Self::validate_json_field_name(field_name)?;
+        }
+        let (remaining, delimiter) =
+            whitespace_and_then(alt((tag(","), peek(tag("}"))))).parse(remaining)?;
+        remaining.validate_json_delimiter(delimiter, "}")?;
+        Ok((remaining, (field_name, value)))
     }
 
     /// Matches a struct field (name, value expression) pair.
@@ -538,12 +587,127 @@ impl<'top> TextBuffer<'top> {
 
     /// Matches an optional annotation sequence and a trailing value.
     pub fn match_annotated_value(self) -> IonParseResult<'top, LazyRawTextValue_1_0<'top>> {
-        pair(
+        let (remaining, value) = pair(
             opt(Self::match_annotations),
             whitespace_and_then(Self::match_value),
         )
         .map(|(maybe_annotations, value)| self.apply_annotations(maybe_annotations, value))
-        .parse(self)
+        .parse(self)?;
+        match self.context.json_decoding() {
+            Some(json_decoding) => {
+                Ok((remaining, Self::validate_json_value(value, json_decoding)?))
+            }
+            None => Ok((remaining, value)),
+        }
+    }
+
+    /// Returns a fatal error if `value` uses syntax that is not valid JSON. Otherwise, returns
+    /// `value`, with any number typed according to `json_decoding`'s number policy.
+    fn validate_json_value(
+        value: LazyRawTextValue_1_0<'top>,
+        json_decoding: JsonDecoding,
+    ) -> Result<LazyRawTextValue_1_0<'top>, nom::Err<IonParseError<'top>>> {
+        if value.encoded_value.has_annotations() {
+            return Err(not_json(value.input, "annotations are not valid JSON"));
+        }
+        let description = match value.encoded_value.matched() {
+            MatchedValue::Null(_) if value.input.bytes() != b"null" => {
+                "typed nulls are not valid JSON"
+            }
+            MatchedValue::Int(_) | MatchedValue::Float(_) | MatchedValue::Decimal(_) => {
+                let Some(number) =
+                    MatchedJsonNumber::new(value.input.bytes(), json_decoding.number_policy())
+                else {
+                    return Err(not_json(
+                        value.input,
+                        "this number syntax is not valid JSON",
+                    ));
+                };
+                return Ok(LazyRawTextValue_1_0 {
+                    encoded_value: EncodedTextValue::new(MatchedValue::JsonNumber(number)),
+                    input: value.input,
+                });
+            }
+            MatchedValue::String(_) => {
+                Self::validate_json_string(value.input)?;
+                return Ok(value);
+            }
+            MatchedValue::Timestamp(_) => "timestamps are not valid JSON",
+            MatchedValue::Symbol(_) => "symbols are not valid JSON",
+            MatchedValue::Blob(_) => "blobs are not valid JSON",
+            MatchedValue::Clob(_) => "clobs are not valid JSON",
+            MatchedValue::SExp(_) => "s-expressions are not valid JSON",
+            _ => return Ok(value),
+        };
+        Err(not_json(value.input, description))
+    }
+
+    /// Returns a fatal error if `field_name` is not a JSON string.
+    fn validate_json_field_name(
+        field_name: MatchedFieldName<'top>,
+    ) -> Result<(), nom::Err<IonParseError<'top>>> {
+        match field_name.syntax() {
+            MatchedFieldNameSyntax::String(_) => Self::validate_json_string(field_name.input()),
+            MatchedFieldNameSyntax::Symbol(_) => Err(not_json(
+                field_name.input(),
+                "field names must be JSON strings",
+            )),
+        }
+    }
+
+    /// Returns a fatal error if the matched string `input` is not a JSON string. JSON does not
+    /// have long strings (`'''...'''`), and only permits a subset of Ion's escape sequences.
+    fn validate_json_string(input: TextBuffer<'top>) -> Result<(), nom::Err<IonParseError<'top>>> {
+        let bytes = input.bytes();
+        if bytes.starts_with(b"'") {
+            return Err(not_json(input, "long strings are not valid JSON"));
+        }
+        // Skip the opening and closing quotes.
+        let body = &bytes[1..bytes.len() - 1];
+        let mut index = 0;
+        while index < body.len() {
+            match body[index] {
+                b'\\' => match body.get(index + 1) {
+                    Some(b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't' | b'u') => {
+                        index += 2
+                    }
+                    _ => {
+                        return Err(not_json(
+                            input.slice(index + 1, 2.min(body.len() - index)),
+                            "this escape sequence is not valid JSON",
+                        ))
+                    }
+                },
+                byte if byte < 0x20 => {
+                    return Err(not_json(
+                        input.slice(index + 1, 1),
+                        "unescaped control characters are not valid JSON",
+                    ))
+                }
+                _ => index += 1,
+            }
+        }
+        Ok(())
+    }
+
+    /// JSON does not permit a comma after the last value in a list or struct. If the reader only
+    /// accepts JSON, returns a fatal error if `delimiter` is a comma and the next thing in the
+    /// input (`self`) is `closing_delimiter`.
+    fn validate_json_delimiter(
+        self,
+        delimiter: TextBuffer<'top>,
+        closing_delimiter: &str,
+    ) -> Result<(), nom::Err<IonParseError<'top>>> {
+        if self.context.json_decoding().is_none() || delimiter.bytes() != b"," {
+            return Ok(());
+        }
+        if whitespace_and_then(peek(tag(closing_delimiter)))
+            .parse(self)
+            .is_ok()
+        {
+            return Err(not_json(delimiter, "trailing commas are not valid JSON"));
+        }
+        Ok(())
     }
 
     /// Matches an optional annotation sequence and a trailing v1.1 value.
@@ -645,6 +809,14 @@ impl<'top> TextBuffer<'top> {
                 )),
             ));
         }
+        // JSON does not have version markers; text like `$ion_1_0` is matched (and rejected) as
+        // a symbol.
+        if self.context.json_decoding().is_some() {
+            return Self::match_annotated_value
+                .map(LazyRawTextValue_1_0::from)
+                .map(RawStreamItem::Value)
+                .parse(input_after_ws);
+        }
         // Otherwise, the next item must be an IVM or a value.
         // We check for IVMs first because the rules for a symbol identifier will match them.
         alt((
@@ -811,12 +983,13 @@ impl<'top> TextBuffer<'top> {
             return Err(nom::Err::Error(IonParseError::Invalid(error)));
         }
         // Scan ahead to find the end of this list.
-        let list_body = self.slice_to_end(1);
+        let list_body = self.slice_to_end(1).enter_container()?;
         let sequence_iter = RawTextListIterator_1_0::new(list_body);
         let span = match sequence_iter.find_span() {
             Ok(span) => span,
             // If the complete container isn't available, return an incomplete.
             Err(IonError::Incomplete(_)) => return Err(nom::Err::Incomplete(Needed::Unknown)),
+            Err(IonError::LimitExceeded(e)) => return Err(limit_exceeded_failure(self, e)),
             // If invalid syntax was encountered, return a failure to prevent nom from trying
             // other parser kinds.
             Err(e) => {
@@ -855,7 +1028,7 @@ impl<'top> TextBuffer<'top> {
             return Err(nom::Err::Error(IonParseError::Invalid(error)));
         }
         // Scan ahead to find the end of this list.
-        let list_body = self.slice_to_end(1);
+        let list_body = self.slice_to_end(1).enter_container()?;
         let sequence_iter = RawTextListIterator_1_1::new(list_body);
         let (span, child_exprs) = match TextListSpanFinder_1_1::new(
             self.context.allocator(),
@@ -866,6 +1039,7 @@ impl<'top> TextBuffer<'top> {
             Ok((span, child_exprs)) => (span, child_exprs),
             // If the complete container isn't available, return an incomplete.
             Err(IonError::Incomplete(_)) => return Err(nom::Err::Incomplete(Needed::Unknown)),
+            Err(IonError::LimitExceeded(e)) => return Err(limit_exceeded_failure(self, e)),
             // If invalid syntax was encountered, return a failure to prevent nom from trying
             // other parser kinds.
             Err(e) => {
@@ -899,13 +1073,14 @@ impl<'top> TextBuffer<'top> {
             return Err(nom::Err::Error(IonParseError::Invalid(error)));
         }
         // Scan ahead to find the end of this sexp
-        let sexp_body = self.slice_to_end(1);
+        let sexp_body = self.slice_to_end(1).enter_container()?;
         let sexp_iter = RawTextSExpIterator_1_1::new(sexp_body);
         let (span, child_expr_cache) =
             match TextSExpSpanFinder_1_1::new(self.context.allocator(), sexp_iter).find_span(1) {
                 Ok((span, child_expr_cache)) => (span, child_expr_cache),
                 // If the complete container isn't available, return an incomplete.
                 Err(IonError::Incomplete(_)) => return Err(nom::Err::Incomplete(Needed::Unknown)),
+                Err(IonError::LimitExceeded(e)) => return Err(limit_exceeded_failure(self, e)),
                 // If invalid syntax was encountered, return a failure to prevent nom from trying
                 // other parser kinds.
                 Err(e) => {
@@ -976,10 +1151,12 @@ impl<'top> TextBuffer<'top> {
     /// Matches syntax that is expected to follow a value in a list: any amount of whitespace and/or
     /// comments followed by either a comma (consumed) or an end-of-list `]` (not consumed).
     fn match_delimiter_after_list_value(self) -> IonMatchResult<'top> {
-        preceded(
+        let (remaining, delimiter) = preceded(
             Self::match_optional_comments_and_whitespace,
             alt((tag(","), peek(tag("]")))),
-        )(self)
+        )(self)?;
+        remaining.validate_json_delimiter(delimiter, "]")?;
+        Ok((remaining, delimiter))
     }
 
     /// Matches an s-expression (sexp).
@@ -991,12 +1168,13 @@ impl<'top> TextBuffer<'top> {
             return Err(nom::Err::Error(IonParseError::Invalid(error)));
         }
         // Scan ahead to find the end of this sexp
-        let sexp_body = self.slice_to_end(1);
+        let sexp_body = self.slice_to_end(1).enter_container()?;
         let sexp_iter = RawTextSExpIterator_1_0::new(sexp_body);
         let span = match sexp_iter.find_span(1) {
             Ok(span) => span,
             // If the complete container isn't available, return an incomplete.
             Err(IonError::Incomplete(_)) => return Err(nom::Err::Incomplete(Needed::Unknown)),
+            Err(IonError::LimitExceeded(e)) => return Err(limit_exceeded_failure(self, e)),
             // If invalid syntax was encountered, return a failure to prevent nom from trying
             // other parser kinds.
             Err(e) => {
@@ -1024,12 +1202,13 @@ impl<'top> TextBuffer<'top> {
             return Err(nom::Err::Error(IonParseError::Invalid(error)));
         }
         // Scan ahead to find the end of this struct.
-        let struct_body = self.slice_to_end(1);
+        let struct_body = self.slice_to_end(1).enter_container()?;
         let struct_iter = RawTextStructIterator_1_0::new(struct_body);
         let span = match struct_iter.find_span() {
             Ok(span) => span,
             // If the complete container isn't available, return an incomplete.
             Err(IonError::Incomplete(_)) => return Err(nom::Err::Incomplete(Needed::Unknown)),
+            Err(IonError::LimitExceeded(e)) => return Err(limit_exceeded_failure(self, e)),
             // If invalid syntax was encountered, return a failure to prevent nom from trying
             // other parser kinds.
             Err(e) => {
@@ -1063,7 +1242,7 @@ impl<'top> TextBuffer<'top> {
             return Err(nom::Err::Error(IonParseError::Invalid(error)));
         }
         // Scan ahead to find the end of this struct.
-        let struct_body = self.slice_to_end(1);
+        let struct_body = self.slice_to_end(1).enter_container()?;
         let struct_iter = RawTextStructIterator_1_1::new(struct_body);
         let (span, fields) = match TextStructSpanFinder_1_1::new(
             self.context.allocator(),
@@ -1074,6 +1253,7 @@ impl<'top> TextBuffer<'top> {
             Ok((span, fields)) => (span, fields),
             // If the complete container isn't available, return an incomplete.
             Err(IonError::Incomplete(_)) => return Err(nom::Err::Incomplete(Needed::Unknown)),
+            Err(IonError::LimitExceeded(e)) => return Err(limit_exceeded_failure(self, e)),
             // If invalid syntax was encountered, return a failure to prevent nom from trying
             // other parser kinds.
             Err(e) => {
@@ -1126,7 +1306,7 @@ impl<'top> TextBuffer<'top> {
 
         // The rest of the group uses s-expression syntax. Scan ahead to find the end of this
         // group.
-        let sexp_iter = RawTextSExpIterator_1_1::new(group_body);
+        let sexp_iter = RawTextSExpIterator_1_1::new(group_body.enter_container()?);
         // The sexp iterator holds the body of the expression. When finding the input span it occupies,
         // we tell the iterator how many bytes comprised the head of the expression: `(:` followed
         // by whitespace.
@@ -1138,6 +1318,7 @@ impl<'top> TextBuffer<'top> {
                 Ok((span, child_expr_cache)) => (span, child_expr_cache),
                 // If the complete group isn't available, return an incomplete.
                 Err(IonError::Incomplete(_)) => return Err(nom::Err::Incomplete(Needed::Unknown)),
+                Err(IonError::LimitExceeded(e)) => return Err(limit_exceeded_failure(self, e)),
                 // If invalid syntax was encountered, return a failure to prevent nom from trying
                 // other parser kinds.
                 Err(e) => {
@@ -1196,6 +1377,8 @@ impl<'top> TextBuffer<'top> {
     /// If the input does not contain the entire e-expression, returns `IonError::Incomplete(_)`.
     pub fn match_e_expression(self) -> IonParseResult<'top, TextEExpression_1_1<'top>> {
         let (eexp_body, _opening_tag) = tag("(:")(self)?;
+        // Arguments are nested inside the e-expression, so they count toward the depth limit.
+        let eexp_body = eexp_body.enter_container()?;
         let (mut remaining, id) = Self::match_e_expression_id(eexp_body)?;
         let mut arg_expr_cache = BumpVec::new_in(self.context.allocator());
 
@@ -1247,6 +1430,8 @@ impl<'top> TextBuffer<'top> {
         };
 
         let matched_input = self.slice(0, remaining.offset() - self.offset());
+        // Continue from `self` so that the input that follows is at this e-expression's depth.
+        let remaining = self.slice_to_end(matched_input.len());
 
         let parameters = macro_ref.signature().parameters();
         if arg_expr_cache.len() < parameters.len() {
@@ -2547,9 +2732,8 @@ impl<'data> nom::InputTake for TextBuffer<'data> {
     }
 
     fn take_split(&self, count: usize) -> (Self, Self) {
-        let (before, after) = self.data.split_at(count);
-        let buffer_before = TextBuffer::new_with_offset(self.context, before, self.offset());
-        let buffer_after = TextBuffer::new_with_offset(self.context, after, self.offset() + count);
+        let buffer_before = self.slice(0, count);
+        let buffer_after = self.slice_to_end(count);
         // Nom's convention is to place the remaining portion of the buffer first, which leads to
         // a potentially surprising reversed tuple order.
         (buffer_after, buffer_before)
@@ -2702,6 +2886,18 @@ where
     preceded(TextBuffer::match_optional_comments_and_whitespace, parser)
 }
 
+/// Constructs a `nom::Err::Failure` reporting that `input` is not valid JSON.
+fn not_json<'data>(
+    input: TextBuffer<'data>,
+    description: &'static str,
+) -> nom::Err<IonParseError<'data>> {
+    nom::Err::Failure(
+        InvalidInputError::new(input)
+            .with_description(description)
+            .into(),
+    )
+}
+
 /// Augments a given parser such that it returns the matched value and the number of input bytes
 /// that it matched.
 fn match_and_length<'data, P, O>(
@@ -67,6 +67,7 @@ impl<'top, E: TextEncoding<'top>> EncodedTextValue<'top, E> {
             MatchedValue::Int(_) => IonType::Int,
             MatchedValue::Float(_) => IonType::Float,
             MatchedValue::Decimal(_) => IonType::Decimal,
+            MatchedValue::JsonNumber(n) => n.ion_type(),
             MatchedValue::Timestamp(_) => IonType::Timestamp,
             MatchedValue::String(_) => IonType::String,
             MatchedValue::Symbol(_) => IonType::Symbol,
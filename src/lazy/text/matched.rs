@@ -31,7 +31,8 @@ use nom::bytes::streaming::tag;
 use nom::character::is_hex_digit;
 use nom::sequence::preceded;
 use nom::{AsBytes, AsChar, Parser};
-use num_traits::Zero;
+use num_bigint::BigUint;
+use num_traits::{Num, Zero};
 use smallvec::SmallVec;
 
 use crate::decimal::coefficient::Coefficient;
@@ -42,6 +43,7 @@ use crate::lazy::str_ref::StrRef;
 use crate::lazy::text::as_utf8::AsUtf8;
 use crate::lazy::text::buffer::TextBuffer;
 use crate::lazy::text::parse_result::InvalidInputError;
+use crate::read_config::JsonNumberPolicy;
 use crate::result::{DecodingError, IonFailure};
 use crate::{
     Decimal, Int, IonError, IonResult, IonType, RawSymbolRef, Timestamp, TimestampPrecision,
@@ -56,6 +58,8 @@ pub enum MatchedValue<'top, D: Decoder> {
     Int(MatchedInt),
     Float(MatchedFloat),
     Decimal(MatchedDecimal),
+    // A number read by a reader that only accepts JSON. It may be an int, float, or decimal.
+    JsonNumber(MatchedJsonNumber),
     Timestamp(MatchedTimestamp),
     String(MatchedString),
     Symbol(MatchedSymbol),
@@ -75,6 +79,7 @@ impl<'top, D: Decoder> PartialEq for MatchedValue<'top, D> {
             (Int(i1), Int(i2)) => i1 == i2,
             (Float(f1), Float(f2)) => f1 == f2,
             (Decimal(d1), Decimal(d2)) => d1 == d2,
+            (JsonNumber(n1), JsonNumber(n2)) => n1 == n2,
             (Timestamp(t1), Timestamp(t2)) => t1 == t2,
             (String(s1), String(s2)) => s1 == s2,
             (Symbol(s1), Symbol(s2)) => s1 == s2,
@@ -130,6 +135,10 @@ impl<'top> MatchedFieldName<'top> {
         self.syntax
     }
 
+    pub fn input(&self) -> TextBuffer<'top> {
+        self.input
+    }
+
     pub fn read(&self) -> IonResult<RawSymbolRef<'top>> {
         self.syntax.read(self.input.context.allocator(), self.input)
     }
@@ -201,10 +210,15 @@ impl MatchedInt {
                     parse_int_error.kind() == &IntErrorKind::NegOverflow
                         || parse_int_error.kind() == &IntErrorKind::PosOverflow
                 );
-                return cold_path!(IonResult::decoding_error(format!(
-                    "encountered an int whose value was exceeded the supported range: '{}'",
-                    std::str::from_utf8(matched_input.bytes()).unwrap_or("invalid UTF-8")
-                )));
+                cold_path! {{
+                    BigUint::from_str_radix(text, self.radix()).map_err(|_| {
+                        IonError::decoding_error(format!(
+                            "encountered an invalid int: '{}'",
+                            std::str::from_utf8(matched_input.bytes()).unwrap_or("invalid UTF-8")
+                        ))
+                    })
+                }}?
+                .into()
             }
         };
 
@@ -308,13 +322,18 @@ impl MatchedDecimal {
         );
 
         let digits_text = sanitized.as_utf8(digits.offset())?;
-        let magnitude: Int = i128::from_str(digits_text)
-            .map_err(|e| {
-                IonError::decoding_error(format!(
-                    "decimal magnitude '{digits_text}' was larger than supported size ({e:?}"
-                ))
-            })?
-            .into();
+        let magnitude: Int = match i128::from_str(digits_text) {
+            Ok(magnitude) => magnitude.into(),
+            // The magnitude is too large to fit in an i128; fall back to using `BigUint`.
+            Err(_) => cold_path! {{
+                BigUint::from_str(digits_text).map_err(|e| {
+                    IonError::decoding_error(format!(
+                        "found an invalid decimal magnitude '{digits_text}' ({e:?})"
+                    ))
+                })
+            }}?
+            .into(),
+        };
 
         let coefficient = if self.is_negative {
             if magnitude.is_zero() {
@@ -363,6 +382,162 @@ impl MatchedDecimal {
     }
 }
 
+// Whole numbers with more digits than this are never read as ints by
+// `JsonNumberPolicy::IntWhenIntegral`; text like `1e1000000000` would otherwise require
+// materializing an enormous integer.
+const MAX_INTEGRAL_JSON_DIGITS: i64 = 1_000;
+
+/// A number matched by a reader that only accepts JSON. Its Ion type is chosen by the reader's
+/// [`JsonNumberPolicy`] when the number is matched.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MatchedJsonNumber {
+    ion_type: IonType,
+}
+
+impl MatchedJsonNumber {
+    /// Returns `None` if `text` is not a JSON number. Ion numbers like `1.`, `0x10`, `1_000`, and
+    /// `5d3` are not valid JSON.
+    pub fn new(text: &[u8], policy: JsonNumberPolicy) -> Option<Self> {
+        use JsonNumberPolicy::*;
+        let number = JsonNumberText::parse(text)?;
+        let has_fraction = !number.fraction_digits.is_empty();
+        let has_exponent = number.exponent.is_some();
+        let ion_type = match policy {
+            AlwaysFloat => IonType::Float,
+            IonText if has_exponent => IonType::Float,
+            IonText | DecimalWhenFractional if !has_fraction && !has_exponent => IonType::Int,
+            IonText | DecimalWhenFractional => IonType::Decimal,
+            IntWhenIntegral if number.integral_digits().is_some() => IonType::Int,
+            IntWhenIntegral => IonType::Decimal,
+        };
+        Some(Self { ion_type })
+    }
+
+    pub fn ion_type(&self) -> IonType {
+        self.ion_type
+    }
+
+    pub fn read_int(&self, matched_input: TextBuffer) -> IonResult<Int> {
+        let number = JsonNumberText::from_matched_input(matched_input)?;
+        match number.integral_digits() {
+            Some(digits) => Int::from_str(&digits),
+            None => IonResult::decoding_error("JSON number is not a whole number"),
+        }
+    }
+
+    pub fn read_float(&self, matched_input: TextBuffer) -> IonResult<f64> {
+        let number = JsonNumberText::from_matched_input(matched_input)?;
+        f64::from_str(number.text).map_err(|e| {
+            InvalidInputError::new(matched_input)
+                .with_description(format!("encountered an unexpected error ({:?})", e))
+                .with_label("parsing a JSON number as a float")
+                .into()
+        })
+    }
+
+    pub fn read_decimal(&self, matched_input: TextBuffer) -> IonResult<Decimal> {
+        let number = JsonNumberText::from_matched_input(matched_input)?;
+        let (coefficient, exponent) = number.coefficient_and_exponent()?;
+        let is_zero = coefficient.bytes().all(|byte| matches!(byte, b'-' | b'0'));
+        if number.is_negative && is_zero {
+            return Ok(Decimal::negative_zero_with_exponent(exponent));
+        }
+        Ok(Decimal::new(Int::from_str(&coefficient)?, exponent))
+    }
+}
+
+/// The components of a JSON number's text.
+struct JsonNumberText<'a> {
+    text: &'a str,
+    is_negative: bool,
+    integer_digits: &'a str,
+    fraction_digits: &'a str,
+    exponent: Option<&'a str>,
+}
+
+impl<'a> JsonNumberText<'a> {
+    fn parse(text: &'a [u8]) -> Option<Self> {
+        let text = std::str::from_utf8(text).ok()?;
+        let (is_negative, unsigned) = match text.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, text),
+        };
+        let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+            None => (unsigned, None),
+        };
+        let (integer_digits, fraction_digits) = match mantissa.split_once('.') {
+            Some((integer_digits, fraction_digits)) => (integer_digits, Some(fraction_digits)),
+            None => (mantissa, None),
+        };
+        let is_digits =
+            |digits: &str| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit());
+        // JSON does not permit leading zeros (`01`), but does permit a single `0`.
+        let integer_is_valid = is_digits(integer_digits)
+            && (integer_digits == "0" || !integer_digits.starts_with('0'));
+        let fraction_is_valid = fraction_digits.map_or(true, is_digits);
+        let exponent_is_valid = exponent.map_or(true, |exponent| {
+            is_digits(exponent.strip_prefix(['+', '-']).unwrap_or(exponent))
+        });
+        if !(integer_is_valid && fraction_is_valid && exponent_is_valid) {
+            return None;
+        }
+        Some(Self {
+            text,
+            is_negative,
+            integer_digits,
+            fraction_digits: fraction_digits.unwrap_or(""),
+            exponent,
+        })
+    }
+
+    fn from_matched_input(matched_input: TextBuffer<'a>) -> IonResult<Self> {
+        Self::parse(matched_input.bytes()).ok_or_else(|| {
+            InvalidInputError::new(matched_input)
+                .with_description("expected a JSON number")
+                .into()
+        })
+    }
+
+    /// Returns the number's value as a decimal coefficient and exponent.
+    fn coefficient_and_exponent(&self) -> IonResult<(String, i64)> {
+        let exponent = match self.exponent {
+            Some(exponent) => i64::from_str(exponent)
+                .map_err(|_| IonError::decoding_error("JSON number exponent is too large"))?,
+            None => 0,
+        };
+        let exponent = exponent
+            .checked_sub(self.fraction_digits.len() as i64)
+            .ok_or_else(|| IonError::decoding_error("JSON number exponent is too large"))?;
+        let sign = if self.is_negative { "-" } else { "" };
+        let digits = format!("{}{}", self.integer_digits, self.fraction_digits);
+        // `Int::from_str` does not accept leading zeros, which are common in fractions (`0.25`).
+        let digits = match digits.trim_start_matches('0') {
+            "" => "0",
+            digits => digits,
+        };
+        Ok((format!("{sign}{digits}"), exponent))
+    }
+
+    /// If the number is a whole number with no more than 1,000 digits, returns the text of the
+    /// equivalent integer (for example, `1.5e1` is `15`). Otherwise, returns `None`.
+    fn integral_digits(&self) -> Option<String> {
+        let (coefficient, exponent) = self.coefficient_and_exponent().ok()?;
+        let significant = coefficient.trim_end_matches('0');
+        if significant.trim_start_matches('-').is_empty() {
+            return Some(String::from("0"));
+        }
+        let trailing_zeros = (coefficient.len() - significant.len()) as i64;
+        // The exponent that applies to the coefficient once its trailing zeros are removed.
+        let exponent = exponent.checked_add(trailing_zeros)?;
+        let digit_count = (significant.len() as i64).checked_add(exponent)?;
+        if exponent < 0 || digit_count > MAX_INTEGRAL_JSON_DIGITS {
+            return None;
+        }
+        Some(format!("{significant}{}", "0".repeat(exponent as usize)))
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum MatchedString {
     /// The string only has one segment. (e.g. "foo")
@@ -1037,7 +1212,7 @@ impl MatchedTimestamp {
             }
             _ => {
                 // For less common precisions, store a Decimal
-                let coefficient = i128::from_str(fractional_text).unwrap();
+                let coefficient = Int::from(BigUint::from_str(fractional_text).unwrap());
                 let decimal = Decimal::new(coefficient, -(fractional_text.len() as i64));
                 timestamp.with_fractional_seconds(decimal)
             }
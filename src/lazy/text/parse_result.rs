@@ -10,7 +10,7 @@
 
 use crate::lazy::text::buffer::TextBuffer;
 use crate::position::Position;
-use crate::result::{DecodingError, IonFailure};
+use crate::result::{DecodingError, IonFailure, LimitExceededError};
 use crate::{IonError, IonResult};
 use nom::error::{Error as NomError, ErrorKind, ParseError};
 use nom::{Err, IResult};
@@ -70,6 +70,9 @@ pub struct InvalidInputError<'data> {
     // The nom ErrorKind, which indicates which nom-provided parser encountered the error we're
     // bubbling up.
     nom_error_kind: Option<ErrorKind>,
+    // If parsing stopped because the input exceeded one of the reader's limits, the limit that
+    // was exceeded. This is surfaced as an `IonError::LimitExceeded` rather than a decoding error.
+    limit_exceeded: Option<LimitExceededError>,
 }
 
 impl<'data> InvalidInputError<'data> {
@@ -80,6 +83,7 @@ impl<'data> InvalidInputError<'data> {
             label: None,
             description: None,
             nom_error_kind: None,
+            limit_exceeded: None,
         }
     }
 
@@ -101,6 +105,12 @@ impl<'data> InvalidInputError<'data> {
         self
     }
 
+    /// Records that parsing stopped because the input exceeded one of the reader's limits.
+    pub(crate) fn with_limit_exceeded(mut self, limit_exceeded: LimitExceededError) -> Self {
+        self.limit_exceeded = Some(limit_exceeded);
+        self
+    }
+
     /// Returns a reference to the `description` text, if any.
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
@@ -122,6 +132,9 @@ impl<'data> From<InvalidInputError<'data>> for IonParseError<'data> {
 // We cannot provide an analogous impl for `Incomplete` because it is missing necessary data.
 impl<'data> From<InvalidInputError<'data>> for IonError {
     fn from(invalid_input_error: InvalidInputError) -> Self {
+        if let Some(limit_exceeded) = invalid_input_error.limit_exceeded {
+            return IonError::LimitExceeded(limit_exceeded);
+        }
         let mut message = String::from(
             invalid_input_error
                 .description()
@@ -339,6 +352,18 @@ pub(crate) fn fatal_parse_error<D: Into<Cow<'static, str>>, O>(
     ))
 }
 
+/// Constructs a `nom::Err::Failure` that will be surfaced as the provided `LimitExceededError`.
+pub(crate) fn limit_exceeded_failure(
+    input: TextBuffer,
+    limit_exceeded: LimitExceededError,
+) -> nom::Err<IonParseError> {
+    nom::Err::Failure(
+        InvalidInputError::new(input)
+            .with_limit_exceeded(limit_exceeded)
+            .into(),
+    )
+}
+
 /// An extension trait that allows a [std::result::Result] of any kind to be mapped to an
 /// `IonParseResult` concisely.
 pub(crate) trait OrFatalParseError<T> {
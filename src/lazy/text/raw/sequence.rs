@@ -33,7 +33,7 @@ impl<'data> LazyRawTextList_1_0<'data> {
         // Skip past any annotations and the opening '['
         let list_contents_start = self.value.encoded_value.data_offset() + 1;
         // Make an iterator over the input bytes that follow the initial `[`
-        RawTextListIterator_1_0::new(self.value.input.slice_to_end(list_contents_start))
+        RawTextListIterator_1_0::new(self.value.input.slice_to_end(list_contents_start).nested())
     }
 }
 
@@ -176,7 +176,7 @@ impl<'data> LazyRawTextSExp_1_0<'data> {
         // Make an iterator over the input bytes that follow the initial `(`; account for
         // a leading annotations sequence.
         let sexp_contents_start = self.value.encoded_value.data_offset() + 1;
-        RawTextSExpIterator_1_0::new(self.value.input.slice_to_end(sexp_contents_start))
+        RawTextSExpIterator_1_0::new(self.value.input.slice_to_end(sexp_contents_start).nested())
     }
 }
 
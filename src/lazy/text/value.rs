@@ -203,6 +203,11 @@ impl<'top, E: TextEncoding<'top>> LazyRawValue<'top, E> for LazyRawTextValue<'to
             Int(i) => RawValueRef::Int(i.read(matched_input)?),
             Float(f) => RawValueRef::Float(f.read(matched_input)?),
             Decimal(d) => RawValueRef::Decimal(d.read(matched_input)?),
+            JsonNumber(n) => match n.ion_type() {
+                IonType::Int => RawValueRef::Int(n.read_int(matched_input)?),
+                IonType::Float => RawValueRef::Float(n.read_float(matched_input)?),
+                _ => RawValueRef::Decimal(n.read_decimal(matched_input)?),
+            },
             Timestamp(t) => RawValueRef::Timestamp(t.read(matched_input)?),
             String(s) => RawValueRef::String(s.read(allocator, matched_input)?),
             Symbol(s) => RawValueRef::Symbol(s.read(allocator, matched_input)?),
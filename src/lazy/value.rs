@@ -1,5 +1,8 @@
+use std::io;
+
 use crate::lazy::decoder::Decoder;
-use crate::lazy::encoding::BinaryEncoding_1_0;
+use crate::lazy::encoder::write_as_ion::WriteAsIon;
+use crate::lazy::encoding::{BinaryEncoding_1_0, JsonEncoding};
 use crate::lazy::expanded::{EncodingContextRef, ExpandedAnnotationsIterator, LazyExpandedValue};
 use crate::lazy::value_ref::ValueRef;
 use crate::result::IonFailure;
@@ -260,15 +263,47 @@ impl<'top, D: Decoder> LazyValue<'top, D> {
     pub fn read(&self) -> IonResult<ValueRef<'top, D>> {
         self.expanded_value.read_resolved()
     }
-}
 
-impl<'top, D: Decoder> TryFrom<LazyValue<'top, D>> for Element {
-    type Error = IonError;
+    /// Transcodes this value to JSON, following the conversion rules described in
+    /// [`JsonEncoding`]. Container values are read and written one child value at a time; no
+    /// [`Element`] is constructed.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{v1_0, Reader};
+    ///
+    /// let mut reader = Reader::new(v1_0::Text, "point::{x: 1.5, y: (a b), z: null.int}")?;
+    /// let json = reader.expect_next()?.to_json()?;
+    /// assert_eq!(json.trim_end(), r#"{"x": 1.5, "y": ["a", "b"], "z": null}"#);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn to_json(&self) -> IonResult<String> {
+        self.encode_as(JsonEncoding)
+    }
 
-    fn try_from(lazy_value: LazyValue<'top, D>) -> Result<Self, Self::Error> {
-        let value: Value = lazy_value.read()?.try_into()?;
-        if lazy_value.has_annotations() {
-            let annotations: Annotations = lazy_value.annotations().try_into()?;
+    /// Transcodes this value to JSON, writing the resulting text to `output`. See
+    /// [`to_json`](Self::to_json) for details.
+    pub fn write_json_to<W: io::Write>(&self, output: W) -> IonResult<W> {
+        self.encode_to(JsonEncoding, output)
+    }
+}
+
+impl<'top, D: Decoder> LazyValue<'top, D> {
+    /// Materializes this value as an [`Element`]. `depth` is the number of containers enclosing
+    /// this value, which is used to enforce the reader's container depth limit.
+    pub(crate) fn to_element_at_depth(self, depth: usize) -> IonResult<Element> {
+        let value = match self.read()? {
+            ValueRef::List(list) => Value::List(list.to_sequence_at_depth(depth)?),
+            ValueRef::SExp(sexp) => Value::SExp(sexp.to_sequence_at_depth(depth)?),
+            ValueRef::Struct(strukt) => Value::Struct(strukt.to_struct_at_depth(depth)?),
+            scalar => scalar.try_into()?,
+        };
+        if self.has_annotations() {
+            let annotations: Annotations = self.annotations().try_into()?;
             Ok(value.with_annotations(annotations))
         } else {
             Ok(value.into())
@@ -276,6 +311,16 @@ impl<'top, D: Decoder> TryFrom<LazyValue<'top, D>> for Element {
     }
 }
 
+// The container depth limit is counted relative to `lazy_value`, which is treated as though it
+// were at the top level; see `ReadLimits::with_max_container_depth`.
+impl<'top, D: Decoder> TryFrom<LazyValue<'top, D>> for Element {
+    type Error = IonError;
+
+    fn try_from(lazy_value: LazyValue<'top, D>) -> Result<Self, Self::Error> {
+        lazy_value.to_element_at_depth(0)
+    }
+}
+
 /// Iterates over a slice of bytes, lazily reading them as a sequence of symbol tokens encoded
 /// using the format described by generic type parameter `D`.
 pub struct AnnotationsIterator<'top, D: Decoder> {
@@ -427,10 +472,12 @@ impl<'top, D: Decoder> TryFrom<AnnotationsIterator<'top, D>> for Annotations {
     type Error = IonError;
 
     fn try_from(iter: AnnotationsIterator<'top, D>) -> Result<Self, Self::Error> {
+        let read_limits = iter.context.read_limits();
         let annotations = iter
-            .map(|symbol_ref| match symbol_ref {
-                Ok(symbol_ref) => Ok(symbol_ref.to_owned()),
-                Err(e) => Err(e),
+            .enumerate()
+            .map(|(index, symbol_ref)| {
+                read_limits.check_annotations(index + 1)?;
+                Ok(symbol_ref?.to_owned())
             })
             .collect::<IonResult<Vec<_>>>()?;
         Ok(Annotations::from(annotations))
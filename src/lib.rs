@@ -139,17 +139,20 @@
 use rstest_reuse;
 
 // Exposed to allow benchmark comparisons between the 1.0 primitives and 1.1 primitives
-pub use catalog::{Catalog, EmptyCatalog, MapCatalog};
+pub use catalog::{Catalog, EmptyCatalog, FileSystemCatalog, MapCatalog};
 pub use element::builders::{SequenceBuilder, StructBuilder};
 pub use element::{
     element_writer::ElementWriter, reader::ElementReader, Annotations, Element,
     IntoAnnotatedElement, IntoAnnotations, Sequence, Value,
 };
 pub use ion_data::IonData;
+pub use path::IonPath;
 
 #[doc(inline)]
 pub use result::{IonError, IonResult};
 pub use shared_symbol_table::SharedSymbolTable;
+#[cfg(feature = "experimental-reader-writer")]
+pub use symbol_frequencies::SymbolFrequencies;
 pub use symbol_ref::SymbolRef;
 #[doc(inline)]
 pub use types::{
@@ -159,6 +162,9 @@ pub use types::{
 // Allow access to less commonly used types like decimal::coefficient::{Coefficient, Sign}
 pub use types::decimal;
 
+pub use crate::text::text_format_config::{
+    DecimalStyle, FloatStyle, SymbolQuoting, TextFormatConfig, TimestampStyle,
+};
 #[cfg(feature = "experimental-tooling-apis")]
 pub use crate::text::text_formatter::{FmtValueFormatter, IoValueFormatter};
 
@@ -169,6 +175,8 @@ mod constants;
 mod ion_data;
 mod raw_symbol_ref;
 mod shared_symbol_table;
+#[cfg(feature = "experimental-reader-writer")]
+mod symbol_frequencies;
 mod symbol_ref;
 mod symbol_table;
 mod text;
@@ -178,19 +186,25 @@ mod element;
 pub(crate) mod result;
 mod types;
 
+pub mod diff;
+pub mod path;
 mod position;
 mod read_config;
 #[cfg(feature = "experimental-serde")]
 pub mod serde;
 pub(crate) mod unsafe_helpers;
 
-#[cfg(feature = "experimental-ion-hash")]
+#[cfg(feature = "ion-hash")]
 pub mod ion_hash;
 pub(crate) mod lazy;
 mod write_config;
 
 pub use crate::lazy::any_encoding::AnyEncoding;
+#[cfg(feature = "experimental-async")]
+pub use crate::lazy::async_reader::{AsyncIonStream, AsyncReader};
 pub use crate::lazy::decoder::{HasRange, HasSpan};
+#[cfg(feature = "experimental-async")]
+pub use crate::lazy::encoder::async_writer::AsyncWriter;
 pub use crate::lazy::span::Span;
 macro_rules! v1_x_reader_writer {
     ($visibility:vis) => {
@@ -199,10 +213,10 @@ macro_rules! v1_x_reader_writer {
             lazy::streaming_raw_reader::{IonInput, IonSlice, IonStream},
             lazy::decoder::Decoder,
             lazy::encoder::Encoder,
-            lazy::encoding::Encoding,
+            lazy::encoding::{Encoding, JsonEncoding},
             lazy::encoder::annotate::Annotatable,
             lazy::encoder::write_as_ion::WriteAsIon,
-            lazy::encoder::writer::Writer,
+            lazy::encoder::writer::{JsonWriter, Writer},
             lazy::reader::Reader,
             raw_symbol_ref::RawSymbolRef,
             symbol_table::SymbolTable,
@@ -233,6 +247,9 @@ macro_rules! v1_x_reader_writer {
     };
 }
 
+pub use crate::read_config::{
+    ImportResolution, JsonDecoding, JsonNumberPolicy, ReadConfig, ReadLimit, ReadLimits,
+};
 pub use crate::write_config::WriteConfig;
 
 macro_rules! v1_0_reader_writer {
@@ -281,6 +298,7 @@ macro_rules! v1_x_tooling_apis {
             lazy::encoder::{
                 LazyRawWriter,
             },
+            lazy::encoder::text::json::writer::LazyRawJsonWriter,
             lazy::encoder::value_writer_config::{
                 ValueWriterConfig,
                 ContainerEncoding,
@@ -0,0 +1,877 @@
+//! A small query language for locating values within an Ion value.
+//!
+//! An [`IonPath`] is compiled once from text like `$.orders[*].items[0]` and can then be evaluated
+//! any number of times, either against an [`Element`] tree or lazily against a `LazyValue`. Each
+//! match is reported along with the [`ValuePath`] at which it was found.
+//!
+//! | Syntax                         | Step                                                       |
+//! |--------------------------------|------------------------------------------------------------|
+//! | `$`                            | The root value. Optional; `foo.bar` and `$.foo.bar` are equivalent. |
+//! | `.name`, `.'name'`, `['name']` | Every struct field called `name`.                          |
+//! | `[3]`                          | The value at the given (zero-based) index of a list or s-expression. |
+//! | `.*`, `[*]`                    | Every child value of a struct, list or s-expression.       |
+//! | `::name`                       | Keeps only the values that have the annotation `name`.     |
+//! | `..`                           | Recursive descent: the current value and all of its descendants. |
+//!
+//! Recursive descent is usually followed by another step; `$..price` finds every field named
+//! `price` at any depth, and `$..::point` finds every value annotated with `point`.
+//!
+//! ```
+//!# use ion_rs::IonResult;
+//!# fn main() -> IonResult<()> {
+//! use ion_rs::{Element, IonPath};
+//!
+//! let element = Element::read_one(r#"
+//!     {
+//!         orders: [
+//!             {id: 1, items: [{sku: "a", price: 5}, {sku: "b", price: 7}]},
+//!             {id: 2, items: [{sku: "c", price: 3}]},
+//!         ]
+//!     }
+//! "#)?;
+//!
+//! let path: IonPath = "$.orders[*].items[0].sku".parse()?;
+//! let skus: Vec<_> = path
+//!     .query(&element)
+//!     .map(|(location, value)| (location.to_string(), value.as_string().unwrap()))
+//!     .collect();
+//! assert_eq!(
+//!     skus,
+//!     vec![
+//!         ("$.orders[0].items[0].sku".to_string(), "a"),
+//!         ("$.orders[1].items[0].sku".to_string(), "c"),
+//!     ]
+//! );
+//!
+//! let prices: IonPath = "$..price".parse()?;
+//! assert_eq!(prices.query(&element).count(), 3);
+//!# Ok(())
+//!# }
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::element::Element;
+use crate::lazy::decoder::Decoder;
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
+use crate::result::IonFailure;
+use crate::{IonError, IonResult, Symbol};
+
+/// A single step in an [`IonPath`]. See the [module documentation](self) for the text syntax of
+/// each step.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathStep {
+    /// Selects every field of a struct with the given name.
+    Field(String),
+    /// Selects the value at the given index of a list or s-expression.
+    Index(usize),
+    /// Selects every child value of a container.
+    Wildcard,
+    /// Keeps the current value only if it has the given annotation.
+    Annotated(String),
+    /// Selects the current value and each of its descendants.
+    RecursiveDescent,
+}
+
+/// A compiled path expression that can be evaluated against an [`Element`] or a `LazyValue`.
+///
+/// `IonPath` is typically constructed by parsing its text form with [`str::parse`]; see the
+/// [module documentation](self) for the syntax. Its [`Display`] implementation produces text that
+/// parses back into an equivalent path.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IonPath {
+    steps: Vec<PathStep>,
+}
+
+impl IonPath {
+    /// Constructs a path from a sequence of steps.
+    pub fn new<I: IntoIterator<Item = PathStep>>(steps: I) -> Self {
+        Self {
+            steps: steps.into_iter().collect(),
+        }
+    }
+
+    /// Returns the steps that make up this path.
+    pub fn steps(&self) -> &[PathStep] {
+        &self.steps
+    }
+
+    /// Evaluates this path against `element`, returning an iterator over each matching value and
+    /// its location. Matches are produced in document order, and each matching value is produced
+    /// once even if the path can reach it in more than one way.
+    pub fn query<'a>(&'a self, element: &'a Element) -> ElementMatches<'a> {
+        ElementMatches {
+            steps: &self.steps,
+            stack: vec![(vec![0], ValuePath::root(), element)],
+        }
+    }
+
+    /// Evaluates this path against a [`LazyValue`], returning an iterator over
+    /// each matching value and its location. As with [`IonPath::query`], matches are produced in
+    /// document order and each matching value is produced once.
+    ///
+    /// Only the values that the path actually visits are read. Containers whose contents cannot
+    /// produce a match are skipped without being stepped into, and the matching values themselves
+    /// are returned unread.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{IonPath, Reader};
+    /// use ion_rs::v1_0::Text;
+    ///
+    /// let mut reader = Reader::new(Text, r#"{name: "widget", tags: [a::x, b::y, a::z]}"#)?;
+    /// let value = reader.expect_next()?;
+    ///
+    /// let path: IonPath = "tags[*]::a".parse()?;
+    /// let mut symbols = vec![];
+    /// for result in path.query_lazy(value) {
+    ///     let (location, value) = result?;
+    ///     symbols.push((location.to_string(), value.read()?.expect_symbol()?.to_owned()));
+    /// }
+    /// assert_eq!(symbols, vec![("$.tags[0]".to_string(), "x".into()), ("$.tags[2]".to_string(), "z".into())]);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    #[cfg(feature = "experimental-reader-writer")]
+    pub fn query_lazy<'a, 'top, D: Decoder>(
+        &'a self,
+        value: LazyValue<'top, D>,
+    ) -> LazyMatches<'a, 'top, D> {
+        LazyMatches::new(&self.steps, value)
+    }
+}
+
+impl FromStr for IonPath {
+    type Err = IonError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        PathParser::new(text).parse()
+    }
+}
+
+impl Display for IonPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "$")?;
+        let mut after_recursive_descent = false;
+        for step in &self.steps {
+            // Following `..`, field names and wildcards are written without their leading `.`.
+            let separator = if after_recursive_descent { "" } else { "." };
+            match step {
+                PathStep::Field(name) => {
+                    write!(f, "{separator}")?;
+                    write_name(f, name)?;
+                }
+                PathStep::Index(index) => write!(f, "[{index}]")?,
+                PathStep::Wildcard => write!(f, "{separator}*")?,
+                PathStep::Annotated(annotation) => {
+                    write!(f, "::")?;
+                    write_name(f, annotation)?;
+                }
+                PathStep::RecursiveDescent => write!(f, "..")?,
+            }
+            after_recursive_descent = *step == PathStep::RecursiveDescent;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `name` as a bare identifier if possible and as a quoted name otherwise.
+fn write_name(f: &mut Formatter<'_>, name: &str) -> std::fmt::Result {
+    let mut chars = name.chars();
+    let is_identifier = chars.next().is_some_and(is_identifier_start)
+        && chars.all(is_identifier_char)
+        // A bare `$` would be read back as the root.
+        && name != "$";
+    if is_identifier {
+        return write!(f, "{name}");
+    }
+    write!(f, "'")?;
+    for c in name.chars() {
+        if c == '\'' || c == '\\' {
+            write!(f, "\\")?;
+        }
+        write!(f, "{c}")?;
+    }
+    write!(f, "'")
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_' || c == '$'
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '$'
+}
+
+/// A single component of a [`ValuePath`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathComponent {
+    /// The value of a struct field with this name.
+    Field(Symbol),
+    /// The value at this index of a list or s-expression.
+    Index(usize),
+}
+
+/// The concrete location of a value relative to the root value a query was evaluated against.
+///
+/// When displayed, a `ValuePath` uses the same syntax as [`IonPath`]; for example,
+/// `$.orders[1].id`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ValuePath {
+    components: Vec<PathComponent>,
+}
+
+impl ValuePath {
+    /// Returns the location of the root value.
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    /// Returns the components of this path, starting from the root.
+    pub fn components(&self) -> &[PathComponent] {
+        &self.components
+    }
+
+    /// Returns the number of components in this path.
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Returns `true` if this path refers to the root value.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// Appends a component to the end of this path.
+    pub fn push(&mut self, component: PathComponent) {
+        self.components.push(component)
+    }
+
+    /// Removes and returns the last component of this path, if any.
+    pub fn pop(&mut self) -> Option<PathComponent> {
+        self.components.pop()
+    }
+
+    /// Returns a copy of this path with `component` appended.
+    pub(crate) fn child(&self, component: PathComponent) -> Self {
+        let mut child = self.clone();
+        child.push(component);
+        child
+    }
+}
+
+impl Display for ValuePath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "$")?;
+        for component in &self.components {
+            match component {
+                PathComponent::Field(name) => match name.text() {
+                    Some(text) => {
+                        write!(f, ".")?;
+                        write_name(f, text)?;
+                    }
+                    None => write!(f, ".$0")?,
+                },
+                PathComponent::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromIterator<PathComponent> for ValuePath {
+    fn from_iter<T: IntoIterator<Item = PathComponent>>(iter: T) -> Self {
+        Self {
+            components: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// The indexes of the steps that still need to be applied to a value. A value that can be reached
+/// through the path in more than one way (for example, by way of two recursive descents) is only
+/// visited once, with the union of its pending steps.
+type PendingSteps = Vec<usize>;
+
+/// Expands `pending` with the steps that can be applied to a value without moving to one of its
+/// children: annotation filters that the value satisfies and the current-value half of each
+/// recursive descent. `has_annotation` reports whether the value has the given annotation.
+fn expand_pending_steps(
+    steps: &[PathStep],
+    mut pending: PendingSteps,
+    mut has_annotation: impl FnMut(&str) -> IonResult<bool>,
+) -> IonResult<PendingSteps> {
+    let mut index = 0;
+    while let Some(&step_index) = pending.get(index) {
+        let applies = match steps.get(step_index) {
+            Some(PathStep::Annotated(annotation)) => has_annotation(annotation)?,
+            Some(PathStep::RecursiveDescent) => true,
+            _ => false,
+        };
+        if applies && !pending.contains(&(step_index + 1)) {
+            pending.push(step_index + 1);
+        }
+        index += 1;
+    }
+    Ok(pending)
+}
+
+/// Returns `true` if any of the `pending` steps can only be satisfied by a child value.
+fn steps_visit_children(steps: &[PathStep], pending: &[usize]) -> bool {
+    pending.iter().any(|step_index| {
+        matches!(
+            steps.get(*step_index),
+            Some(
+                PathStep::Field(_)
+                    | PathStep::Index(_)
+                    | PathStep::Wildcard
+                    | PathStep::RecursiveDescent
+            )
+        )
+    })
+}
+
+/// Returns the largest index that the `pending` steps can select from a list or s-expression, or
+/// `None` if they can select children at any index.
+fn max_child_index(steps: &[PathStep], pending: &[usize]) -> Option<usize> {
+    let mut max_index = 0;
+    for step_index in pending {
+        match steps.get(*step_index) {
+            Some(PathStep::Index(index)) => max_index = max_index.max(*index),
+            Some(PathStep::Wildcard | PathStep::RecursiveDescent) => return None,
+            _ => {}
+        }
+    }
+    Some(max_index)
+}
+
+/// Returns the steps that are pending for the child at `location` of a value with the `pending`
+/// steps.
+fn child_pending_steps(
+    steps: &[PathStep],
+    pending: &[usize],
+    location: ChildLocation<'_>,
+) -> PendingSteps {
+    let mut child_pending = PendingSteps::new();
+    for &step_index in pending {
+        let child_step_index = match (steps.get(step_index), location) {
+            (Some(PathStep::Field(name)), ChildLocation::Field(Some(field_name)))
+                if name == field_name =>
+            {
+                step_index + 1
+            }
+            (Some(PathStep::Index(index)), ChildLocation::Index(child_index))
+                if *index == child_index =>
+            {
+                step_index + 1
+            }
+            (Some(PathStep::Wildcard), _) => step_index + 1,
+            // Recursive descent remains pending for every descendant.
+            (Some(PathStep::RecursiveDescent), _) => step_index,
+            _ => continue,
+        };
+        if !child_pending.contains(&child_step_index) {
+            child_pending.push(child_step_index);
+        }
+    }
+    child_pending
+}
+
+/// Where a child value is found within its parent.
+#[derive(Debug, Clone, Copy)]
+enum ChildLocation<'a> {
+    /// A struct field. The name is `None` if its text is unknown.
+    Field(Option<&'a str>),
+    /// An element of a list or s-expression.
+    Index(usize),
+}
+
+/// An iterator over the values matched by an [`IonPath`] within an [`Element`].
+///
+/// See [`IonPath::query`].
+pub struct ElementMatches<'a> {
+    steps: &'a [PathStep],
+    // Values waiting to be visited, stored as (pending steps, location, value). Values are visited
+    // in pre-order; each container's children are pushed in reverse document order so that
+    // popping them yields matches in document order.
+    stack: Vec<(PendingSteps, ValuePath, &'a Element)>,
+}
+
+impl<'a> ElementMatches<'a> {
+    /// Schedules each child of `element` that could lead to a match to be visited.
+    fn push_children(&mut self, pending: &[usize], path: &ValuePath, element: &'a Element) {
+        let start = self.stack.len();
+        if let Some(struct_) = element.as_struct() {
+            for (name, value) in struct_.fields() {
+                let location = ChildLocation::Field(name.text());
+                let child_pending = child_pending_steps(self.steps, pending, location);
+                if !child_pending.is_empty() {
+                    let location = path.child(PathComponent::Field(name.clone()));
+                    self.stack.push((child_pending, location, value));
+                }
+            }
+        } else if let Some(sequence) = element.as_sequence() {
+            for (index, value) in sequence.iter().enumerate() {
+                let location = ChildLocation::Index(index);
+                let child_pending = child_pending_steps(self.steps, pending, location);
+                if !child_pending.is_empty() {
+                    let location = path.child(PathComponent::Index(index));
+                    self.stack.push((child_pending, location, value));
+                }
+            }
+        }
+        self.stack[start..].reverse();
+    }
+}
+
+impl<'a> Iterator for ElementMatches<'a> {
+    type Item = (ValuePath, &'a Element);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((pending, path, element)) = self.stack.pop() {
+            let annotations = element.annotations();
+            let pending = expand_pending_steps(self.steps, pending, |annotation| {
+                Ok(annotations.contains(annotation))
+            })
+            .expect("checking an Element's annotations cannot fail");
+            if steps_visit_children(self.steps, &pending) {
+                self.push_children(&pending, &path, element);
+            }
+            // Once every step has been applied, the value is a match. It is produced before any
+            // of its descendants.
+            if pending.contains(&self.steps.len()) {
+                return Some((path, element));
+            }
+        }
+        None
+    }
+}
+
+/// An iterator over the values matched by an [`IonPath`] within a `LazyValue`.
+///
+/// Because reading the input can fail, each item is an [`IonResult`]. After an error is returned,
+/// the iterator produces no further items.
+pub struct LazyMatches<'a, 'top, D: Decoder> {
+    steps: &'a [PathStep],
+    // See `ElementMatches::stack`.
+    stack: Vec<(PendingSteps, ValuePath, LazyValue<'top, D>)>,
+}
+
+impl<'a, 'top, D: Decoder> LazyMatches<'a, 'top, D> {
+    fn new(steps: &'a [PathStep], value: LazyValue<'top, D>) -> Self {
+        Self {
+            steps,
+            stack: vec![(vec![0], ValuePath::root(), value)],
+        }
+    }
+
+    /// Schedules each child of `value` that could lead to a match to be visited. Scalar values
+    /// are not read, and children that follow the last index the pending steps can select are
+    /// not visited.
+    fn push_children(
+        &mut self,
+        pending: &[usize],
+        path: &ValuePath,
+        value: LazyValue<'top, D>,
+    ) -> IonResult<()> {
+        if !value.is_container() {
+            return Ok(());
+        }
+        let start = self.stack.len();
+        let max_index = max_child_index(self.steps, pending);
+        let mut push_sequence_child = |index: usize, child: LazyValue<'top, D>| {
+            let location = ChildLocation::Index(index);
+            let child_pending = child_pending_steps(self.steps, pending, location);
+            if !child_pending.is_empty() {
+                let location = path.child(PathComponent::Index(index));
+                self.stack.push((child_pending, location, child));
+            }
+        };
+        match value.read()? {
+            ValueRef::List(list) => {
+                for (index, child) in list.iter().enumerate() {
+                    if max_index.is_some_and(|max_index| index > max_index) {
+                        break;
+                    }
+                    push_sequence_child(index, child?);
+                }
+            }
+            ValueRef::SExp(sexp) => {
+                for (index, child) in sexp.iter().enumerate() {
+                    if max_index.is_some_and(|max_index| index > max_index) {
+                        break;
+                    }
+                    push_sequence_child(index, child?);
+                }
+            }
+            ValueRef::Struct(struct_) => {
+                for field in &struct_ {
+                    let field = field?;
+                    let name = field.name()?;
+                    let location = ChildLocation::Field(name.text());
+                    let child_pending = child_pending_steps(self.steps, pending, location);
+                    if !child_pending.is_empty() {
+                        let location = path.child(PathComponent::Field(name.to_owned()));
+                        self.stack.push((child_pending, location, field.value()));
+                    }
+                }
+            }
+            // A null container has no children.
+            _ => {}
+        }
+        self.stack[start..].reverse();
+        Ok(())
+    }
+
+    fn next_match(&mut self) -> IonResult<Option<(ValuePath, LazyValue<'top, D>)>> {
+        while let Some((pending, path, value)) = self.stack.pop() {
+            let pending = expand_pending_steps(self.steps, pending, |annotation| {
+                for value_annotation in value.annotations() {
+                    if value_annotation? == annotation {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            })?;
+            if steps_visit_children(self.steps, &pending) {
+                self.push_children(&pending, &path, value)?;
+            }
+            if pending.contains(&self.steps.len()) {
+                return Ok(Some((path, value)));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<'a, 'top, D: Decoder> Iterator for LazyMatches<'a, 'top, D> {
+    type Item = IonResult<(ValuePath, LazyValue<'top, D>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_match() {
+            Ok(Some(found)) => Some(Ok(found)),
+            Ok(None) => None,
+            Err(e) => {
+                self.stack.clear();
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Converts the text form of an [`IonPath`] into its steps.
+struct PathParser<'a> {
+    text: &'a str,
+    // The byte offset of the next unread character
+    position: usize,
+    steps: Vec<PathStep>,
+}
+
+impl<'a> PathParser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            position: 0,
+            steps: Vec::new(),
+        }
+    }
+
+    fn parse(mut self) -> IonResult<IonPath> {
+        self.skip_root();
+        while let Some(c) = self.peek() {
+            match c {
+                '.' if self.remaining().starts_with("..") => {
+                    self.position += 2;
+                    self.steps.push(PathStep::RecursiveDescent);
+                    // Field names and wildcards may follow `..` directly; other steps bring their
+                    // own leading punctuation.
+                    if self
+                        .peek()
+                        .is_some_and(|c| c != '.' && c != '[' && c != ':')
+                    {
+                        self.parse_name_or_wildcard()?;
+                    }
+                }
+                '.' => {
+                    self.position += 1;
+                    self.parse_name_or_wildcard()?;
+                }
+                '[' => {
+                    self.position += 1;
+                    self.parse_bracketed_step()?;
+                }
+                ':' if self.remaining().starts_with("::") => {
+                    self.position += 2;
+                    let annotation = self.parse_name()?;
+                    self.steps.push(PathStep::Annotated(annotation));
+                }
+                // A path may begin with a bare field name, as in `foo.bar`.
+                _ if self.position == 0 => self.parse_name_or_wildcard()?,
+                _ => return self.error("expected '.', '[', or '::'"),
+            }
+        }
+        Ok(IonPath { steps: self.steps })
+    }
+
+    /// Consumes the leading `$` that denotes the root value, if present.
+    fn skip_root(&mut self) {
+        let mut chars = self.text.chars();
+        if chars.next() == Some('$') && !chars.next().is_some_and(is_identifier_char) {
+            self.position = 1;
+        }
+    }
+
+    fn parse_name_or_wildcard(&mut self) -> IonResult<()> {
+        if self.peek() == Some('*') {
+            self.position += 1;
+            self.steps.push(PathStep::Wildcard);
+        } else {
+            let name = self.parse_name()?;
+            self.steps.push(PathStep::Field(name));
+        }
+        Ok(())
+    }
+
+    /// Parses the contents of a `[...]` step; the opening bracket has already been consumed.
+    fn parse_bracketed_step(&mut self) -> IonResult<()> {
+        let step = match self.peek() {
+            Some('*') => {
+                self.position += 1;
+                PathStep::Wildcard
+            }
+            Some('\'' | '"') => PathStep::Field(self.parse_quoted_name()?),
+            Some(c) if c.is_ascii_digit() => {
+                let digits = self.take_while(|c| c.is_ascii_digit());
+                match digits.parse::<usize>() {
+                    Ok(index) => PathStep::Index(index),
+                    Err(_) => return self.error("index is too large"),
+                }
+            }
+            _ => return self.error("expected an index, a quoted field name, or '*'"),
+        };
+        if self.peek() != Some(']') {
+            return self.error("expected ']'");
+        }
+        self.position += 1;
+        self.steps.push(step);
+        Ok(())
+    }
+
+    fn parse_name(&mut self) -> IonResult<String> {
+        match self.peek() {
+            Some('\'' | '"') => self.parse_quoted_name(),
+            Some(c) if is_identifier_start(c) => Ok(self.take_while(is_identifier_char).to_owned()),
+            _ => self.error("expected a name"),
+        }
+    }
+
+    fn parse_quoted_name(&mut self) -> IonResult<String> {
+        let start = self.position;
+        let mut chars = self.remaining().char_indices();
+        let (_, quote) = chars.next().expect("caller checked for an opening quote");
+        let mut name = String::new();
+        let mut escaped = false;
+        for (offset, c) in chars {
+            if escaped {
+                name.push(c);
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == quote {
+                self.position = start + offset + c.len_utf8();
+                return Ok(name);
+            } else {
+                name.push(c);
+            }
+        }
+        self.error("unterminated quoted name")
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let remaining = &self.text[self.position..];
+        let length = remaining.find(|c| !predicate(c)).unwrap_or(remaining.len());
+        self.position += length;
+        &remaining[..length]
+    }
+
+    fn remaining(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.remaining().chars().next()
+    }
+
+    fn error<T>(&self, reason: &str) -> IonResult<T> {
+        IonResult::decoding_error(format!(
+            "invalid path expression '{}': {reason} at offset {}",
+            self.text, self.position
+        ))
+    }
+}
+
+#[cfg(test)]
+mod path_tests {
+    use rstest::*;
+
+    use super::*;
+    #[cfg(feature = "experimental-reader-writer")]
+    use crate::{AnyEncoding, Reader};
+
+    const DOCUMENT: &str = r#"
+        {
+            name: "store",
+            inventory: [
+                widget::{sku: "w1", price: 5, tags: [red, blue]},
+                gadget::{sku: "g1", price: 7, parts: {price: 2}},
+                widget::{sku: "w2", price: 3},
+            ],
+            pairs: (1 (2 3)),
+            'odd name': 0,
+        }
+    "#;
+
+    fn element_matches(path: &str) -> IonResult<Vec<(String, Element)>> {
+        let document = Element::read_one(DOCUMENT)?;
+        let path: IonPath = path.parse()?;
+        Ok(path
+            .query(&document)
+            .map(|(location, value)| (location.to_string(), value.clone()))
+            .collect())
+    }
+
+    #[cfg(feature = "experimental-reader-writer")]
+    fn lazy_matches(path: &str) -> IonResult<Vec<(String, Element)>> {
+        let mut reader = Reader::new(AnyEncoding, DOCUMENT)?;
+        let document = reader.expect_next()?;
+        let path: IonPath = path.parse()?;
+        let mut matches = Vec::new();
+        for result in path.query_lazy(document) {
+            let (location, value) = result?;
+            matches.push((location.to_string(), Element::try_from(value)?));
+        }
+        Ok(matches)
+    }
+
+    #[rstest]
+    #[case::root("$", &["$"])]
+    #[case::empty("", &["$"])]
+    #[case::field("$.name", &["$.name"])]
+    #[case::bare_field("name", &["$.name"])]
+    #[case::missing_field("$.nope", &[])]
+    #[case::quoted_field("$.'odd name'", &["$.'odd name'"])]
+    #[case::bracketed_field("$['odd name']", &["$.'odd name'"])]
+    #[case::index("$.inventory[1].sku", &["$.inventory[1].sku"])]
+    #[case::index_out_of_bounds("$.inventory[3]", &[])]
+    #[case::index_into_struct("$.name[0]", &[])]
+    #[case::sexp_index("$.pairs[1][0]", &["$.pairs[1][0]"])]
+    #[case::wildcard("$.inventory[*].sku", &["$.inventory[0].sku", "$.inventory[1].sku", "$.inventory[2].sku"])]
+    #[case::dot_wildcard("$.inventory[0].*", &["$.inventory[0].sku", "$.inventory[0].price", "$.inventory[0].tags"])]
+    #[case::annotation("$.inventory[*]::widget.sku", &["$.inventory[0].sku", "$.inventory[2].sku"])]
+    #[case::recursive_field("$..price", &["$.inventory[0].price", "$.inventory[1].price", "$.inventory[1].parts.price", "$.inventory[2].price"])]
+    #[case::recursive_index("$..[1]", &["$.inventory[0].tags[1]", "$.inventory[1]", "$.pairs[1]", "$.pairs[1][1]"])]
+    #[case::nested_recursive_descent("$..*..price", &["$.inventory[0].price", "$.inventory[1].price", "$.inventory[1].parts.price", "$.inventory[2].price"])]
+    #[case::recursive_annotation("$..::gadget.sku", &["$.inventory[1].sku"])]
+    #[case::recursive_wildcard("$.pairs..*", &["$.pairs[0]", "$.pairs[1]", "$.pairs[1][0]", "$.pairs[1][1]"])]
+    fn query(#[case] path: &str, #[case] expected: &[&str]) -> IonResult<()> {
+        let element_locations: Vec<String> = element_matches(path)?
+            .into_iter()
+            .map(|(location, _)| location)
+            .collect();
+        assert_eq!(element_locations, expected);
+        // Lazy evaluation must find the same values at the same locations.
+        #[cfg(feature = "experimental-reader-writer")]
+        assert_eq!(lazy_matches(path)?, element_matches(path)?);
+        Ok(())
+    }
+
+    #[test]
+    fn matched_values() -> IonResult<()> {
+        let matches = element_matches("$.inventory[*]::widget.price")?;
+        let prices: Vec<Element> = matches.into_iter().map(|(_, value)| value).collect();
+        assert_eq!(prices, vec![Element::from(5), Element::from(3)]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "experimental-reader-writer")]
+    fn lazy_query_on_binary_struct() -> IonResult<()> {
+        let bytes = Element::read_one(DOCUMENT)?.encode_as(crate::v1_0::Binary)?;
+        let mut reader = Reader::new(AnyEncoding, bytes)?;
+        let document = reader.expect_next()?.read()?.expect_struct()?;
+        let path: IonPath = "inventory[*].parts.price".parse()?;
+        let matches: Vec<_> = path
+            .query_lazy(document.as_value())
+            .collect::<IonResult<_>>()?;
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].0.to_string(), "$.inventory[1].parts.price");
+        assert_eq!(matches[0].1.read()?.expect_i64()?, 2);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "experimental-reader-writer")]
+    fn lazy_query_stops_after_error() -> IonResult<()> {
+        // The list's second child uses the reserved type code 0xF; the query must report the
+        // error rather than skip the value.
+        let bytes = [0xE0, 0x01, 0x00, 0xEA, 0xB4, 0x21, 0x01, 0xF0, 0x00];
+        let mut reader = Reader::new(AnyEncoding, bytes.as_slice())?;
+        let list = reader.expect_next()?;
+        let path: IonPath = "[*]".parse()?;
+        let mut matches = path.query_lazy(list);
+        assert!(matches.next().is_some_and(|result| result.is_err()));
+        assert!(matches.next().is_none());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::root("$", vec![])]
+    #[case::steps(
+        "$.a[2].*[*]::b..c..*..[0]..::d",
+        vec![
+            PathStep::Field("a".into()),
+            PathStep::Index(2),
+            PathStep::Wildcard,
+            PathStep::Wildcard,
+            PathStep::Annotated("b".into()),
+            PathStep::RecursiveDescent,
+            PathStep::Field("c".into()),
+            PathStep::RecursiveDescent,
+            PathStep::Wildcard,
+            PathStep::RecursiveDescent,
+            PathStep::Index(0),
+            PathStep::RecursiveDescent,
+            PathStep::Annotated("d".into()),
+        ]
+    )]
+    #[case::identifier_with_dollar("$ion.$0", vec![PathStep::Field("$ion".into()), PathStep::Field("$0".into())])]
+    #[case::quoted_names(
+        r#"$."a.b"['c\'d']::'e f'"#,
+        vec![PathStep::Field("a.b".into()), PathStep::Field("c'd".into()), PathStep::Annotated("e f".into())]
+    )]
+    fn parse(#[case] text: &str, #[case] expected: Vec<PathStep>) -> IonResult<()> {
+        let path: IonPath = text.parse()?;
+        assert_eq!(path.steps(), expected.as_slice());
+        // The displayed form must parse back into the same path.
+        assert_eq!(path.to_string().parse::<IonPath>()?, path);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::trailing_dot("$.")]
+    #[case::unclosed_bracket("$[0")]
+    #[case::empty_brackets("$[]")]
+    #[case::negative_index("$[-1]")]
+    #[case::unterminated_quote("$.'abc")]
+    #[case::single_colon("$.a:b")]
+    #[case::missing_separator("$.a b")]
+    #[case::huge_index("$[99999999999999999999999999]")]
+    fn parse_errors(#[case] text: &str) {
+        assert!(text.parse::<IonPath>().is_err(), "{text} should not parse");
+    }
+}
@@ -3,11 +3,16 @@ use crate::lazy::any_encoding::AnyEncoding;
 use crate::lazy::encoding::{
     BinaryEncoding_1_0, BinaryEncoding_1_1, TextEncoding_1_0, TextEncoding_1_1,
 };
-use crate::{Catalog, Decoder};
+use crate::result::IonFailure;
+use crate::{Catalog, Decoder, IonResult};
+use std::fmt::{Display, Formatter};
 
 /// Provides configuration details for reader construction.
 pub struct ReadConfig<D: Decoder> {
-    pub(crate) catalog: Box<dyn Catalog>,
+    pub(crate) catalog: Box<dyn Catalog + Send>,
+    pub(crate) limits: ReadLimits,
+    pub(crate) import_resolution: ImportResolution,
+    pub(crate) json_decoding: Option<JsonDecoding>,
     encoding: D,
 }
 
@@ -16,16 +21,365 @@ impl<D: Decoder> ReadConfig<D> {
         ReadConfig::new_with_catalog(encoding, EmptyCatalog)
     }
 
-    pub(crate) fn new_with_catalog(encoding: D, catalog: impl Catalog + 'static) -> Self {
+    pub(crate) fn new_with_catalog(encoding: D, catalog: impl Catalog + Send + 'static) -> Self {
         ReadConfig {
             catalog: Box::new(catalog),
+            limits: ReadLimits::default(),
+            import_resolution: ImportResolution::default(),
+            json_decoding: None,
             encoding,
         }
     }
 
+    /// Sets the resource limits that the reader will enforce. See [`ReadLimits`] for details.
+    pub fn with_limits(mut self, limits: ReadLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Sets how the reader resolves shared symbol table imports that do not exactly match a
+    /// table in its catalog. See [`ImportResolution`] for details.
+    pub fn with_import_resolution(mut self, import_resolution: ImportResolution) -> Self {
+        self.import_resolution = import_resolution;
+        self
+    }
+
     pub fn encoding(&self) -> D {
         self.encoding
     }
+
+    /// Returns the resource limits that the reader will enforce.
+    pub fn limits(&self) -> ReadLimits {
+        self.limits
+    }
+
+    /// Returns how the reader resolves shared symbol table imports.
+    pub fn import_resolution(&self) -> ImportResolution {
+        self.import_resolution
+    }
+}
+
+impl ReadConfig<TextEncoding_1_0> {
+    /// Requires the input to be JSON. See [`JsonDecoding`] for details.
+    pub fn with_json_decoding(mut self, json_decoding: impl Into<JsonDecoding>) -> Self {
+        self.json_decoding = Some(json_decoding.into());
+        self
+    }
+
+    /// Returns the JSON decoding settings, if the reader only accepts JSON.
+    pub fn json_decoding(&self) -> Option<JsonDecoding> {
+        self.json_decoding
+    }
+}
+
+/// Determines how a reader handles a local symbol table import when its [`Catalog`] does not
+/// contain the requested version of the shared symbol table.
+///
+/// In both modes, an import whose exact version is found in the catalog is padded with
+/// unknown-text symbols or truncated to match its `max_id`, if one is specified. Padding is
+/// bounded by the reader's [maximum symbol table size](ReadLimits::with_max_symbol_table_size)
+/// or, if none is set, by [`ReadLimits::DEFAULT_MAX_IMPORTED_SYMBOL_TABLE_SIZE`]; an import that
+/// would exceed it produces an [`IonError::LimitExceeded`](crate::IonError::LimitExceeded).
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum ImportResolution {
+    /// Raises an error if the catalog does not contain the requested version of each imported
+    /// table. This is the default.
+    #[default]
+    Strict,
+    /// Follows the fallback rules in the Ion specification. If the requested version is not in
+    /// the catalog and the import specifies a `max_id`, the reader uses the catalog's
+    /// [best match](Catalog::get_best_match) instead, padded or truncated to `max_id`. If the
+    /// catalog has no table with that name, the import's `max_id` symbol IDs all have unknown
+    /// text. An import that does not specify a `max_id` must still match a table exactly.
+    Lenient,
+}
+
+/// Determines the Ion type used to represent each JSON number. See [`JsonDecoding`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum JsonNumberPolicy {
+    /// Numbers are typed the way the Ion text reader would type them: `1` is an int, `1.5` is a
+    /// decimal, and numbers with an exponent (`1.5e3`) are floats.
+    #[default]
+    IonText,
+    /// Every number is a float.
+    AlwaysFloat,
+    /// Numbers without a fraction or an exponent (`15`) are ints. All other numbers (`1.5`,
+    /// `15e-1`) are decimals.
+    DecimalWhenFractional,
+    /// Numbers whose value is a whole number (`15`, `15.0`, `1.5e1`) are ints. All other numbers
+    /// are decimals. Whole numbers with more than 1,000 digits are also read as decimals.
+    IntWhenIntegral,
+}
+
+/// Configures a text Ion 1.0 reader to accept only JSON.
+///
+/// Ion text is a superset of JSON, so any JSON document can be read by an Ion text reader. With
+/// JSON decoding enabled, the reader also rejects input that is not JSON, including annotations,
+/// symbols, s-expressions, comments, typed nulls, timestamps, blobs, clobs, Ion-only number and
+/// string syntax, and trailing commas. Each error reports the offset at which the problem was
+/// found. The input may contain any number of top-level values separated by whitespace, as in
+/// the JSON Lines format.
+///
+/// JSON numbers are read as ints, floats, or decimals according to the configured
+/// [`JsonNumberPolicy`].
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{v1_0, Element, ElementReader, JsonNumberPolicy, Reader};
+///
+/// let config = v1_0::Text.with_json_decoding(JsonNumberPolicy::AlwaysFloat);
+/// let mut reader = Reader::new(config, r#"{"x": 1, "y": 2.5}"#)?;
+/// assert_eq!(reader.read_one_element()?, Element::read_one("{x: 1e0, y: 2.5e0}")?);
+///
+/// let config = v1_0::Text.with_json_decoding(JsonNumberPolicy::IonText);
+/// let mut reader = Reader::new(config, r#"{"a": foo::1}"#)?;
+/// let error = reader.read_next_element().unwrap_err();
+/// assert!(error.to_string().contains("not valid JSON"));
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct JsonDecoding {
+    number_policy: JsonNumberPolicy,
+}
+
+impl JsonDecoding {
+    /// Constructs a `JsonDecoding` that uses [`JsonNumberPolicy::IonText`].
+    pub const fn new() -> Self {
+        Self {
+            number_policy: JsonNumberPolicy::IonText,
+        }
+    }
+
+    /// Sets the policy used to decide the Ion type of each JSON number.
+    pub const fn with_number_policy(mut self, number_policy: JsonNumberPolicy) -> Self {
+        self.number_policy = number_policy;
+        self
+    }
+
+    /// Returns the policy used to decide the Ion type of each JSON number.
+    pub const fn number_policy(&self) -> JsonNumberPolicy {
+        self.number_policy
+    }
+}
+
+impl From<JsonNumberPolicy> for JsonDecoding {
+    fn from(number_policy: JsonNumberPolicy) -> Self {
+        JsonDecoding::new().with_number_policy(number_policy)
+    }
+}
+
+/// Bounds the resources a reader may use while processing its input. Reading data that would
+/// exceed one of these limits produces an [`IonError::LimitExceeded`](crate::IonError::LimitExceeded)
+/// that names the limit in question.
+///
+/// By default, the only limit enforced is on symbol table imports: an import's `max_id` can
+/// declare more symbols than the imported table defines, so imports may not grow a symbol table
+/// beyond [`ReadLimits::DEFAULT_MAX_IMPORTED_SYMBOL_TABLE_SIZE`] symbols unless
+/// [`with_max_symbol_table_size`](ReadLimits::with_max_symbol_table_size) sets a different
+/// maximum. Applications reading untrusted input should set limits appropriate to the data they
+/// expect.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{v1_0, Decoder, Element, ElementReader, IonError, ReadLimit, ReadLimits, Reader};
+///
+/// let limits = ReadLimits::new()
+///     .with_max_buffer_size(64 * 1024)
+///     .with_max_container_depth(2);
+/// let mut reader = Reader::new(v1_0::Text.with_limits(limits), "[[1]] [[[2]]]")?;
+/// assert_eq!(reader.read_next_element()?, Some(Element::read_one("[[1]]")?));
+/// let Err(IonError::LimitExceeded(error)) = reader.read_next_element() else {
+///     panic!("expected the second value to exceed the depth limit");
+/// };
+/// assert_eq!(error.limit(), ReadLimit::MaxContainerDepth);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReadLimits {
+    max_buffer_size: Option<usize>,
+    max_value_size: Option<usize>,
+    max_container_depth: Option<usize>,
+    max_annotations: Option<usize>,
+    max_symbol_table_size: Option<usize>,
+}
+
+impl ReadLimits {
+    /// The largest symbol table that imports can produce when no maximum symbol table size has
+    /// been configured. Symbol IDs beyond the end of an imported table have unknown text but
+    /// still occupy space, so without a limit a single `max_id` could exhaust memory.
+    pub const DEFAULT_MAX_IMPORTED_SYMBOL_TABLE_SIZE: usize = 1 << 20;
+
+    /// Constructs a `ReadLimits` that does not set any limits. Imports are still bounded by
+    /// [`ReadLimits::DEFAULT_MAX_IMPORTED_SYMBOL_TABLE_SIZE`].
+    pub const fn new() -> Self {
+        Self {
+            max_buffer_size: None,
+            max_value_size: None,
+            max_container_depth: None,
+            max_annotations: None,
+            max_symbol_table_size: None,
+        }
+    }
+
+    /// Sets the maximum number of bytes a streaming input source (for example, a `File`) may
+    /// buffer at once. The buffer grows when a single stream item does not fit in it; it will not
+    /// grow beyond this size. Inputs that are already in memory (like a `Vec<u8>` or `&str`) are
+    /// not copied into a buffer and are not affected by this limit.
+    pub const fn with_max_buffer_size(mut self, bytes: usize) -> Self {
+        self.max_buffer_size = Some(bytes);
+        self
+    }
+
+    /// Sets the maximum number of bytes in a single top-level stream item (a value, symbol table,
+    /// or version marker), including any whitespace or comments that precede it.
+    pub const fn with_max_value_size(mut self, bytes: usize) -> Self {
+        self.max_value_size = Some(bytes);
+        self
+    }
+
+    /// Sets the maximum number of containers that can be nested inside one another. For example,
+    /// `{a: [1]}` has a depth of 2 and a top-level scalar has a depth of 0.
+    ///
+    /// Finding the end of a text container (or an Ion 1.1 delimited container or e-expression)
+    /// requires parsing everything inside of it, so the limit is checked as that input is parsed.
+    /// This keeps input that is nested deeply enough to exhaust the stack from being parsed at all.
+    ///
+    /// Binary containers with a length prefix can be skipped without reading their contents, so
+    /// they are checked when values are materialized as [`Element`](crate::Element)s. Lazy values
+    /// do not record how deeply they are nested, so that count starts from the value being
+    /// materialized: converting a nested lazy value only counts the containers inside of it.
+    pub const fn with_max_container_depth(mut self, depth: usize) -> Self {
+        self.max_container_depth = Some(depth);
+        self
+    }
+
+    /// Sets the maximum number of annotations on a single value. Like the container depth limit,
+    /// this is applied when values are materialized as [`Element`](crate::Element)s.
+    pub const fn with_max_annotations(mut self, annotations: usize) -> Self {
+        self.max_annotations = Some(annotations);
+        self
+    }
+
+    /// Sets the maximum number of symbols in the reader's symbol table, including system symbols
+    /// and symbols added by imports.
+    ///
+    /// An import's `max_id` can declare more symbols than the imported table defines, so even if
+    /// this is not set, imports cannot grow a symbol table beyond
+    /// [`ReadLimits::DEFAULT_MAX_IMPORTED_SYMBOL_TABLE_SIZE`] symbols.
+    pub const fn with_max_symbol_table_size(mut self, symbols: usize) -> Self {
+        self.max_symbol_table_size = Some(symbols);
+        self
+    }
+
+    /// Returns the maximum number of bytes a streaming input source may buffer, if set.
+    pub const fn max_buffer_size(&self) -> Option<usize> {
+        self.max_buffer_size
+    }
+
+    /// Returns the maximum number of bytes in a single top-level stream item, if set.
+    pub const fn max_value_size(&self) -> Option<usize> {
+        self.max_value_size
+    }
+
+    /// Returns the maximum container nesting depth, if set.
+    pub const fn max_container_depth(&self) -> Option<usize> {
+        self.max_container_depth
+    }
+
+    /// Returns the maximum number of annotations on a single value, if set.
+    pub const fn max_annotations(&self) -> Option<usize> {
+        self.max_annotations
+    }
+
+    /// Returns the maximum number of symbols in the reader's symbol table, if set.
+    pub const fn max_symbol_table_size(&self) -> Option<usize> {
+        self.max_symbol_table_size
+    }
+
+    /// Returns an error naming `limit` if `actual` exceeds its configured `maximum`.
+    fn check(limit: ReadLimit, maximum: Option<usize>, actual: usize) -> IonResult<()> {
+        match maximum {
+            Some(maximum) if actual > maximum => IonResult::limit_exceeded(limit, maximum),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn check_value_size(&self, bytes: usize) -> IonResult<()> {
+        Self::check(ReadLimit::MaxValueSize, self.max_value_size, bytes)
+    }
+
+    pub(crate) fn check_container_depth(&self, depth: usize) -> IonResult<()> {
+        Self::check(
+            ReadLimit::MaxContainerDepth,
+            self.max_container_depth,
+            depth,
+        )
+    }
+
+    pub(crate) fn check_annotations(&self, annotations: usize) -> IonResult<()> {
+        Self::check(ReadLimit::MaxAnnotations, self.max_annotations, annotations)
+    }
+
+    pub(crate) fn check_symbol_table_size(&self, symbols: usize) -> IonResult<()> {
+        Self::check(
+            ReadLimit::MaxSymbolTableSize,
+            self.max_symbol_table_size,
+            symbols,
+        )
+    }
+
+    /// Returns the size of a symbol table with `symbols` symbols after importing `max_id` more,
+    /// or an error if that would exceed the maximum symbol table size (or, if none was set,
+    /// [`ReadLimits::DEFAULT_MAX_IMPORTED_SYMBOL_TABLE_SIZE`]).
+    pub(crate) fn check_import(&self, symbols: usize, max_id: usize) -> IonResult<usize> {
+        let maximum = self
+            .max_symbol_table_size
+            .unwrap_or(Self::DEFAULT_MAX_IMPORTED_SYMBOL_TABLE_SIZE);
+        match symbols.checked_add(max_id) {
+            Some(total) if total <= maximum => Ok(total),
+            _ => IonResult::limit_exceeded(ReadLimit::MaxSymbolTableSize, maximum),
+        }
+    }
+}
+
+/// Identifies one of the limits in [`ReadLimits`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ReadLimit {
+    /// See [`ReadLimits::with_max_buffer_size`].
+    MaxBufferSize,
+    /// See [`ReadLimits::with_max_value_size`].
+    MaxValueSize,
+    /// See [`ReadLimits::with_max_container_depth`].
+    MaxContainerDepth,
+    /// See [`ReadLimits::with_max_annotations`].
+    MaxAnnotations,
+    /// See [`ReadLimits::with_max_symbol_table_size`].
+    MaxSymbolTableSize,
+}
+
+impl Display for ReadLimit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ReadLimit::MaxBufferSize => "max_buffer_size",
+            ReadLimit::MaxValueSize => "max_value_size",
+            ReadLimit::MaxContainerDepth => "max_container_depth",
+            ReadLimit::MaxAnnotations => "max_annotations",
+            ReadLimit::MaxSymbolTableSize => "max_symbol_table_size",
+        };
+        f.write_str(name)
+    }
 }
 
 impl From<TextEncoding_1_0> for ReadConfig<TextEncoding_1_0> {
@@ -57,3 +411,224 @@ impl From<AnyEncoding> for ReadConfig<AnyEncoding> {
         ReadConfig::new(encoding)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::lazy::decoder::Decoder;
+    use crate::{
+        v1_0, AnyEncoding, Decimal, Element, ElementReader, IonError, IonResult, JsonNumberPolicy,
+        MapCatalog, ReadLimit, ReadLimits, Reader, Sequence, SharedSymbolTable,
+    };
+
+    fn read_all_with_limits(ion: &str, limits: ReadLimits) -> IonResult<Vec<Element>> {
+        let mut map_catalog = MapCatalog::new();
+        map_catalog.insert_table(SharedSymbolTable::new("shared", 1, ["a", "b", "c"])?);
+        let config = v1_0::Text.with_catalog(map_catalog).with_limits(limits);
+        Reader::new(config, ion)?.elements().collect()
+    }
+
+    #[rstest]
+    #[case::depth_ok("{a: [1, (2)]}", ReadLimits::new().with_max_container_depth(3))]
+    #[case::depth_scalar("1", ReadLimits::new().with_max_container_depth(0))]
+    #[case::annotations_ok("a::b::1", ReadLimits::new().with_max_annotations(2))]
+    #[case::nested_annotations_ok("[a::b::1]", ReadLimits::new().with_max_annotations(2))]
+    #[case::symbols_ok(
+        "$ion_symbol_table::{symbols: [\"x\", \"y\"]} x",
+        ReadLimits::new().with_max_symbol_table_size(12)
+    )]
+    #[case::import_ok(
+        "$ion_symbol_table::{imports: [{name: \"shared\", version: 1}]} a",
+        ReadLimits::new().with_max_symbol_table_size(13)
+    )]
+    fn within_limits(#[case] ion: &str, #[case] limits: ReadLimits) -> IonResult<()> {
+        let expected = read_all_with_limits(ion, ReadLimits::new())?;
+        assert_eq!(read_all_with_limits(ion, limits)?, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::depth("{a: [1, (2)]}", ReadLimits::new().with_max_container_depth(2), ReadLimit::MaxContainerDepth)]
+    #[case::empty_container_depth("[[[]]]", ReadLimits::new().with_max_container_depth(2), ReadLimit::MaxContainerDepth)]
+    #[case::containers_at_depth_zero("[]", ReadLimits::new().with_max_container_depth(0), ReadLimit::MaxContainerDepth)]
+    #[case::annotations("a::b::c::1", ReadLimits::new().with_max_annotations(2), ReadLimit::MaxAnnotations)]
+    #[case::nested_annotations("{x: a::b::c::1}", ReadLimits::new().with_max_annotations(2), ReadLimit::MaxAnnotations)]
+    #[case::container_annotations("a::b::c::[]", ReadLimits::new().with_max_annotations(2), ReadLimit::MaxAnnotations)]
+    #[case::symbols(
+        "$ion_symbol_table::{symbols: [\"x\", \"y\", \"z\"]} x",
+        ReadLimits::new().with_max_symbol_table_size(12),
+        ReadLimit::MaxSymbolTableSize
+    )]
+    #[case::appended_symbols(
+        "$ion_symbol_table::{symbols: [\"x\", \"y\"]} $ion_symbol_table::{imports: $ion_symbol_table, symbols: [\"z\"]} x",
+        ReadLimits::new().with_max_symbol_table_size(12),
+        ReadLimit::MaxSymbolTableSize
+    )]
+    #[case::import(
+        "$ion_symbol_table::{imports: [{name: \"shared\", version: 1}]} a",
+        ReadLimits::new().with_max_symbol_table_size(12),
+        ReadLimit::MaxSymbolTableSize
+    )]
+    #[case::import_max_id(
+        "$ion_symbol_table::{imports: [{name: \"shared\", version: 1, max_id: 1000000000000}]} a",
+        ReadLimits::new().with_max_symbol_table_size(1000),
+        ReadLimit::MaxSymbolTableSize
+    )]
+    fn exceeds_limits(#[case] ion: &str, #[case] limits: ReadLimits, #[case] expected: ReadLimit) {
+        match read_all_with_limits(ion, limits) {
+            Err(IonError::LimitExceeded(error)) => {
+                assert_eq!(error.limit(), expected);
+                assert!(error.to_string().contains(&expected.to_string()));
+            }
+            other => panic!("expected the {expected} limit to be exceeded, found {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case::lists("", "[", "]")]
+    #[case::structs("", "{a:", "}")]
+    #[cfg_attr(
+        feature = "experimental-ion-1-1",
+        case::e_expressions("$ion_1_1 ", "(:values ", ")")
+    )]
+    fn deeply_nested_text_exceeds_depth_limit(
+        #[case] prefix: &str,
+        #[case] open: &str,
+        #[case] close: &str,
+    ) -> IonResult<()> {
+        // Without the limit, matching this input would recurse once per level and overflow the
+        // stack. With it, the reader stops as soon as it finds the 11th level.
+        let ion = prefix.to_owned() + &open.repeat(100_000) + &close.repeat(100_000);
+        let limits = ReadLimits::new().with_max_container_depth(10);
+        let mut reader = Reader::new(AnyEncoding.with_limits(limits), ion)?;
+        match reader.next() {
+            Err(IonError::LimitExceeded(error)) => {
+                assert_eq!(error.limit(), ReadLimit::MaxContainerDepth)
+            }
+            other => panic!("expected the depth limit to be exceeded, found {other:?}"),
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn deeply_nested_binary_1_1_exceeds_depth_limit() -> IonResult<()> {
+        use crate::v1_1;
+        // Finding the end of a delimited list requires scanning its contents.
+        let mut ion = vec![0xE0, 0x01, 0x01, 0xEA];
+        ion.extend(std::iter::repeat(0xF1).take(100_000));
+        ion.extend(std::iter::repeat(0xF0).take(100_000));
+        let limits = ReadLimits::new().with_max_container_depth(10);
+        let mut reader = Reader::new(v1_1::Binary.with_limits(limits), ion)?;
+        match reader.next() {
+            Err(IonError::LimitExceeded(error)) => {
+                assert_eq!(error.limit(), ReadLimit::MaxContainerDepth)
+            }
+            other => panic!("expected the depth limit to be exceeded, found {other:?}"),
+        }
+        Ok(())
+    }
+
+    fn read_all_as_json(json: &str, policy: JsonNumberPolicy) -> IonResult<Sequence> {
+        let mut reader = Reader::new(v1_0::Text.with_json_decoding(policy), json)?;
+        reader.read_all_elements()
+    }
+
+    #[rstest]
+    #[case::null("null", "null")]
+    #[case::bools("true false", "true false")]
+    #[case::ints("0 -0 42 -17", "0 0 42 -17")]
+    #[case::big_int("123456789012345678901234567890", "123456789012345678901234567890")]
+    #[case::decimals("1.5 -0.25", "1.5 -0.25")]
+    #[case::floats("1e3 -2.5E-2 1e+2", "1e3 -2.5e-2 1e2")]
+    #[case::strings(r#""hi" "a\"b\\c\/d\b\f\n\r\té""#, r#""hi" "a\"b\\c/d\b\f\n\r\té""#)]
+    #[case::arrays("[1, [2, 3], {\"a\": 4}]", "[1, [2, 3], {a: 4}]")]
+    #[case::empty_containers("[] {}", "[] {}")]
+    #[case::objects("{\"a\": 1, \"b\": [true]}", "{a: 1, b: [true]}")]
+    #[case::whitespace(" \t\r\n 1 \n", "1")]
+    #[case::adjacent_containers("[1][2]{}", "[1] [2] {}")]
+    fn read_valid_json(#[case] json: &str, #[case] expected: &str) -> IonResult<()> {
+        let actual = read_all_as_json(json, JsonNumberPolicy::IonText)?;
+        assert_eq!(actual, Element::read_all(expected)?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::annotation("a::1", "annotations are not valid JSON")]
+    #[case::symbol("foo", "symbols are not valid JSON")]
+    #[case::quoted_symbol("'foo'", "symbols are not valid JSON")]
+    #[case::sexp("(1 2)", "s-expressions are not valid JSON")]
+    #[case::blob("{{aGVsbG8=}}", "blobs are not valid JSON")]
+    #[case::clob("{{\"hi\"}}", "clobs are not valid JSON")]
+    #[case::line_comment("// c\n1", "comments are not valid JSON")]
+    #[case::block_comment("/* c */ 1", "comments are not valid JSON")]
+    #[case::typed_null("null.int", "typed nulls are not valid JSON")]
+    #[case::nan("nan", "this number syntax is not valid JSON")]
+    #[case::positive_infinity("+inf", "this number syntax is not valid JSON")]
+    #[case::timestamp("2020-01-01T", "timestamps are not valid JSON")]
+    #[case::ion_decimal("1.5d3", "this number syntax is not valid JSON")]
+    #[case::hex_int("0x10", "this number syntax is not valid JSON")]
+    #[case::underscores("1_000", "this number syntax is not valid JSON")]
+    #[case::missing_fraction_digits("1.", "this number syntax is not valid JSON")]
+    #[case::trailing_list_comma("[1, 2,]", "trailing commas are not valid JSON")]
+    #[case::trailing_struct_comma("{\"a\": 1,}", "trailing commas are not valid JSON")]
+    #[case::unquoted_field_name("{a: 1}", "field names must be JSON strings")]
+    #[case::single_quoted_field_name("{'a': 1}", "field names must be JSON strings")]
+    #[case::ion_escape(r#""\x41""#, "this escape sequence is not valid JSON")]
+    #[case::long_string("'''long'''", "long strings are not valid JSON")]
+    #[case::nested_number("[1, nan]", "this number syntax is not valid JSON")]
+    #[case::nested_symbol("{\"a\": foo}", "symbols are not valid JSON")]
+    fn reject_non_json(#[case] json: &str, #[case] expected_description: &str) {
+        match read_all_as_json(json, JsonNumberPolicy::IonText) {
+            Err(IonError::Decoding(error)) => {
+                assert!(
+                    error.to_string().contains(expected_description),
+                    "unexpected error for {json:?}: {error}"
+                )
+            }
+            other => panic!("expected a decoding error for {json:?}, found {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case::leading_zero("012")]
+    #[case::missing_integral_digits(".5")]
+    #[case::missing_exponent_digits("1e")]
+    #[case::unescaped_newline("\"a\nb\"")]
+    #[case::top_level_comma("1, 2")]
+    fn reject_invalid_syntax(#[case] json: &str) {
+        assert!(read_all_as_json(json, JsonNumberPolicy::IonText).is_err());
+    }
+
+    #[rstest]
+    #[case::ion_text(JsonNumberPolicy::IonText, "[1, 1.50, 15e-1, 2.0, 2e0, -0.0]")]
+    #[case::always_float(JsonNumberPolicy::AlwaysFloat, "[1e0, 1.5e0, 1.5e0, 2e0, 2e0, -0e0]")]
+    #[case::decimal_when_fractional(
+        JsonNumberPolicy::DecimalWhenFractional,
+        "[1, 1.50, 15d-1, 2.0, 2d0, -0.0]"
+    )]
+    #[case::int_when_integral(JsonNumberPolicy::IntWhenIntegral, "[1, 1.50, 15d-1, 2, 2, 0]")]
+    fn json_number_policies(
+        #[case] policy: JsonNumberPolicy,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let actual = read_all_as_json("[1, 1.50, 15e-1, 2.0, 2e0, -0.0]", policy)?;
+        assert_eq!(actual, Element::read_all(expected)?);
+        Ok(())
+    }
+
+    #[test]
+    fn int_when_integral_large_values() -> IonResult<()> {
+        let json = "1.2e30 1e5000 25e-1 10e9223372036854775807";
+        let actual = read_all_as_json(json, JsonNumberPolicy::IntWhenIntegral)?;
+        let expected: Vec<Element> = vec![
+            Element::read_one("1200000000000000000000000000000")?,
+            Element::read_one("1d5000")?,
+            Element::read_one("25d-1")?,
+            Decimal::new(10, i64::MAX).into(),
+        ];
+        assert_eq!(actual, Sequence::from(expected));
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::ReadLimit;
+use thiserror::Error;
+
+/// Indicates that reading the input would have exceeded one of the limits configured in the
+/// reader's [`ReadLimits`](crate::ReadLimits).
+#[derive(Clone, Debug, Error, PartialEq)]
+#[error("exceeded the reader's {limit} limit of {maximum}")]
+pub struct LimitExceededError {
+    limit: ReadLimit,
+    maximum: usize,
+}
+
+impl LimitExceededError {
+    pub(crate) fn new(limit: ReadLimit, maximum: usize) -> Self {
+        LimitExceededError { limit, maximum }
+    }
+
+    /// Returns the limit that was exceeded.
+    pub fn limit(&self) -> ReadLimit {
+        self.limit
+    }
+
+    /// Returns the configured value of the limit that was exceeded.
+    pub fn maximum(&self) -> usize {
+        self.maximum
+    }
+}
@@ -15,14 +15,17 @@ mod encoding_error;
 mod illegal_operation;
 mod incomplete;
 mod io_error;
+mod limit_exceeded;
 
 pub use decoding_error::DecodingError;
 pub use encoding_error::EncodingError;
 pub use illegal_operation::IllegalOperation;
 pub use incomplete::IncompleteError;
 pub use io_error::IoError;
+pub use limit_exceeded::LimitExceededError;
 
 use crate::position::Position;
+use crate::ReadLimit;
 
 /// A unified Result type representing the outcome of method calls that may fail.
 pub type IonResult<T> = Result<T, IonError>;
@@ -53,6 +56,11 @@ pub enum IonError {
     /// on the cursor at the top level.)
     #[error("{0}")]
     IllegalOperation(#[from] IllegalOperation),
+
+    /// Indicates that reading the input would have exceeded one of the resource limits
+    /// configured in the reader's [`ReadLimits`](crate::ReadLimits).
+    #[error("{0}")]
+    LimitExceeded(#[from] LimitExceededError),
 }
 
 impl From<io::Error> for IonError {
@@ -117,6 +125,7 @@ pub(crate) trait IonFailure {
     fn decoding_error<S: Into<Cow<'static, str>>>(description: S) -> Self;
     fn encoding_error<S: Into<Cow<'static, str>>>(description: S) -> Self;
     fn illegal_operation<S: Into<Cow<'static, str>>>(operation: S) -> Self;
+    fn limit_exceeded(limit: ReadLimit, maximum: usize) -> Self;
 }
 
 impl IonFailure for IonError {
@@ -135,6 +144,10 @@ impl IonFailure for IonError {
     fn illegal_operation<S: Into<Cow<'static, str>>>(operation: S) -> Self {
         IllegalOperation::new(operation).into()
     }
+
+    fn limit_exceeded(limit: ReadLimit, maximum: usize) -> Self {
+        LimitExceededError::new(limit, maximum).into()
+    }
 }
 
 impl<T> IonFailure for IonResult<T> {
@@ -153,4 +166,8 @@ impl<T> IonFailure for IonResult<T> {
     fn illegal_operation<S: Into<Cow<'static, str>>>(operation: S) -> Self {
         Err(IonError::illegal_operation(operation))
     }
+
+    fn limit_exceeded(limit: ReadLimit, maximum: usize) -> Self {
+        Err(IonError::limit_exceeded(limit, maximum))
+    }
 }
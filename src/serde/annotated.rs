@@ -0,0 +1,198 @@
+use serde::de::{SeqAccess, Visitor};
+use serde::ser::SerializeTupleStruct;
+use serde::{self, de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+use std::fmt;
+use std::marker::PhantomData;
+
+pub(crate) const TUNNELED_ANNOTATED_TYPE_NAME: &str = "$__ion_rs_annotated__";
+
+/// A value together with the Ion annotations that precede it, like the `circle` in
+/// `circle::{radius: 2}`.
+///
+/// `Annotated<T>` can be used anywhere a `T` could be: as the top-level value, as a struct field,
+/// as a list element, and so on. When it is deserialized, it captures the text of each of the
+/// value's annotations in order; when it is serialized, it writes them before the value.
+/// Annotations with unknown text (like `$0`) cannot be deserialized.
+///
+/// Because the value's annotations are captured by the `Annotated<T>`, `T` should not be an enum
+/// whose variants are represented as annotations (newtype, tuple, and struct variants).
+///
+/// ```
+/// use ion_rs::serde::{from_ion, to_string, Annotated};
+/// use ion_rs::IonResult;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Circle {
+///     radius: u32,
+/// }
+///
+/// fn main() -> IonResult<()> {
+///     let circle: Annotated<Circle> = from_ion("shape::circle::{radius: 2}")?;
+///     assert_eq!(circle.annotations, ["shape", "circle"]);
+///     assert_eq!(circle.value, Circle { radius: 2 });
+///
+///     assert_eq!(to_string(&circle)?, "shape::circle::{radius: 2, } ");
+///     Ok(())
+/// }
+/// ```
+///
+/// _Note: Since the serde framework doesn't support Ion annotations, this type is serialized and
+/// deserialized as a `tuple_struct` named `$__ion_rs_annotated__` with two fields: the list of
+/// annotations and the value. Other data formats will treat it as a two-element sequence, like
+/// `[["shape", "circle"], {"radius": 2}]` in JSON._
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Annotated<T> {
+    /// The text of each of the value's annotations, in order.
+    pub annotations: Vec<String>,
+    pub value: T,
+}
+
+impl<T> Annotated<T> {
+    pub fn new<A: Into<String>>(annotations: impl IntoIterator<Item = A>, value: T) -> Self {
+        Self {
+            annotations: annotations.into_iter().map(Into::into).collect(),
+            value,
+        }
+    }
+
+    /// Returns `true` if any of the value's annotations has the given text.
+    pub fn has_annotation(&self, annotation: &str) -> bool {
+        self.annotations.iter().any(|a| a == annotation)
+    }
+}
+
+impl<T: Serialize> Serialize for Annotated<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple_struct =
+            serializer.serialize_tuple_struct(TUNNELED_ANNOTATED_TYPE_NAME, 2)?;
+        tuple_struct.serialize_field(&self.annotations)?;
+        tuple_struct.serialize_field(&self.value)?;
+        tuple_struct.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Annotated<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct AnnotatedVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for AnnotatedVisitor<T> {
+            type Value = Annotated<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("an annotated Ion value")
+            }
+
+            // The Ion deserializer presents an annotated value in the same shape that it is
+            // serialized: a sequence of two elements, the annotations and the value itself.
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let annotations = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let value = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                Ok(Annotated { annotations, value })
+            }
+        }
+
+        deserializer.deserialize_tuple_struct(
+            TUNNELED_ANNOTATED_TYPE_NAME,
+            2,
+            AnnotatedVisitor(PhantomData),
+        )
+    }
+}
+
+/// Names the annotation that identifies a type in Ion data, like the `circle` in
+/// `circle::{radius: 2}`. See [`Tagged`].
+pub trait AnnotationTag {
+    const ANNOTATION: &'static str;
+}
+
+/// Serializes a value whose type implements [`AnnotationTag`] with its tag as an annotation, and
+/// requires the tag to be the value's first annotation when it is deserialized.
+///
+/// `Tagged` is used with the `serde_with` crate's `serde_as` field attribute:
+///
+/// ```
+/// use ion_rs::serde::{from_ion, to_string, AnnotationTag, Tagged};
+/// use ion_rs::IonResult;
+/// use serde::{Deserialize, Serialize};
+/// use serde_with::serde_as;
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Circle {
+///     radius: u32,
+/// }
+///
+/// impl AnnotationTag for Circle {
+///     const ANNOTATION: &'static str = "circle";
+/// }
+///
+/// #[serde_as]
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Drawing {
+///     #[serde_as(as = "Tagged")]
+///     shape: Circle,
+///     #[serde_as(as = "Vec<Tagged>")]
+///     others: Vec<Circle>,
+/// }
+///
+/// fn main() -> IonResult<()> {
+///     let drawing: Drawing = from_ion("{shape: circle::{radius: 2}, others: []}")?;
+///     assert_eq!(drawing.shape, Circle { radius: 2 });
+///     assert_eq!(to_string(&drawing)?, "{shape: circle::{radius: 2, }, others: [], } ");
+///
+///     // A value without the tag is rejected
+///     assert!(from_ion::<Drawing, _>("{shape: square::{radius: 2}, others: []}").is_err());
+///     Ok(())
+/// }
+/// ```
+pub struct Tagged;
+
+impl<T> SerializeAs<T> for Tagged
+where
+    T: AnnotationTag + Serialize,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Annotated {
+            annotations: vec![T::ANNOTATION.to_owned()],
+            value: source,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T> DeserializeAs<'de, T> for Tagged
+where
+    T: AnnotationTag + Deserialize<'de>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let annotated = Annotated::<T>::deserialize(deserializer)?;
+        match annotated.annotations.first() {
+            Some(annotation) if annotation == T::ANNOTATION => Ok(annotated.value),
+            _ => Err(de::Error::custom(format!(
+                "expected a value annotated with '{}', found annotations {:?}",
+                T::ANNOTATION,
+                annotated.annotations
+            ))),
+        }
+    }
+}
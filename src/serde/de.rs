@@ -1,4 +1,5 @@
 use serde::de;
+use serde::de::value::SeqDeserializer;
 use serde::de::{DeserializeOwned, DeserializeSeed, EnumAccess, MapAccess, SeqAccess, Visitor};
 
 use crate::lazy::any_encoding::AnyEncoding;
@@ -7,6 +8,7 @@ use crate::lazy::streaming_raw_reader::IonInput;
 use crate::lazy::value::LazyValue;
 use crate::lazy::value_ref::ValueRef;
 use crate::result::IonFailure;
+use crate::serde::annotated::TUNNELED_ANNOTATED_TYPE_NAME;
 use crate::serde::decimal::TUNNELED_DECIMAL_TYPE_NAME;
 use crate::serde::timestamp::TUNNELED_TIMESTAMP_TYPE_NAME;
 use crate::{
@@ -382,13 +384,16 @@ impl<'a, 'de> de::Deserializer<'de> for ValueDeserializer<'a, 'de> {
 
     fn deserialize_tuple_struct<V>(
         self,
-        _name: &'static str,
+        name: &'static str,
         _len: usize,
         visitor: V,
     ) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
+        if name == TUNNELED_ANNOTATED_TYPE_NAME {
+            return visitor.visit_seq(AnnotatedValueAccess::new(self));
+        }
         self.deserialize_as_sequence(visitor)
     }
 
@@ -477,6 +482,54 @@ where
     }
 }
 
+/// Presents an annotated value to [`Annotated`](crate::serde::Annotated) as a sequence of two
+/// elements: the text of the value's annotations and the value itself.
+struct AnnotatedValueAccess<'a, 'de> {
+    deserializer: ValueDeserializer<'a, 'de>,
+    elements_read: usize,
+}
+
+impl<'a, 'de> AnnotatedValueAccess<'a, 'de> {
+    fn new(deserializer: ValueDeserializer<'a, 'de>) -> Self {
+        Self {
+            deserializer,
+            elements_read: 0,
+        }
+    }
+
+    fn annotations(&self) -> IonResult<Vec<String>> {
+        self.deserializer
+            .value
+            .annotations()
+            .map(|annotation| {
+                annotation?.text().map(str::to_owned).ok_or_else(|| {
+                    IonError::decoding_error("found an annotation with unknown text")
+                })
+            })
+            .collect()
+    }
+}
+
+impl<'a, 'de> SeqAccess<'de> for AnnotatedValueAccess<'a, 'de> {
+    type Error = IonError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let element = match self.elements_read {
+            0 => {
+                let annotations = self.annotations()?;
+                seed.deserialize(SeqDeserializer::<_, IonError>::new(annotations.into_iter()))?
+            }
+            1 => seed.deserialize(self.deserializer)?,
+            _ => return Ok(None),
+        };
+        self.elements_read += 1;
+        Ok(Some(element))
+    }
+}
+
 struct StructAsMap<'de> {
     iter: StructIterator<'de, AnyEncoding>,
     current_field: Option<LazyField<'de, AnyEncoding>>,
@@ -3,8 +3,9 @@
 //! This module offers APIs for serialization of Rust data structures into Ion data and deserialization
 //! of Ion data into Rust data structures. The APIs use the `serde` framework for serialization and
 //! deserialization. See [the Serde website](https://serde.rs/) for additional documentation and
-//! usage examples. This feature doesn't yet support [Ion annotations] and [Ion SExpressions] for
-//! serialization and deserialization.
+//! usage examples. This feature doesn't yet support [Ion SExpressions] for serialization and
+//! deserialization. [Ion annotations] are supported through the [`Annotated`] and [`Tagged`] types;
+//! see [Annotations](#annotations).
 //!
 //! There are three different APIs for serializing Ion data:
 //!
@@ -30,6 +31,7 @@
 //!| struct        | struct                               | struct                                                |
 //!| list          | vector                               | seq                                                   |
 //!| null          | None                                 | unit                                                  |
+//!| annotations   | `Annotated<T>`                       | tuple_struct (with name as `$__ion_rs_annotated__`)   |
 //!
 //! ## Mapping of serde data types to Ion representation
 //!
@@ -53,6 +55,56 @@
 //! implementation of decimal and timestamp serialization and deserialization. If one wants to use [chrono::DateTime],
 //! it needs to be tagged with `#[serde_as(as = crate::Timestamp)]`._
 //!
+//! ## Annotations
+//!
+//! The serde data model has no equivalent of Ion annotations, so annotations are ignored by default
+//! (apart from enum variants, which are represented as annotations). There are two ways to read and
+//! write them:
+//!
+//! * [`Annotated<T>`](Annotated) captures the text of all of a value's annotations when it is
+//!   deserialized, and writes them when it is serialized.
+//! * The `#[serde_as(as = "Tagged")]` field attribute writes a type tag, named by the field type's
+//!   [`AnnotationTag`] implementation, as an annotation, and requires the value to be annotated
+//!   with it when it is deserialized.
+//!
+//!```
+//! use ion_rs::serde::{from_ion, to_string, Annotated, AnnotationTag, Tagged};
+//! use ion_rs::IonResult;
+//! use serde::{Deserialize, Serialize};
+//! use serde_with::serde_as;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Circle {
+//!     radius: u32,
+//! }
+//!
+//! impl AnnotationTag for Circle {
+//!     const ANNOTATION: &'static str = "circle";
+//! }
+//!
+//! #[serde_as]
+//! #[derive(Serialize, Deserialize)]
+//! struct Drawing {
+//!     #[serde_as(as = "Tagged")]
+//!     shape: Circle,
+//!     color: Annotated<String>,
+//! }
+//!
+//! fn main() -> IonResult<()> {
+//!     let drawing: Drawing = from_ion(r#"{shape: circle::{radius: 2}, color: rgb::"red"}"#)?;
+//!     assert_eq!(drawing.shape.radius, 2);
+//!     assert_eq!(drawing.color.annotations, ["rgb"]);
+//!     assert_eq!(drawing.color.value, "red");
+//!
+//!     let ion = to_string(&drawing)?;
+//!     assert_eq!(r#"{shape: circle::{radius: 2, }, color: rgb::"red", } "#, ion);
+//!
+//!     // The `shape` field must be annotated with `circle`
+//!     assert!(from_ion::<Drawing, _>(r#"{shape: {radius: 2}, color: "red"}"#).is_err());
+//!     Ok(())
+//! }
+//! ```
+//!
 //! ## Example of serialization of Rust struct into Ion data
 //! ```
 //! use ion_rs::IonResult;
@@ -193,18 +245,22 @@
 //! [Ion timestamp]: https://amazon-ion.github.io/ion-docs/docs/spec.html#timestamp
 //! [serde data model]: https://serde.rs/data-model.html#types
 
+mod annotated;
 pub mod de;
 mod decimal;
 pub mod ser;
 mod timestamp;
 
+pub use annotated::{Annotated, AnnotationTag, Tagged};
 pub use de::from_ion;
 pub use ser::{to_binary, to_pretty, to_string};
 
 #[cfg(test)]
 #[cfg(feature = "experimental-serde")]
 mod tests {
-    use crate::serde::{from_ion, to_binary, to_pretty, to_string};
+    use crate::serde::{
+        from_ion, to_binary, to_pretty, to_string, Annotated, AnnotationTag, Tagged,
+    };
     use std::net::IpAddr;
 
     use crate::{Decimal, Element, Timestamp};
@@ -262,8 +318,8 @@ mod tests {
             float: 3.46,
             binary: b"EDO".to_vec(),
             seq: vec!["a".to_string(), "b".to_string()],
-            decimal: my_decimal,
-            date: my_date,
+            decimal: my_decimal.clone(),
+            date: my_date.clone(),
             date0: my_date0,
             date1: datetime,
             nested_struct: NestedTest {
@@ -343,6 +399,102 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_annotations() {
+        #[serde_as]
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Point {
+            x: i64,
+            y: Annotated<Decimal>,
+        }
+
+        impl AnnotationTag for Point {
+            const ANNOTATION: &'static str = "point";
+        }
+
+        #[serde_as]
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Path {
+            #[serde_as(as = "Tagged")]
+            start: Point,
+            #[serde_as(as = "Vec<Tagged>")]
+            points: Vec<Point>,
+            labels: Vec<Annotated<String>>,
+        }
+
+        let i = r#"
+            {
+                start: point::{x: 1, y: meters::2.5},
+                points: [point::unit::{x: 2, y: 3.0}],
+                labels: [en::"start", "end"],
+            }
+        "#;
+        let expected = Path {
+            start: Point {
+                x: 1,
+                y: Annotated::new(["meters"], Decimal::new(25, -1)),
+            },
+            points: vec![Point {
+                x: 2,
+                y: Annotated::new(Vec::<String>::new(), Decimal::new(30, -1)),
+            }],
+            labels: vec![
+                Annotated::new(["en"], "start".to_owned()),
+                Annotated::new(Vec::<String>::new(), "end".to_owned()),
+            ],
+        };
+        assert_eq!(expected, from_ion(i).unwrap());
+
+        // Only the tag is written, so the `unit` annotation is not round-tripped.
+        let round_tripped = r#"
+            {
+                start: point::{x: 1, y: meters::2.5},
+                points: [point::{x: 2, y: 3.0}],
+                labels: [en::"start", "end"],
+            }
+        "#;
+        assert_eq!(
+            Element::read_first(round_tripped),
+            Element::read_first(to_string(&expected).unwrap())
+        );
+        assert_eq!(
+            expected,
+            from_ion::<Path, _>(to_binary(&expected).unwrap()).unwrap()
+        );
+
+        // A tagged value's first annotation must be the tag.
+        let missing_tag = r#"{start: {x: 1, y: 2.5}, points: [], labels: []}"#;
+        assert!(from_ion::<Path, _>(missing_tag).is_err());
+        let wrong_tag =
+            r#"{start: point::{x: 1, y: 2.5}, points: [unit::point::{x: 2, y: 3.0}], labels: []}"#;
+        assert!(from_ion::<Path, _>(wrong_tag).is_err());
+
+        // Annotations with unknown text can't be deserialized.
+        assert!(from_ion::<Annotated<i64>, _>("$0::5").is_err());
+
+        let annotated: Annotated<Vec<u32>> = from_ion("a::b::c::[1, 2]").unwrap();
+        assert_eq!(annotated.annotations, ["a", "b", "c"]);
+        assert!(annotated.has_annotation("b"));
+        assert!(!annotated.has_annotation("d"));
+        assert_eq!(to_string(&annotated).unwrap(), "a::b::c::[1, 2, ] ");
+    }
+
+    #[test]
+    fn annotations_round_trip_through_other_formats() {
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Circle {
+            radius: u32,
+        }
+
+        let circle = Annotated::new(["shape", "circle"], Circle { radius: 2 });
+        let json = serde_json::to_string(&circle).unwrap();
+        assert_eq!(json, r#"[["shape","circle"],{"radius":2}]"#);
+        assert_eq!(
+            serde_json::from_str::<Annotated<Circle>>(&json).unwrap(),
+            circle
+        );
+    }
+
     #[test]
     fn test_symbol() {
         let i = r#"inches"#;
@@ -9,6 +9,7 @@ use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter, ValueWrit
 use crate::lazy::encoder::writer::Writer;
 use crate::lazy::encoding::{BinaryEncoding_1_0, Encoding, TextEncoding_1_0};
 use crate::result::IonFailure;
+use crate::serde::annotated::TUNNELED_ANNOTATED_TYPE_NAME;
 use crate::serde::decimal::TUNNELED_DECIMAL_TYPE_NAME;
 use crate::serde::timestamp::TUNNELED_TIMESTAMP_TYPE_NAME;
 use crate::symbol_ref::AsSymbolRef;
@@ -87,7 +88,7 @@ impl<'a, V: ValueWriter + 'a> ser::Serializer for ValueSerializer<'a, V> {
 
     type SerializeSeq = SeqWriter<V>;
     type SerializeTuple = SeqWriter<V>;
-    type SerializeTupleStruct = SeqWriter<V>;
+    type SerializeTupleStruct = TupleStructWriter<V>;
     type SerializeTupleVariant = SeqWriter<V::AnnotatedValueWriter<'a>>;
     type SerializeMap = MapWriter<V>;
     type SerializeStruct = MapWriter<V>;
@@ -257,13 +258,21 @@ impl<'a, V: ValueWriter + 'a> ser::Serializer for ValueSerializer<'a, V> {
 
     fn serialize_tuple_struct(
         self,
-        _name: &'static str,
+        name: &'static str,
         _len: usize,
     ) -> Result<Self::SerializeTupleStruct, Self::Error> {
-        Ok(SeqWriter {
+        if name == TUNNELED_ANNOTATED_TYPE_NAME {
+            // The fields of an `Annotated` value are its annotations and then the value itself.
+            return Ok(TupleStructWriter(TupleStructKind::Annotated {
+                value_writer: Some(self.value_writer),
+                annotations: None,
+                is_human_readable: self.is_human_readable,
+            }));
+        }
+        Ok(TupleStructWriter(TupleStructKind::List(SeqWriter {
             seq_writer: self.value_writer.list_writer()?,
             is_human_readable: self.is_human_readable,
-        })
+        })))
     }
 
     fn serialize_tuple_variant(
@@ -370,7 +379,22 @@ impl<V: ValueWriter> ser::SerializeTuple for SeqWriter<V> {
     }
 }
 
-impl<V: ValueWriter> ser::SerializeTupleStruct for SeqWriter<V> {
+/// Serializes a tuple struct as a list or, if it is an [`Annotated`](crate::serde::Annotated)
+/// value, as the annotated value.
+pub struct TupleStructWriter<V: ValueWriter>(TupleStructKind<V>);
+
+enum TupleStructKind<V: ValueWriter> {
+    List(SeqWriter<V>),
+    Annotated {
+        // This is `None` once the value has been written.
+        value_writer: Option<V>,
+        // This is `None` until the annotations have been serialized.
+        annotations: Option<Vec<String>>,
+        is_human_readable: bool,
+    },
+}
+
+impl<V: ValueWriter> ser::SerializeTupleStruct for TupleStructWriter<V> {
     type Ok = ();
     type Error = IonError;
 
@@ -378,12 +402,44 @@ impl<V: ValueWriter> ser::SerializeTupleStruct for SeqWriter<V> {
     where
         T: ?Sized + Serialize,
     {
-        let is_human_readable = self.is_human_readable;
-        value.serialize(ValueSerializer::new(self.value_writer(), is_human_readable))
+        match &mut self.0 {
+            TupleStructKind::List(seq_writer) => {
+                let is_human_readable = seq_writer.is_human_readable;
+                value.serialize(ValueSerializer::new(
+                    seq_writer.value_writer(),
+                    is_human_readable,
+                ))
+            }
+            TupleStructKind::Annotated {
+                value_writer,
+                annotations,
+                is_human_readable,
+            } => {
+                let Some(annotations) = annotations else {
+                    *annotations = Some(value.serialize(AnnotationsSerializer {})?);
+                    return Ok(());
+                };
+                let Some(value_writer) = value_writer.take() else {
+                    return IonResult::encoding_error("an annotated value can only have one value");
+                };
+                let annotations: Vec<&str> = annotations.iter().map(String::as_str).collect();
+                value.serialize(ValueSerializer::new(
+                    value_writer.with_annotations(annotations)?,
+                    *is_human_readable,
+                ))
+            }
+        }
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        self.seq_writer.close()
+        match self.0 {
+            TupleStructKind::List(seq_writer) => seq_writer.seq_writer.close(),
+            TupleStructKind::Annotated {
+                value_writer: Some(_),
+                ..
+            } => IonResult::encoding_error("an annotated value must have a value"),
+            TupleStructKind::Annotated { .. } => Ok(()),
+        }
     }
 }
 
@@ -667,3 +723,220 @@ impl ser::Serializer for MapKeySerializer {
         Err(key_must_be_a_string())
     }
 }
+
+/// Serializes the annotations of an [`Annotated`](crate::serde::Annotated) value, which are a
+/// sequence of strings, as the text of each annotation.
+struct AnnotationsSerializer {}
+
+fn annotations_must_be_a_sequence() -> IonError {
+    IonError::encoding_error("the annotations of an annotated value must be a sequence of strings")
+}
+
+impl ser::Serializer for AnnotationsSerializer {
+    type Ok = Vec<String>;
+    type Error = IonError;
+
+    type SerializeSeq = AnnotationsWriter;
+    type SerializeTuple = AnnotationsWriter;
+    type SerializeTupleStruct = Impossible<Vec<String>, IonError>;
+    type SerializeTupleVariant = Impossible<Vec<String>, IonError>;
+    type SerializeMap = Impossible<Vec<String>, IonError>;
+    type SerializeStruct = Impossible<Vec<String>, IonError>;
+    type SerializeStructVariant = Impossible<Vec<String>, IonError>;
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(AnnotationsWriter {
+            annotations: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_i16(self, _v: i16) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_i32(self, _v: i32) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_i64(self, _v: i64) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_u8(self, _v: u8) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_u16(self, _v: u16) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_u64(self, _v: u64) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_char(self, _v: char) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_str(self, _v: &str) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_some<T>(self, _value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(annotations_must_be_a_sequence())
+    }
+}
+
+struct AnnotationsWriter {
+    annotations: Vec<String>,
+}
+
+impl ser::SerializeSeq for AnnotationsWriter {
+    type Ok = Vec<String>;
+    type Error = IonError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        // Like field names, annotations must be serialized as text.
+        self.annotations.push(value.serialize(MapKeySerializer {})?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.annotations)
+    }
+}
+
+impl ser::SerializeTuple for AnnotationsWriter {
+    type Ok = Vec<String>;
+    type Error = IonError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
@@ -1,6 +1,10 @@
 use crate::element::Element;
+use crate::lazy::decoder::Decoder;
+use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter, ValueWriter};
+use crate::lazy::encoder::write_as_ion::WriteAsIon;
+use crate::lazy::value::LazyValue;
 use crate::result::IonFailure;
-use crate::{ion_seq, IonResult};
+use crate::{ion_seq, IonResult, IonType};
 use crate::{Int, IonError, Symbol};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -117,11 +121,45 @@ impl TryFrom<Element> for SharedSymbolTable {
     }
 }
 
+/// Reads a shared symbol table from its serialized form, as found by a
+/// [`Reader`](crate::Reader) in a catalog or config file.
+impl<D: Decoder> TryFrom<LazyValue<'_, D>> for SharedSymbolTable {
+    type Error = IonError;
+
+    fn try_from(sst_value: LazyValue<'_, D>) -> Result<Self, Self::Error> {
+        Element::try_from(sst_value)?.try_into()
+    }
+}
+
+/// Writes this table in its serialized form, a struct annotated with `$ion_shared_symbol_table`.
+/// Symbols with unknown text are written as `null.string`, which reserves their symbol IDs.
+impl WriteAsIon for SharedSymbolTable {
+    fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+        let mut table = writer
+            .with_annotations("$ion_shared_symbol_table")?
+            .struct_writer()?;
+        table
+            .write("name", self.name())?
+            .write("version", self.version())?;
+        let mut symbols = table.field_writer("symbols").list_writer()?;
+        for symbol in self.symbols() {
+            let value_writer = symbols.value_writer();
+            match symbol.text() {
+                Some(text) => value_writer.write_string(text)?,
+                None => value_writer.write_null(IonType::String)?,
+            }
+        }
+        symbols.close()?;
+        table.close()
+    }
+}
+
 #[cfg(test)]
 mod shared_symbol_table_tests {
     use crate::element::Element;
+    use crate::lazy::encoder::write_as_ion::WriteAsIon;
     use crate::shared_symbol_table::SharedSymbolTable;
-    use crate::{IonResult, Symbol};
+    use crate::{v1_0, IonResult, Reader, Symbol};
 
     #[test]
     fn shared_symbol_table_read_test() -> IonResult<()> {
@@ -147,4 +185,49 @@ mod shared_symbol_table_tests {
         assert_eq!(sst.symbols()[2], Symbol::owned("foe"));
         Ok(())
     }
+
+    #[test]
+    fn shared_symbol_table_write_test() -> IonResult<()> {
+        let sst = SharedSymbolTable::new(
+            "com.amazon.test.symbols",
+            2,
+            [
+                Symbol::owned("fee"),
+                Symbol::unknown_text(),
+                Symbol::owned("foe"),
+            ],
+        )?;
+        let ion_text = sst.encode_as(v1_0::Text)?;
+        let element = Element::read_one(&ion_text)?;
+        assert_eq!(
+            element,
+            Element::read_one(
+                r#"$ion_shared_symbol_table::{
+                    name: "com.amazon.test.symbols",
+                    version: 2,
+                    symbols: ["fee", null.string, "foe"]
+                }"#
+            )?
+        );
+        assert_eq!(SharedSymbolTable::try_from(element)?, sst);
+        Ok(())
+    }
+
+    #[test]
+    fn shared_symbol_table_lazy_value_round_trip() -> IonResult<()> {
+        let sst = SharedSymbolTable::new(
+            "com.amazon.test.symbols",
+            3,
+            [
+                Symbol::owned("fee"),
+                Symbol::owned("fie"),
+                Symbol::unknown_text(),
+            ],
+        )?;
+        let ion_bytes = sst.encode_as(v1_0::Binary)?;
+        let mut reader = Reader::new(v1_0::Binary, ion_bytes)?;
+        let value = reader.expect_next()?;
+        assert_eq!(SharedSymbolTable::try_from(value)?, sst);
+        Ok(())
+    }
 }
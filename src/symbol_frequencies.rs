@@ -0,0 +1,243 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::constants::v1_0::SYSTEM_SYMBOL_TEXT_TO_ID;
+use crate::lazy::decoder::Decoder;
+use crate::lazy::reader::Reader;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
+use crate::{IonResult, SharedSymbolTable, SymbolRef};
+
+/// Counts how often each symbol appears as a field name, annotation, or symbol value in a corpus
+/// of Ion data.
+///
+/// The most frequently occurring symbols make a good [`SharedSymbolTable`]. A writer that
+/// [imports](crate::WriteConfig::with_import) the table can encode those symbols by ID without
+/// defining them in each stream's local symbol table.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{v1_0, Reader, SymbolFrequencies};
+///
+/// let mut frequencies = SymbolFrequencies::new();
+/// let mut reader = Reader::new(v1_0::Text, "{a: b, c: d} {a: e} [b, b]")?;
+/// frequencies.count_all(&mut reader)?;
+/// assert_eq!(frequencies.count("b"), 3);
+///
+/// let table = frequencies.to_shared_symbol_table("com.example.symbols", 1, 2)?;
+/// let symbols: Vec<_> = table.symbols().iter().map(|s| s.text().unwrap()).collect();
+/// assert_eq!(symbols, ["b", "a"]);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SymbolFrequencies {
+    counts: HashMap<String, usize>,
+}
+
+impl SymbolFrequencies {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts the symbols in each of the remaining top-level values in `reader`.
+    pub fn count_all<D: Decoder, I: IonInput>(
+        &mut self,
+        reader: &mut Reader<D, I>,
+    ) -> IonResult<()> {
+        while let Some(value) = reader.next()? {
+            self.count_value(value)?;
+        }
+        Ok(())
+    }
+
+    /// Counts the symbols in `value` and, if it is a container, in each of its nested values.
+    pub fn count_value<D: Decoder>(&mut self, value: LazyValue<'_, D>) -> IonResult<()> {
+        for annotation in value.annotations() {
+            self.observe(annotation?);
+        }
+        match value.read()? {
+            ValueRef::Symbol(symbol) => self.observe(symbol),
+            ValueRef::List(list) => {
+                for child in list {
+                    self.count_value(child?)?;
+                }
+            }
+            ValueRef::SExp(sexp) => {
+                for child in sexp {
+                    self.count_value(child?)?;
+                }
+            }
+            ValueRef::Struct(strukt) => {
+                for field in strukt {
+                    let field = field?;
+                    self.observe(field.name()?);
+                    self.count_value(field.value())?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Records an occurrence of `symbol`. Symbols with unknown text and Ion 1.0 system symbols
+    /// (which every symbol table already defines) are ignored.
+    fn observe(&mut self, symbol: SymbolRef<'_>) {
+        let Some(text) = symbol.text() else {
+            return;
+        };
+        if SYSTEM_SYMBOL_TEXT_TO_ID.contains_key(text) {
+            return;
+        }
+        match self.counts.get_mut(text) {
+            Some(count) => *count += 1,
+            None => {
+                self.counts.insert(text.to_owned(), 1);
+            }
+        }
+    }
+
+    /// Returns the number of times a symbol with the given text has been observed.
+    pub fn count(&self, text: &str) -> usize {
+        self.counts.get(text).copied().unwrap_or(0)
+    }
+
+    /// Returns the number of distinct symbols that have been observed.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Returns up to `max_symbols` of the observed symbols and their counts, most frequent first.
+    /// Symbols that were observed the same number of times are sorted by their text.
+    pub fn most_frequent(&self, max_symbols: usize) -> Vec<(&str, usize)> {
+        let mut symbols: Vec<(&str, usize)> = self
+            .counts
+            .iter()
+            .map(|(text, count)| (text.as_str(), *count))
+            .collect();
+        symbols.sort_unstable_by_key(|(text, count)| (Reverse(*count), *text));
+        symbols.truncate(max_symbols);
+        symbols
+    }
+
+    /// Constructs a [`SharedSymbolTable`] containing up to `max_symbols` of the most frequently
+    /// observed symbols, ordered as in [`most_frequent`](Self::most_frequent).
+    pub fn to_shared_symbol_table(
+        &self,
+        name: impl Into<String>,
+        version: usize,
+        max_symbols: usize,
+    ) -> IonResult<SharedSymbolTable> {
+        let symbols = self
+            .most_frequent(max_symbols)
+            .into_iter()
+            .map(|(text, _count)| text.to_owned());
+        SharedSymbolTable::new(name, version, symbols)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lazy::encoder::write_as_ion::WriteAsIon;
+    use crate::{
+        v1_0, AnyEncoding, Decoder, Element, ElementReader, ElementWriter, IonResult, MapCatalog,
+        Reader, SymbolFrequencies, WriteConfig, Writer,
+    };
+
+    const CORPUS: &str = r#"
+        {device_id: "a1", region: 'us-east-1', readings: [temperature::1.5, humidity::40]}
+        {device_id: "b2", region: 'us-west-2', readings: [temperature::2.5]}
+        {device_id: "c3", region: 'us-east-1', status: (online 'us-east-1')}
+        $ion_symbol_table::{symbols: ["unused"]}
+        $10 // 'unused'
+        name // A system symbol
+    "#;
+
+    fn frequencies_of(ion: &str) -> IonResult<SymbolFrequencies> {
+        let mut frequencies = SymbolFrequencies::new();
+        frequencies.count_all(&mut Reader::new(v1_0::Text, ion)?)?;
+        Ok(frequencies)
+    }
+
+    #[test]
+    fn count_symbols() -> IonResult<()> {
+        let frequencies = frequencies_of(CORPUS)?;
+        assert_eq!(frequencies.count("device_id"), 3);
+        assert_eq!(frequencies.count("us-east-1"), 3);
+        assert_eq!(frequencies.count("temperature"), 2);
+        assert_eq!(frequencies.count("online"), 1);
+        assert_eq!(frequencies.count("unused"), 1);
+        // Strings and system symbols are not counted
+        assert_eq!(frequencies.count("a1"), 0);
+        assert_eq!(frequencies.count("name"), 0);
+        assert_eq!(frequencies.len(), 10);
+        assert!(!frequencies.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn most_frequent_symbols() -> IonResult<()> {
+        let frequencies = frequencies_of(CORPUS)?;
+        assert_eq!(
+            frequencies.most_frequent(6),
+            [
+                ("device_id", 3),
+                ("region", 3),
+                ("us-east-1", 3),
+                ("readings", 2),
+                ("temperature", 2),
+                ("humidity", 1),
+            ]
+        );
+        assert_eq!(frequencies.most_frequent(100).len(), 10);
+        Ok(())
+    }
+
+    #[test]
+    fn write_shared_symbol_table() -> IonResult<()> {
+        let frequencies = frequencies_of(CORPUS)?;
+        let table = frequencies.to_shared_symbol_table("telemetry", 3, 4)?;
+        let element = Element::read_one(table.encode_as(v1_0::Text)?)?;
+        assert_eq!(
+            element,
+            Element::read_one(
+                r#"$ion_shared_symbol_table::{
+                    name: "telemetry",
+                    version: 3,
+                    symbols: ["device_id", "region", "us-east-1", "readings"]
+                }"#
+            )?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn imported_table_shrinks_output() -> IonResult<()> {
+        let corpus = Element::read_all(CORPUS)?;
+        let table = frequencies_of(CORPUS)?.to_shared_symbol_table("telemetry", 1, 10)?;
+
+        let encode = |config: WriteConfig<v1_0::Binary>| -> IonResult<Vec<u8>> {
+            let mut writer = Writer::new(config, Vec::new())?;
+            writer.write_elements(&corpus)?;
+            writer.close()
+        };
+        let without_import = encode(WriteConfig::<v1_0::Binary>::new())?;
+        let with_import = encode(WriteConfig::<v1_0::Binary>::new().with_import(table.clone()))?;
+        assert!(with_import.len() < without_import.len());
+
+        let mut catalog = MapCatalog::new();
+        catalog.insert_table(table);
+        let mut reader = Reader::new(AnyEncoding.with_catalog(catalog), with_import)?;
+        assert_eq!(reader.read_all_elements()?, corpus);
+        Ok(())
+    }
+}
@@ -164,6 +164,15 @@ impl SymbolTable {
         };
     }
 
+    /// Returns the number of symbols in the permanent prefix used by the current Ion version.
+    /// These are the symbols that remain after a call to [`Self::reset_to_prefix_only`].
+    pub(crate) fn num_prefix_symbols(&self) -> usize {
+        match self.ion_version {
+            IonVersion::v1_0 => Self::NUM_PREFIX_SYSTEM_SYMBOLS_1_0,
+            IonVersion::v1_1 => Self::NUM_PREFIX_SYSTEM_SYMBOLS_1_1,
+        }
+    }
+
     pub(crate) fn reset_to_version(&mut self, new_version: IonVersion) {
         self.ion_version = new_version;
         self.reset_to_default();
@@ -1,2 +1,3 @@
+pub(crate) mod text_format_config;
 pub(crate) mod text_formatter;
 pub(crate) mod whitespace_config;
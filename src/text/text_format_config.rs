@@ -0,0 +1,259 @@
+use crate::TextFormat;
+
+/// Configures how text Ion is laid out and how individual scalar values are spelled.
+///
+/// A `TextFormatConfig` starts from one of the [`TextFormat`] presets and can then be adjusted
+/// using its builder-style `with_*` methods:
+///
+/// ```
+/// use ion_rs::{FloatStyle, TextFormat, TextFormatConfig};
+///
+/// let config = TextFormatConfig::new(TextFormat::Pretty)
+///     .with_indentation_width(4)
+///     .with_max_line_width(80)
+///     .with_trailing_commas(false)
+///     .with_float_style(FloatStyle::Plain);
+/// assert_eq!(config.indentation_width(), 4);
+/// assert_eq!(config.max_line_width(), Some(80));
+/// ```
+///
+/// For applications that need a single deterministic rendering of each value (for example, to
+/// sign or compare documents), [`with_canonical`](Self::with_canonical) enables canonical mode.
+///
+/// Both the Ion 1.0 and Ion 1.1 text writers accept a `TextFormatConfig` anywhere a
+/// [`TextFormat`] is accepted. An [`Element`](crate::Element) can be formatted using a
+/// `TextFormatConfig` via [`Element::display_with`](crate::Element::display_with).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct TextFormatConfig {
+    format: TextFormat,
+    indentation_width: usize,
+    max_line_width: Option<usize>,
+    trailing_commas: bool,
+    symbol_quoting: SymbolQuoting,
+    blob_line_width: Option<usize>,
+    float_style: FloatStyle,
+    decimal_style: DecimalStyle,
+    timestamp_style: TimestampStyle,
+    canonical: bool,
+}
+
+impl TextFormatConfig {
+    /// Constructs a new `TextFormatConfig` that lays out values using the provided `format` and
+    /// uses the default style for everything else.
+    pub const fn new(format: TextFormat) -> Self {
+        Self {
+            format,
+            indentation_width: 2,
+            max_line_width: None,
+            trailing_commas: true,
+            symbol_quoting: SymbolQuoting::WhenRequired,
+            blob_line_width: None,
+            float_style: FloatStyle::Scientific,
+            decimal_style: DecimalStyle::Default,
+            timestamp_style: TimestampStyle::Offset,
+            canonical: false,
+        }
+    }
+
+    /// Sets the number of spaces used for each level of nesting. This only affects formats that
+    /// write nested values on their own lines, like [`TextFormat::Pretty`].
+    pub const fn with_indentation_width(mut self, width: usize) -> Self {
+        self.indentation_width = width;
+        self
+    }
+
+    /// Sets the maximum desired line width. Containers whose contents would otherwise be spread
+    /// across several lines are written on a single line if doing so keeps that line within
+    /// `width` columns. Lines can still exceed `width` if a single value is too long to fit.
+    pub const fn with_max_line_width(mut self, width: usize) -> Self {
+        self.max_line_width = Some(width);
+        self
+    }
+
+    /// Sets whether a delimiter is written after the last value in a list or struct.
+    /// (`[1, 2, ]` vs `[1, 2]`) Trailing commas are written by default.
+    pub const fn with_trailing_commas(mut self, trailing_commas: bool) -> Self {
+        self.trailing_commas = trailing_commas;
+        self
+    }
+
+    /// Sets when symbol text (including field names and annotations) is wrapped in single quotes.
+    pub const fn with_symbol_quoting(mut self, symbol_quoting: SymbolQuoting) -> Self {
+        self.symbol_quoting = symbol_quoting;
+        self
+    }
+
+    /// Sets the maximum number of base64 characters written on a single line of a blob. Blobs
+    /// with longer encodings are split across several lines. By default, blobs are not wrapped.
+    pub const fn with_blob_line_width(mut self, width: usize) -> Self {
+        self.blob_line_width = Some(width);
+        self
+    }
+
+    /// Sets the notation used to write float values.
+    pub const fn with_float_style(mut self, float_style: FloatStyle) -> Self {
+        self.float_style = float_style;
+        self
+    }
+
+    /// Sets the notation used to write decimal values.
+    pub const fn with_decimal_style(mut self, decimal_style: DecimalStyle) -> Self {
+        self.decimal_style = decimal_style;
+        self
+    }
+
+    /// Sets the notation used to write the offset of timestamp values.
+    pub const fn with_timestamp_style(mut self, timestamp_style: TimestampStyle) -> Self {
+        self.timestamp_style = timestamp_style;
+        self
+    }
+
+    /// Sets whether values are written in canonical form. In canonical mode, each value has
+    /// exactly one text rendering for a given layout:
+    /// * struct fields are sorted by field name; fields with the same name are sorted by the
+    ///   [`IonData`](crate::IonData) ordering of their values
+    /// * ints are written in base 10
+    /// * floats use [`FloatStyle::Scientific`], which writes the fewest digits that read back as
+    ///   the same value
+    /// * decimals use [`DecimalStyle::Shortest`]
+    /// * timestamps keep their precision and use [`TimestampStyle::UtcZ`]
+    /// * symbols use [`SymbolQuoting::WhenRequired`]
+    ///
+    /// These choices take precedence over the corresponding `with_*` settings. Layout settings
+    /// like the format, indentation, and trailing commas still apply.
+    ///
+    /// Writing a value in canonical mode, reading the output, and writing it again in canonical
+    /// mode with the same config produces identical text.
+    pub const fn with_canonical(mut self, canonical: bool) -> Self {
+        self.canonical = canonical;
+        self
+    }
+
+    /// Returns the [`TextFormat`] preset used to lay out values.
+    pub const fn format(&self) -> TextFormat {
+        self.format
+    }
+
+    /// Returns the number of spaces used for each level of nesting.
+    pub const fn indentation_width(&self) -> usize {
+        self.indentation_width
+    }
+
+    /// Returns the maximum desired line width, if one has been set.
+    pub const fn max_line_width(&self) -> Option<usize> {
+        self.max_line_width
+    }
+
+    /// Returns `true` if a delimiter is written after the last value in a list or struct.
+    pub const fn trailing_commas(&self) -> bool {
+        self.trailing_commas
+    }
+
+    /// Returns the policy used to decide when symbol text is wrapped in quotes.
+    pub const fn symbol_quoting(&self) -> SymbolQuoting {
+        if self.canonical {
+            return SymbolQuoting::WhenRequired;
+        }
+        self.symbol_quoting
+    }
+
+    /// Returns the maximum number of base64 characters written on a single line of a blob,
+    /// if one has been set.
+    pub const fn blob_line_width(&self) -> Option<usize> {
+        self.blob_line_width
+    }
+
+    /// Returns the notation used to write float values.
+    pub const fn float_style(&self) -> FloatStyle {
+        if self.canonical {
+            return FloatStyle::Scientific;
+        }
+        self.float_style
+    }
+
+    /// Returns the notation used to write decimal values.
+    pub const fn decimal_style(&self) -> DecimalStyle {
+        if self.canonical {
+            return DecimalStyle::Shortest;
+        }
+        self.decimal_style
+    }
+
+    /// Returns the notation used to write the offset of timestamp values.
+    pub const fn timestamp_style(&self) -> TimestampStyle {
+        if self.canonical {
+            return TimestampStyle::UtcZ;
+        }
+        self.timestamp_style
+    }
+
+    /// Returns `true` if values are written in canonical form.
+    pub const fn is_canonical(&self) -> bool {
+        self.canonical
+    }
+}
+
+impl Default for TextFormatConfig {
+    fn default() -> Self {
+        Self::new(TextFormat::default())
+    }
+}
+
+impl From<TextFormat> for TextFormatConfig {
+    fn from(format: TextFormat) -> Self {
+        Self::new(format)
+    }
+}
+
+/// Determines when symbol text is wrapped in single quotes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum SymbolQuoting {
+    /// Symbols are only quoted when their text is not an identifier or could be mistaken for a
+    /// keyword or symbol ID. (`foo`, `'foo bar'`, `'null'`, `'$10'`)
+    #[default]
+    WhenRequired,
+    /// Symbols with known text are always quoted. (`'foo'`, `'foo bar'`)
+    Always,
+}
+
+/// The notation used to write float values.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum FloatStyle {
+    /// Floats are written in scientific notation with a single digit before the decimal point.
+    /// (`1.2345e3`)
+    #[default]
+    Scientific,
+    /// Floats are written with all of their digits followed by an `e0` exponent. (`1234.5e0`)
+    Plain,
+}
+
+/// The notation used to write decimal values.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum DecimalStyle {
+    /// Decimals are written using a decimal point where practical, switching to `d` notation for
+    /// very large or very small values. (`12.30`, `1.23d10`)
+    #[default]
+    Default,
+    /// Decimals are always written as their coefficient followed by a `d` and their exponent.
+    /// (`1230d-2`, `123d8`)
+    Exponent,
+    /// Decimals are written in whichever of the decimal point or `d` notations is shorter,
+    /// preferring the decimal point when both are the same length. Positive exponents always
+    /// use `d` notation. (`12.30`, `123d-7`, `123d8`)
+    Shortest,
+}
+
+/// The notation used to write the offset of timestamp values.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum TimestampStyle {
+    /// Offsets are always written numerically. (`2024-01-01T00:00+00:00`)
+    #[default]
+    Offset,
+    /// A UTC offset is written as `Z`; other offsets are written numerically.
+    /// (`2024-01-01T00:00Z`)
+    UtcZ,
+}
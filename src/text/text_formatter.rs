@@ -1,7 +1,12 @@
 use crate::constants::v1_1;
+use crate::decimal::coefficient::Sign;
+use crate::ion_data::IonOrd;
 use crate::raw_symbol_ref::{AsRawSymbolRef, RawSymbolRef};
 use crate::result::IonFailure;
-use crate::{Annotations, Sequence};
+use crate::text::text_format_config::{
+    DecimalStyle, FloatStyle, SymbolQuoting, TextFormatConfig, TimestampStyle,
+};
+use crate::{Annotations, Element, Sequence, Value};
 use crate::{Decimal, Int, Struct, Timestamp};
 use crate::{IonResult, IonType};
 use std::{fmt, io};
@@ -183,13 +188,66 @@ pub(crate) const fn string_escape_code_init() -> [&'static str; 256] {
 
 /// Provides a text formatter for Ion values
 /// This is used with the Display implementation of `OwnedElement`
+/// Displays a decimal using either a decimal point or an exponent introduced by `exponent_marker`,
+/// whichever is shorter. Decimals with a positive exponent always use an exponent, and a decimal
+/// point is always written for those whose exponent is zero (for example, `123.`).
+pub(crate) struct ShortestDecimal<'a> {
+    value: &'a Decimal,
+    exponent_marker: char,
+}
+
+impl<'a> ShortestDecimal<'a> {
+    pub(crate) fn new(value: &'a Decimal, exponent_marker: char) -> Self {
+        Self {
+            value,
+            exponent_marker,
+        }
+    }
+}
+
+impl fmt::Display for ShortestDecimal<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = match self.value.coefficient().sign() {
+            Sign::Negative => "-",
+            Sign::Positive => "",
+        };
+        let digits = self.value.coefficient().magnitude().to_string();
+        let exponent = self.value.exponent();
+        let marker = self.exponent_marker;
+        // The number of digits that appear before the decimal point
+        let dot_index = digits.len() as i128 + exponent as i128;
+        if exponent <= 0 && dot_index > 0 {
+            // Inserting a decimal point is never longer than appending an exponent.
+            let (whole, fraction) = digits.split_at(dot_index as usize);
+            return write!(f, "{sign}{whole}.{fraction}");
+        }
+        // Compare the lengths of the two forms before writing either one; the number of leading
+        // zeros that a decimal point would require is unbounded.
+        let leading_zeros = dot_index.unsigned_abs();
+        let exponent_length = exponent.to_string().len() as u128;
+        // `0.` and the leading zeros, versus the marker and the exponent
+        if exponent <= 0 && 2 + leading_zeros <= 1 + exponent_length {
+            let width = leading_zeros as usize + digits.len();
+            write!(f, "{sign}0.{digits:0>width$}")
+        } else {
+            write!(f, "{sign}{digits}{marker}{exponent}")
+        }
+    }
+}
+
 pub struct FmtValueFormatter<'a, W: fmt::Write> {
     pub(crate) output: &'a mut W,
+    pub(crate) config: TextFormatConfig,
 }
 
 impl<'a, W: fmt::Write> FmtValueFormatter<'a, W> {
     pub fn new(output: &'a mut W) -> Self {
-        Self { output }
+        Self::with_config(output, TextFormatConfig::default())
+    }
+
+    /// Constructs a formatter that writes scalar values using the styles set in `config`.
+    pub fn with_config(output: &'a mut W, config: TextFormatConfig) -> Self {
+        Self { output, config }
     }
 }
 
@@ -219,6 +277,15 @@ impl<W: io::Write> IoValueFormatter<W> {
     pub fn value_formatter(&mut self) -> FmtValueFormatter<'_, Self> {
         FmtValueFormatter::new(self)
     }
+
+    /// Like [`value_formatter`](Self::value_formatter), but the returned formatter writes scalar
+    /// values using the styles set in `config`.
+    pub fn value_formatter_with_config(
+        &mut self,
+        config: TextFormatConfig,
+    ) -> FmtValueFormatter<'_, Self> {
+        FmtValueFormatter::with_config(self, config)
+    }
 }
 
 impl<W: io::Write> fmt::Write for IoValueFormatter<W> {
@@ -280,8 +347,11 @@ impl<'a, W: std::fmt::Write> FmtValueFormatter<'a, W> {
 
     pub(crate) fn format_symbol_token<A: AsRawSymbolRef>(&mut self, token: A) -> IonResult<()> {
         use RawSymbolRef::*;
+        let always_quote = self.config.symbol_quoting() == SymbolQuoting::Always;
         let write_result = match token.as_raw_symbol_ref() {
             SymbolId(sid) => write!(self.output, "${sid}"),
+            // If the config asks for all symbol text to be quoted, system symbols are quoted too.
+            SystemSymbol_1_1(symbol) if always_quote => write!(self.output, "'{}'", symbol.text()),
             // '' is the only system symbol that requires quoting; the rest are identifiers.
             SystemSymbol_1_1(v1_1::system_symbols::EMPTY_TEXT) => write!(self.output, "\'\'"),
             // Any other system symbol is an identifier and doesn't require quoting.
@@ -291,7 +361,9 @@ impl<'a, W: std::fmt::Write> FmtValueFormatter<'a, W> {
                 write!(self.output, "'{text}'")
             }
             // If the text is an identifier, it doesn't require quotes.
-            Text(text) if Self::token_is_identifier(text) => write!(self.output, "{text}"),
+            Text(text) if !always_quote && Self::token_is_identifier(text) => {
+                write!(self.output, "{text}")
+            }
             // Any other text has its escape sequences substituted and is wrapped in quotes.
             Text(text) => {
                 // Write the symbol text using quotes and escaping any characters that require it.
@@ -406,16 +478,43 @@ impl<'a, W: std::fmt::Write> FmtValueFormatter<'a, W> {
             return Ok(());
         }
 
-        write!(self.output, "{value:e}")?;
+        match self.config.float_style() {
+            FloatStyle::Scientific => write!(self.output, "{value:e}")?,
+            // The {} formatter writes every digit of the value without an exponent, so we add
+            // an `e0` to keep the text from being read as a decimal.
+            FloatStyle::Plain => write!(self.output, "{value}e0")?,
+        };
         Ok(())
     }
 
     pub fn format_decimal(&mut self, value: &Decimal) -> IonResult<()> {
-        write!(self.output, "{value}")?;
+        match self.config.decimal_style() {
+            DecimalStyle::Default => write!(self.output, "{value}")?,
+            DecimalStyle::Exponent => {
+                write!(self.output, "{}d{}", value.coefficient(), value.exponent())?
+            }
+            DecimalStyle::Shortest => self.format_shortest_decimal(value)?,
+        };
+        Ok(())
+    }
+
+    /// Writes the decimal using either a decimal point or `d` notation, whichever is shorter.
+    fn format_shortest_decimal(&mut self, value: &Decimal) -> IonResult<()> {
+        write!(self.output, "{}", ShortestDecimal::new(value, 'd'))?;
         Ok(())
     }
 
     pub fn format_timestamp(&mut self, value: &Timestamp) -> IonResult<()> {
+        if self.config.timestamp_style() == TimestampStyle::UtcZ && value.offset() == Some(0) {
+            let mut text = String::new();
+            value.format(&mut text)?;
+            // Timestamps with a precision of year, month, or day do not include an offset.
+            match text.strip_suffix("+00:00") {
+                Some(without_offset) => write!(self.output, "{without_offset}Z")?,
+                None => write!(self.output, "{text}")?,
+            };
+            return Ok(());
+        }
         value.format(self.output)
     }
 
@@ -456,16 +555,108 @@ impl<'a, W: std::fmt::Write> FmtValueFormatter<'a, W> {
     }
 
     pub fn format_blob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()> {
-        write!(self.output, "{{{{{}}}}}", base64::encode(value))?;
+        self.format_wrapped_blob(value, "")
+    }
+
+    /// Writes a blob, splitting its base64 text across lines if it is longer than the configured
+    /// blob line width. Each line after the first begins with `line_prefix`.
+    pub(crate) fn format_wrapped_blob<A: AsRef<[u8]>>(
+        &mut self,
+        value: A,
+        line_prefix: &str,
+    ) -> IonResult<()> {
+        let base64_text = base64::encode(value);
+        let line_width = match self.config.blob_line_width() {
+            Some(width) if width > 0 && base64_text.len() > width => width,
+            // Rust format strings escape curly braces by doubling them. The following string is:
+            // * The opening {{ from a text Ion blob, with each brace doubled to escape it.
+            // * A {} pair used by the format string to indicate where the base64-encoded bytes
+            //   should be inserted.
+            // * The closing }} from a text Ion blob, with each brace doubled to escape it.
+            _ => {
+                write!(self.output, "{{{{{base64_text}}}}}")?;
+                return Ok(());
+            }
+        };
+        write!(self.output, "{{{{")?;
+        // Base64 text is always ASCII, so it can safely be split at any byte offset.
+        for (index, line) in base64_text.as_bytes().chunks(line_width).enumerate() {
+            if index > 0 {
+                write!(self.output, "\n{line_prefix}")?;
+            }
+            // This cannot fail; the chunk is a slice of an ASCII string.
+            let line = std::str::from_utf8(line).unwrap();
+            write!(self.output, "{line}")?;
+        }
+        write!(self.output, "}}}}")?;
         Ok(())
     }
 
+    /// Writes the element's annotations (if any) followed by its value.
+    pub fn format_element(&mut self, element: &Element) -> IonResult<()> {
+        self.format_annotations(element.annotations())?;
+        self.format_value(element.value())
+    }
+
+    /// Writes the provided value. Container values are written on a single line.
+    pub fn format_value(&mut self, value: &Value) -> IonResult<()> {
+        match value {
+            Value::Null(ion_type) => self.format_null(*ion_type),
+            Value::Bool(bool) => self.format_bool(*bool),
+            Value::Int(integer) => self.format_integer(integer),
+            Value::Float(float) => self.format_float(*float),
+            Value::Decimal(decimal) => self.format_decimal(decimal),
+            Value::Timestamp(timestamp) => self.format_timestamp(timestamp),
+            Value::Symbol(symbol) => self.format_symbol(symbol),
+            Value::String(string) => self.format_string(string),
+            Value::Clob(clob) => self.format_clob(clob),
+            Value::Blob(blob) => self.format_blob(blob),
+            Value::List(sequence) => self.format_list(sequence),
+            Value::SExp(sequence) => self.format_sexp(sequence),
+            Value::Struct(struct_) => self.format_struct(struct_),
+        }
+    }
+
     pub(crate) fn format_struct(&mut self, value: &Struct) -> IonResult<()> {
+        if self.config.is_canonical() {
+            return self.format_canonical_struct(value);
+        }
         write!(self.output, "{{")?;
         let mut peekable_itr = value.fields().peekable();
         while let Some((field_name, field_value)) = peekable_itr.next() {
             self.format_symbol(field_name.as_raw_symbol_ref())?;
-            write!(self.output, ": {field_value}")?;
+            write!(self.output, ": ")?;
+            self.format_element(field_value)?;
+            if peekable_itr.peek().is_some() {
+                write!(self.output, ", ")?;
+            }
+        }
+        write!(self.output, "}}")?;
+        Ok(())
+    }
+
+    /// Writes the struct with its fields ordered by name and then by their values, matching the
+    /// order used by the canonical text writer.
+    fn format_canonical_struct(&mut self, value: &Struct) -> IonResult<()> {
+        let mut fields = value
+            .fields()
+            .map(|(name, value)| {
+                let mut text = String::new();
+                FmtValueFormatter::with_config(&mut text, self.config).format_element(value)?;
+                Ok((name, value, text))
+            })
+            .collect::<IonResult<Vec<_>>>()?;
+        fields.sort_by(|(name1, value1, text1), (name2, value2, text2)| {
+            name1
+                .ion_cmp(name2)
+                .then_with(|| value1.ion_cmp(value2))
+                .then_with(|| text1.cmp(text2))
+        });
+        write!(self.output, "{{")?;
+        let mut peekable_itr = fields.into_iter().peekable();
+        while let Some((field_name, _, field_text)) = peekable_itr.next() {
+            self.format_symbol(field_name.as_raw_symbol_ref())?;
+            write!(self.output, ": {field_text}")?;
             if peekable_itr.peek().is_some() {
                 write!(self.output, ", ")?;
             }
@@ -496,7 +687,7 @@ impl<'a, W: std::fmt::Write> FmtValueFormatter<'a, W> {
         let mut peekable_itr = sequence.as_ref().elements().peekable();
         while peekable_itr.peek().is_some() {
             let list_value = peekable_itr.next().unwrap();
-            write!(self.output, "{list_value}")?;
+            self.format_element(list_value)?;
             if peekable_itr.peek().is_some() {
                 write!(self.output, "{}", delimiter)?;
             }
@@ -508,16 +699,29 @@ impl<'a, W: std::fmt::Write> FmtValueFormatter<'a, W> {
 #[cfg(test)]
 mod formatter_test {
     use crate::text::text_formatter::FmtValueFormatter;
-    use crate::{ion_list, ion_sexp, ion_struct, IonResult, IonType, Timestamp};
+    use crate::{
+        ion_list, ion_sexp, ion_struct, Decimal, DecimalStyle, Element, FloatStyle, IonResult,
+        IonType, SymbolQuoting, TextFormatConfig, Timestamp, TimestampStyle,
+    };
+    use rstest::rstest;
 
     fn formatter<F>(mut f: F, expected: &str)
     where
         F: for<'a> FnMut(&mut FmtValueFormatter<'a, String>) -> IonResult<()>,
     {
         let mut actual = String::new();
-        let mut ivf = FmtValueFormatter {
-            output: &mut actual,
-        };
+        let mut ivf = FmtValueFormatter::new(&mut actual);
+
+        let _ = f(&mut ivf);
+        assert_eq!(actual, expected)
+    }
+
+    fn formatter_with_config<F>(config: TextFormatConfig, mut f: F, expected: &str)
+    where
+        F: for<'a> FnMut(&mut FmtValueFormatter<'a, String>) -> IonResult<()>,
+    {
+        let mut actual = String::new();
+        let mut ivf = FmtValueFormatter::with_config(&mut actual, config);
 
         let _ = f(&mut ivf);
         assert_eq!(actual, expected)
@@ -558,6 +762,117 @@ mod formatter_test {
         Ok(())
     }
 
+    #[test]
+    fn test_format_float_with_style() -> IonResult<()> {
+        let config = TextFormatConfig::default().with_float_style(FloatStyle::Plain);
+        formatter_with_config(config, |ivf| ivf.format_float(400.5f64), "400.5e0");
+        formatter_with_config(config, |ivf| ivf.format_float(-0.25f64), "-0.25e0");
+        formatter_with_config(config, |ivf| ivf.format_float(-0f64), "-0e0");
+        formatter_with_config(config, |ivf| ivf.format_float(f64::INFINITY), "+inf");
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_decimal_with_style() -> IonResult<()> {
+        let config = TextFormatConfig::default().with_decimal_style(DecimalStyle::Exponent);
+        formatter_with_config(
+            config,
+            |ivf| ivf.format_decimal(&Decimal::new(150, -2)),
+            "150d-2",
+        );
+        formatter_with_config(
+            config,
+            |ivf| ivf.format_decimal(&Decimal::new(-7, 3)),
+            "-7d3",
+        );
+        formatter_with_config(
+            config,
+            |ivf| ivf.format_decimal(&Decimal::negative_zero()),
+            "-0d0",
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(Decimal::new(150, -2), "1.50")]
+    #[case(Decimal::new(123, 0), "123.")]
+    #[case(Decimal::new(-123, -5), "-123d-5")]
+    #[case(Decimal::new(123, -4), "0.0123")]
+    #[case(Decimal::new(123, 2), "123d2")]
+    #[case(Decimal::new(0, -2), "0.00")]
+    #[case(Decimal::negative_zero(), "-0.")]
+    #[case(Decimal::new(1, -1_000_000_000), "1d-1000000000")]
+    #[case(Decimal::new(-1, i64::MIN), "-1d-9223372036854775808")]
+    #[case(Decimal::new(1, i64::MAX), "1d9223372036854775807")]
+    fn test_format_shortest_decimal(#[case] value: Decimal, #[case] expected: &str) {
+        let config = TextFormatConfig::default().with_decimal_style(DecimalStyle::Shortest);
+        formatter_with_config(config, |ivf| ivf.format_decimal(&value), expected);
+    }
+
+    #[test]
+    fn test_format_canonical_element() -> IonResult<()> {
+        let element = Element::read_one(
+            r#"{b: 0x1F, a: "two", a: 1, c: [1.0e0, 2.50, 'foo'::2000-01-01T00:00+00:00]}"#,
+        )?;
+        let config = TextFormatConfig::default()
+            .with_canonical(true)
+            .with_symbol_quoting(SymbolQuoting::Always);
+        formatter_with_config(
+            config,
+            |ivf| ivf.format_element(&element),
+            r#"{a: 1, a: "two", b: 31, c: [1e0, 2.50, foo::2000-01-01T00:00Z]}"#,
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_timestamp_with_style() -> IonResult<()> {
+        let config = TextFormatConfig::default().with_timestamp_style(TimestampStyle::UtcZ);
+        let utc = Timestamp::with_ymd(2024, 5, 6)
+            .with_hms(7, 8, 9)
+            .with_offset(0)
+            .build()?;
+        formatter_with_config(
+            config,
+            |ivf| ivf.format_timestamp(&utc),
+            "2024-05-06T07:08:09Z",
+        );
+        let offset = Timestamp::with_ymd(2024, 5, 6)
+            .with_hour_and_minute(7, 8)
+            .with_offset(-300)
+            .build()?;
+        formatter_with_config(
+            config,
+            |ivf| ivf.format_timestamp(&offset),
+            "2024-05-06T07:08-05:00",
+        );
+        let date = Timestamp::with_ymd(2024, 5, 6).build()?;
+        formatter_with_config(config, |ivf| ivf.format_timestamp(&date), "2024-05-06T");
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_symbol_with_quoting() -> IonResult<()> {
+        let config = TextFormatConfig::default().with_symbol_quoting(SymbolQuoting::Always);
+        formatter_with_config(config, |ivf| ivf.format_symbol("foo"), "'foo'");
+        formatter_with_config(config, |ivf| ivf.format_symbol("foo bar"), "'foo bar'");
+        formatter_with_config(config, |ivf| ivf.format_symbol(10), "$10");
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_blob_with_line_width() -> IonResult<()> {
+        let config = TextFormatConfig::default().with_blob_line_width(4);
+        formatter_with_config(
+            config,
+            |ivf| ivf.format_wrapped_blob("hello".as_bytes(), "  "),
+            "{{aGVs\n  bG8=}}",
+        );
+        // Blobs that fit on a single line are not wrapped.
+        formatter_with_config(config, |ivf| ivf.format_blob("hi".as_bytes()), "{{aGk=}}");
+        Ok(())
+    }
+
     #[test]
     fn test_format_timestamp() -> IonResult<()> {
         let timestamp = Timestamp::with_year(2000)
@@ -1,15 +1,41 @@
+use crate::text::text_format_config::TextFormatConfig;
+use crate::TextFormat;
+
 #[derive(Clone, Copy)]
 pub(crate) struct WhitespaceConfig {
     // Top-level values are independent of other values in the stream, we may separate differently
     pub(crate) space_between_top_level_values: &'static str,
     // Non-top-level values are within a container
     pub(crate) space_between_nested_values: &'static str,
-    // Indentation is repeated before nested values, corresponding to the level of nesting
-    pub(crate) indentation: &'static str,
+    // The number of spaces written before nested values for each level of nesting
+    pub(crate) indentation_width: usize,
     // e.g. after 'foo:' in "{foo: bar}"
     pub(crate) space_after_field_name: &'static str,
     // Between the container open and any value in it
     pub(crate) space_after_container_start: &'static str,
+    // Between the last value in a container and the closing delimiter when trailing commas
+    // are disabled
+    pub(crate) space_before_container_end: &'static str,
+}
+
+impl WhitespaceConfig {
+    /// Returns the whitespace config for the layout described by `config`.
+    pub(crate) fn for_format_config(config: &TextFormatConfig) -> Self {
+        let base = match config.format() {
+            TextFormat::Compact => COMPACT_WHITESPACE_CONFIG,
+            TextFormat::Lines => LINES_WHITESPACE_CONFIG,
+            TextFormat::Pretty => PRETTY_WHITESPACE_CONFIG,
+        };
+        // Formats that don't indent ignore the configured indentation width.
+        let indentation_width = match base.indentation_width {
+            0 => 0,
+            _ => config.indentation_width(),
+        };
+        Self {
+            indentation_width,
+            ..base
+        }
+    }
 }
 
 pub(crate) static COMPACT_WHITESPACE_CONFIG: WhitespaceConfig = WhitespaceConfig {
@@ -18,11 +44,13 @@ pub(crate) static COMPACT_WHITESPACE_CONFIG: WhitespaceConfig = WhitespaceConfig
     // Single space between values
     space_between_nested_values: " ",
     // No indentation
-    indentation: "",
+    indentation_width: 0,
     // Single space between field names and values
     space_after_field_name: " ",
     // The first value in a container appears next to the opening delimiter
     space_after_container_start: "",
+    // The closing delimiter appears next to the last value
+    space_before_container_end: "",
 };
 
 pub(crate) static LINES_WHITESPACE_CONFIG: WhitespaceConfig = WhitespaceConfig {
@@ -38,9 +66,11 @@ pub(crate) static PRETTY_WHITESPACE_CONFIG: WhitespaceConfig = WhitespaceConfig
     // Each value appears on its own line
     space_between_nested_values: "\n",
     // Values get two spaces of indentation per level of depth
-    indentation: "  ",
+    indentation_width: 2,
     // Field names and values are separated by a single space
     space_after_field_name: " ",
     // The first value in a container appears on a line by itself
     space_after_container_start: "\n",
+    // The closing delimiter appears on a line by itself
+    space_before_container_end: "\n",
 };
@@ -2,7 +2,6 @@
 
 use std::convert::TryFrom;
 use std::fmt::{Display, Formatter};
-use std::ops::Neg;
 
 use num_traits::Zero;
 
@@ -26,9 +25,9 @@ pub enum Sign {
 /// considered equal. When tested for Ion data equality using [`IonData::eq`](crate::IonData::eq),
 /// they will be considered unequal.
 ///
-/// While the Ion specification allows this type to be of arbitrary size, this implementation currently
-/// supports coefficients in the integer range supported by `i128`.
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+/// Like `Int`, coefficients that fit in an `i128` are stored inline while larger coefficients
+/// are stored on the heap.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub struct Coefficient {
     /// This field exists solely to preserve the distinction between `0` and `-0`.
     /// It will agree with the sign information in the `magnitude` field in all cases *except*
@@ -112,23 +111,23 @@ impl Coefficient {
             // Returning an unsigned zero would be lossy.
             return None;
         }
-        match i64::try_from(self.magnitude.data) {
-            Ok(signed) => match self.sign {
-                Sign::Negative => Some(signed.neg()),
-                Sign::Positive => Some(signed),
-            },
-            Err(_) => None,
-        }
+        self.magnitude.as_i64()
     }
 
-    /// If the value can be represented as an `i128`, return it as such.
-    /// If the coefficient is negative zero or outside the range of an `i128`, returns `None`.
-    pub(crate) fn as_int(&self) -> Option<Int> {
+    /// If the value can be represented as an `Int`, return it as such.
+    /// If the coefficient is negative zero, returns `None`.
+    pub(crate) fn as_int(&self) -> Option<&Int> {
         if self.is_negative_zero() {
             // Returning an unsigned zero would be lossy.
             return None;
         }
-        Some(self.magnitude)
+        Some(&self.magnitude)
+    }
+
+    /// Returns the signed value of the coefficient as an `Int`. Negative zero is returned as `0`;
+    /// callers that need to preserve it must consult [`Coefficient::sign`] separately.
+    pub(crate) fn signed_value(&self) -> &Int {
+        &self.magnitude
     }
 }
 
@@ -171,7 +170,7 @@ impl TryFrom<&Coefficient> for Int {
     type Error = IonError;
 
     fn try_from(value: &Coefficient) -> Result<Self, Self::Error> {
-        (*value).try_into()
+        value.clone().try_into()
     }
 }
 
@@ -190,7 +189,10 @@ impl TryFrom<&Coefficient> for UInt {
     type Error = IonError;
 
     fn try_from(value: &Coefficient) -> Result<Self, Self::Error> {
-        (*value).try_into()
+        if value.is_negative() {
+            return IonResult::illegal_operation("cannot convert a negative Coefficient to a UInt");
+        }
+        Ok(value.magnitude.unsigned_abs())
     }
 }
 
@@ -200,7 +202,7 @@ impl Display for Coefficient {
             Sign::Positive => {}
             Sign::Negative => write!(f, "-")?,
         };
-        write!(f, "{}", self.magnitude)
+        write!(f, "{}", self.magnitude())
     }
 }
 
@@ -298,11 +300,11 @@ mod coefficient_tests {
 
         let enormous_int = Int::try_from(12345678901234567890123456789u128).unwrap();
         assert_eq!(
-            Int::try_from(Coefficient::new(enormous_int)),
-            Ok(enormous_int)
+            Int::try_from(Coefficient::new(enormous_int.clone())),
+            Ok(enormous_int.clone())
         );
         assert_eq!(
-            Int::try_from(Coefficient::new(enormous_int.neg())),
+            Int::try_from(Coefficient::new(enormous_int.clone().neg())),
             Ok(enormous_int.neg())
         );
 
@@ -4,12 +4,16 @@ use std::cmp::Ordering;
 
 use crate::decimal::coefficient::{Coefficient, Sign};
 use crate::ion_data::{IonEq, IonOrd};
+use crate::lazy::expanded::EncodingContext;
+use crate::lazy::text::buffer::TextBuffer;
 use crate::result::{IonError, IonFailure};
 use crate::{Int, IonResult, UInt};
+use num_bigint::BigUint;
 use num_traits::Zero;
 use std::convert::{TryFrom, TryInto};
 use std::fmt::{Display, Formatter};
-use std::ops::Neg;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
 
 pub mod coefficient;
 
@@ -36,7 +40,7 @@ pub mod coefficient;
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct Decimal {
     // A Coefficient is a `(Sign, UInt)` pair supporting integers of arbitrary size
     pub(crate) coefficient: Coefficient,
@@ -54,6 +58,12 @@ impl Decimal {
         exponent: 0,
     };
 
+    /// The largest number of decimal digits that a coefficient may have after being scaled up
+    /// to pair it with a smaller exponent. Operations that would need to produce a longer
+    /// coefficient, such as adding `1d4000000000` to `1.0`, fail instead of allocating an
+    /// arbitrarily large integer.
+    pub const MAX_SCALED_DIGITS: u32 = 100_000;
+
     /// Constructs a new Decimal with the provided components. The value of the decimal is:
     ///    `coefficient * 10^exponent`
     pub fn new<C: Into<Coefficient>, E: Into<i64>>(coefficient: C, exponent: E) -> Decimal {
@@ -113,7 +123,7 @@ impl Decimal {
     /// Returns true if this Decimal's coefficient has a negative sign AND a magnitude greater than
     /// zero. Otherwise, returns false. (Negative zero returns false.)
     pub fn is_less_than_zero(&self) -> bool {
-        self.coefficient.sign() == Sign::Negative && self.coefficient.magnitude().data > 0
+        self.coefficient.sign() == Sign::Negative && !self.coefficient.is_zero()
     }
 
     /// Semantically identical to `self >= Decimal::new(1, 0)`, but much cheaper to compute.
@@ -186,7 +196,21 @@ impl Decimal {
     // Scales up the coefficient associated with a greater exponent and compares it with the
     // other coefficient. `d1` must have a larger exponent than `d2`.
     fn compare_scaled_coefficients(d1: &Decimal, d2: &Decimal) -> Ordering {
-        let exponent_delta = d1.exponent - d2.exponent;
+        // The exponents are i64s, so their difference might not fit in an i64.
+        let exponent_delta = d1.exponent as i128 - d2.exponent as i128;
+        let d1_magnitude = d1.coefficient.magnitude();
+        let d2_magnitude = d2.coefficient.magnitude();
+        if d1_magnitude.is_zero() {
+            return d1_magnitude.cmp(&d2_magnitude);
+        }
+        // Before doing any arithmetic, see whether the number of digits in each coefficient is
+        // enough to tell them apart. Scaling d1's coefficient up adds `exponent_delta` digits.
+        // This also keeps us from attempting to scale by an enormous power of ten.
+        let d1_scaled_digits = d1_magnitude.number_of_decimal_digits() as i128 + exponent_delta;
+        let d2_digits = d2_magnitude.number_of_decimal_digits() as i128;
+        if d1_scaled_digits != d2_digits {
+            return d1_scaled_digits.cmp(&d2_digits);
+        }
         // d1 has a larger exponent, so scale up its coefficient to match d2's exponent.
         // For example, when comparing these values of d1 and d2:
         //     d1 =  8 * 10^3
@@ -194,9 +218,462 @@ impl Decimal {
         // d1 has the larger exponent (3). We need to scale its coefficient up to d2's 10^2 scale.
         // We do this by multiplying it times 10^exponent_delta, which is 1 in this case.
         // This lets us compare 80 and 80, determining that the decimals are equal.
-        let mut scaled_coefficient: u128 = d1.coefficient.magnitude().data;
-        scaled_coefficient *= 10u128.pow(exponent_delta as u32);
-        UInt::from(scaled_coefficient).cmp(&d2.coefficient.magnitude())
+        // Because the scaled d1 has as many digits as d2, `exponent_delta` fits in a u32.
+        let exponent_delta = exponent_delta as u32;
+        let scaled_coefficient = match d1_magnitude.as_u128() {
+            Some(magnitude) => 10u128
+                .checked_pow(exponent_delta)
+                .and_then(|scale| magnitude.checked_mul(scale))
+                .map(UInt::from),
+            None => None,
+        }
+        .unwrap_or_else(|| {
+            UInt::from(BigUint::from(d1_magnitude) * BigUint::from(10u32).pow(exponent_delta))
+        });
+        scaled_coefficient.cmp(&d2_magnitude)
+    }
+
+    /// Adds `other` to this `Decimal`, returning `None` if the result cannot be represented.
+    ///
+    /// The exponent of the sum is the smaller of the two operands' exponents, so no precision is
+    /// lost. Aligning the operands requires scaling one of the coefficients by `10^d`, where `d`
+    /// is the difference between the exponents; if the scaled coefficient would have more than
+    /// [`Decimal::MAX_SCALED_DIGITS`] digits, this method returns `None`.
+    ///
+    /// ```
+    /// use ion_rs::Decimal;
+    /// let sum = Decimal::new(125, -2).checked_add(&Decimal::new(5, -1)).unwrap();
+    /// assert_eq!(sum, Decimal::new(175, -2));
+    /// ```
+    pub fn checked_add(&self, other: &Decimal) -> Option<Decimal> {
+        let exponent = self.exponent.min(other.exponent);
+        let lhs = self.coefficient_with_exponent(exponent)?;
+        let rhs = other.coefficient_with_exponent(exponent)?;
+        let sum = lhs + rhs;
+        let coefficient =
+            if sum.is_zero() && self.coefficient.is_negative() && other.coefficient.is_negative() {
+                // As in IEEE-754, the sum of two negative zeros is a negative zero. Any other
+                // combination of operands that sums to zero produces a positive zero.
+                Coefficient::negative_zero()
+            } else {
+                Coefficient::new(sum)
+            };
+        Some(Decimal::new(coefficient, exponent))
+    }
+
+    /// Subtracts `other` from this `Decimal`, returning `None` if the result cannot be represented.
+    /// See [`Decimal::checked_add`] for details.
+    pub fn checked_sub(&self, other: &Decimal) -> Option<Decimal> {
+        self.checked_add(&-other)
+    }
+
+    /// Multiplies this `Decimal` by `other`, returning `None` if the exponent of the product
+    /// would overflow an `i64`.
+    ///
+    /// The coefficient of the product is the product of the coefficients and its exponent is the
+    /// sum of the exponents. The sign of the product is negative if exactly one of the operands is
+    /// negative; this holds for zeros as well.
+    ///
+    /// ```
+    /// use ion_rs::Decimal;
+    /// let product = Decimal::new(15, -1).checked_mul(&Decimal::new(-25, -2)).unwrap();
+    /// assert_eq!(product, Decimal::new(-375, -3));
+    /// ```
+    pub fn checked_mul(&self, other: &Decimal) -> Option<Decimal> {
+        let exponent = self.exponent.checked_add(other.exponent)?;
+        let product = self.coefficient.signed_value() * other.coefficient.signed_value();
+        let coefficient = if product.is_zero()
+            && self.coefficient.is_negative() != other.coefficient.is_negative()
+        {
+            Coefficient::negative_zero()
+        } else {
+            Coefficient::new(product)
+        };
+        Some(Decimal::new(coefficient, exponent))
+    }
+
+    /// Returns a `Decimal` that is numerically equivalent to this one (subject to rounding) and
+    /// whose [`scale`](Self::scale) is `scale`.
+    ///
+    /// If the new scale is larger than the current one, the coefficient is multiplied by the
+    /// appropriate power of ten and no rounding occurs. If the new scale is smaller, digits are
+    /// discarded and the result is rounded according to `mode`. The sign of the value is retained
+    /// even if rounding produces a zero.
+    ///
+    /// ```
+    /// use ion_rs::Decimal;
+    /// use ion_rs::decimal::RoundingMode;
+    /// let value = Decimal::new(12345, -3); // 12.345
+    /// assert_eq!(value.rescale(2, RoundingMode::HalfEven), Decimal::new(1234, -2));
+    /// assert_eq!(value.rescale(2, RoundingMode::HalfUp), Decimal::new(1235, -2));
+    /// assert_eq!(value.rescale(5, RoundingMode::HalfUp), Decimal::new(1234500, -5));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if increasing the scale would produce a coefficient with more than
+    /// [`Decimal::MAX_SCALED_DIGITS`] digits, or if `scale` is `i64::MIN`. See
+    /// [`Decimal::checked_rescale`].
+    pub fn rescale(&self, scale: i64, mode: RoundingMode) -> Decimal {
+        self.checked_rescale(scale, mode)
+            .expect("decimal rescale overflowed")
+    }
+
+    /// Like [`Decimal::rescale`], but returns `None` instead of panicking if increasing the scale
+    /// would produce a coefficient with more than [`Decimal::MAX_SCALED_DIGITS`] digits. Also returns `None` if `scale` is `i64::MIN`, whose corresponding exponent does not fit
+    /// in an `i64`.
+    pub fn checked_rescale(&self, scale: i64, mode: RoundingMode) -> Option<Decimal> {
+        // `scale` is the negation of the exponent. Negating `i64::MIN` overflows, but the
+        // exponent can be computed losslessly using an i128.
+        let target_exponent = -(scale as i128);
+        let exponent = self.exponent as i128;
+        if target_exponent <= exponent {
+            // No rounding is necessary. `target_exponent` will fit in an i64 because it is bounded
+            // by `self.exponent` and the negation of an i64.
+            let target_exponent = target_exponent as i64;
+            let coefficient = match self.coefficient_with_exponent(target_exponent)? {
+                scaled if scaled.is_zero() => self.zero_coefficient(),
+                scaled => Coefficient::new(scaled),
+            };
+            return Some(Decimal::new(coefficient, target_exponent));
+        }
+        // If the exponent is being raised, the target exponent is in the range
+        // `(i64::MIN, i64::MAX + 1]`. The latter can only occur when `scale` is `i64::MIN`.
+        let target_exponent = i64::try_from(target_exponent).ok()?;
+        let digits_to_discard = (target_exponent as i128 - exponent) as u128;
+        let is_negative = self.coefficient.is_negative();
+        let magnitude = self.coefficient.magnitude();
+        let (quotient, remainder) = divide_by_power_of_ten(&magnitude, digits_to_discard);
+        let magnitude = if mode.rounds_away_from_zero(is_negative, &quotient, remainder) {
+            quotient + UInt::from(1u32)
+        } else {
+            quotient
+        };
+        // Every `UInt` can be represented as an `Int`, so this conversion cannot fail.
+        let coefficient = match Int::try_from(magnitude).ok()? {
+            magnitude if magnitude.is_zero() => self.zero_coefficient(),
+            magnitude if is_negative => Coefficient::new(-magnitude),
+            magnitude => Coefficient::new(magnitude),
+        };
+        Some(Decimal::new(coefficient, target_exponent))
+    }
+
+    /// Rounds this `Decimal` to an integral value using the specified rounding `mode`. Values
+    /// whose exponent is already zero or greater are returned unchanged.
+    ///
+    /// ```
+    /// use ion_rs::Decimal;
+    /// use ion_rs::decimal::RoundingMode;
+    /// let value = Decimal::new(-25, -1); // -2.5
+    /// assert_eq!(value.round(RoundingMode::HalfEven), Decimal::new(-2, 0));
+    /// assert_eq!(value.round(RoundingMode::HalfUp), Decimal::new(-3, 0));
+    /// assert_eq!(value.round(RoundingMode::Floor), Decimal::new(-3, 0));
+    /// assert_eq!(value.round(RoundingMode::Ceiling), Decimal::new(-2, 0));
+    /// ```
+    pub fn round(&self, mode: RoundingMode) -> Decimal {
+        if self.exponent >= 0 {
+            return self.clone();
+        }
+        // Decreasing the scale never requires scaling the coefficient up, so this cannot fail.
+        self.rescale(0, mode)
+    }
+
+    /// Returns the `f64` nearest to this `Decimal`'s value. Values whose magnitude is too large
+    /// to represent become infinity; values whose magnitude is too small become zero. Negative
+    /// zero is converted to `-0.0`.
+    ///
+    /// ```
+    /// use ion_rs::Decimal;
+    /// assert_eq!(Decimal::new(-15, -1).to_f64(), -1.5);
+    /// assert_eq!(Decimal::new(1, 400).to_f64(), f64::INFINITY);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        // Rust's float parsing is correctly rounded, and the coefficient's Display impl preserves
+        // the sign of negative zero.
+        format!("{}e{}", self.coefficient, self.exponent)
+            .parse()
+            .expect("a coefficient and exponent are always a valid f64 literal")
+    }
+
+    /// Formats this `Decimal` in plain (non-exponential) notation with exactly `places` digits
+    /// following the decimal point, rounding according to `mode` if necessary. If `places` is
+    /// zero, no decimal point is written.
+    ///
+    /// Unlike this type's [`Display`] implementation, the output is not Ion text.
+    ///
+    /// ```
+    /// use ion_rs::Decimal;
+    /// use ion_rs::decimal::RoundingMode;
+    /// let value = Decimal::new(-4205, -3); // -4.205
+    /// assert_eq!(value.to_fixed(2, RoundingMode::HalfUp), "-4.21");
+    /// assert_eq!(value.to_fixed(5, RoundingMode::HalfUp), "-4.20500");
+    /// assert_eq!(Decimal::new(5, 2).to_fixed(1, RoundingMode::HalfUp), "500.0");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if writing `places` fractional digits would require scaling a non-zero coefficient
+    /// to more than [`Decimal::MAX_SCALED_DIGITS`] digits. See [`Decimal::checked_to_fixed`].
+    pub fn to_fixed(&self, places: u32, mode: RoundingMode) -> String {
+        self.checked_to_fixed(places, mode)
+            .expect("decimal rescale overflowed")
+    }
+
+    /// Like [`Decimal::to_fixed`], but returns `None` instead of panicking if the scaled
+    /// coefficient would have more than [`Decimal::MAX_SCALED_DIGITS`] digits.
+    ///
+    /// ```
+    /// use ion_rs::Decimal;
+    /// use ion_rs::decimal::RoundingMode;
+    /// let mode = RoundingMode::HalfUp;
+    /// assert_eq!(Decimal::new(15, -1).checked_to_fixed(0, mode), Some("2".to_owned()));
+    /// assert_eq!(Decimal::new(1, i64::MAX).checked_to_fixed(0, mode), None);
+    /// ```
+    pub fn checked_to_fixed(&self, places: u32, mode: RoundingMode) -> Option<String> {
+        let rescaled = self.checked_rescale(places as i64, mode)?;
+        let digits = rescaled.coefficient.magnitude().to_string();
+        let places = places as usize;
+        let mut text = String::with_capacity(digits.len().max(places + 1) + 2);
+        if rescaled.coefficient.is_negative() {
+            text.push('-');
+        }
+        if places == 0 {
+            text.push_str(&digits);
+            return Some(text);
+        }
+        // Left-pad the digits with zeros so there is at least one digit before the decimal point.
+        let padded = format!("{digits:0>width$}", width = places + 1);
+        let (integer_digits, fractional_digits) = padded.split_at(padded.len() - places);
+        text.push_str(integer_digits);
+        text.push('.');
+        text.push_str(fractional_digits);
+        Some(text)
+    }
+
+    /// Returns this `Decimal`'s coefficient scaled so that it can be paired with `exponent`, which
+    /// must be less than or equal to `self.exponent`. Returns `None` if the scaled coefficient
+    /// would have more than [`Decimal::MAX_SCALED_DIGITS`] digits.
+    fn coefficient_with_exponent(&self, exponent: i64) -> Option<Int> {
+        let coefficient = self.coefficient.signed_value();
+        if coefficient.is_zero() || exponent == self.exponent {
+            return Some(coefficient.clone());
+        }
+        let exponent_delta = self.exponent as i128 - exponent as i128;
+        let scaled_digits = self.coefficient.number_of_decimal_digits() as i128 + exponent_delta;
+        if scaled_digits > Decimal::MAX_SCALED_DIGITS as i128 {
+            return None;
+        }
+        // `exponent_delta` is smaller than `MAX_SCALED_DIGITS`, so it fits in a u32.
+        Some(coefficient * Int::from(10).pow(exponent_delta as u32))
+    }
+
+    /// Returns a zero coefficient with the same sign as this `Decimal`'s coefficient.
+    fn zero_coefficient(&self) -> Coefficient {
+        if self.coefficient.is_negative() {
+            Coefficient::negative_zero()
+        } else {
+            Coefficient::ZERO
+        }
+    }
+}
+
+/// Strategies for discarding digits when reducing the scale of a [`Decimal`]. The names and
+/// behavior of each variant mirror those of Java's `java.math.RoundingMode`.
+///
+/// | Input | `Up` | `Down` | `Ceiling` | `Floor` | `HalfUp` | `HalfDown` | `HalfEven` |
+/// |-------|------|--------|-----------|---------|----------|------------|------------|
+/// |  5.5  |  6   |   5    |     6     |    5    |    6     |     5      |     6      |
+/// |  2.5  |  3   |   2    |     3     |    2    |    3     |     2      |     2      |
+/// |  1.6  |  2   |   1    |     2     |    1    |    2     |     2      |     2      |
+/// |  1.1  |  2   |   1    |     2     |    1    |    1     |     1      |     1      |
+/// | -1.1  |  -2  |   -1   |    -1     |   -2    |    -1    |     -1     |     -1     |
+/// | -2.5  |  -3  |   -2   |    -2     |   -3    |    -3    |     -2     |     -2     |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round away from zero.
+    Up,
+    /// Round toward zero; that is, truncate the discarded digits.
+    Down,
+    /// Round toward positive infinity.
+    Ceiling,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward the nearest neighbor. If both neighbors are equidistant, round away from zero.
+    HalfUp,
+    /// Round toward the nearest neighbor. If both neighbors are equidistant, round toward zero.
+    HalfDown,
+    /// Round toward the nearest neighbor. If both neighbors are equidistant, round toward the even
+    /// neighbor. This is sometimes called "banker's rounding."
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Given the truncated magnitude of a value and how its discarded digits compare to half of
+    /// a unit in the last retained place, returns `true` if the magnitude should be incremented.
+    fn rounds_away_from_zero(
+        &self,
+        is_negative: bool,
+        truncated_magnitude: &UInt,
+        discarded: DiscardedDigits,
+    ) -> bool {
+        use DiscardedDigits::*;
+        use RoundingMode::*;
+        match (self, discarded) {
+            (_, Zero) => false,
+            (Up, _) => true,
+            (Down, _) => false,
+            (Ceiling, _) => !is_negative,
+            (Floor, _) => is_negative,
+            (HalfUp | HalfDown | HalfEven, LessThanHalf) => false,
+            (HalfUp | HalfDown | HalfEven, MoreThanHalf) => true,
+            (HalfUp, Half) => true,
+            (HalfDown, Half) => false,
+            (HalfEven, Half) => !is_even(truncated_magnitude),
+        }
+    }
+}
+
+/// Describes the digits that were discarded when reducing the scale of a [`Decimal`] relative to
+/// half of a unit in the last retained place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiscardedDigits {
+    Zero,
+    LessThanHalf,
+    Half,
+    MoreThanHalf,
+}
+
+impl DiscardedDigits {
+    fn new(remainder: &UInt, divisor: &UInt) -> Self {
+        if remainder.is_zero() {
+            return DiscardedDigits::Zero;
+        }
+        match (remainder + remainder).cmp(divisor) {
+            Ordering::Less => DiscardedDigits::LessThanHalf,
+            Ordering::Equal => DiscardedDigits::Half,
+            Ordering::Greater => DiscardedDigits::MoreThanHalf,
+        }
+    }
+}
+
+fn is_even(value: &UInt) -> bool {
+    (value & UInt::from(1u32)).is_zero()
+}
+
+/// Divides `magnitude` by `10^digits`, returning the quotient and a description of the remainder.
+fn divide_by_power_of_ten(magnitude: &UInt, digits: u128) -> (UInt, DiscardedDigits) {
+    if magnitude.is_zero() {
+        return (UInt::ZERO, DiscardedDigits::Zero);
+    }
+    // If the divisor has more digits than the magnitude, the quotient is zero and the remainder
+    // (the entire magnitude) is less than half of the divisor. Handling this case up front also
+    // spares us from computing enormous powers of ten.
+    if digits > magnitude.number_of_decimal_digits() as u128 {
+        return (UInt::ZERO, DiscardedDigits::LessThanHalf);
+    }
+    // `digits` is no larger than the number of digits in the magnitude, so it fits in a u32.
+    let divisor = UInt::from(10u32).pow(digits as u32);
+    let quotient = magnitude / &divisor;
+    let remainder = magnitude % &divisor;
+    (quotient, DiscardedDigits::new(&remainder, &divisor))
+}
+
+macro_rules! impl_decimal_binary_operator {
+    ($trait_name:ident, $method_name:ident, $checked_method_name:ident, $description:literal) => {
+        impl $trait_name<&Decimal> for &Decimal {
+            type Output = Decimal;
+
+            fn $method_name(self, rhs: &Decimal) -> Decimal {
+                self.$checked_method_name(rhs).expect(concat!(
+                    "decimal ",
+                    $description,
+                    " overflowed"
+                ))
+            }
+        }
+
+        impl $trait_name for Decimal {
+            type Output = Decimal;
+
+            fn $method_name(self, rhs: Decimal) -> Decimal {
+                (&self).$method_name(&rhs)
+            }
+        }
+
+        impl $trait_name<&Decimal> for Decimal {
+            type Output = Decimal;
+
+            fn $method_name(self, rhs: &Decimal) -> Decimal {
+                (&self).$method_name(rhs)
+            }
+        }
+
+        impl $trait_name<Decimal> for &Decimal {
+            type Output = Decimal;
+
+            fn $method_name(self, rhs: Decimal) -> Decimal {
+                self.$method_name(&rhs)
+            }
+        }
+    };
+}
+
+// Like the operators for primitive integers, these panic if the result cannot be represented.
+// The `checked_*` methods can be used to detect that case instead.
+impl_decimal_binary_operator!(Add, add, checked_add, "addition");
+impl_decimal_binary_operator!(Sub, sub, checked_sub, "subtraction");
+impl_decimal_binary_operator!(Mul, mul, checked_mul, "multiplication");
+
+impl Neg for &Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Decimal {
+        let coefficient = if self.coefficient.is_zero() {
+            // Negation flips the sign of zero coefficients, too.
+            if self.coefficient.is_negative() {
+                Coefficient::ZERO
+            } else {
+                Coefficient::negative_zero()
+            }
+        } else {
+            Coefficient::new(-self.coefficient.signed_value())
+        };
+        Decimal::new(coefficient, self.exponent)
+    }
+}
+
+impl Neg for Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Decimal {
+        -&self
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = IonError;
+
+    /// Parses a `Decimal` from its Ion text representation, like `1.5`, `-0.`, or `15d-1`.
+    /// Leading and trailing whitespace are not permitted. Note that text like `15` is an Ion
+    /// integer, not a decimal, and will be rejected.
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::Decimal;
+    /// let decimal: Decimal = "1_000.25".parse()?;
+    /// assert_eq!(decimal, Decimal::new(100025, -2));
+    /// assert!("1000".parse::<Decimal>().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let encoding_context = EncodingContext::empty();
+        let buffer = TextBuffer::new(encoding_context.get_ref(), text.as_bytes());
+        match buffer.match_decimal() {
+            Ok((remaining, matched)) if remaining.is_empty() => matched.read(buffer),
+            _ => IonResult::decoding_error(format!("'{text}' is not a valid Ion decimal")),
+        }
     }
 }
 
@@ -442,6 +919,7 @@ impl Display for Decimal {
 #[cfg(test)]
 mod decimal_tests {
     use crate::decimal::coefficient::Coefficient;
+    use crate::decimal::RoundingMode;
     use crate::result::IonResult;
     use crate::{Decimal, Int};
 
@@ -705,4 +1183,326 @@ mod decimal_tests {
     ) {
         assert_eq!(Decimal::new(coefficient, 0), expected);
     }
+
+    fn big_coefficient(digits: &str) -> Int {
+        Int::from(digits.parse::<num_bigint::BigInt>().unwrap())
+    }
+
+    #[rstest]
+    #[case::big_vs_small_same_value(
+        Decimal::new(big_coefficient(&format!("1{}", "0".repeat(45))), -45),
+        Decimal::new(1, 0),
+        Ordering::Equal
+    )]
+    #[case::big_vs_small(
+        Decimal::new(big_coefficient(&"9".repeat(45)), 0),
+        Decimal::new(i128::MAX, 0),
+        Ordering::Greater
+    )]
+    #[case::negative_big_vs_small(
+        Decimal::new(big_coefficient(&format!("-{}", "9".repeat(45))), 0),
+        Decimal::new(i128::MIN, 0),
+        Ordering::Less
+    )]
+    #[case::huge_exponent_delta(
+        Decimal::new(1, i64::MAX),
+        Decimal::new(1, i64::MIN),
+        Ordering::Greater
+    )]
+    fn big_decimal_ordering(
+        #[case] this: Decimal,
+        #[case] other: Decimal,
+        #[case] expected: Ordering,
+    ) {
+        assert_eq!(this.cmp(&other), expected);
+    }
+
+    #[rstest]
+    #[case::fifty_digits("1234567890123456789012345678901234567890.1234567890")]
+    #[case::negative_fifty_digits("-1234567890123456789012345678901234567890.1234567890")]
+    #[case::big_exponent("12345678901234567890123456789012345678901234567890d-500")]
+    fn big_decimal_round_trip(#[case] text: &str) -> IonResult<()> {
+        use crate::{v1_0, Element};
+        let element = Element::read_one(text)?;
+        assert_eq!(element.expect_decimal()?.precision(), 50);
+
+        let text_1_0 = element.encode_as(v1_0::Text)?;
+        assert_eq!(Element::read_one(text_1_0)?, element);
+        let binary_1_0 = element.encode_as(v1_0::Binary)?;
+        assert_eq!(Element::read_one(binary_1_0)?, element);
+        #[cfg(feature = "experimental-ion-1-1")]
+        {
+            use crate::v1_1;
+            let text_1_1 = element.encode_as(v1_1::Text)?;
+            assert_eq!(Element::read_one(text_1_1)?, element);
+            let binary_1_1 = element.encode_as(v1_1::Binary)?;
+            assert_eq!(Element::read_one(binary_1_1)?, element);
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::same_exponent(Decimal::new(15, -1), Decimal::new(25, -1), Decimal::new(40, -1))]
+    #[case::different_exponents(Decimal::new(125, -2), Decimal::new(5, -1), Decimal::new(175, -2))]
+    #[case::positive_exponent(Decimal::new(3, 2), Decimal::new(4, 0), Decimal::new(304, 0))]
+    #[case::mixed_signs(Decimal::new(-125, -2), Decimal::new(25, -2), Decimal::new(-100, -2))]
+    #[case::sums_to_zero(Decimal::new(-5, -1), Decimal::new(5, -1), Decimal::new(0, -1))]
+    #[case::negative_zeros(Decimal::negative_zero(), Decimal::negative_zero_with_exponent(-2), Decimal::negative_zero_with_exponent(-2))]
+    #[case::mixed_zeros(Decimal::negative_zero(), Decimal::new(0, 0), Decimal::new(0, 0))]
+    #[case::overflows_i128(
+        Decimal::new(i128::MAX, 0),
+        Decimal::new(1, 0),
+        Decimal::new(big_coefficient("170141183460469231731687303715884105728"), 0)
+    )]
+    fn test_decimal_add(#[case] lhs: Decimal, #[case] rhs: Decimal, #[case] expected: Decimal) {
+        let sum = lhs.checked_add(&rhs).unwrap();
+        assert!(
+            sum.ion_eq(&expected),
+            "{lhs} + {rhs} = {sum}, expected {expected}"
+        );
+        assert!((&rhs + &lhs).ion_eq(&expected));
+        assert!((lhs - (-rhs)).ion_eq(&expected));
+    }
+
+    #[rstest]
+    #[case::simple(Decimal::new(15, -1), Decimal::new(-25, -2), Decimal::new(-375, -3))]
+    #[case::positive_exponents(Decimal::new(2, 3), Decimal::new(3, 4), Decimal::new(6, 7))]
+    #[case::zero_times_negative(Decimal::new(0, -1), Decimal::new(-3, 0), Decimal::negative_zero_with_exponent(-1))]
+    #[case::negative_zeros(Decimal::negative_zero(), Decimal::negative_zero(), Decimal::new(0, 0))]
+    #[case::overflows_i128(Decimal::new(i128::MAX, 0), Decimal::new(10, -1), Decimal::new(big_coefficient("1701411834604692317316873037158841057270"), -1))]
+    fn test_decimal_mul(#[case] lhs: Decimal, #[case] rhs: Decimal, #[case] expected: Decimal) {
+        let product = lhs.checked_mul(&rhs).unwrap();
+        assert!(
+            product.ion_eq(&expected),
+            "{lhs} * {rhs} = {product}, expected {expected}"
+        );
+        assert!((rhs * lhs).ion_eq(&expected));
+    }
+
+    #[test]
+    fn test_decimal_checked_arithmetic_overflow() {
+        assert_eq!(
+            Decimal::new(1, i64::MAX).checked_mul(&Decimal::new(1, 1)),
+            None
+        );
+        assert_eq!(
+            Decimal::new(1, i64::MAX).checked_add(&Decimal::new(1, i64::MIN)),
+            None
+        );
+        // Zeros never need to be scaled, so they can be aligned with any exponent.
+        assert_eq!(
+            Decimal::new(0, i64::MAX).checked_add(&Decimal::new(1, i64::MIN)),
+            Some(Decimal::new(1, i64::MIN))
+        );
+    }
+
+    #[test]
+    fn test_decimal_checked_arithmetic_with_distant_exponents() {
+        // Aligning `1d4000000000` with `1.0` would produce a coefficient with four billion digits.
+        let huge = Decimal::new(1, 4_000_000_000i64);
+        let one = Decimal::new(10, -1);
+        assert_eq!(huge.checked_add(&one), None);
+        assert_eq!(one.checked_sub(&huge), None);
+        assert_eq!(huge.checked_rescale(1, RoundingMode::HalfUp), None);
+        assert_eq!(
+            one.checked_rescale(4_000_000_000, RoundingMode::HalfUp),
+            None
+        );
+
+        // Scaling is allowed up to `MAX_SCALED_DIGITS` digits and no further.
+        let max_digits = i64::from(Decimal::MAX_SCALED_DIGITS);
+        let sum = Decimal::new(1, max_digits - 1)
+            .checked_add(&Decimal::new(1, 0))
+            .unwrap();
+        assert_eq!(sum.exponent(), 0);
+        assert_eq!(
+            sum.coefficient().number_of_decimal_digits(),
+            Decimal::MAX_SCALED_DIGITS
+        );
+        assert_eq!(
+            Decimal::new(1, max_digits).checked_add(&Decimal::new(1, 0)),
+            None
+        );
+        assert_eq!(
+            Decimal::new(10, max_digits - 1).checked_add(&Decimal::new(1, 0)),
+            None
+        );
+
+        // Reducing the scale discards digits rather than adding them, so it is never limited.
+        assert_eq!(
+            Decimal::new(15, -4_000_000_000i64).checked_rescale(0, RoundingMode::Up),
+            Some(Decimal::new(1, 0))
+        );
+    }
+
+    #[rstest]
+    #[case::positive(Decimal::new(5, -1), Decimal::new(-5, -1))]
+    #[case::negative(Decimal::new(-5, -1), Decimal::new(5, -1))]
+    #[case::zero(Decimal::new(0, 3), Decimal::negative_zero_with_exponent(3))]
+    #[case::negative_zero(Decimal::negative_zero(), Decimal::new(0, 0))]
+    #[case::i128_min(
+        Decimal::new(i128::MIN, 0),
+        Decimal::new(big_coefficient("170141183460469231731687303715884105728"), 0)
+    )]
+    fn test_decimal_neg(#[case] value: Decimal, #[case] expected: Decimal) {
+        assert!((-&value).ion_eq(&expected));
+        assert!((-(-value.clone())).ion_eq(&value));
+    }
+
+    #[rstest]
+    #[case(Decimal::new(12345, -3), 2, RoundingMode::HalfEven, Decimal::new(1234, -2))]
+    #[case(Decimal::new(12345, -3), 2, RoundingMode::HalfUp, Decimal::new(1235, -2))]
+    #[case(Decimal::new(12345, -3), 2, RoundingMode::HalfDown, Decimal::new(1234, -2))]
+    #[case(Decimal::new(12346, -3), 2, RoundingMode::HalfDown, Decimal::new(1235, -2))]
+    #[case(Decimal::new(12341, -3), 2, RoundingMode::Up, Decimal::new(1235, -2))]
+    #[case(Decimal::new(12349, -3), 2, RoundingMode::Down, Decimal::new(1234, -2))]
+    #[case(Decimal::new(-12341, -3), 2, RoundingMode::Ceiling, Decimal::new(-1234, -2))]
+    #[case(Decimal::new(-12341, -3), 2, RoundingMode::Floor, Decimal::new(-1235, -2))]
+    #[case(Decimal::new(12, -1), 3, RoundingMode::Down, Decimal::new(1200, -3))]
+    #[case(Decimal::new(12, 2), 0, RoundingMode::Down, Decimal::new(1200, 0))]
+    #[case(Decimal::new(1250, 0), -2, RoundingMode::HalfEven, Decimal::new(12, 2))]
+    #[case(Decimal::new(-1, -3), 2, RoundingMode::HalfEven, Decimal::negative_zero_with_exponent(-2))]
+    #[case(Decimal::new(-1, -3), 2, RoundingMode::Up, Decimal::new(-1, -2))]
+    #[case::far_smaller_scale(
+        Decimal::new(5, 0),
+        -i64::MAX,
+        RoundingMode::Up,
+        Decimal::new(1, i64::MAX)
+    )]
+    #[case::big_coefficient(
+        Decimal::new(big_coefficient("123456789012345678901234567890123456789012345"), -5),
+        0,
+        RoundingMode::HalfUp,
+        Decimal::new(big_coefficient("1234567890123456789012345678901234567890"), 0)
+    )]
+    fn test_rescale(
+        #[case] value: Decimal,
+        #[case] scale: i64,
+        #[case] mode: RoundingMode,
+        #[case] expected: Decimal,
+    ) {
+        let rescaled = value.rescale(scale, mode);
+        assert!(
+            rescaled.ion_eq(&expected),
+            "{value} rescaled to {scale} ({mode:?}) was {rescaled}, expected {expected}"
+        );
+    }
+
+    #[rstest]
+    #[case(5.5, [6, 5, 6, 5, 6, 5, 6])]
+    #[case(2.5, [3, 2, 3, 2, 3, 2, 2])]
+    #[case(1.6, [2, 1, 2, 1, 2, 2, 2])]
+    #[case(1.1, [2, 1, 2, 1, 1, 1, 1])]
+    #[case(1.0, [1, 1, 1, 1, 1, 1, 1])]
+    #[case(-1.1, [-2, -1, -1, -2, -1, -1, -1])]
+    #[case(-2.5, [-3, -2, -2, -3, -3, -2, -2])]
+    fn test_rounding_modes(#[case] value: f64, #[case] expected: [i64; 7]) {
+        use RoundingMode::*;
+        let value = Decimal::try_from(value).unwrap();
+        for (mode, expected) in [Up, Down, Ceiling, Floor, HalfUp, HalfDown, HalfEven]
+            .into_iter()
+            .zip(expected)
+        {
+            assert_eq!(
+                value.round(mode),
+                Decimal::from(expected),
+                "{value} rounded with {mode:?}"
+            );
+        }
+    }
+
+    #[rstest]
+    #[case(Decimal::new(15, -1), 1.5)]
+    #[case(Decimal::new(-15, 3), -15000.0)]
+    #[case(Decimal::new(1, -400), 0.0)]
+    #[case(Decimal::new(1, 400), f64::INFINITY)]
+    #[case(Decimal::new(-1, i64::MAX), f64::NEG_INFINITY)]
+    #[case(Decimal::new(big_coefficient("123456789012345678901234567890123456789012345"), -44), 1.2345678901234568)]
+    fn test_to_f64(#[case] value: Decimal, #[case] expected: f64) {
+        assert_eq!(value.to_f64(), expected);
+    }
+
+    #[test]
+    fn test_to_f64_negative_zero() {
+        let value = Decimal::negative_zero().to_f64();
+        assert_eq!(value, 0.0);
+        assert!(value.is_sign_negative());
+    }
+
+    #[rstest]
+    #[case(Decimal::new(-4205, -3), 2, RoundingMode::HalfUp, "-4.21")]
+    #[case(Decimal::new(-4205, -3), 2, RoundingMode::HalfEven, "-4.20")]
+    #[case(Decimal::new(-4205, -3), 5, RoundingMode::HalfUp, "-4.20500")]
+    #[case(Decimal::new(5, 2), 1, RoundingMode::HalfUp, "500.0")]
+    #[case(Decimal::new(5, -3), 2, RoundingMode::HalfUp, "0.01")]
+    #[case(Decimal::new(5, -3), 4, RoundingMode::HalfUp, "0.0050")]
+    #[case(Decimal::new(49, -1), 0, RoundingMode::HalfUp, "5")]
+    #[case(Decimal::new(0, 0), 2, RoundingMode::HalfUp, "0.00")]
+    fn test_to_fixed(
+        #[case] value: Decimal,
+        #[case] places: u32,
+        #[case] mode: RoundingMode,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(value.to_fixed(places, mode), expected);
+    }
+
+    #[test]
+    fn test_checked_to_fixed_at_scaling_limit() {
+        // The scaled coefficient can have at most `MAX_SCALED_DIGITS` digits. Zero never needs to
+        // be scaled.
+        let value = Decimal::new(0, i64::from(u32::MAX) - 1);
+        assert_eq!(
+            value.checked_to_fixed(2, RoundingMode::HalfUp),
+            Some("0.00".to_owned())
+        );
+        let value = Decimal::new(1, i64::from(u32::MAX) - 1);
+        assert_eq!(value.checked_to_fixed(2, RoundingMode::HalfUp), None);
+        let value = Decimal::new(1, i64::from(Decimal::MAX_SCALED_DIGITS) - 3);
+        let text = value.checked_to_fixed(2, RoundingMode::HalfUp).unwrap();
+        assert_eq!(text.len(), Decimal::MAX_SCALED_DIGITS as usize + 1);
+        let value = Decimal::new(1, i64::from(Decimal::MAX_SCALED_DIGITS) - 2);
+        assert_eq!(value.checked_to_fixed(2, RoundingMode::HalfUp), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "decimal rescale overflowed")]
+    fn test_to_fixed_panics_past_scaling_limit() {
+        Decimal::new(1, i64::MAX).to_fixed(0, RoundingMode::HalfUp);
+    }
+
+    #[rstest]
+    #[case("1.5", Decimal::new(15, -1))]
+    #[case("-0.", Decimal::negative_zero())]
+    #[case("0d3", Decimal::new(0, 3))]
+    #[case("15d-1", Decimal::new(15, -1))]
+    #[case("1_000.25", Decimal::new(100025, -2))]
+    #[case("-1.25D2", Decimal::new(-125, 0))]
+    #[case(
+        "1234567890123456789012345678901234567890.1234567890",
+        Decimal::new(big_coefficient("12345678901234567890123456789012345678901234567890"), -10)
+    )]
+    fn test_from_str(#[case] text: &str, #[case] expected: Decimal) {
+        let actual: Decimal = text.parse().unwrap();
+        assert!(
+            actual.ion_eq(&expected),
+            "parsed '{text}' as {actual}, expected {expected}"
+        );
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::int("15")]
+    #[case::float("1.5e0")]
+    #[case::leading_plus("+1.5")]
+    #[case::leading_whitespace(" 1.5")]
+    #[case::trailing_whitespace("1.5 ")]
+    #[case::trailing_text("1.5foo")]
+    #[case::two_values("1.5 2.5")]
+    fn test_from_str_err(#[case] text: &str) {
+        assert!(
+            text.parse::<Decimal>().is_err(),
+            "'{text}' should not parse"
+        );
+    }
 }
@@ -1,25 +1,59 @@
 use crate::ion_data::{IonEq, IonOrd};
+use crate::lazy::expanded::EncodingContext;
+use crate::lazy::text::buffer::TextBuffer;
 use crate::result::IonFailure;
 use crate::types::CountDecimalDigits;
 use crate::{IonError, IonResult};
-use num_traits::Zero;
+use num_bigint::{BigInt, BigUint};
+use num_traits::{Signed, ToPrimitive, Zero};
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::mem;
-use std::ops::{Add, Neg};
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+/// The storage backing a [`UInt`]. Values that fit in a `u128` are stored inline; larger values
+/// are stored on the heap.
+///
+/// The `BigUInt` variant is only ever used for values that do not fit in a `u128`. Because
+/// each value has exactly one representation, the derived `PartialEq` and `Hash` implementations
+/// are correct.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum UIntData {
+    U128(u128),
+    BigUInt(Box<BigUint>),
+}
 
 /// Represents an unsigned integer of any size.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct UInt {
-    pub(crate) data: u128,
+    pub(crate) data: UIntData,
 }
 
 impl UInt {
-    pub const ZERO: UInt = UInt { data: 0u128 };
+    pub const ZERO: UInt = UInt {
+        data: UIntData::U128(0u128),
+    };
 
     #[inline]
     pub(crate) fn new(data: impl Into<u128>) -> Self {
-        Self { data: data.into() }
+        Self {
+            data: UIntData::U128(data.into()),
+        }
+    }
+
+    /// Constructs a `UInt` from a [`BigUint`], storing it inline if it fits in a `u128`.
+    pub(crate) fn from_big_uint(value: BigUint) -> Self {
+        match value.to_u128() {
+            Some(small) => UInt::new(small),
+            None => UInt {
+                data: UIntData::BigUInt(Box::new(value)),
+            },
+        }
     }
 
     /// Attempts to convert this `UInt` to a `usize`. If the value is too large to fit,
@@ -37,7 +71,10 @@ impl UInt {
     /// Attempts to convert this `UInt` to a `u128`. If the value is too large to fit,
     /// returns `None`.
     pub fn as_u128(&self) -> Option<u128> {
-        Some(self.data)
+        match &self.data {
+            UIntData::U128(value) => Some(*value),
+            UIntData::BigUInt(_) => None,
+        }
     }
 
     /// Attempts to convert this `UInt` to a `usize`. If the value is too large to fit,
@@ -63,7 +100,19 @@ impl UInt {
 
     /// Returns the number of digits in the base-10 representation of the UInteger.
     pub(crate) fn number_of_decimal_digits(&self) -> u32 {
-        self.data.count_decimal_digits()
+        self.count_decimal_digits()
+    }
+
+    /// Returns the minimal big-endian byte representation of this value's magnitude.
+    /// Zero is represented as an empty `Vec`.
+    pub(crate) fn to_be_bytes(&self) -> Vec<u8> {
+        match &self.data {
+            UIntData::U128(value) => {
+                let empty_leading_bytes = (value.leading_zeros() / 8) as usize;
+                value.to_be_bytes()[empty_leading_bytes..].to_vec()
+            }
+            UIntData::BigUInt(value) => value.to_bytes_be(),
+        }
     }
 }
 
@@ -94,6 +143,30 @@ impl From<usize> for UInt {
     }
 }
 
+impl From<BigUint> for UInt {
+    fn from(value: BigUint) -> Self {
+        UInt::from_big_uint(value)
+    }
+}
+
+impl From<UInt> for BigUint {
+    fn from(value: UInt) -> Self {
+        match value.data {
+            UIntData::U128(value) => BigUint::from(value),
+            UIntData::BigUInt(value) => *value,
+        }
+    }
+}
+
+impl From<&UInt> for BigUint {
+    fn from(value: &UInt) -> Self {
+        match &value.data {
+            UIntData::U128(value) => BigUint::from(*value),
+            UIntData::BigUInt(value) => value.as_ref().clone(),
+        }
+    }
+}
+
 macro_rules! impl_uint_try_from_signed_int_types {
     ($($t:ty),*) => ($(
         impl TryFrom<$t> for UInt {
@@ -116,7 +189,7 @@ macro_rules! impl_int_types_try_from_uint {
             type Error = IonError;
 
             fn try_from(value: &UInt) -> Result<Self, Self::Error> {
-                <$t>::try_from(value.data).map_err(|_| {
+                value.as_u128().and_then(|data| <$t>::try_from(data).ok()).ok_or_else(|| {
                     IonError::decoding_error(
                             concat!("UInt was too large to fit in a ", stringify!($t))
                         )
@@ -133,10 +206,7 @@ impl TryFrom<Int> for UInt {
     type Error = IonError;
 
     fn try_from(value: Int) -> Result<Self, Self::Error> {
-        if value.data.is_negative() {
-            return IonResult::decoding_error("cannot convert negative Int to a UInt");
-        }
-        Ok(value.data.unsigned_abs().into())
+        (&value).try_into()
     }
 }
 
@@ -144,19 +214,22 @@ impl TryFrom<&Int> for UInt {
     type Error = IonError;
 
     fn try_from(value: &Int) -> Result<Self, Self::Error> {
-        (*value).try_into()
+        if value.is_negative() {
+            return IonResult::decoding_error("cannot convert negative Int to a UInt");
+        }
+        Ok(value.unsigned_abs())
     }
 }
 
 impl From<&UInt> for UInt {
     fn from(value: &UInt) -> Self {
-        *value
+        value.clone()
     }
 }
 
 impl From<&Int> for Int {
     fn from(value: &Int) -> Self {
-        *value
+        value.clone()
     }
 }
 
@@ -166,7 +239,7 @@ macro_rules! impl_small_int_try_from_int {
             type Error = IonError;
 
             fn try_from(value: Int) -> Result<Self, Self::Error> {
-                <$t>::try_from(value.data).map_err(|_| {
+                value.as_i128().and_then(|data| <$t>::try_from(data).ok()).ok_or_else(|| {
                     IonError::decoding_error(concat!("Int was outside the range of a(n) ", stringify!($t)))
                 })
             }
@@ -175,7 +248,20 @@ macro_rules! impl_small_int_try_from_int {
 }
 
 impl_small_int_try_from_int!(i8, i16, i32, i64, i128, isize);
-impl_small_int_try_from_int!(u8, u16, u32, u64, u128, usize);
+impl_small_int_try_from_int!(u8, u16, u32, u64, usize);
+
+// A `u128` can hold values that do not fit in an i128, so it needs to look at the `UInt`
+// magnitude instead of going through `as_i128`.
+impl TryFrom<Int> for u128 {
+    type Error = IonError;
+
+    fn try_from(value: Int) -> Result<Self, Self::Error> {
+        UInt::try_from(&value)
+            .ok()
+            .and_then(|magnitude| magnitude.as_u128())
+            .ok_or_else(|| IonError::decoding_error("Int was outside the range of a(n) u128"))
+    }
+}
 
 macro_rules! impl_small_unsigned_int_try_from_uint {
     ($($t:ty),*) => ($(
@@ -183,7 +269,7 @@ macro_rules! impl_small_unsigned_int_try_from_uint {
             type Error = IonError;
 
             fn try_from(value: UInt) -> Result<Self, Self::Error> {
-                <$t>::try_from(value.data).map_err(|_| {
+                value.as_u128().and_then(|data| <$t>::try_from(data).ok()).ok_or_else(|| {
                     IonError::decoding_error(concat!("UInt was outside the range of a(n) ", stringify!($t)))
                 })
             }
@@ -193,7 +279,19 @@ macro_rules! impl_small_unsigned_int_try_from_uint {
 
 impl_small_unsigned_int_try_from_uint!(u8, u16, u32, u64, u128, usize);
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The storage backing an [`Int`]. Values that fit in an `i128` are stored inline; larger values
+/// are stored on the heap.
+///
+/// The `BigInt` variant is only ever used for values that do not fit in an `i128`. Because
+/// each value has exactly one representation, the derived `PartialEq` and `Hash` implementations
+/// are correct.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum IntData {
+    I128(i128),
+    BigInt(Box<BigInt>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 /// A signed integer of arbitrary size.
 /// ```
 /// # use ion_rs::IonResult;
@@ -214,26 +312,62 @@ impl_small_unsigned_int_try_from_uint!(u8, u16, u32, u64, u128, usize);
 /// # Ok(())
 /// # }
 /// ```
+///
+/// Values outside the range of an `i128` are supported, and can be converted to and from
+/// [`num_bigint::BigInt`].
+/// ```
+/// # use ion_rs::IonResult;
+/// # fn main() -> IonResult<()> {
+/// use ion_rs::{Element, Int};
+/// use num_bigint::BigInt;
+///
+/// let element = Element::read_one("340282366920938463463374607431768211456")?;
+/// let int: &Int = element.expect_int()?;
+/// assert_eq!(int.as_i128(), None);
+/// assert_eq!(BigInt::from(int), BigInt::from(u128::MAX) + 1);
+/// # Ok(())
+/// # }
+/// ```
 pub struct Int {
-    pub(crate) data: i128,
+    pub(crate) data: IntData,
 }
 
 impl Int {
-    pub const ZERO: Int = Int { data: 0i128 };
+    pub const ZERO: Int = Int {
+        data: IntData::I128(0i128),
+    };
 
     pub(crate) fn new(data: impl Into<i128>) -> Self {
-        Self { data: data.into() }
+        Self {
+            data: IntData::I128(data.into()),
+        }
+    }
+
+    /// Constructs an `Int` from a [`BigInt`], storing it inline if it fits in an `i128`.
+    pub(crate) fn from_big_int(value: BigInt) -> Self {
+        match value.to_i128() {
+            Some(small) => Int::new(small),
+            None => Int {
+                data: IntData::BigInt(Box::new(value)),
+            },
+        }
     }
 
     /// Returns a [`UInt`] representing the unsigned magnitude of this `Int`.
     pub fn unsigned_abs(&self) -> UInt {
-        self.data.unsigned_abs().into()
+        match &self.data {
+            IntData::I128(value) => value.unsigned_abs().into(),
+            IntData::BigInt(value) => UInt::from_big_uint(value.magnitude().clone()),
+        }
     }
 
     /// Returns `true` if this value is less than zero.
     /// If this value is greater than or equal to zero, returns `false`.
     pub fn is_negative(&self) -> bool {
-        self.data.is_negative()
+        match &self.data {
+            IntData::I128(value) => value.is_negative(),
+            IntData::BigInt(value) => value.is_negative(),
+        }
     }
 
     /// If this value is small enough to fit in an `i64`, returns `Ok(i64)`. Otherwise,
@@ -248,7 +382,7 @@ impl Int {
 
     #[inline(always)]
     pub fn as_u32(&self) -> Option<u32> {
-        u32::try_from(self.data).ok()
+        self.as_i128().and_then(|data| u32::try_from(data).ok())
     }
 
     #[inline]
@@ -261,7 +395,7 @@ impl Int {
 
     #[inline(always)]
     pub fn as_usize(&self) -> Option<usize> {
-        usize::try_from(self.data).ok()
+        self.as_i128().and_then(|data| usize::try_from(data).ok())
     }
 
     #[inline]
@@ -283,15 +417,69 @@ impl Int {
     /// If this value is small enough to fit in an `i64`, returns `Some(i64)`. Otherwise, returns
     /// `None`.
     pub fn as_i64(&self) -> Option<i64> {
-        i64::try_from(self.data).ok()
+        self.as_i128().and_then(|data| i64::try_from(data).ok())
     }
 
     /// If this value is small enough to fit in an `i128`, returns `Some(i128)`. Otherwise, returns
     /// `None`.
+    #[inline(always)]
     pub fn as_i128(&self) -> Option<i128> {
-        Some(self.data)
+        match &self.data {
+            IntData::I128(value) => Some(*value),
+            IntData::BigInt(_) => None,
+        }
+    }
+
+    /// Returns the minimal two's complement, little-endian byte representation of this value.
+    /// Zero is represented as a single `0x00` byte.
+    pub(crate) fn to_signed_le_bytes(&self) -> Vec<u8> {
+        match &self.data {
+            IntData::I128(value) => {
+                // Each leading bit that matches the sign bit is redundant, but we need to keep
+                // one of them to preserve the sign.
+                let redundant_bits = if value.is_negative() {
+                    value.leading_ones()
+                } else {
+                    value.leading_zeros()
+                };
+                let num_bytes = (128 - redundant_bits as usize) / 8 + 1;
+                value.to_le_bytes()[..num_bytes.min(mem::size_of::<i128>())].to_vec()
+            }
+            IntData::BigInt(value) => value.to_signed_bytes_le(),
+        }
+    }
+}
+
+impl From<BigInt> for Int {
+    fn from(value: BigInt) -> Self {
+        Int::from_big_int(value)
     }
 }
+
+impl From<BigUint> for Int {
+    fn from(value: BigUint) -> Self {
+        Int::from_big_int(BigInt::from(value))
+    }
+}
+
+impl From<Int> for BigInt {
+    fn from(value: Int) -> Self {
+        match value.data {
+            IntData::I128(value) => BigInt::from(value),
+            IntData::BigInt(value) => *value,
+        }
+    }
+}
+
+impl From<&Int> for BigInt {
+    fn from(value: &Int) -> Self {
+        match &value.data {
+            IntData::I128(value) => BigInt::from(*value),
+            IntData::BigInt(value) => value.as_ref().clone(),
+        }
+    }
+}
+
 impl IonEq for Int {
     fn ion_eq(&self, other: &Self) -> bool {
         self == other
@@ -304,65 +492,729 @@ impl IonOrd for Int {
     }
 }
 
+impl PartialOrd for Int {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Int {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use IntData::*;
+        match (&self.data, &other.data) {
+            (I128(this), I128(that)) => this.cmp(that),
+            (BigInt(this), BigInt(that)) => this.cmp(that),
+            // A heap-allocated value is always outside the i128 range, so its sign is enough
+            // to determine how it compares to an inline value.
+            (BigInt(this), I128(_)) if this.is_negative() => Ordering::Less,
+            (BigInt(_), I128(_)) => Ordering::Greater,
+            (I128(_), BigInt(that)) if that.is_negative() => Ordering::Greater,
+            (I128(_), BigInt(_)) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for UInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use UIntData::*;
+        match (&self.data, &other.data) {
+            (U128(this), U128(that)) => this.cmp(that),
+            (BigUInt(this), BigUInt(that)) => this.cmp(that),
+            // A heap-allocated value is always larger than any value that fits in a u128.
+            (BigUInt(_), U128(_)) => Ordering::Greater,
+            (U128(_), BigUInt(_)) => Ordering::Less,
+        }
+    }
+}
+
 impl Neg for Int {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        self.data.neg().into()
+        match self.data {
+            IntData::I128(value) => match value.checked_neg() {
+                Some(negated) => negated.into(),
+                // i128::MIN has no positive counterpart in the i128 range
+                None => Int::from_big_int(BigInt::from(value).neg()),
+            },
+            IntData::BigInt(value) => Int::from_big_int(value.neg()),
+        }
     }
 }
 
-impl Add<Self> for Int {
+impl Neg for &Int {
     type Output = Int;
 
-    fn add(self, rhs: Self) -> Self::Output {
-        self.data.add(rhs.data).into()
+    fn neg(self) -> Self::Output {
+        match &self.data {
+            IntData::I128(value) => match value.checked_neg() {
+                Some(negated) => negated.into(),
+                None => Int::from_big_int(BigInt::from(*value).neg()),
+            },
+            IntData::BigInt(value) => Int::from_big_int(value.as_ref().neg()),
+        }
     }
 }
 
 impl Zero for Int {
     fn zero() -> Self {
-        Int { data: 0i128 }
+        Int::ZERO
     }
 
     fn is_zero(&self) -> bool {
-        self.data == 0
+        self.data == IntData::I128(0)
     }
 }
 
-impl Add<Self> for UInt {
-    type Output = UInt;
+impl Zero for UInt {
+    fn zero() -> Self {
+        UInt::ZERO
+    }
 
-    fn add(self, rhs: Self) -> Self::Output {
-        self.data.add(rhs.data).into()
+    fn is_zero(&self) -> bool {
+        self.data == UIntData::U128(0)
     }
 }
 
-impl Zero for UInt {
-    fn zero() -> Self {
-        UInt { data: 0u128 }
+impl Int {
+    /// Applies a binary operation to `self` and `other`. If both values are stored inline and
+    /// `small_op` is able to produce a result that fits in an `i128`, that result is used.
+    /// Otherwise, `big_op` is applied to the values' [`BigInt`] representations.
+    #[inline]
+    fn apply_binary_op(
+        &self,
+        other: &Int,
+        small_op: impl FnOnce(i128, i128) -> Option<i128>,
+        big_op: impl FnOnce(BigInt, BigInt) -> BigInt,
+    ) -> Int {
+        if let (IntData::I128(lhs), IntData::I128(rhs)) = (&self.data, &other.data) {
+            if let Some(result) = small_op(*lhs, *rhs) {
+                return Int::new(result);
+            }
+        }
+        Int::from_big_int(big_op(BigInt::from(self), BigInt::from(other)))
     }
 
-    fn is_zero(&self) -> bool {
-        self.data == 0
+    /// Divides `self` by `other`, truncating toward zero. Returns `None` if `other` is zero.
+    ///
+    /// Because `Int` has arbitrary precision, the only way that division can fail is a zero
+    /// divisor; addition, subtraction, and multiplication cannot overflow and so have no
+    /// `checked_` counterparts.
+    ///
+    /// ```
+    /// use ion_rs::Int;
+    /// assert_eq!(Int::from(-7).checked_div(&Int::from(2)), Some(Int::from(-3)));
+    /// assert_eq!(Int::from(-7).checked_div(&Int::from(0)), None);
+    /// ```
+    pub fn checked_div(&self, other: &Int) -> Option<Int> {
+        if other.is_zero() {
+            return None;
+        }
+        Some(self.apply_binary_op(other, i128::checked_div, |lhs, rhs| lhs / rhs))
+    }
+
+    /// Computes the remainder of dividing `self` by `other`. The result has the same sign as
+    /// `self`. Returns `None` if `other` is zero.
+    ///
+    /// ```
+    /// use ion_rs::Int;
+    /// assert_eq!(Int::from(-7).checked_rem(&Int::from(2)), Some(Int::from(-1)));
+    /// assert_eq!(Int::from(-7).checked_rem(&Int::from(0)), None);
+    /// ```
+    pub fn checked_rem(&self, other: &Int) -> Option<Int> {
+        if other.is_zero() {
+            return None;
+        }
+        Some(self.apply_binary_op(other, i128::checked_rem, |lhs, rhs| lhs % rhs))
+    }
+
+    /// Raises `self` to the power of `exponent`.
+    pub fn pow(&self, exponent: u32) -> Int {
+        if let IntData::I128(value) = self.data {
+            if let Some(result) = value.checked_pow(exponent) {
+                return Int::new(result);
+            }
+        }
+        Int::from_big_int(BigInt::from(self).pow(exponent))
+    }
+
+    /// Adds `other` to `self`. Because `Int` has arbitrary precision, the sum never saturates;
+    /// this method is equivalent to `self + other` and exists for parity with the primitive
+    /// integer types.
+    ///
+    /// ```
+    /// use ion_rs::Int;
+    /// let sum = Int::from(i128::MAX).saturating_add(&Int::from(1));
+    /// assert_eq!(sum, Int::from(i128::MAX) + Int::from(1));
+    /// assert!(sum > Int::from(i128::MAX));
+    /// ```
+    pub fn saturating_add(&self, other: &Int) -> Int {
+        self + other
+    }
+
+    /// Subtracts `other` from `self`. Like [`Int::saturating_add`], this never saturates.
+    pub fn saturating_sub(&self, other: &Int) -> Int {
+        self - other
+    }
+
+    /// Multiplies `self` by `other`. Like [`Int::saturating_add`], this never saturates.
+    pub fn saturating_mul(&self, other: &Int) -> Int {
+        self * other
+    }
+
+    /// Raises `self` to the power of `exponent`. Like [`Int::saturating_add`], this never
+    /// saturates.
+    pub fn saturating_pow(&self, exponent: u32) -> Int {
+        self.pow(exponent)
+    }
+
+    /// Parses an `Int` from a string of digits in the specified `radix`, which must be in the
+    /// range `2..=36`. The digits may be preceded by a `-` and, as in Ion text, may be separated
+    /// by single underscores. Radix prefixes like `0x` are not accepted; to parse an Ion integer
+    /// literal that may include a prefix, use [`str::parse`].
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::Int;
+    /// assert_eq!(Int::from_str_radix("-ff_ff", 16)?, Int::from(-0xFFFF));
+    /// assert_eq!(Int::from_str_radix("1010", 2)?, Int::from(10));
+    /// assert!(Int::from_str_radix("1__0", 10).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_str_radix(text: &str, radix: u32) -> IonResult<Int> {
+        let (is_negative, digits) = match text.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, text),
+        };
+        let magnitude = Int::try_from(UInt::from_str_radix(digits, radix)?)?;
+        if is_negative {
+            Ok(-magnitude)
+        } else {
+            Ok(magnitude)
+        }
+    }
+}
+
+impl UInt {
+    /// Applies a binary operation to `self` and `other`. If both values are stored inline and
+    /// `small_op` is able to produce a result that fits in a `u128`, that result is used.
+    /// Otherwise, `big_op` is applied to the values' [`BigUint`] representations.
+    #[inline]
+    fn apply_binary_op(
+        &self,
+        other: &UInt,
+        small_op: impl FnOnce(u128, u128) -> Option<u128>,
+        big_op: impl FnOnce(BigUint, BigUint) -> BigUint,
+    ) -> UInt {
+        if let (UIntData::U128(lhs), UIntData::U128(rhs)) = (&self.data, &other.data) {
+            if let Some(result) = small_op(*lhs, *rhs) {
+                return UInt::new(result);
+            }
+        }
+        UInt::from_big_uint(big_op(BigUint::from(self), BigUint::from(other)))
+    }
+
+    /// Subtracts `other` from `self`, returning `None` if the result would be negative.
+    ///
+    /// ```
+    /// use ion_rs::UInt;
+    /// assert_eq!(UInt::from(7u32).checked_sub(&UInt::from(2u32)), Some(UInt::from(5u32)));
+    /// assert_eq!(UInt::from(2u32).checked_sub(&UInt::from(7u32)), None);
+    /// ```
+    pub fn checked_sub(&self, other: &UInt) -> Option<UInt> {
+        if other > self {
+            return None;
+        }
+        Some(self.apply_binary_op(other, u128::checked_sub, |lhs, rhs| lhs - rhs))
+    }
+
+    /// Subtracts `other` from `self`, returning zero if the result would be negative.
+    ///
+    /// ```
+    /// use ion_rs::UInt;
+    /// assert_eq!(UInt::from(7u32).saturating_sub(&UInt::from(2u32)), UInt::from(5u32));
+    /// assert_eq!(UInt::from(2u32).saturating_sub(&UInt::from(7u32)), UInt::ZERO);
+    /// ```
+    pub fn saturating_sub(&self, other: &UInt) -> UInt {
+        self.checked_sub(other).unwrap_or(UInt::ZERO)
+    }
+
+    /// Divides `self` by `other`, returning `None` if `other` is zero.
+    pub fn checked_div(&self, other: &UInt) -> Option<UInt> {
+        if other.is_zero() {
+            return None;
+        }
+        Some(self.apply_binary_op(other, u128::checked_div, |lhs, rhs| lhs / rhs))
+    }
+
+    /// Computes the remainder of dividing `self` by `other`, returning `None` if `other` is zero.
+    pub fn checked_rem(&self, other: &UInt) -> Option<UInt> {
+        if other.is_zero() {
+            return None;
+        }
+        Some(self.apply_binary_op(other, u128::checked_rem, |lhs, rhs| lhs % rhs))
+    }
+
+    /// Raises `self` to the power of `exponent`.
+    pub fn pow(&self, exponent: u32) -> UInt {
+        if let UIntData::U128(value) = self.data {
+            if let Some(result) = value.checked_pow(exponent) {
+                return UInt::new(result);
+            }
+        }
+        UInt::from_big_uint(BigUint::from(self).pow(exponent))
+    }
+
+    /// Parses a `UInt` from a string of digits in the specified `radix`, which must be in the
+    /// range `2..=36`. As in Ion text, the digits may be separated by single underscores.
+    /// See [`Int::from_str_radix`].
+    pub fn from_str_radix(text: &str, radix: u32) -> IonResult<UInt> {
+        if !(2..=36).contains(&radix) {
+            return IonResult::illegal_operation(format!(
+                "radix must be in the range 2 to 36, found {radix}"
+            ));
+        }
+        let is_digit = |c: char| c.is_digit(radix);
+        let is_valid = text
+            .split('_')
+            .all(|group| !group.is_empty() && group.chars().all(is_digit));
+        if !is_valid {
+            return IonResult::decoding_error(format!(
+                "'{text}' is not a valid base-{radix} unsigned integer"
+            ));
+        }
+        let digits: Cow<str> = if text.contains('_') {
+            Cow::Owned(text.replace('_', ""))
+        } else {
+            Cow::Borrowed(text)
+        };
+        // All of the characters have been validated, so the only way parsing the digits can
+        // fail is overflow. In that case, fall back to `BigUint`.
+        if let Ok(value) = u128::from_str_radix(&digits, radix) {
+            return Ok(UInt::new(value));
+        }
+        BigUint::parse_bytes(digits.as_bytes(), radix)
+            .map(UInt::from_big_uint)
+            .ok_or_else(|| IonError::decoding_error(format!("'{text}' is not a valid UInt")))
+    }
+}
+
+impl FromStr for Int {
+    type Err = IonError;
+
+    /// Parses an `Int` from an Ion integer literal like `-42`, `1_000`, `0xBEEF`, or `0b1010`.
+    /// Leading and trailing whitespace are not permitted.
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::Int;
+    /// assert_eq!("-0xff".parse::<Int>()?, Int::from(-255));
+    /// assert_eq!("0b1010".parse::<Int>()?, Int::from(10));
+    /// assert_eq!("1_000_000".parse::<Int>()?, Int::from(1_000_000));
+    /// assert!("0x_ff".parse::<Int>().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let encoding_context = EncodingContext::empty();
+        let buffer = TextBuffer::new(encoding_context.get_ref(), text.as_bytes());
+        match buffer.match_int() {
+            Ok((remaining, matched)) if remaining.is_empty() => matched.read(buffer),
+            _ => IonResult::decoding_error(format!("'{text}' is not a valid Ion int")),
+        }
+    }
+}
+
+impl FromStr for UInt {
+    type Err = IonError;
+
+    /// Parses a `UInt` from an Ion integer literal like `42`, `1_000`, `0xBEEF`, or `0b1010`.
+    /// Negative values are rejected.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let int: Int = text.parse()?;
+        UInt::try_from(int)
+    }
+}
+
+// Implements `$trait_name` and `$assign_trait_name` for every combination of owned and borrowed
+// operands in terms of `$op`, a function that accepts two references.
+macro_rules! impl_binary_op {
+    ($int_type:ty, $trait_name:ident::$method:ident, $assign_trait_name:ident::$assign_method:ident, $op:expr) => {
+        impl $trait_name<&$int_type> for &$int_type {
+            type Output = $int_type;
+
+            fn $method(self, rhs: &$int_type) -> Self::Output {
+                $op(self, rhs)
+            }
+        }
+
+        impl $trait_name<$int_type> for &$int_type {
+            type Output = $int_type;
+
+            fn $method(self, rhs: $int_type) -> Self::Output {
+                $op(self, &rhs)
+            }
+        }
+
+        impl $trait_name<&$int_type> for $int_type {
+            type Output = $int_type;
+
+            fn $method(self, rhs: &$int_type) -> Self::Output {
+                $op(&self, rhs)
+            }
+        }
+
+        impl $trait_name<$int_type> for $int_type {
+            type Output = $int_type;
+
+            fn $method(self, rhs: $int_type) -> Self::Output {
+                $op(&self, &rhs)
+            }
+        }
+
+        impl $assign_trait_name<&$int_type> for $int_type {
+            fn $assign_method(&mut self, rhs: &$int_type) {
+                *self = $op(self, rhs);
+            }
+        }
+
+        impl $assign_trait_name<$int_type> for $int_type {
+            fn $assign_method(&mut self, rhs: $int_type) {
+                *self = $op(self, &rhs);
+            }
+        }
+    };
+}
+
+// As with primitive integers, division and remainder operations panic if the divisor is zero.
+// Because `Int` is arbitrary-precision, its other arithmetic operations never overflow.
+impl_binary_op!(
+    Int,
+    Add::add,
+    AddAssign::add_assign,
+    |lhs: &Int, rhs: &Int| { lhs.apply_binary_op(rhs, i128::checked_add, |lhs, rhs| lhs + rhs) }
+);
+impl_binary_op!(
+    Int,
+    Sub::sub,
+    SubAssign::sub_assign,
+    |lhs: &Int, rhs: &Int| { lhs.apply_binary_op(rhs, i128::checked_sub, |lhs, rhs| lhs - rhs) }
+);
+impl_binary_op!(
+    Int,
+    Mul::mul,
+    MulAssign::mul_assign,
+    |lhs: &Int, rhs: &Int| { lhs.apply_binary_op(rhs, i128::checked_mul, |lhs, rhs| lhs * rhs) }
+);
+impl_binary_op!(
+    Int,
+    Div::div,
+    DivAssign::div_assign,
+    |lhs: &Int, rhs: &Int| { lhs.checked_div(rhs).expect("attempt to divide by zero") }
+);
+impl_binary_op!(
+    Int,
+    Rem::rem,
+    RemAssign::rem_assign,
+    |lhs: &Int, rhs: &Int| {
+        lhs.checked_rem(rhs)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+    }
+);
+// Bitwise operations treat the values as though they were stored in two's complement with
+// infinite sign extension, just as primitive integers do within their fixed width.
+impl_binary_op!(
+    Int,
+    BitAnd::bitand,
+    BitAndAssign::bitand_assign,
+    |lhs: &Int, rhs: &Int| {
+        lhs.apply_binary_op(rhs, |lhs, rhs| Some(lhs & rhs), |lhs, rhs| lhs & rhs)
+    }
+);
+impl_binary_op!(
+    Int,
+    BitOr::bitor,
+    BitOrAssign::bitor_assign,
+    |lhs: &Int, rhs: &Int| {
+        lhs.apply_binary_op(rhs, |lhs, rhs| Some(lhs | rhs), |lhs, rhs| lhs | rhs)
+    }
+);
+impl_binary_op!(
+    Int,
+    BitXor::bitxor,
+    BitXorAssign::bitxor_assign,
+    |lhs: &Int, rhs: &Int| {
+        lhs.apply_binary_op(rhs, |lhs, rhs| Some(lhs ^ rhs), |lhs, rhs| lhs ^ rhs)
+    }
+);
+
+// A `UInt` cannot represent negative values, so subtraction panics if the result would be
+// negative, just as it does for primitive unsigned integers in debug builds.
+impl_binary_op!(
+    UInt,
+    Add::add,
+    AddAssign::add_assign,
+    |lhs: &UInt, rhs: &UInt| { lhs.apply_binary_op(rhs, u128::checked_add, |lhs, rhs| lhs + rhs) }
+);
+impl_binary_op!(
+    UInt,
+    Sub::sub,
+    SubAssign::sub_assign,
+    |lhs: &UInt, rhs: &UInt| {
+        lhs.checked_sub(rhs)
+            .expect("attempt to subtract with overflow")
+    }
+);
+impl_binary_op!(
+    UInt,
+    Mul::mul,
+    MulAssign::mul_assign,
+    |lhs: &UInt, rhs: &UInt| { lhs.apply_binary_op(rhs, u128::checked_mul, |lhs, rhs| lhs * rhs) }
+);
+impl_binary_op!(
+    UInt,
+    Div::div,
+    DivAssign::div_assign,
+    |lhs: &UInt, rhs: &UInt| { lhs.checked_div(rhs).expect("attempt to divide by zero") }
+);
+impl_binary_op!(
+    UInt,
+    Rem::rem,
+    RemAssign::rem_assign,
+    |lhs: &UInt, rhs: &UInt| {
+        lhs.checked_rem(rhs)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+    }
+);
+impl_binary_op!(
+    UInt,
+    BitAnd::bitand,
+    BitAndAssign::bitand_assign,
+    |lhs: &UInt, rhs: &UInt| {
+        lhs.apply_binary_op(rhs, |lhs, rhs| Some(lhs & rhs), |lhs, rhs| lhs & rhs)
+    }
+);
+impl_binary_op!(
+    UInt,
+    BitOr::bitor,
+    BitOrAssign::bitor_assign,
+    |lhs: &UInt, rhs: &UInt| {
+        lhs.apply_binary_op(rhs, |lhs, rhs| Some(lhs | rhs), |lhs, rhs| lhs | rhs)
+    }
+);
+impl_binary_op!(
+    UInt,
+    BitXor::bitxor,
+    BitXorAssign::bitxor_assign,
+    |lhs: &UInt, rhs: &UInt| {
+        lhs.apply_binary_op(rhs, |lhs, rhs| Some(lhs ^ rhs), |lhs, rhs| lhs ^ rhs)
+    }
+);
+
+impl Not for &Int {
+    type Output = Int;
+
+    fn not(self) -> Self::Output {
+        match &self.data {
+            IntData::I128(value) => Int::new(!value),
+            IntData::BigInt(value) => Int::from_big_int(!value.as_ref()),
+        }
     }
 }
 
-impl CountDecimalDigits for Int {
+impl Not for Int {
+    type Output = Int;
+
+    fn not(self) -> Self::Output {
+        !&self
+    }
+}
+
+// Implements the shift operators for `Int` or `UInt`. Left shifts never overflow; bits that do
+// not fit in the inline representation cause the value to be moved to the heap.
+macro_rules! impl_shift_ops {
+    ($int_type:ty, $data_type:ident::$inline_variant:ident, $inline_type:ty, $from_big:path, $big_type:ty) => {
+        impl Shl<u32> for &$int_type {
+            type Output = $int_type;
+
+            fn shl(self, shift: u32) -> Self::Output {
+                if let $data_type::$inline_variant(value) = self.data {
+                    if let Some(shifted) = value.checked_shl(shift) {
+                        // `checked_shl` only guards against shifting by more than the bit width.
+                        // Make sure that no significant bits were shifted out.
+                        if shifted >> shift == value {
+                            return <$int_type>::new(shifted);
+                        }
+                    }
+                }
+                $from_big(<$big_type>::from(self) << shift)
+            }
+        }
+
+        impl Shl<u32> for $int_type {
+            type Output = $int_type;
+
+            fn shl(self, shift: u32) -> Self::Output {
+                &self << shift
+            }
+        }
+
+        impl ShlAssign<u32> for $int_type {
+            fn shl_assign(&mut self, shift: u32) {
+                *self = &*self << shift;
+            }
+        }
+
+        impl Shr<u32> for &$int_type {
+            type Output = $int_type;
+
+            fn shr(self, shift: u32) -> Self::Output {
+                match &self.data {
+                    $data_type::$inline_variant(value) => {
+                        // Shifting by the bit width or more leaves only the sign: `0` for
+                        // non-negative values and `-1` for negative ones. Shifting in two steps
+                        // produces that result for both signed and unsigned values.
+                        let sign_only = (value >> (<$inline_type>::BITS - 1)) >> 1;
+                        <$int_type>::new(value.checked_shr(shift).unwrap_or(sign_only))
+                    }
+                    _ => $from_big(<$big_type>::from(self) >> shift),
+                }
+            }
+        }
+
+        impl Shr<u32> for $int_type {
+            type Output = $int_type;
+
+            fn shr(self, shift: u32) -> Self::Output {
+                &self >> shift
+            }
+        }
+
+        impl ShrAssign<u32> for $int_type {
+            fn shr_assign(&mut self, shift: u32) {
+                *self = &*self >> shift;
+            }
+        }
+    };
+}
+
+impl_shift_ops!(Int, IntData::I128, i128, Int::from_big_int, BigInt);
+impl_shift_ops!(UInt, UIntData::U128, u128, UInt::from_big_uint, BigUint);
+
+// Comparisons with primitive integers are only implemented with the primitive on the left-hand
+// side (`5 < int`). If `Int` and `UInt` implemented `PartialEq` for more than one type, an
+// expression like `int == 5.into()` would no longer be able to infer the target of `into()`.
+
+// Implements comparisons between primitive integer types that can be converted to an `Int`
+// without allocating and `Int`.
+macro_rules! impl_int_cmp_primitive {
+    ($($t:ty),*) => ($(
+        impl PartialEq<Int> for $t {
+            fn eq(&self, other: &Int) -> bool {
+                other.as_i128() == Some(*self as i128)
+            }
+        }
+
+        impl PartialOrd<Int> for $t {
+            fn partial_cmp(&self, other: &Int) -> Option<Ordering> {
+                Some(Int::from(*self).cmp(other))
+            }
+        }
+    )*)
+}
+
+impl_int_cmp_primitive!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl PartialEq<Int> for u128 {
+    fn eq(&self, other: &Int) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd<Int> for u128 {
+    fn partial_cmp(&self, other: &Int) -> Option<Ordering> {
+        if other.is_negative() {
+            return Some(Ordering::Greater);
+        }
+        Some(UInt::from(*self).cmp(&other.unsigned_abs()))
+    }
+}
+
+// Implements comparisons between unsigned primitive integer types and `UInt`.
+macro_rules! impl_uint_cmp_unsigned_primitive {
+    ($($t:ty),*) => ($(
+        impl PartialEq<UInt> for $t {
+            fn eq(&self, other: &UInt) -> bool {
+                other.as_u128() == Some(*self as u128)
+            }
+        }
+
+        impl PartialOrd<UInt> for $t {
+            fn partial_cmp(&self, other: &UInt) -> Option<Ordering> {
+                Some(UInt::from(*self).cmp(other))
+            }
+        }
+    )*)
+}
+
+impl_uint_cmp_unsigned_primitive!(u8, u16, u32, u64, u128, usize);
+
+// Implements comparisons between signed primitive integer types and `UInt`. Any negative value is
+// less than every `UInt`.
+macro_rules! impl_uint_cmp_signed_primitive {
+    ($($t:ty),*) => ($(
+        impl PartialEq<UInt> for $t {
+            fn eq(&self, other: &UInt) -> bool {
+                self.partial_cmp(other) == Some(Ordering::Equal)
+            }
+        }
+
+        impl PartialOrd<UInt> for $t {
+            fn partial_cmp(&self, other: &UInt) -> Option<Ordering> {
+                if self.is_negative() {
+                    return Some(Ordering::Less);
+                }
+                Some(UInt::from(self.unsigned_abs()).cmp(other))
+            }
+        }
+    )*)
+}
+
+impl_uint_cmp_signed_primitive!(i8, i16, i32, i64, i128, isize);
+
+impl CountDecimalDigits for &Int {
     fn count_decimal_digits(self) -> u32 {
-        self.data.count_decimal_digits()
+        match &self.data {
+            IntData::I128(value) => value.count_decimal_digits(),
+            IntData::BigInt(value) => value.magnitude().to_str_radix(10).len() as u32,
+        }
     }
 }
 
-impl CountDecimalDigits for UInt {
+impl CountDecimalDigits for &UInt {
     fn count_decimal_digits(self) -> u32 {
-        self.data.count_decimal_digits()
+        match &self.data {
+            UIntData::U128(value) => value.count_decimal_digits(),
+            UIntData::BigUInt(value) => value.to_str_radix(10).len() as u32,
+        }
     }
 }
 
 impl Display for UInt {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", self.data)
+        match &self.data {
+            UIntData::U128(value) => write!(f, "{value}"),
+            UIntData::BigUInt(value) => write!(f, "{value}"),
+        }
     }
 }
 
@@ -371,14 +1223,15 @@ macro_rules! impl_int_i128_from {
     ($($t:ty),*) => ($(
         impl From<$t> for Int {
             fn from(value: $t) -> Int {
-                Int {data: value as i128 }
+                Int::new(value as i128)
             }
         }
     )*)
 }
 impl_int_i128_from!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
 
-// Conversion to Integer from integer types that may or may not fit in an i128
+// Conversion to Integer from integer types that may or may not fit in an i128. Values
+// outside the i128 range are stored as a `BigInt`, so this conversion always succeeds.
 macro_rules! impl_int_try_from {
     ($($t:ty),*) => ($(
         impl TryFrom<$t> for Int {
@@ -386,7 +1239,7 @@ macro_rules! impl_int_try_from {
             fn try_from(value: $t) -> Result<Self, Self::Error> {
                 match i128::try_from(value) {
                     Ok(i128_value) => Ok(i128_value.into()),
-                    Err(e) => IonResult::decoding_error(format!("could not safely convert value {} of type '{}' to Int: {e:?}", value, stringify!($t))),
+                    Err(_) => Ok(Int::from_big_int(BigInt::from(value))),
                 }
             }
         }
@@ -399,9 +1252,7 @@ impl TryFrom<UInt> for Int {
     type Error = IonError;
 
     fn try_from(value: UInt) -> Result<Self, Self::Error> {
-        i128::try_from(value.data)
-            .map_err(|_| IonError::decoding_error("UInt was outside the supported Int range"))
-            .map(Int::new)
+        (&value).try_into()
     }
 }
 
@@ -409,13 +1260,19 @@ impl TryFrom<&UInt> for Int {
     type Error = IonError;
 
     fn try_from(value: &UInt) -> Result<Self, Self::Error> {
-        value.data.try_into()
+        if let Some(small) = value.as_u128() {
+            return small.try_into();
+        }
+        Ok(Int::from(BigUint::from(value)))
     }
 }
 
 impl Display for Int {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", self.data)
+        match &self.data {
+            IntData::I128(value) => write!(f, "{value}"),
+            IntData::BigInt(value) => write!(f, "{value}"),
+        }
     }
 }
 
@@ -425,6 +1282,7 @@ mod integer_tests {
 
     use super::*;
     use crate::types::UInt;
+    use num_bigint::{BigInt, BigUint};
     use num_traits::Zero;
     use rstest::*;
     use std::cmp::Ordering;
@@ -600,4 +1458,305 @@ mod integer_tests {
         assert_eq!(UInt::from(128_000u128).expect_u64(), Ok(128_000u64));
         assert!(UInt::from(u128::MAX).expect_u64().is_err())
     }
+
+    fn big_int(text: &str) -> Int {
+        Int::from(text.parse::<BigInt>().unwrap())
+    }
+
+    #[test]
+    fn big_int_is_normalized() {
+        // Values that fit in an i128 are always stored inline, no matter how they were constructed.
+        assert_eq!(Int::from(BigInt::from(i128::MAX)), Int::from(i128::MAX));
+        assert_eq!(Int::from(BigInt::from(i128::MIN)), Int::from(i128::MIN));
+        assert_eq!(
+            Int::from(BigInt::from(i128::MAX)).as_i128(),
+            Some(i128::MAX)
+        );
+        assert_eq!(Int::try_from(u128::MAX).unwrap().as_i128(), None);
+        assert_eq!(
+            UInt::from(BigUint::from(u128::MAX)).as_u128(),
+            Some(u128::MAX)
+        );
+    }
+
+    #[test]
+    fn big_int_arithmetic() {
+        let i128_max = Int::from(i128::MAX);
+        let sum = i128_max.clone() + Int::from(1);
+        assert_eq!(sum, big_int("170141183460469231731687303715884105728"));
+        assert_eq!(sum + Int::from(-1), i128_max);
+        // i128::MIN has no positive counterpart in the i128 range
+        assert_eq!(
+            -Int::from(i128::MIN),
+            big_int("170141183460469231731687303715884105728")
+        );
+        assert_eq!(
+            UInt::from(u128::MAX) + UInt::from(1u8),
+            UInt::from(BigUint::from(u128::MAX) + 1u8)
+        );
+    }
+
+    #[rstest]
+    #[case::big_positive_gt_i128(big_int(&"1".repeat(50)), Int::from(i128::MAX), Ordering::Greater)]
+    #[case::big_negative_lt_i128(big_int(&format!("-{}", "1".repeat(50))), Int::from(i128::MIN), Ordering::Less)]
+    #[case::i128_lt_big_positive(Int::from(0), big_int(&"9".repeat(40)), Ordering::Less)]
+    #[case::i128_gt_big_negative(Int::from(0), big_int(&format!("-{}", "9".repeat(40))), Ordering::Greater)]
+    #[case::big_ints(big_int(&"9".repeat(40)), big_int(&"9".repeat(41)), Ordering::Less)]
+    fn big_integer_ordering_tests(
+        #[case] this: Int,
+        #[case] other: Int,
+        #[case] expected: Ordering,
+    ) {
+        assert_eq!(this.cmp(&other), expected)
+    }
+
+    #[rstest]
+    #[case::i128_max_plus_one("170141183460469231731687303715884105728")]
+    #[case::i128_min_minus_one("-170141183460469231731687303715884105729")]
+    #[case::u128_max_plus_one("340282366920938463463374607431768211456")]
+    #[case::fifty_digits("12345678901234567890123456789012345678901234567890")]
+    #[case::negative_fifty_digits("-12345678901234567890123456789012345678901234567890")]
+    fn big_int_round_trip(#[case] text: &str) -> IonResult<()> {
+        use crate::{v1_0, Element};
+        let int = big_int(text);
+        assert_eq!(int.to_string(), text);
+        let element = Element::read_one(text)?;
+        assert_eq!(element.expect_int()?, &int);
+
+        let text_1_0 = element.encode_as(v1_0::Text)?;
+        assert_eq!(Element::read_one(text_1_0)?, element);
+        let binary_1_0 = element.encode_as(v1_0::Binary)?;
+        assert_eq!(Element::read_one(binary_1_0)?, element);
+        #[cfg(feature = "experimental-ion-1-1")]
+        {
+            use crate::v1_1;
+            let text_1_1 = element.encode_as(v1_1::Text)?;
+            assert_eq!(Element::read_one(text_1_1)?, element);
+            let binary_1_1 = element.encode_as(v1_1::Binary)?;
+            assert_eq!(Element::read_one(binary_1_1)?, element);
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::small(Int::from(17), Int::from(5), [22, 12, 85, 3, 2])]
+    #[case::negative_dividend(Int::from(-17), Int::from(5), [-12, -22, -85, -3, -2])]
+    #[case::negative_divisor(Int::from(17), Int::from(-5), [12, 22, -85, -3, 2])]
+    fn int_arithmetic(#[case] lhs: Int, #[case] rhs: Int, #[case] expected: [i128; 5]) {
+        let [sum, difference, product, quotient, remainder] = expected;
+        assert_eq!(sum, &lhs + &rhs);
+        assert_eq!(difference, &lhs - &rhs);
+        assert_eq!(product, &lhs * &rhs);
+        assert_eq!(quotient, &lhs / &rhs);
+        assert_eq!(remainder, &lhs % &rhs);
+        // Compound assignment produces the same results
+        let mut value = lhs.clone();
+        value *= &rhs;
+        value -= rhs.clone();
+        assert_eq!(product - rhs.as_i128().unwrap(), value);
+    }
+
+    #[test]
+    fn int_arithmetic_beyond_i128() {
+        let i128_max_plus_one = big_int("170141183460469231731687303715884105728");
+        assert_eq!(
+            Int::from(i128::MIN) - Int::from(1),
+            -(&i128_max_plus_one + Int::from(1))
+        );
+        assert_eq!(
+            Int::from(i128::MAX) * Int::from(i128::MAX),
+            big_int(
+                "28948022309329048855892746252171976962977213799489202546401021394546514198529"
+            )
+        );
+        // i128::MIN / -1 overflows an i128
+        assert_eq!(Int::from(i128::MIN) / Int::from(-1), i128_max_plus_one);
+        assert_eq!(0, Int::from(i128::MIN) % Int::from(-1));
+        assert_eq!(&i128_max_plus_one / Int::from(2), Int::from(1i128 << 126));
+        assert_eq!(8, &i128_max_plus_one % Int::from(10));
+        assert_eq!(Int::from(-2).pow(127), Int::from(i128::MIN));
+        assert_eq!(Int::from(2).pow(127), i128_max_plus_one);
+    }
+
+    #[test]
+    fn checked_division() {
+        assert_eq!(Int::from(7).checked_div(&Int::ZERO), None);
+        assert_eq!(Int::from(7).checked_rem(&Int::ZERO), None);
+        assert_eq!(big_int(&"9".repeat(40)).checked_div(&Int::ZERO), None);
+        assert_eq!(UInt::from(7u32).checked_div(&UInt::ZERO), None);
+        assert_eq!(UInt::from(7u32).checked_rem(&UInt::ZERO), None);
+        assert_eq!(
+            UInt::from(7u32).checked_div(&UInt::from(2u32)),
+            Some(UInt::from(3u32))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn divide_by_zero_panics() {
+        let _ = Int::from(7) / Int::ZERO;
+    }
+
+    #[test]
+    fn uint_arithmetic() {
+        let u128_max = UInt::from(u128::MAX);
+        let u128_max_plus_one = &u128_max + UInt::from(1u32);
+        assert_eq!(1u32, &u128_max_plus_one - &u128_max);
+        assert_eq!(&u128_max * UInt::from(2u32), &u128_max + &u128_max);
+        assert_eq!(1u128 << 127, &u128_max_plus_one / UInt::from(2u32));
+        assert_eq!(6u32, &u128_max_plus_one % UInt::from(10u32));
+        assert_eq!(UInt::from(2u32).pow(128), u128_max_plus_one);
+        assert_eq!(UInt::from(3u32).checked_sub(&UInt::from(4u32)), None);
+        assert_eq!(u128_max.checked_sub(&u128_max_plus_one), None);
+        assert_eq!(u128_max.saturating_sub(&u128_max_plus_one), UInt::ZERO);
+        assert_eq!(1u32, u128_max_plus_one.saturating_sub(&u128_max));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn uint_underflow_panics() {
+        let _ = UInt::from(3u32) - UInt::from(4u32);
+    }
+
+    #[test]
+    fn int_bit_operations() {
+        let big = Int::from(i128::MAX) + Int::from(1); // 2^127
+        let negative_big = -&big - Int::from(1); // -(2^127) - 1
+        assert_eq!(0b1000, Int::from(0b1100) & Int::from(0b1010));
+        assert_eq!(0b1110, Int::from(0b1100) | Int::from(0b1010));
+        assert_eq!(0b0110, Int::from(0b1100) ^ Int::from(0b1010));
+        assert_eq!(0xFF, Int::from(-1) & Int::from(0xFF));
+        assert_eq!(-1, !Int::from(0));
+        assert_eq!(i128::MIN, !Int::from(i128::MAX));
+        assert_eq!(!&negative_big, big);
+        assert_eq!(&big | Int::from(1), &big + Int::from(1));
+        assert_eq!(&negative_big & Int::from(-1), negative_big);
+        assert_eq!(0, &big ^ &big);
+
+        assert_eq!(16, Int::from(1) << 4);
+        assert_eq!(Int::from(1) << 127, big);
+        assert_eq!(i128::MIN, Int::from(-1) << 127);
+        assert_eq!(Int::from(-1) << 128, -(&big + &big));
+        assert_eq!(-4, Int::from(-16) >> 2);
+        assert_eq!(-1, Int::from(-16) >> 200);
+        assert_eq!(0, Int::from(16) >> 200);
+        assert_eq!(1, &big >> 127);
+        let mut value = Int::from(3);
+        value <<= 130;
+        value >>= 129;
+        assert_eq!(6, value);
+    }
+
+    #[test]
+    fn uint_bit_operations() {
+        let big = UInt::from(u128::MAX) + UInt::from(1u32); // 2^128
+        assert_eq!(0b1000u32, UInt::from(0b1100u32) & UInt::from(0b1010u32));
+        assert_eq!(0b1110u32, UInt::from(0b1100u32) | UInt::from(0b1010u32));
+        assert_eq!(0b0110u32, UInt::from(0b1100u32) ^ UInt::from(0b1010u32));
+        assert_eq!(&big | UInt::from(u128::MAX), &big + UInt::from(u128::MAX));
+        assert_eq!(0u32, &big & UInt::from(u128::MAX));
+        assert_eq!(UInt::from(1u32) << 128, big);
+        assert_eq!(1u32, UInt::from(u128::MAX) >> 127);
+        assert_eq!(0u32, UInt::from(u128::MAX) >> 128);
+        assert_eq!(1u32, &big >> 128);
+    }
+
+    #[rstest]
+    #[case::zero("0", Int::from(0))]
+    #[case::negative_zero("-0", Int::from(0))]
+    #[case::decimal("-1_024", Int::from(-1024))]
+    #[case::hex("0xDEAD_beef", Int::from(0xDEAD_BEEFi64))]
+    #[case::negative_hex("-0XfF", Int::from(-255))]
+    #[case::binary("0b1010_1010", Int::from(0b1010_1010))]
+    #[case::negative_binary("-0B11", Int::from(-3))]
+    #[case::big(
+        "-12_345_678_901_234_567_890_123_456_789_012_345_678_901_234_567_890",
+        big_int("-12345678901234567890123456789012345678901234567890")
+    )]
+    fn int_from_str(#[case] text: &str, #[case] expected: Int) {
+        assert_eq!(text.parse::<Int>().unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::leading_plus("+1")]
+    #[case::leading_zero("01")]
+    #[case::leading_underscore("_1")]
+    #[case::trailing_underscore("1_")]
+    #[case::double_underscore("1__0")]
+    #[case::underscore_after_prefix("0x_ff")]
+    #[case::invalid_hex_digit("0xfg")]
+    #[case::invalid_binary_digit("0b102")]
+    #[case::decimal("1.0")]
+    #[case::whitespace(" 1")]
+    fn int_from_str_err(#[case] text: &str) {
+        assert!(text.parse::<Int>().is_err(), "'{text}' should not parse");
+    }
+
+    #[test]
+    fn uint_from_str() {
+        assert_eq!(255u32, "0xFF".parse::<UInt>().unwrap());
+        assert_eq!(0u32, "-0".parse::<UInt>().unwrap());
+        assert!("-1".parse::<UInt>().is_err());
+    }
+
+    #[rstest]
+    #[case("ff_ff", 16, Int::from(0xFFFF))]
+    #[case("-ZZ", 36, Int::from(-1295))]
+    #[case("1010", 2, Int::from(10))]
+    #[case("-1_000", 10, Int::from(-1000))]
+    #[case(&"7".repeat(50), 8, Int::from(BigInt::parse_bytes("7".repeat(50).as_bytes(), 8).unwrap()))]
+    fn int_from_str_radix(#[case] text: &str, #[case] radix: u32, #[case] expected: Int) {
+        assert_eq!(Int::from_str_radix(text, radix).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("", 10)]
+    #[case("-", 10)]
+    #[case("12", 2)]
+    #[case("0x12", 16)]
+    #[case("1__2", 10)]
+    #[case("_12", 10)]
+    #[case("+12", 10)]
+    #[case("12", 1)]
+    #[case("12", 37)]
+    fn int_from_str_radix_err(#[case] text: &str, #[case] radix: u32) {
+        assert!(Int::from_str_radix(text, radix).is_err());
+    }
+
+    #[test]
+    fn compare_with_primitives() {
+        assert_eq!(5u8, Int::from(5));
+        assert_eq!(-5i64, Int::from(-5));
+        assert_ne!(u128::MAX, Int::from(-1));
+        assert!(0u128 > Int::from(-1));
+        assert!(u128::MAX > Int::from(i128::MAX));
+        assert_eq!(u128::MAX, Int::try_from(u128::MAX).unwrap());
+        assert!(i128::MAX < big_int(&"9".repeat(40)));
+        assert!(i128::MIN > big_int(&format!("-{}", "9".repeat(40))));
+        assert!(i64::MIN < Int::from(0));
+
+        assert_eq!(5u64, UInt::from(5u32));
+        assert_eq!(5i32, UInt::from(5u32));
+        assert!(-1i8 < UInt::ZERO);
+        assert_ne!(-1i8, UInt::ZERO);
+        assert!(u128::MAX < UInt::from(u128::MAX) + UInt::from(1u32));
+        assert!(usize::MAX > UInt::ZERO);
+
+        // `Int` and `UInt` only implement `PartialEq` for themselves, so the target of `into()`
+        // can be inferred.
+        assert_eq!(Int::from(5), 5.into());
+        assert_eq!(UInt::from(5u32), 5u32.into());
+    }
+
+    #[test]
+    fn int_saturating_arithmetic() {
+        let max = Int::from(i128::MAX);
+        let min = Int::from(i128::MIN);
+        assert_eq!(max.saturating_add(&Int::from(1)), &max + Int::from(1));
+        assert_eq!(min.saturating_sub(&Int::from(1)), &min - Int::from(1));
+        assert_eq!(max.saturating_mul(&min), &max * &min);
+        assert_eq!(max.saturating_pow(3), max.pow(3));
+        assert_eq!(Int::from(-3).saturating_add(&Int::from(5)), 2.into());
+    }
 }
@@ -35,6 +35,12 @@ impl List {
             pub fn get(&self, index: usize) -> Option<&Element>;
             pub fn len(&self) -> usize;
             pub fn is_empty(&self) -> bool;
+            pub fn get_mut(&mut self, index: usize) -> Option<&mut Element>;
+            pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Element>;
+            pub fn push<E: Into<Element>>(&mut self, element: E);
+            pub fn insert<E: Into<Element>>(&mut self, index: usize, element: E);
+            pub fn remove(&mut self, index: usize) -> Element;
+            pub fn retain<F: FnMut(&mut Element) -> bool>(&mut self, predicate: F);
         }
     }
 }
@@ -70,7 +76,7 @@ impl<'a> IntoIterator for &'a List {
 
 impl Display for List {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut ivf = FmtValueFormatter { output: f };
+        let mut ivf = FmtValueFormatter::new(f);
         ivf.format_list(self).map_err(|_| std::fmt::Error)?;
         Ok(())
     }
@@ -64,7 +64,7 @@ impl Str {
 
 impl Display for Str {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut formatter = FmtValueFormatter { output: f };
+        let mut formatter = FmtValueFormatter::new(f);
         formatter
             .format_string(self.as_ref())
             .map_err(|_| std::fmt::Error)
@@ -78,6 +78,72 @@ impl Fields {
     fn iter(&self) -> impl Iterator<Item = &(Symbol, Element)> {
         self.by_index.iter()
     }
+
+    /// Gets a mutable reference to the last value in the Struct that is associated with the
+    /// specified field name. See [`get_last`](Self::get_last).
+    fn get_last_mut<A: AsSymbolRef>(&mut self, field_name: A) -> Option<&mut Element> {
+        let index = *self.get_indexes(field_name)?.last()?;
+        self.by_index.get_mut(index).map(|(_name, value)| value)
+    }
+
+    /// Appends a (field name, field value) pair to the end of the struct.
+    fn push(&mut self, field_name: Symbol, value: Element) {
+        self.by_name
+            .entry(field_name.clone())
+            .or_default()
+            .push(self.by_index.len());
+        self.by_index.push((field_name, value));
+    }
+
+    /// Removes the last value associated with the given field name, returning it if found.
+    fn remove_last<A: AsSymbolRef>(&mut self, field_name: A) -> Option<Element> {
+        let index = *self.get_indexes(field_name)?.last()?;
+        let (_name, value) = self.by_index.remove(index);
+        // Every field that followed the removed one has moved, so the index must be rebuilt.
+        self.rebuild_index();
+        Some(value)
+    }
+
+    /// Removes all of the values associated with the given field name, returning them in the
+    /// order in which they appeared.
+    fn remove_all<A: AsSymbolRef>(&mut self, field_name: A) -> Vec<Element> {
+        let indexes = match self.get_indexes(field_name) {
+            Some(indexes) => indexes.clone(),
+            None => return Vec::new(),
+        };
+        let mut removed = Vec::with_capacity(indexes.len());
+        // The indexes are stored in ascending order, so we can walk both sequences in lockstep.
+        let mut indexes_to_remove = indexes.iter().peekable();
+        let fields = std::mem::take(&mut self.by_index);
+        for (index, (name, value)) in fields.into_iter().enumerate() {
+            if indexes_to_remove.next_if_eq(&&index).is_some() {
+                removed.push(value);
+            } else {
+                self.by_index.push((name, value));
+            }
+        }
+        self.rebuild_index();
+        removed
+    }
+
+    /// Retains only the fields for which `predicate` returns `true`, preserving their order.
+    fn retain<F: FnMut(&Symbol, &mut Element) -> bool>(&mut self, mut predicate: F) {
+        let len_before = self.by_index.len();
+        self.by_index
+            .retain_mut(|(name, value)| predicate(name, value));
+        if self.by_index.len() != len_before {
+            self.rebuild_index();
+        }
+    }
+
+    /// Reconstructs the `by_name` index from the contents of `by_index`. This must be called any
+    /// time that fields are removed from the middle of `by_index`.
+    fn rebuild_index(&mut self) {
+        self.by_name.clear();
+        for (index, (name, _value)) in self.by_index.iter().enumerate() {
+            self.by_name.entry(name.clone()).or_default().push(index);
+        }
+    }
 }
 
 /// Iterates over the (field name, field value) pairs in a Struct.
@@ -151,7 +217,7 @@ pub struct Struct {
 
 impl Display for Struct {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut ivf = FmtValueFormatter { output: f };
+        let mut ivf = FmtValueFormatter::new(f);
         ivf.format_struct(self).map_err(|_| std::fmt::Error)?;
         Ok(())
     }
@@ -237,6 +303,82 @@ impl Struct {
         self.fields.get_all(field_name)
     }
 
+    /// Returns a mutable reference to the value associated with the specified field name.
+    ///
+    /// As with [`get`](Self::get), if more than one field in this struct has that name, this method
+    /// will return the value of the _last_ field with that name.
+    ///
+    /// ```
+    /// use ion_rs::{ion_struct, Element};
+    /// let mut struct_ = ion_struct! { "foo": 1, "bar": 2 };
+    /// *struct_.get_mut("foo").unwrap() = Element::string("hello");
+    /// assert_eq!(struct_, ion_struct! { "foo": "hello", "bar": 2 });
+    /// ```
+    pub fn get_mut<A: AsSymbolRef>(&mut self, field_name: A) -> Option<&mut Element> {
+        self.fields.get_last_mut(field_name)
+    }
+
+    /// Returns an iterator over the field name/value pairs in this Struct that allows each value
+    /// to be modified. Field names cannot be modified in place; to rename a field, remove it and
+    /// [`insert`](Self::insert) it again.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&Symbol, &mut Element)> {
+        self.fields
+            .by_index
+            .iter_mut()
+            .map(|(name, value)| (&*name, value))
+    }
+
+    /// Adds a field with the specified name and value to the end of this Struct.
+    ///
+    /// Because an Ion struct can contain more than one field with the same name, any existing
+    /// fields named `field_name` are left in place. To replace an existing field's value, use
+    /// [`get_mut`](Self::get_mut) or call [`remove_all`](Self::remove_all) first.
+    ///
+    /// ```
+    /// use ion_rs::ion_struct;
+    /// let mut struct_ = ion_struct! { "foo": 1 };
+    /// struct_.insert("bar", 2);
+    /// struct_.insert("foo", 3);
+    /// assert_eq!(struct_, ion_struct! { "foo": 1, "bar": 2, "foo": 3 });
+    /// assert_eq!(struct_.get("foo").unwrap().expect_i64().unwrap(), 3);
+    /// ```
+    pub fn insert<S: Into<Symbol>, E: Into<Element>>(&mut self, field_name: S, value: E) {
+        self.fields.push(field_name.into(), value.into());
+    }
+
+    /// Removes the field with the specified name and returns its value. If more than one field
+    /// has that name, the _last_ one is removed, mirroring the behavior of [`get`](Self::get).
+    pub fn remove<A: AsSymbolRef>(&mut self, field_name: A) -> Option<Element> {
+        self.fields.remove_last(field_name)
+    }
+
+    /// Removes every field with the specified name, returning their values in the order in which
+    /// they appeared. The relative order of the remaining fields is preserved.
+    ///
+    /// ```
+    /// use ion_rs::{ion_struct, Element};
+    /// let mut struct_ = ion_struct! { "foo": 1, "bar": 2, "foo": 3 };
+    /// let removed = struct_.remove_all("foo");
+    /// assert_eq!(removed, vec![Element::int(1), Element::int(3)]);
+    /// assert_eq!(struct_, ion_struct! { "bar": 2 });
+    /// ```
+    pub fn remove_all<A: AsSymbolRef>(&mut self, field_name: A) -> Vec<Element> {
+        self.fields.remove_all(field_name)
+    }
+
+    /// Retains only the fields for which `predicate` returns `true`. The predicate may modify the
+    /// values that it visits. The relative order of the remaining fields is preserved.
+    ///
+    /// ```
+    /// use ion_rs::ion_struct;
+    /// let mut struct_ = ion_struct! { "foo": 1, "bar": "hello", "baz": 3 };
+    /// struct_.retain(|_name, value| value.as_int().is_some());
+    /// assert_eq!(struct_, ion_struct! { "foo": 1, "baz": 3 });
+    /// ```
+    pub fn retain<F: FnMut(&Symbol, &mut Element) -> bool>(&mut self, predicate: F) {
+        self.fields.retain(predicate)
+    }
+
     #[allow(clippy::map_identity)]
     // ^-- This is a temporary workaround for a bug in Clippy that should be fixed in the next release.
     // See: https://github.com/rust-lang/rust-clippy/issues/9280
@@ -339,7 +481,7 @@ fn ion_cmp_field(this: &&(Symbol, Element), that: &&(Symbol, Element)) -> Orderi
 #[cfg(test)]
 mod tests {
     use crate::element::Element;
-    use crate::ion_struct;
+    use crate::{ion_struct, IonResult, Struct, Symbol};
 
     #[test]
     fn for_field_in_struct() {
@@ -353,4 +495,84 @@ mod tests {
         }
         assert_eq!(baz_value, Some(&Element::int(3)));
     }
+
+    /// Confirms that every field can be found via the `by_name` index at the position it
+    /// occupies in `by_index`.
+    fn assert_index_is_consistent(s: &Struct) {
+        let indexed_fields: usize = s.fields.by_name.values().map(|indexes| indexes.len()).sum();
+        assert_eq!(indexed_fields, s.len());
+        for (name, indexes) in &s.fields.by_name {
+            assert!(indexes.windows(2).all(|pair| pair[0] < pair[1]));
+            for index in indexes {
+                assert_eq!(&s.fields.by_index[*index].0, name);
+            }
+        }
+    }
+
+    #[test]
+    fn insert_and_get_mut() {
+        let mut s = ion_struct! { "foo": 1, "bar": 2 };
+        s.insert("foo", 3);
+        s.insert(Symbol::unknown_text(), 4);
+        assert_index_is_consistent(&s);
+        assert_eq!(s.len(), 4);
+        assert_eq!(s.get("foo"), Some(&Element::int(3)));
+        assert_eq!(s.get(Symbol::unknown_text()), Some(&Element::int(4)));
+
+        // `get_mut` modifies the last field with a given name
+        *s.get_mut("foo").unwrap() = Element::string("hello");
+        assert_eq!(
+            s.get_all("foo").collect::<Vec<_>>(),
+            vec![&Element::int(1), &Element::string("hello")]
+        );
+        assert_eq!(s.get_mut("quux"), None);
+
+        for (_name, value) in s.iter_mut() {
+            *value = value.clone().with_annotations(["edited"]);
+        }
+        assert!(s
+            .fields()
+            .all(|(_, value)| value.annotations().contains("edited")));
+        assert_index_is_consistent(&s);
+    }
+
+    #[test]
+    fn remove_fields() -> IonResult<()> {
+        let mut s = ion_struct! { "a": 1, "b": 2, "a": 3, "c": 4, "a": 5 };
+        assert_eq!(s.remove("a"), Some(Element::int(5)));
+        assert_index_is_consistent(&s);
+        assert_eq!(s.get("a"), Some(&Element::int(3)));
+        assert_eq!(s.remove("quux"), None);
+
+        assert_eq!(s.remove_all("a"), vec![Element::int(1), Element::int(3)]);
+        assert_index_is_consistent(&s);
+        assert_eq!(s.get("a"), None);
+        assert!(s.remove_all("a").is_empty());
+        let names: Vec<_> = s.fields().map(|(name, _)| name.text().unwrap()).collect();
+        assert_eq!(names, vec!["b", "c"]);
+
+        // Fields added after removals are indexed correctly
+        s.insert("b", 6);
+        assert_index_is_consistent(&s);
+        assert_eq!(
+            s,
+            Element::read_one("{b: 2, c: 4, b: 6}")?
+                .expect_struct()?
+                .clone()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn retain_fields() {
+        let mut s = ion_struct! { "a": 1, "b": 2, "a": 3, "c": 4 };
+        s.retain(|name, value| {
+            *value = Element::int(value.expect_i64().unwrap() * 10);
+            *name != "a"
+        });
+        assert_index_is_consistent(&s);
+        assert_eq!(s, ion_struct! { "b": 20, "c": 40 });
+        assert_eq!(s.get("a"), None);
+        assert_eq!(s.get("c"), Some(&Element::int(40)));
+    }
 }
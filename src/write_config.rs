@@ -1,25 +1,27 @@
 use std::io;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 use crate::lazy::encoder::value_writer::SequenceWriter;
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::encoder::writer::Writer;
-use crate::lazy::encoder::LazyRawWriter;
+use crate::lazy::encoder::{Encoder, LazyRawWriter};
 use crate::lazy::encoding::{
-    BinaryEncoding_1_0, BinaryEncoding_1_1, Encoding, OutputFromBytes, TextEncoding_1_0,
+    BinaryEncoding_1_0, BinaryEncoding_1_1, JsonEncoding, OutputFromBytes, TextEncoding_1_0,
     TextEncoding_1_1,
 };
-use crate::{IonResult, TextFormat};
+use crate::{IonResult, SharedSymbolTable, TextFormat, TextFormatConfig};
 
 /// Writer configuration to provide format and Ion version details to writer through encoding
 /// This will be used to create a writer without specifying which writer methods to use
 #[derive(Clone, Debug)]
-pub struct WriteConfig<E: Encoding> {
+pub struct WriteConfig<E: Encoder> {
     pub(crate) kind: WriteConfigKind,
+    pub(crate) imports: Vec<SymbolTableImport>,
     phantom_data: PhantomData<E>,
 }
 
-impl<E: Encoding> WriteConfig<E> {
+impl<E: Encoder> WriteConfig<E> {
     pub(crate) fn encode<V: WriteAsIon>(&self, value: V) -> IonResult<E::Output> {
         let bytes = self.encode_to(value, Vec::new())?;
         Ok(E::Output::from_bytes(bytes))
@@ -53,6 +55,15 @@ impl<E: Encoding> WriteConfig<E> {
         writer.close()
     }
 
+    fn add_import(mut self, table: SharedSymbolTable, max_id: Option<usize>) -> Self {
+        let max_id = max_id.unwrap_or(table.symbols().len());
+        self.imports.push(SymbolTableImport {
+            table: Arc::new(table),
+            max_id,
+        });
+        self
+    }
+
     #[cfg(feature = "experimental-reader-writer")]
     pub fn build_writer<W: io::Write>(self, output: W) -> IonResult<Writer<E, W>> {
         Writer::new(self, output)
@@ -80,18 +91,61 @@ impl<E: Encoding> WriteConfig<E> {
 }
 
 impl WriteConfig<TextEncoding_1_0> {
-    pub fn new(text_kind: TextFormat) -> Self {
+    /// Constructs a text writer configuration. `format` can be either a [`TextFormat`] preset
+    /// or a [`TextFormatConfig`] for finer control over the output.
+    pub fn new(format: impl Into<TextFormatConfig>) -> Self {
         Self {
-            kind: WriteConfigKind::Text(TextWriteConfig { text_kind }),
+            kind: WriteConfigKind::Text(TextWriteConfig {
+                format_config: format.into(),
+            }),
+            imports: Vec::new(),
             phantom_data: Default::default(),
         }
     }
+
+    /// Adds `table` to the list of shared symbol tables that the writer imports. The writer
+    /// declares its imports in a symbol table at the head of the stream and encodes any of
+    /// their symbols by symbol ID instead of redefining them. Readers must be able to find
+    /// `table` in their [`Catalog`](crate::Catalog).
+    pub fn with_import(self, table: SharedSymbolTable) -> Self {
+        self.add_import(table, None)
+    }
+
+    /// Like [`with_import`](Self::with_import), but only imports the first `max_id` symbols
+    /// of `table`. If `max_id` is larger than the number of symbols in `table`, the remaining
+    /// symbol IDs are reserved but have unknown text. Constructing the writer fails if its imports
+    /// would grow the symbol table beyond the
+    /// [limit](crate::ReadLimits::DEFAULT_MAX_IMPORTED_SYMBOL_TABLE_SIZE) that readers apply to
+    /// imports by default.
+    pub fn with_import_max_id(self, table: SharedSymbolTable, max_id: usize) -> Self {
+        self.add_import(table, Some(max_id))
+    }
 }
 
 impl WriteConfig<TextEncoding_1_1> {
-    pub fn new(text_kind: TextFormat) -> Self {
+    /// Constructs a text writer configuration. `format` can be either a [`TextFormat`] preset
+    /// or a [`TextFormatConfig`] for finer control over the output.
+    pub fn new(format: impl Into<TextFormatConfig>) -> Self {
+        Self {
+            kind: WriteConfigKind::Text(TextWriteConfig {
+                format_config: format.into(),
+            }),
+            imports: Vec::new(),
+            phantom_data: Default::default(),
+        }
+    }
+}
+
+impl WriteConfig<JsonEncoding> {
+    /// Constructs a JSON writer configuration. `format` can be either a [`TextFormat`] preset
+    /// or a [`TextFormatConfig`] for finer control over the output. JSON does not permit
+    /// trailing commas, so they are always disabled.
+    pub fn new(format: impl Into<TextFormatConfig>) -> Self {
         Self {
-            kind: WriteConfigKind::Text(TextWriteConfig { text_kind }),
+            kind: WriteConfigKind::Text(TextWriteConfig {
+                format_config: format.into().with_trailing_commas(false),
+            }),
+            imports: Vec::new(),
             phantom_data: Default::default(),
         }
     }
@@ -101,15 +155,35 @@ impl WriteConfig<BinaryEncoding_1_0> {
     pub fn new() -> Self {
         Self {
             kind: WriteConfigKind::Binary(BinaryWriteConfig),
+            imports: Vec::new(),
             phantom_data: Default::default(),
         }
     }
+
+    /// Adds `table` to the list of shared symbol tables that the writer imports. The writer
+    /// declares its imports in a symbol table at the head of the stream and encodes any of
+    /// their symbols by symbol ID instead of redefining them. Readers must be able to find
+    /// `table` in their [`Catalog`](crate::Catalog).
+    pub fn with_import(self, table: SharedSymbolTable) -> Self {
+        self.add_import(table, None)
+    }
+
+    /// Like [`with_import`](Self::with_import), but only imports the first `max_id` symbols
+    /// of `table`. If `max_id` is larger than the number of symbols in `table`, the remaining
+    /// symbol IDs are reserved but have unknown text. Constructing the writer fails if its imports
+    /// would grow the symbol table beyond the
+    /// [limit](crate::ReadLimits::DEFAULT_MAX_IMPORTED_SYMBOL_TABLE_SIZE) that readers apply to
+    /// imports by default.
+    pub fn with_import_max_id(self, table: SharedSymbolTable, max_id: usize) -> Self {
+        self.add_import(table, Some(max_id))
+    }
 }
 
 impl WriteConfig<BinaryEncoding_1_1> {
     pub fn new() -> Self {
         Self {
             kind: WriteConfigKind::Binary(BinaryWriteConfig),
+            imports: Vec::new(),
             phantom_data: Default::default(),
         }
     }
@@ -127,6 +201,12 @@ impl Default for WriteConfig<TextEncoding_1_1> {
     }
 }
 
+impl Default for WriteConfig<JsonEncoding> {
+    fn default() -> Self {
+        Self::new(TextFormat::Compact)
+    }
+}
+
 impl Default for WriteConfig<BinaryEncoding_1_0> {
     fn default() -> Self {
         Self::new()
@@ -139,6 +219,14 @@ impl Default for WriteConfig<BinaryEncoding_1_1> {
     }
 }
 
+/// A shared symbol table that the writer imports, along with the number of symbol IDs that the
+/// import occupies.
+#[derive(Clone, Debug)]
+pub(crate) struct SymbolTableImport {
+    pub(crate) table: Arc<SharedSymbolTable>,
+    pub(crate) max_id: usize,
+}
+
 /// Writer configuration type enum for text and binary configuration
 #[derive(Clone, Debug)]
 pub(crate) enum WriteConfigKind {
@@ -146,10 +234,10 @@ pub(crate) enum WriteConfigKind {
     Binary(BinaryWriteConfig),
 }
 
-/// Text writer configuration with text kind to be used to create a writer
+/// Text writer configuration with text formatting options to be used to create a writer
 #[derive(Clone, Debug)]
 pub(crate) struct TextWriteConfig {
-    pub(crate) text_kind: TextFormat,
+    pub(crate) format_config: TextFormatConfig,
 }
 
 /// Binary writer configuration to be used to create a writer
@@ -169,6 +257,12 @@ impl From<TextEncoding_1_1> for WriteConfig<TextEncoding_1_1> {
     }
 }
 
+impl From<JsonEncoding> for WriteConfig<JsonEncoding> {
+    fn from(_encoding: JsonEncoding) -> Self {
+        WriteConfig::<JsonEncoding>::default()
+    }
+}
+
 impl From<BinaryEncoding_1_0> for WriteConfig<BinaryEncoding_1_0> {
     fn from(_encoding: BinaryEncoding_1_0) -> Self {
         WriteConfig::<BinaryEncoding_1_0>::default()
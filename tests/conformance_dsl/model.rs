@@ -203,11 +203,11 @@ impl PartialEq<Element> for ModelValue {
             ModelValue::Bool(val) => other.as_bool() == Some(*val),
             ModelValue::Int(val) => other.as_i64() == Some(*val),
             ModelValue::Float(val) => other.as_float() == Some(*val),
-            ModelValue::Decimal(dec) => other.as_decimal() == Some(*dec),
+            ModelValue::Decimal(dec) => other.as_decimal().as_ref() == Some(dec),
             ModelValue::String(val) => other.as_string() == Some(val),
             ModelValue::Blob(data) => other.as_blob() == Some(data.as_slice()),
             ModelValue::Clob(data) => other.as_clob() == Some(data.as_slice()),
-            ModelValue::Timestamp(ts) => other.as_timestamp() == Some(*ts),
+            ModelValue::Timestamp(ts) => other.as_timestamp().as_ref() == Some(ts),
             _ => unreachable!(), // SAFETY: EQ of Symbols, Lists, Structs, and SExps are handled
                                  // via comparison to LazyValues after moving to using a Reader instead of Element
                                  // API. These should join them but haven't yet.
@@ -1,10 +1,11 @@
 // Copyright Amazon.com, Inc. or its affiliates.
 #![cfg(feature = "experimental-ion-hash")]
 
-use digest::consts::U4096;
-use digest::{FixedOutput, Reset, Update};
-use ion_rs::ion_hash::IonHasher;
-use ion_rs::{AnyEncoding, Element, IonResult, Sequence, Struct};
+use ion_rs::ion_hash::{IonDigest, IonHasher, IonValueHasher};
+use ion_rs::{
+    AnyEncoding, Decoder, Element, ImportResolution, IonResult, MapCatalog, ReadConfig, Sequence,
+    Struct,
+};
 
 use ion_rs::IonError;
 use ion_rs::Reader;
@@ -14,87 +15,26 @@ use std::fs::read;
 use std::iter::FromIterator;
 use thiserror::Error;
 
+/// A "hash function" whose digest is its input, which allows the test cases to check the exact
+/// bytes that Ion Hash produces.
 #[derive(Default, Clone)]
 struct IdentityDigest(Vec<u8>);
 
-impl IdentityDigest {
-    fn output_size() -> usize {
-        4096usize
-    }
-}
-
-impl Update for IdentityDigest {
-    fn update(&mut self, data: impl AsRef<[u8]>) {
-        // When we we get the finalized output of another `IdentityDigest`, it's
-        // going to have a ton of trailing zeros. Sometimes Ion Hash actually
-        // does call update with trailing zeros (e.g. float negative zero). So
-        // we have a problem: when should we use the data verbatim and when
-        // should we strip the trailing zeros that are *only there because of
-        // the fixed output trait*? The hack is to strip trailing zeroes if we
-        // get called with something that looks like it came from the result of
-        // another IdentityDigest. Note that `>=` is used here due to escaping
-        // (which ironically, means the output isn't fixed size).
-        let data = data.as_ref();
-        let actual_update = if data.len() >= IdentityDigest::output_size() {
-            without_trailing_zeros(data)
-        } else {
-            data
-        };
-        self.0.extend(actual_update);
-    }
-}
-
-impl FixedOutput for IdentityDigest {
-    type OutputSize = U4096;
+impl IonDigest for IdentityDigest {
+    type Output = Vec<u8>;
 
-    fn finalize_into(self, out: &mut digest::generic_array::GenericArray<u8, Self::OutputSize>) {
-        for (i, byte) in self.0.iter().enumerate() {
-            out[i] = *byte;
-        }
-    }
-
-    fn finalize_into_reset(
-        &mut self,
-        out: &mut digest::generic_array::GenericArray<u8, Self::OutputSize>,
-    ) {
-        for (i, byte) in self.0.iter().enumerate() {
-            out[i] = *byte;
-        }
-        self.reset();
+    fn update(&mut self, data: &[u8]) {
+        self.0.extend_from_slice(data);
     }
-}
 
-impl Reset for IdentityDigest {
-    fn reset(&mut self) {
-        self.0.clear();
+    fn finalize(self) -> Vec<u8> {
+        self.0
     }
 }
 
-fn without_trailing_zeros(data: &[u8]) -> &[u8] {
-    if data.is_empty() {
-        return data;
-    }
-
-    let index = data
-        .as_ref()
-        .iter()
-        .rposition(|byte| *byte != 0x00)
-        .unwrap();
-
-    &data[0..=index]
-}
-
 const IGNORE_LIST: &[&str] = &[
     // Uses md5 (not identity)
     r#"{Metrics:{'Event.Catchup':[{Value:0,Unit:ms}],'FanoutCache.Time':[{Value:1,Unit:ms}]}}"#,
-    // ion-rust doesn't have full support for unknown symbols yet, so we can't properly read these test cases.
-    // The ion-hash crate, however, does have full support for symbols with unknown text.
-    // See `mod unknown_symbol_text_tests` at the end of this file for equivalent tests.
-    "'$0'",
-    "{'$0':1}",
-    "'$0'::{}",
-    // ion-rust reads ints up to 128 bits
-    "intLength512",
 ];
 
 fn should_ignore(test_name: &str) -> bool {
@@ -120,9 +60,17 @@ fn ion_hash_tests() -> IonHashTestResult<()> {
     test_file("ion-hash-test/ion_hash_tests.ion")
 }
 
+/// Test cases may import shared symbol tables that are not available. Symbols from those tables
+/// are read as symbols with unknown text, which Ion Hash treats like `$0`.
+fn read_config() -> ReadConfig<AnyEncoding> {
+    AnyEncoding
+        .with_catalog(MapCatalog::new())
+        .with_import_resolution(ImportResolution::Lenient)
+}
+
 fn test_file(file_name: &str) -> IonHashTestResult<()> {
     let data = read(file_name).map_err(IonError::from)?;
-    let mut reader = Reader::new(AnyEncoding, data)?;
+    let mut reader = Reader::new(read_config(), data)?;
     let mut elems = Vec::new();
     while let Some(value) = reader.next()? {
         // Similar logic to skip test cases with a name on the skip list also appears in the
@@ -165,7 +113,10 @@ fn test_all(elems: Sequence) -> IonHashTestResult<()> {
         // While we could clone the text case, it's just as simple to just
         // inline the call to `test_case` and avoid the clone.
         match (case.get("ion"), case.get("10n")) {
-            (Some(text), None) => test_case(annotated_test_name, text, expect),
+            (Some(text), None) => {
+                let encoded = text.to_string();
+                test_case(annotated_test_name, text, encoded.as_bytes(), expect)
+            }
             (None, Some(binary)) => {
                 // The sexp contains a binary value without the BVM.
                 let value = seq_to_bytes(binary);
@@ -173,12 +124,9 @@ fn test_all(elems: Sequence) -> IonHashTestResult<()> {
                 let mut bytes = vec![0xE0, 0x01, 0x00, 0xEA];
                 bytes.extend(value);
 
-                let loaded = Element::read_all(&bytes)?;
-                let elem = loaded
-                    .into_iter()
-                    .next()
-                    .expect("10n test case should have a single element (there were none)");
-                test_case(annotated_test_name, &elem, expect)
+                let mut reader = Reader::new(read_config(), bytes.as_slice())?;
+                let elem = Element::try_from(reader.expect_next()?)?;
+                test_case(annotated_test_name, &elem, &bytes, expect)
             }
             _ => {
                 unreachable!("test case structs must have either an `ion` field or  an `10n` field")
@@ -195,12 +143,15 @@ fn test_all(elems: Sequence) -> IonHashTestResult<()> {
 
 /// A single test case. `input` is the input to ion-hash, while `expect` is a
 /// "program" that we use to validate ion-hash computed the right result.
+/// `encoded` is an encoding of `input` which is also hashed lazily (that is:
+/// without materializing an `Element`) to check that both paths agree.
 // There are two generics here due to the difference between text and binary
 // cases. Binary cases need another call to `element_reader` and the calling
 // function might be generic over `IonElement`.
 fn test_case(
     test_case_name: Option<String>,
     input: &Element,
+    encoded: &[u8],
     expect: &Element,
 ) -> IonHashTestResult<()> {
     let test_case_name = match test_case_name {
@@ -221,23 +172,23 @@ fn test_case(
 
     let result = IdentityDigest::hash_element(input)?;
 
-    // Ignore trailing empty bytes caused by the identity digest producing a
-    // variable sized result. Without this, any test failure will write lots of
-    // stuff to your console which can be annoying since it takes forever.
-    let expected_string = format!("{:02x?}", without_trailing_zeros(&expected[..]));
-    let actual_string = format!("{:02x?}", without_trailing_zeros(&result[..]));
-
-    if expected_string != actual_string {
-        Err(IonHashTestError::TestFailed {
-            test_case_name,
-            message: Some(format!(
-                "expected: {}\nwas: {}",
-                expected_string, actual_string
-            )),
-        })
-    } else {
-        Ok(())
+    let mut reader = Reader::new(read_config(), encoded)?;
+    let lazy_result = IdentityDigest::hash_value(reader.expect_next()?)?;
+
+    let expected_string = format!("{:02x?}", expected);
+    for (path, result) in [("element", result), ("lazy value", lazy_result)] {
+        let actual_string = format!("{:02x?}", result);
+        if expected_string != actual_string {
+            return Err(IonHashTestError::TestFailed {
+                test_case_name,
+                message: Some(format!(
+                    "expected: {}\n{} was: {}",
+                    expected_string, path, actual_string
+                )),
+            });
+        }
     }
+    Ok(())
 }
 
 fn expected_hash(struct_: &Struct) -> IonResult<Vec<u8>> {
@@ -327,7 +278,7 @@ mod unknown_symbol_text_tests {
         let digest = IdentityDigest::hash_element(&unknown_symbol).unwrap();
 
         let expected_string = format!("{:02x?}", &[0x0b, 0x71, 0x0e]);
-        let actual_string = format!("{:02x?}", without_trailing_zeros(&digest[..]));
+        let actual_string = format!("{:02x?}", digest);
         assert_eq!(expected_string, actual_string)
     }
 
@@ -343,7 +294,7 @@ mod unknown_symbol_text_tests {
             "{:02x?}",
             &[0x0b, 0xd0, 0x0c, 0x0b, 0x71, 0x0c, 0x0e, 0x0c, 0x0b, 0x20, 0x01, 0x0c, 0x0e, 0x0e]
         );
-        let actual_string = format!("{:02x?}", without_trailing_zeros(&digest[..]));
+        let actual_string = format!("{:02x?}", digest);
         assert_eq!(expected_string, actual_string)
     }
 
@@ -357,7 +308,7 @@ mod unknown_symbol_text_tests {
             "{:02x?}",
             &[0x0b, 0xe0, 0x0b, 0x71, 0x0e, 0x0b, 0xd0, 0x0e, 0x0e]
         );
-        let actual_string = format!("{:02x?}", without_trailing_zeros(&digest[..]));
+        let actual_string = format!("{:02x?}", digest);
         assert_eq!(expected_string, actual_string)
     }
 }